@@ -275,8 +275,8 @@ pub fn rename(old_name: &str, new_name: &str, uplift: bool) {
         } else if !lint.module.contains("::")
             // Catch cases like `methods/lint_name.rs` where the lint is stored in `methods/mod.rs`
             && try_rename_file(
-                Path::new(&format!("clippy_lints/src/{}/{old_name}.rs", lint.module)),
-                Path::new(&format!("clippy_lints/src/{}/{new_name}.rs", lint.module)),
+                &Path::new("clippy_lints/src").join(&lint.module).join(format!("{old_name}.rs")),
+                &Path::new("clippy_lints/src").join(&lint.module).join(format!("{new_name}.rs")),
             )
         {
             // Edit the module name in the lint list. Note there could be multiple lints, or none.
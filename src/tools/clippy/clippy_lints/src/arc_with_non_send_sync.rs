@@ -51,10 +51,10 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
             if !cx.tcx
                 .lang_items()
                 .sync_trait()
-                .map_or(false, |id| implements_trait(cx, arg_ty, id, &[])) ||
+                .is_some_and(|id| implements_trait(cx, arg_ty, id, &[])) ||
                 !cx.tcx
                 .get_diagnostic_item(sym::Send)
-                .map_or(false, |id| implements_trait(cx, arg_ty, id, &[]));
+                .is_some_and(|id| implements_trait(cx, arg_ty, id, &[]));
 
             then {
                 span_lint_and_help(
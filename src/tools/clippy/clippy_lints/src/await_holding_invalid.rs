@@ -4,7 +4,7 @@
 use rustc_hir::def_id::DefId;
 use rustc_hir::{AsyncGeneratorKind, Body, BodyId, GeneratorKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty::GeneratorInteriorTypeCause;
+use rustc_middle::ty::{self, GeneratorInteriorTypeCause, Ty};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{sym, Span};
 
@@ -17,12 +17,17 @@
     /// ### Why is this bad?
     /// The Mutex types found in std::sync and parking_lot
     /// are not designed to operate in an async context across await points.
+    /// Other guard types can be added with the `await-holding-invalid-types`
+    /// configuration, they're reported by `await_holding_invalid_type`.
     ///
     /// There are two potential solutions. One is to use an async-aware Mutex
     /// type. Many asynchronous foundation crates provide such a Mutex type. The
     /// other solution is to ensure the mutex is unlocked before calling await,
     /// either by introducing a scope or an explicit call to Drop::drop.
     ///
+    /// Guards returned as an `impl Trait` by functions of the current crate
+    /// are checked as well.
+    ///
     /// ### Known problems
     /// Will report false positive for explicitly dropped guards
     /// ([#6446](https://github.com/rust-lang/rust-clippy/issues/6446)). A workaround for this is
@@ -140,6 +145,9 @@
     /// from a memory access perspective but will cause bugs at runtime if they
     /// are held in such a way.
     ///
+    /// Types returned as an `impl Trait` by functions of the current crate
+    /// are checked as well.
+    ///
     /// ### Example
     ///
     /// ```toml
@@ -213,7 +221,7 @@ fn check_body(&mut self, cx: &LateContext<'_>, body: &'_ Body<'_>) {
 impl AwaitHolding {
     fn check_interior_types(&self, cx: &LateContext<'_>, ty_causes: &[GeneratorInteriorTypeCause<'_>], span: Span) {
         for ty_cause in ty_causes {
-            if let rustc_middle::ty::Adt(adt, _) = ty_cause.ty.kind() {
+            if let ty::Adt(adt, _) = reveal_local_opaque(cx, ty_cause.ty).kind() {
                 if is_mutex_guard(cx, adt.did()) {
                     span_lint_and_then(
                         cx,
@@ -270,6 +278,17 @@ fn emit_invalid_type(cx: &LateContext<'_>, span: Span, disallowed: &DisallowedPa
     );
 }
 
+/// Gets the hidden type of an `impl Trait` returned by a function of the current crate, so guards
+/// returned by helper functions are found.
+fn reveal_local_opaque<'tcx>(cx: &LateContext<'tcx>, mut ty: Ty<'tcx>) -> Ty<'tcx> {
+    while let ty::Alias(ty::Opaque, ty::AliasTy { def_id, substs, .. }) = *ty.kind()
+        && def_id.is_local()
+    {
+        ty = cx.tcx.type_of(def_id).subst(cx.tcx, substs);
+    }
+    ty
+}
+
 fn is_mutex_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
     cx.tcx.is_diagnostic_item(sym::MutexGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockReadGuard, def_id)
@@ -0,0 +1,122 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::visitors::AsyncBodies;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to functions which block the current thread until a future completes,
+    /// such as `futures::executor::block_on` or `tokio::runtime::Runtime::block_on`, inside
+    /// an `async fn` or an `async` block.
+    ///
+    /// More functions can be added with the `blocking-executor-functions` configuration.
+    ///
+    /// ### Why is this bad?
+    /// The async code is already run by an executor. Blocking its thread stops every other task
+    /// scheduled on it, which can deadlock when the blocked on future depends on one of them.
+    /// Some runtimes also panic when they're entered from one of their own tasks.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// async fn fetch() -> u32 {
+    ///     futures::executor::block_on(load())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn fetch() -> u32 {
+    ///     load().await
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub BLOCK_ON_IN_ASYNC,
+    suspicious,
+    "blocking on a future inside async code"
+}
+
+impl_lint_pass!(BlockOnInAsync => [BLOCK_ON_IN_ASYNC]);
+
+/// The functions blocking on a future of the common executors.
+const BLOCKING_FUNCTIONS: [&str; 6] = [
+    "async_std::task::block_on",
+    "futures::executor::block_on",
+    "pollster::block_on",
+    "smol::block_on",
+    "tokio::runtime::Handle::block_on",
+    "tokio::runtime::Runtime::block_on",
+];
+
+pub struct BlockOnInAsync {
+    conf_functions: Vec<String>,
+    def_ids: FxHashSet<DefId>,
+    async_bodies: AsyncBodies,
+}
+
+impl BlockOnInAsync {
+    pub fn new(conf_functions: Vec<String>) -> Self {
+        Self {
+            conf_functions,
+            def_ids: FxHashSet::default(),
+            async_bodies: AsyncBodies::default(),
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BlockOnInAsync {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in BLOCKING_FUNCTIONS
+            .iter()
+            .copied()
+            .chain(self.conf_functions.iter().map(String::as_str))
+        {
+            let segs: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_body(&mut self, _: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        self.async_bodies.enter_body(body);
+    }
+
+    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &'tcx Body<'_>) {
+        self.async_bodies.exit_body();
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if self.async_bodies.in_async_body()
+            && let ExprKind::Call(_, args) | ExprKind::MethodCall(_, _, args, _) = expr.kind
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && self.def_ids.contains(&def_id)
+            && !in_external_macro(cx.sess(), expr.span)
+        {
+            span_lint_and_then(
+                cx,
+                BLOCK_ON_IN_ASYNC,
+                expr.span,
+                &format!("blocking call to `{}` inside async code", cx.tcx.item_name(def_id)),
+                |diag| {
+                    diag.note(AsyncBodies::BLOCKS_EXECUTOR_NOTE);
+                    if let [future] = args
+                        && !expr.span.from_expansion()
+                        && let Some(future_trait) = cx.tcx.lang_items().future_trait()
+                        && implements_trait(cx, cx.typeck_results().expr_ty(future), future_trait, &[])
+                    {
+                        let mut app = Applicability::MaybeIncorrect;
+                        let future = Sugg::hir_with_applicability(cx, future, "..", &mut app).maybe_par();
+                        diag.span_suggestion(expr.span, "await the future instead", format!("{future}.await"), app);
+                    } else {
+                        diag.help("move the blocking call out of the async code, e.g. into `spawn_blocking`");
+                    }
+                },
+            );
+        }
+    }
+}
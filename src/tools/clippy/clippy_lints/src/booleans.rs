@@ -1,6 +1,7 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::eq_expr_value;
 use clippy_utils::source::snippet_opt;
+use clippy_utils::sugg::{make_unop, Sugg};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
@@ -12,6 +13,7 @@
 use rustc_span::def_id::LocalDefId;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
+use std::fmt::Write as _;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -204,9 +206,8 @@ fn recurse(&mut self, suggestion: &Bool) -> Option<()> {
                     if let Some(str) = simplify_not(self.cx, terminal) {
                         self.output.push_str(&str);
                     } else {
-                        self.output.push('!');
-                        let snip = snippet_opt(self.cx, terminal.span)?;
-                        self.output.push_str(&snip);
+                        let sugg = make_unop("!", Sugg::hir_opt(self.cx, terminal)?);
+                        let _ = write!(self.output, "{sugg}");
                     }
                 },
                 True | False | Not(_) => {
@@ -491,7 +492,7 @@ fn implements_ord(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     let ty = cx.typeck_results().expr_ty(expr);
     cx.tcx
         .get_diagnostic_item(sym::Ord)
-        .map_or(false, |id| implements_trait(cx, ty, id, &[]))
+        .is_some_and(|id| implements_trait(cx, ty, id, &[]))
 }
 
 struct NotSimplificationVisitor<'a, 'tcx> {
@@ -48,7 +48,7 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
             && !in_external_macro(cx.sess(), expr.span)
             && (expr.span.eq_ctxt(arg.span) || is_vec_expn(cx, arg))
             && seg.ident.name == sym::new
-            && path_def_id(cx, ty).map_or(false, |id| Some(id) == cx.tcx.lang_items().owned_box())
+            && path_def_id(cx, ty).is_some_and(|id| Some(id) == cx.tcx.lang_items().owned_box())
             && is_default_equivalent(cx, arg)
         {
             span_lint_and_sugg(
@@ -83,7 +83,7 @@ fn is_plain_default(arg_path: &Expr<'_>) -> bool {
 fn is_vec_expn(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     macro_backtrace(expr.span)
         .next()
-        .map_or(false, |call| cx.tcx.is_diagnostic_item(sym::vec_macro, call.def_id))
+        .is_some_and(|call| cx.tcx.is_diagnostic_item(sym::vec_macro, call.def_id))
 }
 
 #[derive(Default)]
@@ -1,9 +1,8 @@
 use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::is_c_void;
+use clippy_utils::ty::{is_c_void, ty_align, ty_size};
 use clippy_utils::{get_parent_expr, is_hir_ty_cfg_dependant, match_any_def_paths, paths};
 use rustc_hir::{Expr, ExprKind, GenericArg};
 use rustc_lint::LateContext;
-use rustc_middle::ty::layout::LayoutOf;
 use rustc_middle::ty::{self, Ty};
 
 use super::CAST_PTR_ALIGNMENT;
@@ -35,13 +34,13 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
 fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_from: Ty<'tcx>, cast_to: Ty<'tcx>) {
     if let ty::RawPtr(from_ptr_ty) = &cast_from.kind()
         && let ty::RawPtr(to_ptr_ty) = &cast_to.kind()
-        && let Ok(from_layout) = cx.layout_of(from_ptr_ty.ty)
-        && let Ok(to_layout) = cx.layout_of(to_ptr_ty.ty)
-        && from_layout.align.abi < to_layout.align.abi
+        && let Some(from_align) = ty_align(cx, cx.param_env, from_ptr_ty.ty)
+        && let Some(to_align) = ty_align(cx, cx.param_env, to_ptr_ty.ty)
+        && from_align < to_align
         // with c_void, we inherently need to trust the user
         && !is_c_void(cx, from_ptr_ty.ty)
         // when casting from a ZST, we don't know enough to properly lint
-        && !from_layout.is_zst()
+        && !(from_ptr_ty.ty.is_sized(cx.tcx, cx.param_env) && ty_size(cx, cx.param_env, from_ptr_ty.ty) == Some(0))
         && !is_used_as_unaligned(cx, expr)
     {
         span_lint(
@@ -49,9 +48,7 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
             CAST_PTR_ALIGNMENT,
             expr.span,
             &format!(
-                "casting from `{cast_from}` to a more-strictly-aligned pointer (`{cast_to}`) ({} < {} bytes)",
-                from_layout.align.abi.bytes(),
-                to_layout.align.abi.bytes(),
+                "casting from `{cast_from}` to a more-strictly-aligned pointer (`{cast_to}`) ({from_align} < {to_align} bytes)"
             ),
         );
     }
@@ -1,6 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::numeric_literal::NumericLiteral;
 use clippy_utils::source::snippet_opt;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::visitors::{for_each_expr, Visitable};
 use clippy_utils::{get_parent_expr, get_parent_node, is_hir_ty_cfg_dependant, is_ty_alias, path_to_local};
 use if_chain::if_chain;
@@ -158,10 +159,10 @@ pub(super) fn check<'tcx>(
             expr.span,
             &format!("casting to the same type is unnecessary (`{cast_from}` -> `{cast_to}`)"),
             "try",
-            if get_parent_expr(cx, expr).map_or(false, |e| matches!(e.kind, ExprKind::AddrOf(..))) {
+            if get_parent_expr(cx, expr).is_some_and(|e| matches!(e.kind, ExprKind::AddrOf(..))) {
                 format!("{{ {cast_str} }}")
             } else {
-                cast_str
+                Sugg::hir(cx, cast_expr, "..").maybe_par_for(cx, expr).to_string()
             },
             Applicability::MachineApplicable,
         );
@@ -71,7 +71,7 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
 /// Returns true if the given item is a union with at least two non-ZST fields.
 fn is_union_with_two_non_zst_fields(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
     if let ItemKind::Union(data, _) = &item.kind {
-        data.fields().iter().filter(|f| !is_zst(cx, f.ty)).count() >= 2
+        data.fields().iter().filter(|f| !is_zst(cx, f.ty)).nth(1).is_some()
     } else {
         false
     }
@@ -567,9 +567,11 @@ fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
     }
 
     fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
-        if self.possible_borrowers.last().map_or(false, |&(local_def_id, _)| {
-            local_def_id == cx.tcx.hir().body_owner_def_id(body.id())
-        }) {
+        if self
+            .possible_borrowers
+            .last()
+            .is_some_and(|&(local_def_id, _)| local_def_id == cx.tcx.hir().body_owner_def_id(body.id()))
+        {
             self.possible_borrowers.pop();
         }
 
@@ -962,7 +964,7 @@ fn is_union<'tcx>(typeck: &'tcx TypeckResults<'_>, path_expr: &'tcx Expr<'_>) ->
     typeck
         .expr_ty_adjusted(path_expr)
         .ty_adt_def()
-        .map_or(false, rustc_middle::ty::AdtDef::is_union)
+        .is_some_and(rustc_middle::ty::AdtDef::is_union)
 }
 
 fn closure_result_position<'tcx>(
@@ -1094,7 +1096,7 @@ fn visit_generic_arg(&mut self, arg: &GenericArg<'_>) {
 fn call_is_qualified(expr: &Expr<'_>) -> bool {
     if let ExprKind::Path(path) = &expr.kind {
         match path {
-            QPath::Resolved(_, path) => path.segments.last().map_or(false, |segment| segment.args.is_some()),
+            QPath::Resolved(_, path) => path.segments.last().is_some_and(|segment| segment.args.is_some()),
             QPath::TypeRelative(_, segment) => segment.args.is_some(),
             QPath::LangItem(..) => false,
         }
@@ -0,0 +1,128 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Local, Pat, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to functions spawning an async task, such as `tokio::spawn` or
+    /// `async_std::task::spawn`, whose `JoinHandle` is dropped right away, either by calling them
+    /// as a statement or by binding the result to `_`.
+    ///
+    /// More functions can be added with the `task-spawn-functions` configuration.
+    ///
+    /// ### Why is this bad?
+    /// Dropping the handle detaches the task: it keeps running in the background, nothing waits
+    /// for it to finish, and its result and panics are lost. This is sometimes intended, but it
+    /// should be visible in the code.
+    ///
+    /// Passing the handle to `drop` documents that the task is detached on purpose, and isn't
+    /// linted.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// tokio::spawn(flush(cache));
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let flush = tokio::spawn(flush(cache));
+    /// // ..
+    /// flush.await?;
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DETACHED_SPAWN,
+    restriction,
+    "dropping the `JoinHandle` of a spawned task"
+}
+
+impl_lint_pass!(DetachedSpawn => [DETACHED_SPAWN]);
+
+/// The functions spawning a task of the common executors.
+const SPAWN_FUNCTIONS: [&str; 8] = [
+    "async_std::task::spawn",
+    "async_std::task::spawn_blocking",
+    "async_std::task::spawn_local",
+    "tokio::runtime::Handle::spawn",
+    "tokio::runtime::Runtime::spawn",
+    "tokio::task::spawn",
+    "tokio::task::spawn_blocking",
+    "tokio::task::spawn_local",
+];
+
+pub struct DetachedSpawn {
+    conf_functions: Vec<String>,
+    def_ids: FxHashSet<DefId>,
+}
+
+impl DetachedSpawn {
+    pub fn new(conf_functions: Vec<String>) -> Self {
+        Self {
+            conf_functions,
+            def_ids: FxHashSet::default(),
+        }
+    }
+
+    /// Gets the spawning function called by `e`, if it returns a handle.
+    fn spawn_call(&self, cx: &LateContext<'_>, e: &Expr<'_>) -> Option<DefId> {
+        if let ExprKind::Call(..) | ExprKind::MethodCall(..) = e.kind
+            && let Some(def_id) = fn_def_id(cx, e)
+            && self.def_ids.contains(&def_id)
+            && !cx.typeck_results().expr_ty(e).is_unit()
+        {
+            Some(def_id)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for DetachedSpawn {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in SPAWN_FUNCTIONS
+            .iter()
+            .copied()
+            .chain(self.conf_functions.iter().map(String::as_str))
+        {
+            let segs: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        let call = match stmt.kind {
+            StmtKind::Semi(e) => e,
+            StmtKind::Local(Local {
+                pat: Pat {
+                    kind: PatKind::Wild, ..
+                },
+                init: Some(init),
+                ..
+            }) => init,
+            _ => return,
+        };
+        if let Some(def_id) = self.spawn_call(cx, call)
+            && !in_external_macro(cx.sess(), stmt.span)
+        {
+            span_lint_and_then(
+                cx,
+                DETACHED_SPAWN,
+                stmt.span,
+                &format!(
+                    "the handle of the task spawned by `{}` is dropped immediately",
+                    cx.tcx.item_name(def_id)
+                ),
+                |diag| {
+                    diag.note("dropping the handle detaches the task, so its result and panics are lost");
+                    diag.help(
+                        "store the handle to await or abort the task, or pass it to `drop` to document that \
+                        the task is detached",
+                    );
+                },
+            );
+        }
+    }
+}
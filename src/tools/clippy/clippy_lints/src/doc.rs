@@ -836,8 +836,8 @@ fn is_camel_case(s: &str) -> bool {
         let s = s.strip_suffix('s').unwrap_or(s);
 
         s.chars().all(char::is_alphanumeric)
-            && s.chars().filter(|&c| c.is_uppercase()).take(2).count() > 1
-            && s.chars().filter(|&c| c.is_lowercase()).take(1).count() > 0
+            && s.chars().filter(|&c| c.is_uppercase()).nth(1).is_some()
+            && s.chars().any(char::is_lowercase)
     }
 
     fn has_underscore(s: &str) -> bool {
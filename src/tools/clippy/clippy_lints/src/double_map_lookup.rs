@@ -0,0 +1,259 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{higher, path_to_local, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_hir::hir_id::HirIdSet;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a key looked up in a `HashMap` or `BTreeMap` which is then looked up again:
+    /// with `get`, `get_mut` or indexing after checking it with `contains_key`, or with `insert`
+    /// in a later statement of the same block after any lookup.
+    ///
+    /// ### Why is this bad?
+    /// Each lookup hashes or compares the key again. `get` and `get_mut` check if the key is
+    /// present and return the value at once, and the entry API allows to look a value up and
+    /// then insert one in its place.
+    ///
+    /// ### Known problems
+    /// Inserting with `entry` needs the key to be owned even if the value is already present.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # let mut map: HashMap<String, u32> = HashMap::new();
+    /// # let key = String::new();
+    /// if map.contains_key(&key) {
+    ///     println!("{}", map[&key]);
+    /// }
+    /// let count = map.get(&key).copied().unwrap_or(0);
+    /// map.insert(key, count + 1);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # let mut map: HashMap<String, u32> = HashMap::new();
+    /// # let key = String::new();
+    /// if let Some(value) = map.get(&key) {
+    ///     println!("{value}");
+    /// }
+    /// *map.entry(key).or_insert(0) += 1;
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DOUBLE_MAP_LOOKUP,
+    pedantic,
+    "looking up the same key in a `HashMap` or `BTreeMap` twice"
+}
+declare_lint_pass!(DoubleMapLookup => [DOUBLE_MAP_LOOKUP]);
+
+impl<'tcx> LateLintPass<'tcx> for DoubleMapLookup {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // `if map.contains_key(&k) { map.get(&k) }`
+        if let Some(higher::If { cond, then, .. }) = higher::If::hir(expr)
+            && !expr.span.from_expansion()
+            && let Some(contains) = parse_lookup(cx, cond)
+            && contains.kind == LookupKind::ContainsKey
+        {
+            let mut lookups = Vec::new();
+            let mut inserts = false;
+            let _: Option<()> = for_each_expr(then, |e| {
+                if let Some(lookup) = parse_lookup(cx, e)
+                    && contains.has_same_entry(cx, &lookup)
+                {
+                    inserts |= lookup.kind == LookupKind::Insert;
+                    lookups.push(lookup);
+                }
+                ControlFlow::Continue(())
+            });
+            // an insertion is linted by `map_entry`, and `get_mut` borrows the map mutably
+            if inserts || lookups.is_empty() || is_mutated(cx, [contains.key], [then]) {
+                return;
+            }
+
+            let method = if lookups.iter().any(|lookup| lookup.kind == LookupKind::GetMut) {
+                "get_mut"
+            } else {
+                "get"
+            };
+            let map = snippet(cx, contains.map.span, "..");
+            let key = snippet(cx, contains.key_arg.span, "..");
+            span_lint_and_then(
+                cx,
+                DOUBLE_MAP_LOOKUP,
+                lookups[0].span,
+                &format!("`{map}` is looked up again after checking that it contains the key"),
+                |diag| {
+                    diag.span_note(contains.span, "the key is first looked up here");
+                    diag.help(format!(
+                        "use `if let Some(value) = {map}.{method}({key})` to check and get the value at once"
+                    ));
+                },
+            );
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        if block.span.from_expansion() {
+            return;
+        }
+        let exprs: Vec<_> = block
+            .stmts
+            .iter()
+            .filter_map(|stmt| match stmt.kind {
+                StmtKind::Local(local) => local.init,
+                StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                StmtKind::Item(_) => None,
+            })
+            .chain(block.expr)
+            .collect();
+
+        for (i, &e) in exprs.iter().enumerate().skip(1) {
+            let lookups: Vec<_> = find_lookups(cx, e).collect();
+            for insert in lookups.iter().filter(|lookup| lookup.kind == LookupKind::Insert) {
+                // lookups in the same statement are linted by `map_entry` or in `check_expr`
+                if lookups
+                    .iter()
+                    .any(|lookup| lookup.kind != LookupKind::Insert && lookup.has_same_entry(cx, insert))
+                {
+                    continue;
+                }
+                // the closest statement looking the key up
+                let Some((j, lookup)) = exprs[..i].iter().enumerate().rev().find_map(|(j, &prev)| {
+                    find_lookups(cx, prev)
+                        .find(|lookup| lookup.kind != LookupKind::Insert && lookup.has_same_entry(cx, insert))
+                        .map(|lookup| (j, lookup))
+                }) else {
+                    continue;
+                };
+                if is_mutated(cx, [insert.map, insert.key], exprs[j + 1..i].iter().copied()) {
+                    continue;
+                }
+
+                let map = snippet(cx, insert.map.span, "..");
+                let key = snippet(cx, insert.key_arg.span, "..");
+                span_lint_and_then(
+                    cx,
+                    DOUBLE_MAP_LOOKUP,
+                    insert.span,
+                    &format!("`{map}` is looked up again to insert into it"),
+                    |diag| {
+                        diag.span_note(lookup.span, "the key is first looked up here");
+                        diag.help(format!("use `{map}.entry({key})` to look the key up once"));
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LookupKind {
+    ContainsKey,
+    Get,
+    GetMut,
+    Index,
+    Insert,
+}
+
+struct Lookup<'tcx> {
+    span: Span,
+    kind: LookupKind,
+    map: &'tcx Expr<'tcx>,
+    /// The key as it's passed to the lookup.
+    key_arg: &'tcx Expr<'tcx>,
+    /// The key without borrows and conversions to an owned value.
+    key: &'tcx Expr<'tcx>,
+}
+
+impl<'tcx> Lookup<'tcx> {
+    fn has_same_entry(&self, cx: &LateContext<'tcx>, other: &Self) -> bool {
+        let mut eq = SpanlessEq::new(cx).deny_side_effects();
+        eq.eq_expr(self.map, other.map) && eq.eq_expr(self.key, other.key)
+    }
+}
+
+/// Parses `map.contains_key(k)`, `map.get(k)`, `map.get_mut(k)`, `map[k]` and
+/// `map.insert(k, v)` on a `HashMap` or `BTreeMap`.
+fn parse_lookup<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<Lookup<'tcx>> {
+    let (kind, map, key_arg) = match e.kind {
+        ExprKind::MethodCall(path, map, [key], _) => {
+            let kind = match path.ident.as_str() {
+                "contains_key" => LookupKind::ContainsKey,
+                "get" => LookupKind::Get,
+                "get_mut" => LookupKind::GetMut,
+                _ => return None,
+            };
+            (kind, map, key)
+        },
+        ExprKind::MethodCall(path, map, [key, _], _) if path.ident.as_str() == "insert" => {
+            (LookupKind::Insert, map, key)
+        },
+        ExprKind::Index(map, key) => (LookupKind::Index, map, key),
+        _ => return None,
+    };
+    let map_ty = cx.typeck_results().expr_ty(map).peel_refs();
+    if e.span.from_expansion()
+        || !(is_type_diagnostic_item(cx, map_ty, sym::HashMap) || is_type_diagnostic_item(cx, map_ty, sym::BTreeMap))
+    {
+        return None;
+    }
+
+    let mut key = key_arg;
+    loop {
+        match key.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, _, inner) => key = inner,
+            ExprKind::MethodCall(path, inner, [], _)
+                if matches!(path.ident.as_str(), "clone" | "to_owned" | "to_string" | "into") =>
+            {
+                key = inner;
+            },
+            _ => break,
+        }
+    }
+    Some(Lookup {
+        span: e.span,
+        kind,
+        map,
+        key_arg,
+        key,
+    })
+}
+
+/// Finds the lookups in `e`, outside of closures.
+fn find_lookups<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> impl Iterator<Item = Lookup<'tcx>> {
+    let mut lookups = Vec::new();
+    let _: Option<()> = for_each_expr(e, |e| {
+        if let Some(lookup) = parse_lookup(cx, e) {
+            lookups.push(lookup);
+        }
+        ControlFlow::Continue(())
+    });
+    lookups.into_iter()
+}
+
+/// Checks if any of the locals used in `used` may be mutated in `exprs`.
+fn is_mutated<'tcx>(
+    cx: &LateContext<'tcx>,
+    used: impl IntoIterator<Item = &'tcx Expr<'tcx>>,
+    exprs: impl IntoIterator<Item = &'tcx Expr<'tcx>>,
+) -> bool {
+    let mut locals = HirIdSet::default();
+    for e in used {
+        let _: Option<()> = for_each_expr(e, |e| {
+            if let Some(id) = path_to_local(e) {
+                locals.insert(id);
+            }
+            ControlFlow::Continue(())
+        });
+    }
+    exprs
+        .into_iter()
+        .any(|e| mutated_variables(e, cx).map_or(true, |mutated| mutated.iter().any(|id| locals.contains(id))))
+}
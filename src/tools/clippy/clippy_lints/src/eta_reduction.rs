@@ -138,7 +138,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                                    fn_mut_id,
                                    &args.iter().copied().map(Into::into).collect::<Vec<_>>(),
                                )
-                            && path_to_local(callee).map_or(false, |l| local_used_after_expr(cx, l, expr))
+                            && path_to_local(callee).is_some_and(|l| local_used_after_expr(cx, l, expr))
                         {
                                 // Mutable closure is used after current expr; we cannot consume it.
                                 snippet = format!("&mut {snippet}");
@@ -2,10 +2,11 @@
 use clippy_utils::macros::{is_panic, root_macro_call_first_node};
 use clippy_utils::method_chain_args;
 use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
 use if_chain::if_chain;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::{sym, Span};
 
@@ -65,55 +66,39 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
 }
 
 fn lint_impl_body(cx: &LateContext<'_>, impl_span: Span, impl_items: &[hir::ImplItemRef]) {
-    use rustc_hir::intravisit::{self, Visitor};
-    use rustc_hir::{Expr, ImplItemKind};
-
-    struct FindPanicUnwrap<'a, 'tcx> {
-        lcx: &'a LateContext<'tcx>,
-        typeck_results: &'tcx ty::TypeckResults<'tcx>,
-        result: Vec<Span>,
-    }
-
-    impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
-        fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
-            if let Some(macro_call) = root_macro_call_first_node(self.lcx, expr) {
-                if is_panic(self.lcx, macro_call.def_id) {
-                    self.result.push(expr.span);
-                }
-            }
-
-            // check for `unwrap`
-            if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-                let receiver_ty = self.typeck_results.expr_ty(arglists[0].0).peel_refs();
-                if is_type_diagnostic_item(self.lcx, receiver_ty, sym::Option)
-                    || is_type_diagnostic_item(self.lcx, receiver_ty, sym::Result)
-                {
-                    self.result.push(expr.span);
-                }
-            }
-
-            // and check sub-expressions
-            intravisit::walk_expr(self, expr);
-        }
-    }
-
     for impl_item in impl_items {
         if_chain! {
             if impl_item.ident.name == sym::from;
-            if let ImplItemKind::Fn(_, body_id) =
+            if let hir::ImplItemKind::Fn(_, body_id) =
                 cx.tcx.hir().impl_item(impl_item.id).kind;
             then {
                 // check the body for `begin_panic` or `unwrap`
                 let body = cx.tcx.hir().body(body_id);
-                let mut fpu = FindPanicUnwrap {
-                    lcx: cx,
-                    typeck_results: cx.tcx.typeck(impl_item.id.owner_id.def_id),
-                    result: Vec::new(),
-                };
-                fpu.visit_expr(body.value);
+                let typeck_results = cx.tcx.typeck(impl_item.id.owner_id.def_id);
+                let mut result = Vec::new();
+                for_each_expr(body.value, |expr| {
+                    if let Some(macro_call) = root_macro_call_first_node(cx, expr) {
+                        if is_panic(cx, macro_call.def_id) {
+                            result.push(expr.span);
+                        }
+                    }
+
+                    // check for `unwrap`
+                    if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
+                        let receiver_ty = typeck_results.expr_ty(arglists[0].0).peel_refs();
+                        if is_type_diagnostic_item(cx, receiver_ty, sym::Option)
+                            || is_type_diagnostic_item(cx, receiver_ty, sym::Result)
+                        {
+                            result.push(expr.span);
+                        }
+                    }
+
+                    // and check sub-expressions
+                    ControlFlow::<!>::Continue(())
+                });
 
                 // if we've found one, lint
-                if !fpu.result.is_empty() {
+                if !result.is_empty() {
                     span_lint_and_then(
                         cx,
                         FALLIBLE_IMPL_FROM,
@@ -123,7 +108,7 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
                             diag.help(
                                 "`From` is intended for infallible conversions only. \
                                 Use `TryFrom` if there's a possibility for the conversion to fail");
-                            diag.span_note(fpu.result, "potential failure(s)");
+                            diag.span_note(result, "potential failure(s)");
                         });
                 }
             }
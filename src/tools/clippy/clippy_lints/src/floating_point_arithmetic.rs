@@ -156,6 +156,7 @@ fn prepare_receiver_sugg<'a>(cx: &LateContext<'_>, mut expr: &'a Expr<'a>) -> Su
 
             suggestion = match suggestion {
                 Sugg::MaybeParen(_) => Sugg::MaybeParen(op),
+                Sugg::Prefix(_) => Sugg::Prefix(op),
                 _ => Sugg::NonParen(op)
             };
         }
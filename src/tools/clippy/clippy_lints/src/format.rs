@@ -57,6 +57,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ([], [_]) => {
                     // Simulate macro expansion, converting {{ and }} to { and }.
                     let Some(snippet) = snippet_opt(cx, format_args.span) else { return };
+                    #[allow(clippy::chained_str_replace)]
                     let s_expand = snippet.replace("{{", "{").replace("}}", "}");
                     let sugg = format!("{s_expand}.to_string()");
                     span_useless_format(cx, call_site, sugg, applicability);
@@ -55,12 +55,13 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for [`ToString::to_string`](https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string)
+    /// or [`ToOwned::to_owned`](https://doc.rust-lang.org/std/borrow/trait.ToOwned.html#tymethod.to_owned)
     /// applied to a type that implements [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
     /// in a macro that does formatting.
     ///
     /// ### Why is this bad?
-    /// Since the type implements `Display`, the use of `to_string` is
-    /// unnecessary.
+    /// Since the type implements `Display`, the use of `to_string` or `to_owned` is
+    /// unnecessary, and allocates an intermediate value.
     ///
     /// ### Example
     /// ```rust
@@ -75,7 +76,7 @@
     #[clippy::version = "1.58.0"]
     pub TO_STRING_IN_FORMAT_ARGS,
     perf,
-    "`to_string` applied to a type that implements `Display` in format args"
+    "`to_string` or `to_owned` applied to a type that implements `Display` in format args"
 }
 
 declare_clippy_lint! {
@@ -412,7 +413,13 @@ fn check_to_string_in_format_args(cx: &LateContext<'_>, name: Symbol, value: &Ex
         if !value.span.from_expansion();
         if let ExprKind::MethodCall(_, receiver, [], to_string_span) = value.kind;
         if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(value.hir_id);
-        if is_diag_trait_item(cx, method_def_id, sym::ToString);
+        if let Some(method_name) = if is_diag_trait_item(cx, method_def_id, sym::ToString) {
+            Some("to_string")
+        } else if is_diag_trait_item(cx, method_def_id, sym::ToOwned) {
+            Some("to_owned")
+        } else {
+            None
+        };
         let receiver_ty = cx.typeck_results().expr_ty(receiver);
         if let Some(display_trait_id) = cx.tcx.get_diagnostic_item(sym::Display);
         let (n_needed_derefs, target) =
@@ -428,7 +435,7 @@ fn check_to_string_in_format_args(cx: &LateContext<'_>, name: Symbol, value: &Ex
                     TO_STRING_IN_FORMAT_ARGS,
                     to_string_span.with_lo(receiver.span.hi()),
                     &format!(
-                        "`to_string` applied to a type that implements `Display` in `{name}!` args"
+                        "`{method_name}` applied to a type that implements `Display` in `{name}!` args"
                     ),
                     "remove this",
                     String::new(),
@@ -440,7 +447,7 @@ fn check_to_string_in_format_args(cx: &LateContext<'_>, name: Symbol, value: &Ex
                     TO_STRING_IN_FORMAT_ARGS,
                     value.span,
                     &format!(
-                        "`to_string` applied to a type that implements `Display` in `{name}!` args"
+                        "`{method_name}` applied to a type that implements `Display` in `{name}!` args"
                     ),
                     "use this",
                     format!(
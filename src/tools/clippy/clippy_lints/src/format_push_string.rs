@@ -1,16 +1,23 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{find_format_args, format_args_inputs_span, root_macro_call_first_node};
+use clippy_utils::source::{indent_of, snippet_with_applicability, snippet_with_context};
 use clippy_utils::ty::is_type_lang_item;
-use clippy_utils::{higher, match_def_path, paths};
-use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem, MatchSource};
+use clippy_utils::{get_parent_node, higher, match_def_path, paths, std_or_core};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, ItemId, ItemKind, LangItem, MatchSource, Node, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::sym;
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
     /// Detects cases where the result of a `format!` call is
     /// appended to an existing `String`.
     ///
+    /// When the `format!` call is appended directly, the suggestion writes to the `String` with
+    /// `write!` instead, and imports `std::fmt::Write` if needed.
+    ///
     /// ### Why is this bad?
     /// Introduces an extra, avoidable heap allocation.
     ///
@@ -65,31 +72,116 @@ fn is_format(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     }
 }
 
+/// Builds the suggestion to `write!` the arguments of `format!` to the `String` when `arg` is
+/// `&format!(..)`.
+fn format_write_sugg(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    string: &Expr<'_>,
+    arg: &Expr<'_>,
+    app: &mut Applicability,
+) -> Option<Vec<(Span, String)>> {
+    let ExprKind::AddrOf(BorrowKind::Ref, _, format_expr) = arg.kind else {
+        return None;
+    };
+    let macro_call = root_macro_call_first_node(cx, format_expr)?;
+    if !cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id) {
+        return None;
+    }
+
+    let mut sugg = None;
+    find_format_args(cx, format_expr, macro_call.expn, |format_args| {
+        let string = snippet_with_context(cx, string.span, expr.span.ctxt(), "..", app).0;
+        let inputs = snippet_with_applicability(cx, format_args_inputs_span(format_args), "..", app);
+        sugg = write_sugg(cx, expr, &string, &inputs);
+    });
+    sugg
+}
+
+/// Builds the suggestion replacing `expr`, which appends to a `String`, with
+/// `let _ = write!(string, format_args)`, importing `fmt::Write` if needed. `write!` returns a
+/// `Result`, so only expressions whose value is discarded are replaced.
+pub(crate) fn write_sugg(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    string: &str,
+    format_args: &str,
+) -> Option<Vec<(Span, String)>> {
+    let Some(Node::Stmt(stmt)) = get_parent_node(cx.tcx, expr.hir_id) else {
+        return None;
+    };
+    if !matches!(stmt.kind, StmtKind::Semi(_)) {
+        return None;
+    }
+    let std_or_core = std_or_core(cx)?;
+
+    let mut sugg = vec![(expr.span, format!("let _ = write!({string}, {format_args})"))];
+    if !is_fmt_write_imported(cx, expr) {
+        let indent = " ".repeat(indent_of(cx, stmt.span).unwrap_or(0));
+        sugg.push((
+            stmt.span.shrink_to_lo(),
+            format!("use {std_or_core}::fmt::Write as _;\n{indent}"),
+        ));
+    }
+    Some(sugg)
+}
+
+/// Checks if `fmt::Write` is imported in the module or one of the blocks containing `expr`.
+fn is_fmt_write_imported(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let hir = cx.tcx.hir();
+    let is_fmt_write_use = |id: ItemId| {
+        if let ItemKind::Use(path, _) = hir.item(id).kind {
+            path.res
+                .iter()
+                .any(|res| matches!(res, Res::Def(DefKind::Trait, id) if match_def_path(cx, *id, &paths::FMT_WRITE)))
+        } else {
+            false
+        }
+    };
+
+    hir.parent_iter(expr.hir_id).any(|(_, node)| {
+        matches!(node, Node::Block(block) if block.stmts.iter().any(|stmt| {
+            matches!(stmt.kind, StmtKind::Item(id) if is_fmt_write_use(id))
+        }))
+    }) || hir
+        .get_module(cx.tcx.parent_module(expr.hir_id))
+        .0
+        .item_ids
+        .iter()
+        .any(|&id| is_fmt_write_use(id))
+}
+
 impl<'tcx> LateLintPass<'tcx> for FormatPushString {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        let arg = match expr.kind {
-            ExprKind::MethodCall(_, _, [arg], _) => {
+        let (string, arg) = match expr.kind {
+            ExprKind::MethodCall(_, recv, [arg], _) => {
                 if let Some(fn_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) &&
                 match_def_path(cx, fn_def_id, &paths::PUSH_STR) {
-                    arg
+                    (recv, arg)
                 } else {
                     return;
                 }
             }
             ExprKind::AssignOp(op, left, arg)
             if op.node == BinOpKind::Add && is_string(cx, left) => {
-                arg
+                (left, arg)
             },
             _ => return,
         };
         if is_format(cx, arg) {
-            span_lint_and_help(
+            span_lint_and_then(
                 cx,
                 FORMAT_PUSH_STRING,
                 expr.span,
                 "`format!(..)` appended to existing `String`",
-                None,
-                "consider using `write!` to avoid the extra allocation",
+                |diag| {
+                    let mut app = Applicability::MachineApplicable;
+                    if let Some(sugg) = format_write_sugg(cx, expr, string, arg, &mut app) {
+                        diag.multipart_suggestion("consider using `write!` to avoid the extra allocation", sugg, app);
+                    } else {
+                        diag.help("consider using `write!` to avoid the extra allocation");
+                    }
+                },
             );
         }
     }
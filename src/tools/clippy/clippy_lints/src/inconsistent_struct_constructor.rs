@@ -92,7 +92,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
                 for ident in idents {
                     let _: fmt::Result = write!(fields_snippet, "{ident}, ");
                 }
-                fields_snippet.push_str(&last_ident.to_string());
+                let _: fmt::Result = write!(fields_snippet, "{last_ident}");
 
                 let base_snippet = if let Some(base) = base {
                         format!(", ..{}", snippet(cx, base.span, ".."))
@@ -0,0 +1,140 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr_with_closures, Descend};
+use clippy_utils::{higher, path_to_local_id, peel_blocks, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, HirId, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterating over the keys of a `HashMap` or `BTreeMap` and looking up each key
+    /// in the same map.
+    ///
+    /// ### Why is this bad?
+    /// The map already holds the value next to the key, `iter` and `values` return both or the
+    /// values without looking each key up again.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # let map: HashMap<u32, u32> = HashMap::new();
+    /// for k in map.keys() {
+    ///     let v = map.get(k).unwrap();
+    ///     println!("{k}: {v}");
+    /// }
+    /// let values: Vec<_> = map.keys().filter_map(|k| map.get(k)).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # let map: HashMap<u32, u32> = HashMap::new();
+    /// for (k, v) in &map {
+    ///     println!("{k}: {v}");
+    /// }
+    /// let values: Vec<_> = map.values().collect();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub KEYS_THEN_GET,
+    perf,
+    "iterating over the keys of a map and looking up each key in it"
+}
+declare_lint_pass!(KeysThenGet => [KEYS_THEN_GET]);
+
+impl<'tcx> LateLintPass<'tcx> for KeysThenGet {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::ForLoop { pat, arg, body, .. }) = higher::ForLoop::hir(expr)
+            && let PatKind::Binding(_, key_id, key, None) = pat.kind
+            && let Some(map) = keys_receiver(cx, arg)
+        {
+            let mut lookups = Vec::new();
+            // the uses of the key outside of the lookups
+            let mut key_uses = 0;
+            let _: Option<()> = for_each_expr_with_closures(cx, body, |e| {
+                if let Some(span) = lookup_span(cx, e, map, key_id) {
+                    lookups.push(span);
+                    return ControlFlow::Continue(Descend::No);
+                }
+                if path_to_local_id(e, key_id) {
+                    key_uses += 1;
+                }
+                ControlFlow::Continue(Descend::Yes)
+            });
+            if lookups.is_empty() || arg.span.from_expansion() {
+                return;
+            }
+
+            let mut app = Applicability::Unspecified;
+            let map_snip = snippet_with_context(cx, map.span, arg.span.ctxt(), "..", &mut app).0;
+            span_lint_and_then(
+                cx,
+                KEYS_THEN_GET,
+                arg.span,
+                &format!("looking up the keys of `{map_snip}` in it while iterating over them"),
+                |diag| {
+                    for span in lookups {
+                        diag.span_note(span, "the key is looked up here");
+                    }
+                    if key_uses == 0 {
+                        diag.help(format!("iterate over `{map_snip}.values()` instead"));
+                    } else {
+                        diag.help(format!(
+                            "iterate over `{map_snip}.iter()` instead, and bind the value along with `{key}`"
+                        ));
+                    }
+                },
+            );
+        } else if let ExprKind::MethodCall(path, keys, [closure], _) = expr.kind
+            && path.ident.name == sym!(filter_map)
+            && let Some(map) = keys_receiver(cx, keys)
+            && let ExprKind::Closure(&Closure { body, .. }) = closure.kind
+            && let body = cx.tcx.hir().body(body)
+            && let [param] = body.params
+            && let PatKind::Binding(_, key_id, _, None) = param.pat.kind
+            && let value = peel_blocks(body.value)
+            // `map[k]` would have to be an `Option`, which `values` doesn't flatten
+            && let ExprKind::MethodCall(..) = value.kind
+            && lookup_span(cx, value, map, key_id).is_some()
+            && !expr.span.from_expansion()
+        {
+            let mut app = Applicability::MachineApplicable;
+            let map_snip = snippet_with_context(cx, map.span, expr.span.ctxt(), "..", &mut app).0;
+            span_lint_and_sugg(
+                cx,
+                KEYS_THEN_GET,
+                expr.span,
+                &format!("looking up the keys of `{map_snip}` in it while iterating over them"),
+                "use",
+                format!("{map_snip}.values()"),
+                app,
+            );
+        }
+    }
+}
+
+/// If `expr` is `map.keys()` on a `HashMap` or `BTreeMap`, returns `map`.
+fn keys_receiver<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(path, map, [], _) = expr.kind
+        && path.ident.name == sym!(keys)
+    {
+        let ty = cx.typeck_results().expr_ty(map).peel_refs();
+        (is_type_diagnostic_item(cx, ty, sym::HashMap) || is_type_diagnostic_item(cx, ty, sym::BTreeMap))
+            .then_some(map)
+    } else {
+        None
+    }
+}
+
+/// Returns the span of `expr` if it's `map.get(key)` or `map[key]`.
+fn lookup_span(cx: &LateContext<'_>, expr: &Expr<'_>, map: &Expr<'_>, key_id: HirId) -> Option<Span> {
+    let (recv, key) = match expr.kind {
+        ExprKind::MethodCall(path, recv, [key], _) if path.ident.name == sym!(get) => (recv, key),
+        ExprKind::Index(recv, key) => (recv, key),
+        _ => return None,
+    };
+    (path_to_local_id(key, key_id) && SpanlessEq::new(cx).eq_expr(recv, map)).then_some(expr.span)
+}
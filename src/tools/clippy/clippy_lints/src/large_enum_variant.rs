@@ -3,7 +3,7 @@
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::{
     diagnostics::span_lint_and_then,
-    ty::{approx_ty_size, is_copy, AdtVariantInfo},
+    ty::{approx_ty_size, is_copy, ty_size, AdtVariantInfo},
 };
 use rustc_errors::Applicability;
 use rustc_hir::{Item, ItemKind};
@@ -103,7 +103,10 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
                     |diag| {
                         diag.span_label(
                             item.span,
-                            format!("the entire enum is at least {} bytes", approx_ty_size(cx, ty)),
+                            match ty_size(cx, cx.param_env, ty) {
+                                Some(size) => format!("the entire enum is {size} bytes"),
+                                None => format!("the entire enum is at least {} bytes", approx_ty_size(cx, ty)),
+                            },
                         );
                         diag.span_label(
                             def.variants[variants_size[0].ind].span,
@@ -2,6 +2,7 @@
 
 use clippy_utils::diagnostics::span_lint_and_note;
 use clippy_utils::fn_has_unsatisfiable_preds;
+use clippy_utils::ty::ty_size;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::Body;
@@ -143,8 +144,8 @@ fn check_fn(
         let mut frame_size = Space::Used(0);
 
         for local in &mir.local_decls {
-            if let Ok(layout) = cx.tcx.layout_of(param_env.and(local.ty)) {
-                frame_size += layout.size.bytes();
+            if let Some(size) = ty_size(cx, param_env, local.ty) {
+                frame_size += size;
             }
         }
 
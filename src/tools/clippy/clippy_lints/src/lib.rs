@@ -76,6 +76,7 @@
 mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
+mod block_on_in_async;
 mod blocks_in_if_conditions;
 mod bool_assert_comparison;
 mod bool_to_int_with_if;
@@ -102,12 +103,14 @@
 mod dereference;
 mod derivable_impls;
 mod derive;
+mod detached_spawn;
 mod disallowed_macros;
 mod disallowed_methods;
 mod disallowed_names;
 mod disallowed_script_idents;
 mod disallowed_types;
 mod doc;
+mod double_map_lookup;
 mod double_parens;
 mod drop_forget_ref;
 mod duplicate_mod;
@@ -164,6 +167,7 @@
 mod items_after_statements;
 mod items_after_test_module;
 mod iter_not_returning_iterator;
+mod keys_then_get;
 mod large_const_arrays;
 mod large_enum_variant;
 mod large_futures;
@@ -188,7 +192,9 @@
 mod manual_let_else;
 mod manual_main_separator_str;
 mod manual_non_exhaustive;
+mod manual_path_join;
 mod manual_range_patterns;
+mod manual_read_to_string;
 mod manual_rem_euclid;
 mod manual_retain;
 mod manual_slice_size_calculation;
@@ -307,6 +313,7 @@
 mod tabs_in_doc_comments;
 mod temporary_assignment;
 mod tests_outside_test_module;
+mod thread_sleep_in_async;
 mod to_digit_is_some;
 mod trailing_empty_array;
 mod trait_bounds;
@@ -651,6 +658,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
     let allow_expect_in_tests = conf.allow_expect_in_tests;
     let allow_unwrap_in_tests = conf.allow_unwrap_in_tests;
+    let naive_string_reversal_all_rev = conf.naive_string_reversal_all_rev;
+    let vec_front_modification_min_iterations = conf.vec_front_modification_min_iterations;
     let suppress_restriction_lint_in_const = conf.suppress_restriction_lint_in_const;
     store.register_late_pass(move |_| Box::new(approx_const::ApproxConstant::new(msrv())));
     store.register_late_pass(move |_| {
@@ -659,9 +668,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             msrv(),
             allow_expect_in_tests,
             allow_unwrap_in_tests,
+            naive_string_reversal_all_rev,
+            vec_front_modification_min_iterations,
+        ))
+    });
+    let match_same_arms_min_complexity = conf.match_same_arms_min_complexity;
+    let match_same_arms_allow_renamed_bindings = conf.match_same_arms_allow_renamed_bindings;
+    let match_same_arms_allow_unused_bindings = conf.match_same_arms_allow_unused_bindings;
+    store.register_late_pass(move |_| {
+        Box::new(matches::Matches::new(
+            msrv(),
+            match_same_arms_min_complexity,
+            match_same_arms_allow_renamed_bindings,
+            match_same_arms_allow_unused_bindings,
         ))
     });
-    store.register_late_pass(move |_| Box::new(matches::Matches::new(msrv())));
     let matches_for_let_else = conf.matches_for_let_else;
     store.register_late_pass(move |_| Box::new(manual_let_else::ManualLetElse::new(msrv(), matches_for_let_else)));
     store.register_early_pass(move || Box::new(manual_non_exhaustive::ManualNonExhaustiveStruct::new(msrv())));
@@ -716,9 +737,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             msrv: msrv(),
         })
     });
-    store.register_late_pass(|_| Box::new(panic_unimplemented::PanicUnimplemented));
+    let allow_panic_in_tests = conf.allow_panic_in_tests;
+    store.register_late_pass(move |_| Box::new(panic_unimplemented::PanicUnimplemented::new(allow_panic_in_tests)));
     store.register_late_pass(|_| Box::new(strings::StringLitAsBytes));
-    store.register_late_pass(|_| Box::new(derive::Derive));
+    store.register_late_pass(|_| Box::<derive::Derive<'_>>::default());
     store.register_late_pass(move |_| Box::new(derivable_impls::DerivableImpls::new(msrv())));
     store.register_late_pass(|_| Box::new(drop_forget_ref::DropForgetRef));
     store.register_late_pass(|_| Box::new(empty_enum::EmptyEnum));
@@ -758,7 +780,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let enum_variant_size_threshold = conf.enum_variant_size_threshold;
     store.register_late_pass(move |_| Box::new(large_enum_variant::LargeEnumVariant::new(enum_variant_size_threshold)));
     store.register_late_pass(|_| Box::new(explicit_write::ExplicitWrite));
-    store.register_late_pass(|_| Box::new(needless_pass_by_value::NeedlessPassByValue));
+    store.register_late_pass(|_| Box::<needless_pass_by_value::NeedlessPassByValue<'_>>::default());
     let pass_by_ref_or_value = pass_by_ref_or_value::PassByRefOrValue::new(
         conf.trivial_copy_size_limit,
         conf.pass_by_value_size_limit,
@@ -772,14 +794,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::<useless_conversion::UselessConversion>::default());
     store.register_late_pass(|_| Box::new(implicit_hasher::ImplicitHasher));
     store.register_late_pass(|_| Box::new(fallible_impl_from::FallibleImplFrom));
-    store.register_late_pass(|_| Box::<question_mark::QuestionMark>::default());
+    store.register_late_pass(|_| Box::new(question_mark::QuestionMark));
     store.register_late_pass(|_| Box::new(question_mark_used::QuestionMarkUsed));
     store.register_early_pass(|| Box::new(suspicious_operation_groupings::SuspiciousOperationGroupings));
     store.register_late_pass(|_| Box::new(suspicious_trait_impl::SuspiciousImpl));
     store.register_late_pass(|_| Box::new(map_unit_fn::MapUnit));
     store.register_late_pass(|_| Box::new(inherent_impl::MultipleInherentImpl));
     store.register_late_pass(|_| Box::new(neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd));
-    store.register_late_pass(|_| Box::new(unwrap::Unwrap));
+    store.register_late_pass(move |_| Box::new(unwrap::Unwrap::new(msrv())));
     store.register_late_pass(move |_| {
         Box::new(indexing_slicing::IndexingSlicing::new(
             suppress_restriction_lint_in_const,
@@ -910,7 +932,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move |_| Box::new(if_then_some_else_none::IfThenSomeElseNone::new(msrv())));
     store.register_late_pass(|_| Box::new(bool_assert_comparison::BoolAssertComparison));
     store.register_early_pass(move || Box::new(module_style::ModStyle));
-    store.register_late_pass(|_| Box::new(unused_async::UnusedAsync));
+    store.register_late_pass(|_| Box::<unused_async::UnusedAsync>::default());
     let disallowed_types = conf.disallowed_types.clone();
     store.register_late_pass(move |_| Box::new(disallowed_types::DisallowedTypes::new(disallowed_types.clone())));
     let import_renames = conf.enforced_import_renames.clone();
@@ -1074,6 +1096,24 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(manual_range_patterns::ManualRangePatterns));
     store.register_early_pass(|| Box::new(visibility::Visibility));
     store.register_late_pass(move |_| Box::new(tuple_array_conversions::TupleArrayConversions { msrv: msrv() }));
+    store.register_late_pass(|_| Box::new(keys_then_get::KeysThenGet));
+    store.register_late_pass(|_| Box::new(manual_path_join::ManualPathJoin));
+    store.register_late_pass(|_| Box::new(manual_read_to_string::ManualReadToString));
+    store.register_late_pass(|_| Box::new(double_map_lookup::DoubleMapLookup));
+    let blocking_executor_functions = conf.blocking_executor_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(block_on_in_async::BlockOnInAsync::new(
+            blocking_executor_functions.clone(),
+        ))
+    });
+    let task_spawn_functions = conf.task_spawn_functions.clone();
+    store.register_late_pass(move |_| Box::new(detached_spawn::DetachedSpawn::new(task_spawn_functions.clone())));
+    let async_sleep_functions = conf.async_sleep_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(thread_sleep_in_async::ThreadSleepInAsync::new(
+            async_sleep_functions.clone(),
+        ))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -0,0 +1,106 @@
+use super::BUSY_WAIT_LOOP;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::visitors::{for_each_expr, Visitable};
+use clippy_utils::{fn_def_id, higher};
+use core::ops::ControlFlow;
+use rustc_hir::def::DefKind;
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+/// Checks a `while` loop, or a `loop` loop when `condition` is `None`, which waits for an atomic
+/// to change without blocking.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    condition: Option<&'tcx Expr<'_>>,
+    body: impl Visitable<'tcx> + Copy,
+    span: Span,
+) {
+    let waits_on_atomic = match condition {
+        Some(condition) => polls_atomic(cx, condition),
+        None => breaks_on_atomic(cx, expr.hir_id, body),
+    };
+    if waits_on_atomic && !condition.is_some_and(|condition| may_block(cx, condition)) && !may_block(cx, body) {
+        span_lint_and_help(
+            cx,
+            BUSY_WAIT_LOOP,
+            span,
+            "busy-waiting loop without a spin loop hint or a blocking call",
+            None,
+            "call `std::hint::spin_loop()` in the loop, or block with `std::thread::park` or a `Condvar` instead",
+        );
+    }
+}
+
+/// Checks for an `if` whose condition reads an atomic, and which breaks out of the loop.
+fn breaks_on_atomic<'tcx>(cx: &LateContext<'tcx>, loop_id: HirId, body: impl Visitable<'tcx>) -> bool {
+    for_each_expr(body, |e| {
+        if let Some(higher::If { cond, then, .. }) = higher::If::hir(e)
+            && polls_atomic(cx, cond)
+            && breaks_out_of(loop_id, then)
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+fn breaks_out_of(loop_id: HirId, e: &Expr<'_>) -> bool {
+    for_each_expr(e, |e| match e.kind {
+        ExprKind::Break(destination, _) if destination.target_id == Ok(loop_id) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
+
+/// Checks if the expression calls a method of one of the `core::sync::atomic` types.
+fn polls_atomic(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    for_each_expr(e, |e| {
+        if let ExprKind::MethodCall(..) = e.kind
+            && let Some(def_id) = fn_def_id(cx, e)
+            && let [sym::core, sync, atomic, ..] = &*cx.get_def_path(def_id)
+            && sync.as_str() == "sync"
+            && atomic.as_str() == "atomic"
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Checks if the loop may wait without spinning: it awaits, gives a spin loop hint, calls a
+/// function which may block, or calls a function outside of the standard library which can't be
+/// checked.
+fn may_block<'tcx>(cx: &LateContext<'tcx>, e: impl Visitable<'tcx>) -> bool {
+    for_each_expr(e, |e| match e.kind {
+        ExprKind::Yield(..) => ControlFlow::Break(()),
+        ExprKind::Call(..) | ExprKind::MethodCall(..) => {
+            let Some(def_id) = fn_def_id(cx, e) else {
+                return ControlFlow::Break(());
+            };
+            if matches!(cx.tcx.def_kind(def_id), DefKind::Ctor(..)) {
+                return ControlFlow::Continue(());
+            }
+            let blocks = match &*cx.get_def_path(def_id) {
+                [sym::core, hint, spin_loop] => hint.as_str() == "hint" && spin_loop.as_str() == "spin_loop",
+                [sym::core | sym::alloc, ..] => false,
+                [sym::std, module, ..] => {
+                    matches!(module.as_str(), "fs" | "io" | "net" | "process" | "sync" | "thread")
+                },
+                _ => true,
+            };
+            if blocks {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
@@ -0,0 +1,88 @@
+use super::utils::make_iterator_snippet;
+use super::CARTESIAN_PRODUCT_LOOP;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use clippy_utils::{higher, path_to_local, peel_blocks_with_stmt};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    span: Span,
+) {
+    // the outer loop does nothing but the inner loop
+    let Some(outer) = higher::ForLoop::hir(expr) else {
+        return;
+    };
+    let Some(inner) = higher::ForLoop::hir(peel_blocks_with_stmt(body)) else {
+        return;
+    };
+    if inner.span.ctxt() != span.ctxt() {
+        return;
+    }
+    let (PatKind::Binding(_, outer_id, outer_name, None), PatKind::Binding(_, _, inner_name, None)) =
+        (pat.kind, inner.pat.kind)
+    else {
+        return;
+    };
+
+    // the inner iterator doesn't depend on the item of the outer loop, nor on anything changed
+    // by the body
+    if is_local_used(cx, inner.arg, outer_id) {
+        return;
+    }
+    let Some(mutated) = mutated_variables(inner.body, cx) else {
+        return;
+    };
+    if for_each_expr(inner.arg, |e| {
+        if path_to_local(e).is_some_and(|id| mutated.contains(&id)) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+    {
+        return;
+    }
+
+    // flattening the loops changes where `break` and `continue 'outer` go
+    if breaks_across_levels(inner.body, outer.loop_id, inner.loop_id) {
+        return;
+    }
+
+    let mut app = Applicability::Unspecified;
+    let outer_iter = make_iterator_snippet(cx, arg, &mut app);
+    let inner_iter = make_iterator_snippet(cx, inner.arg, &mut app);
+    span_lint_and_help(
+        cx,
+        CARTESIAN_PRODUCT_LOOP,
+        span,
+        "nested loops over the cartesian product of two independent iterators",
+        None,
+        &format!(
+            "iterate over the pairs with `{outer_iter}.flat_map(|{outer_name}| {inner_iter}.map(move |{inner_name}| \
+            ({outer_name}, {inner_name})))` or `itertools::iproduct!`"
+        ),
+    );
+}
+
+/// Checks for a `break` out of the inner or outer loop, or a `continue` of the outer loop.
+fn breaks_across_levels(body: &Expr<'_>, outer_id: HirId, inner_id: HirId) -> bool {
+    for_each_expr(body, |e| match e.kind {
+        ExprKind::Break(destination, _) if destination.target_id.is_ok_and(|id| id == outer_id || id == inner_id) => {
+            ControlFlow::Break(())
+        },
+        ExprKind::Continue(destination) if destination.target_id == Ok(outer_id) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
@@ -0,0 +1,139 @@
+use super::utils::{collection_init, preceding_local, Init};
+use super::EXTEND_WITHOUT_RESERVE;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::side_effects::expr_side_effects;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::{make_binop, Sugg};
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::visitors::{for_each_expr_with_closures, is_local_used};
+use clippy_utils::{iter_len_sugg, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_ast::ast::BinOpKind;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, LangItem, PatKind, QPath, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    span: Span,
+) {
+    let Some(local) = preceding_local(cx, expr) else {
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::MUT, binding_id, ident, None) = local.pat.kind
+        && local.els.is_none()
+        && local.span.ctxt() == span.ctxt()
+        && let Some(init) = local.init
+        && let Some(Init::Empty) = collection_init(cx, init)
+        && let ExprKind::Block(block, _) = body.kind
+        && !is_local_used(cx, arg, binding_id)
+        // an iteration leaving the loop body early may skip extending the collection
+        && !expr_side_effects(cx, body).control_flow
+    {
+        let init_ty = cx.typeck_results().expr_ty(init);
+        let (collection, is_string) = if is_type_diagnostic_item(cx, init_ty, sym::Vec) {
+            ("Vec", false)
+        } else if is_type_lang_item(cx, init_ty, LangItem::String) {
+            ("String", true)
+        } else {
+            return;
+        };
+        let Some(added) = added_len(cx, block, binding_id, is_string) else {
+            return;
+        };
+        let mut app = Applicability::MachineApplicable;
+        let Some(len) = iter_len_sugg(cx, arg, &mut app) else {
+            return;
+        };
+        let capacity = if added == 1 {
+            len
+        } else {
+            make_binop(BinOpKind::Mul, &len, &Sugg::NonParen(added.to_string().into()))
+        };
+        // keep the type as written, e.g. `Vec::<u8>::new()`
+        let ty = if let ExprKind::Call(func, _) = init.kind
+            && !init.span.from_expansion()
+            && let ExprKind::Path(QPath::TypeRelative(ty, _)) = func.kind
+        {
+            snippet_with_applicability(cx, ty.span, "..", &mut app)
+        } else {
+            collection.into()
+        };
+        span_lint_and_sugg(
+            cx,
+            EXTEND_WITHOUT_RESERVE,
+            init.span,
+            &format!("`{ident}` is extended in a loop by a known number of elements without reserving its capacity"),
+            "create it with the needed capacity",
+            format!("{ty}::with_capacity({capacity})"),
+            app,
+        );
+    }
+}
+
+/// Returns the length each iteration of the loop adds to the collection, if it's known and each
+/// use of the collection in the loop adds to it unconditionally.
+fn added_len<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'_>,
+    binding_id: HirId,
+    is_string: bool,
+) -> Option<usize> {
+    let mut added = 0;
+    let mut calls = 0;
+    for e in block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        })
+        .chain(block.expr)
+    {
+        if let ExprKind::MethodCall(path, recv, [arg], _) = e.kind
+            && path_to_local_id(recv, binding_id)
+            && let Some(len) = call_len(cx, path.ident.as_str(), arg, is_string)
+        {
+            added += len;
+            calls += 1;
+        }
+    }
+
+    let mut uses = 0;
+    for_each_expr_with_closures(cx, block, |e| {
+        if path_to_local_id(e, binding_id) {
+            uses += 1;
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    (added > 0 && uses == calls).then_some(added)
+}
+
+/// Returns the length added to the collection by calling the given method with `arg`.
+fn call_len(cx: &LateContext<'_>, method: &str, arg: &Expr<'_>, is_string: bool) -> Option<usize> {
+    match (is_string, method) {
+        (false, "push") => Some(1),
+        (false, "extend" | "extend_from_slice") => match cx.typeck_results().expr_ty(arg).peel_refs().kind() {
+            ty::Array(_, len) => len
+                .try_eval_target_usize(cx.tcx, cx.param_env)
+                .and_then(|len| usize::try_from(len).ok()),
+            _ => None,
+        },
+        // the capacity of a `String` is in bytes
+        (true, "push" | "push_str") => match arg.kind {
+            ExprKind::Lit(lit) => match lit.node {
+                LitKind::Char(c) => Some(c.len_utf8()),
+                LitKind::Str(s, _) => Some(s.as_str().len()),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,160 @@
+use super::utils::has_control_flow;
+use super::LEN_MUTATED_IN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{eq_expr_value, higher, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+/// Methods adding elements to a `Vec` or a `VecDeque`.
+const GROWING: &[&str] = &[
+    "append",
+    "extend",
+    "extend_from_slice",
+    "insert",
+    "push",
+    "push_back",
+    "push_front",
+    "resize",
+    "resize_with",
+];
+
+/// Methods removing elements from a `Vec` or a `VecDeque`.
+const SHRINKING: &[&str] = &[
+    "clear",
+    "dedup",
+    "dedup_by",
+    "dedup_by_key",
+    "drain",
+    "pop",
+    "pop_back",
+    "pop_front",
+    "remove",
+    "retain",
+    "retain_mut",
+    "split_off",
+    "swap_remove",
+    "swap_remove_back",
+    "swap_remove_front",
+    "truncate",
+];
+
+/// Checks for `while i < v.len() { ..; v.push(x); }`, and for removals while `i` keeps counting
+/// up.
+pub(super) fn check_while<'tcx>(cx: &LateContext<'tcx>, condition: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    if let ExprKind::Binary(op, lhs, rhs) = condition.kind
+        && let Some((counter, len)) = match op.node {
+            BinOpKind::Lt | BinOpKind::Le => Some((lhs, rhs)),
+            BinOpKind::Gt | BinOpKind::Ge => Some((rhs, lhs)),
+            _ => None,
+        }
+        && let Some(counter_id) = path_to_local(counter)
+        && let Some(collection) = len_recv(cx, len)
+        && !has_control_flow(body)
+        && let Some((method, span)) = mutation(cx, body, collection)
+        // removing the element at the index without moving on to the next one is fine
+        && (GROWING.contains(&method) || increments_unconditionally(body, counter_id))
+    {
+        emit_lint(
+            cx,
+            len.span,
+            collection,
+            method,
+            span,
+            "the loop bound reads the length again in every iteration, but it's changed in the loop",
+        );
+    }
+}
+
+/// Checks for `for i in 0..v.len() { ..; v.remove(i); }`.
+pub(super) fn check_for<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    if let Some(higher::Range { end: Some(len), .. }) = higher::Range::hir(arg)
+        && let Some(collection) = len_recv(cx, len)
+        && !has_control_flow(body)
+        && let Some((method, span)) = mutation(cx, body, collection)
+        // the elements added in the loop just aren't visited
+        && SHRINKING.contains(&method)
+    {
+        emit_lint(
+            cx,
+            len.span,
+            collection,
+            method,
+            span,
+            "the loop bound reads the length once, but it's changed in the loop",
+        );
+    }
+}
+
+fn emit_lint(cx: &LateContext<'_>, len: Span, collection: &Expr<'_>, method: &str, mutation: Span, msg: &str) {
+    let collection = snippet(cx, collection.span, "..");
+    span_lint_and_then(cx, LEN_MUTATED_IN_LOOP, len, msg, |diag| {
+        diag.span_note(mutation, format!("`{collection}` is changed here"));
+        if GROWING.contains(&method) {
+            diag.help("if the added elements shouldn't be visited, store the length in a variable before the loop");
+        } else if method.starts_with("pop") {
+            diag.help(format!(
+                "to take the elements out one by one, use `while let Some(x) = {collection}.{method}()`"
+            ));
+        } else {
+            diag.help(format!(
+                "to remove the elements matching a condition, use `{collection}.retain(..)`"
+            ));
+        }
+    });
+}
+
+/// Gets `v` in `v.len()`, where `v` is a `Vec` or a `VecDeque`.
+fn len_recv<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.name == sym::len
+        && let ty = cx.typeck_results().expr_ty(recv).peel_refs()
+        && (is_type_diagnostic_item(cx, ty, sym::Vec) || is_type_diagnostic_item(cx, ty, sym::VecDeque))
+    {
+        Some(recv)
+    } else {
+        None
+    }
+}
+
+/// Finds a call adding or removing elements of the collection, returning the name of the method
+/// and the span of the call.
+fn mutation(cx: &LateContext<'_>, body: &Expr<'_>, collection: &Expr<'_>) -> Option<(&'static str, Span)> {
+    for_each_expr(body, |e| {
+        if let ExprKind::MethodCall(path, recv, ..) = e.kind
+            && eq_expr_value(cx, recv, collection)
+            && let Some(method) = GROWING
+                .iter()
+                .chain(SHRINKING)
+                .find(|&&method| path.ident.as_str() == method)
+        {
+            ControlFlow::Break((*method, e.span))
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+/// Checks if `i += ..` is a statement at the top level of the loop body, so it's done in every
+/// iteration.
+fn increments_unconditionally(body: &Expr<'_>, counter_id: HirId) -> bool {
+    let ExprKind::Block(block, _) = body.kind else {
+        return false;
+    };
+    block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            StmtKind::Local(_) | StmtKind::Item(_) => None,
+        })
+        .chain(block.expr)
+        .any(|e| {
+            matches!(e.kind, ExprKind::AssignOp(op, lhs, _)
+                if op.node == BinOpKind::Add && path_to_local_id(lhs, counter_id))
+        })
+}
@@ -0,0 +1,188 @@
+use super::LOOP_INVARIANT_COMPUTATION;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::side_effects::{expr_side_effects, SideEffects};
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::{for_each_expr, for_each_expr_with_closures, Descend, Visitable};
+use clippy_utils::{
+    get_parent_node, is_trait_method, match_def_path, path_def_id, path_to_local, path_to_local_id, paths,
+};
+use core::ops::ControlFlow;
+use rustc_hir::{BindingAnnotation, BorrowKind, Expr, ExprKind, HirIdSet, Mutability, Node, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_span::{sym, Span};
+
+/// Checks the body of a `for`, `while` or `loop` loop for expensive computations which don't
+/// depend on the loop.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, loop_expr: &'tcx Expr<'_>, body: impl Visitable<'tcx>) {
+    let Some(mutated) = mutated_variables(loop_expr, cx) else {
+        return;
+    };
+    for_each_expr(body, |e| {
+        // nested loops are checked on their own
+        if let ExprKind::Loop(..) = e.kind {
+            return ControlFlow::<()>::Continue(Descend::No);
+        }
+        let Some((name, span, args)) = expensive_computation(cx, e) else {
+            return ControlFlow::Continue(Descend::Yes);
+        };
+        let computation = with_unwrap(cx, e);
+        if !args.is_allowed(cx, e)
+            || depends_on_loop(cx, computation, span, loop_expr, &mutated)
+            || !is_only_borrowed(cx, computation, loop_expr)
+        {
+            return ControlFlow::Continue(Descend::Yes);
+        }
+
+        let help = if matches!(get_parent_node(cx.tcx, computation.hir_id), Some(Node::Local(_))) {
+            "move the `let` statement before the loop"
+        } else {
+            "compute the value once before the loop"
+        };
+        span_lint_and_help(
+            cx,
+            LOOP_INVARIANT_COMPUTATION,
+            span,
+            &format!("`{name}` is evaluated on every iteration of the loop, but doesn't depend on it"),
+            None,
+            help,
+        );
+        ControlFlow::Continue(Descend::No)
+    });
+}
+
+/// What the side effects of a computation are checked on.
+enum Effects<'tcx> {
+    /// The whole expression.
+    Expr,
+    /// Only the arguments, the function called is known to not have other side effects, or to
+    /// only read the environment, which gives the same result on every iteration.
+    Args(&'tcx [Expr<'tcx>]),
+}
+
+impl<'tcx> Effects<'tcx> {
+    fn is_allowed(&self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+        // allocating again gives the same result, doing I/O again may not
+        let is_allowed = |effects: SideEffects| {
+            !(effects.mutates || effects.does_io || effects.control_flow || effects.calls_unknown)
+        };
+        match self {
+            Self::Expr => is_allowed(expr_side_effects(cx, e)),
+            Self::Args(args) => args.iter().all(|arg| is_allowed(expr_side_effects(cx, arg))),
+        }
+    }
+}
+
+/// Gets the name and span of a computation worth doing only once, e.g. `Regex::new(..)` or
+/// `format!(..)`.
+fn expensive_computation<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'tcx>,
+) -> Option<(&'static str, Span, Effects<'tcx>)> {
+    if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+        return cx
+            .tcx
+            .is_diagnostic_item(sym::format_macro, macro_call.def_id)
+            .then_some(("format!", macro_call.span, Effects::Expr));
+    }
+    if e.span.from_expansion() {
+        return None;
+    }
+    match e.kind {
+        ExprKind::Call(callee, args) => {
+            let def_id = path_def_id(cx, callee)?;
+            if match_def_path(cx, def_id, &paths::REGEX_NEW) || match_def_path(cx, def_id, &paths::REGEX_BYTES_NEW) {
+                Some(("Regex::new", e.span, Effects::Args(args)))
+            } else if match_def_path(cx, def_id, &paths::REGEX_SET_NEW)
+                || match_def_path(cx, def_id, &paths::REGEX_BYTES_SET_NEW)
+            {
+                Some(("RegexSet::new", e.span, Effects::Args(args)))
+            } else if match_def_path(cx, def_id, &paths::ENV_VAR) {
+                Some(("env::var", e.span, Effects::Args(args)))
+            } else if match_def_path(cx, def_id, &paths::ENV_VAR_OS) {
+                Some(("env::var_os", e.span, Effects::Args(args)))
+            } else {
+                None
+            }
+        },
+        ExprKind::MethodCall(path, ..) if path.ident.name == sym::collect && is_trait_method(cx, e, sym::Iterator) => {
+            Some(("collect", e.span, Effects::Expr))
+        },
+        _ => None,
+    }
+}
+
+/// Extends `Regex::new(..)` to `Regex::new(..).unwrap()`, whose result is what is used.
+fn with_unwrap<'tcx>(cx: &LateContext<'tcx>, mut e: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let Some(Node::Expr(parent)) = get_parent_node(cx.tcx, e.hir_id)
+        && let ExprKind::MethodCall(path, recv, _, _) = parent.kind
+        && recv.hir_id == e.hir_id
+        && matches!(path.ident.name, sym::unwrap | sym::expect)
+    {
+        e = parent;
+    }
+    e
+}
+
+/// Checks if the expression, written at `span`, uses a local declared in the loop, or changed by
+/// it.
+fn depends_on_loop(cx: &LateContext<'_>, e: &Expr<'_>, span: Span, loop_expr: &Expr<'_>, mutated: &HirIdSet) -> bool {
+    for_each_expr_with_closures(cx, e, |e| {
+        if let Some(id) = path_to_local(e) {
+            let decl = cx.tcx.hir().span(id);
+            // the parameters of closures in the expression are declared in the loop as well
+            if mutated.contains(&id) || (loop_expr.span.contains(decl) && !span.contains(decl)) {
+                return ControlFlow::Break(());
+            }
+        }
+        ControlFlow::Continue(())
+    })
+    .is_some()
+}
+
+/// Checks if the value is only borrowed, so computing it once doesn't require cloning it in every
+/// iteration instead. It's either borrowed directly, or bound by a `let` statement whose binding is
+/// only borrowed.
+fn is_only_borrowed(cx: &LateContext<'_>, e: &Expr<'_>, loop_expr: &Expr<'_>) -> bool {
+    match get_parent_node(cx.tcx, e.hir_id) {
+        Some(Node::Local(local)) => {
+            if let PatKind::Binding(BindingAnnotation::NONE, id, _, None) = local.pat.kind {
+                for_each_expr_with_closures(cx, loop_expr, |e| {
+                    if path_to_local_id(e, id) && !is_borrowed(cx, e) {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                })
+                .is_none()
+            } else {
+                false
+            }
+        },
+        Some(Node::Expr(_)) => is_borrowed(cx, e),
+        _ => false,
+    }
+}
+
+/// Checks if the expression is borrowed immutably, either explicitly or as the receiver of a
+/// method.
+fn is_borrowed(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match get_parent_node(cx.tcx, e.hir_id) {
+        Some(Node::Expr(parent)) => match parent.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => true,
+            ExprKind::MethodCall(_, recv, ..) if recv.hir_id == e.hir_id => cx
+                .typeck_results()
+                .expr_adjustments(e)
+                .last()
+                .is_some_and(|adjustment| {
+                    matches!(
+                        adjustment.kind,
+                        Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Not))
+                    )
+                }),
+            _ => false,
+        },
+        _ => false,
+    }
+}
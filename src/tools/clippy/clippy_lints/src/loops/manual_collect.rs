@@ -0,0 +1,133 @@
+use super::utils::{collection_init, has_control_flow, make_iterator_snippet, preceding_local, Init};
+use super::MANUAL_COLLECT;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{is_diag_item_method, path_to_local_id, peel_blocks_with_stmt};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Expr, ExprKind, Local, Pat, PatKind, QPath};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span, Symbol};
+
+/// The collections checked, with the method adding an element and its number of arguments.
+const COLLECTIONS: [(Symbol, &str, usize); 4] = [
+    (sym::Vec, "push", 1),
+    (sym::HashMap, "insert", 2),
+    (sym::BTreeMap, "insert", 2),
+    (sym::HashSet, "insert", 1),
+];
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    span: Span,
+) {
+    let Some(local) = preceding_local(cx, expr) else {
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::MUT, binding_id, ident, None) = local.pat.kind
+        && local.els.is_none()
+        && local.span.ctxt() == span.ctxt()
+        && let Some(init_expr) = local.init
+        && let Some(init) = collection_init(cx, init_expr)
+        && let init_ty = cx.typeck_results().expr_ty(init_expr)
+        && let Some(&(collection, method, arg_count)) = COLLECTIONS
+            .iter()
+            .find(|(collection, ..)| is_type_diagnostic_item(cx, init_ty, *collection))
+        && let ExprKind::MethodCall(seg, recv, args, _) = peel_blocks_with_stmt(body).kind
+        && seg.ident.as_str() == method
+        && args.len() == arg_count
+        && path_to_local_id(recv, binding_id)
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(peel_blocks_with_stmt(body).hir_id)
+        && is_diag_item_method(cx, method_id, collection)
+        // The collection must not be used by the iterator or the added values.
+        && !is_local_used(cx, arg, binding_id)
+        && !args.iter().any(|arg| is_local_used(cx, arg, binding_id))
+        && !args.iter().any(has_control_flow)
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let iter = make_iterator_snippet(cx, arg, &mut app);
+        let is_loop_item =
+            |value: &Expr<'_>| matches!(pat.kind, PatKind::Binding(_, id, _, None) if path_to_local_id(value, id));
+        let value = match args {
+            [key, value] => Some(format!(
+                "({}, {})",
+                snippet_with_applicability(cx, key.span, "..", &mut app),
+                snippet_with_applicability(cx, value.span, "..", &mut app),
+            )),
+            [value] if is_loop_item(value) => None,
+            [value] => Some(snippet_with_applicability(cx, value.span, "..", &mut app).into_owned()),
+            _ => return,
+        };
+        let map = if let Some(value) = value {
+            format!(
+                ".map(|{}| {value})",
+                snippet_with_applicability(cx, pat.span, "..", &mut app)
+            )
+        } else {
+            String::new()
+        };
+
+        match init {
+            Init::Empty => {
+                let local_pat = snippet_with_applicability(cx, local.pat.span, "..", &mut app);
+                let ty = collection_ty(cx, local, init_expr, collection, &mut app);
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_COLLECT,
+                    local.span.to(span),
+                    "manual implementation of `Iterator::collect`",
+                    "try",
+                    format!("let {local_pat}: {ty} = {iter}{map}.collect();"),
+                    app,
+                );
+            },
+            Init::WithCapacity => {
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_COLLECT,
+                    span,
+                    "manual implementation of `Extend::extend`",
+                    "try",
+                    format!("{ident}.extend({iter}{map});"),
+                    app,
+                );
+            },
+        }
+    }
+}
+
+/// Returns the type annotation for the collected local.
+fn collection_ty(
+    cx: &LateContext<'_>,
+    local: &Local<'_>,
+    init: &Expr<'_>,
+    collection: Symbol,
+    app: &mut Applicability,
+) -> String {
+    if let Some(ty) = local.ty {
+        return snippet_with_applicability(cx, ty.span, "..", app).into_owned();
+    }
+    let params = if matches!(collection, sym::HashMap | sym::BTreeMap) {
+        "<_, _>"
+    } else {
+        "<_>"
+    };
+    if let ExprKind::Call(func, _) = init.kind
+        && !init.span.from_expansion()
+        && let ExprKind::Path(QPath::TypeRelative(ty, _)) = func.kind
+    {
+        let ty = snippet_with_applicability(cx, ty.span, "..", app);
+        if ty.contains('<') {
+            ty.into_owned()
+        } else {
+            format!("{ty}{params}")
+        }
+    } else {
+        format!("Vec{params}")
+    }
+}
@@ -0,0 +1,101 @@
+use super::utils::{
+    collection_init, has_control_flow, is_mutated_after_loop, make_iterator_snippet, preceding_local, Init,
+};
+use super::MANUAL_PATH_COLLECT;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{is_diag_item_method, path_to_local_id, peel_blocks_with_stmt};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Expr, ExprKind, Pat, PatKind, QPath};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, Span};
+
+/// Checks for `let mut p = PathBuf::new(); for part in parts { p.push(part); }`, and the same with
+/// an `OsString`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    span: Span,
+) {
+    let Some(local) = preceding_local(cx, expr) else {
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::MUT, binding_id, ident, None) = local.pat.kind
+        && local.els.is_none()
+        && local.span.ctxt() == span.ctxt()
+        && let Some(init_expr) = local.init
+        && let Some(Init::Empty) = collection_init(cx, init_expr)
+        && let init_ty = cx.typeck_results().expr_ty(init_expr)
+        && let Some(ty_name) = [sym::PathBuf, sym::OsString]
+            .into_iter()
+            .find(|&name| is_type_diagnostic_item(cx, init_ty, name))
+        && let push = peel_blocks_with_stmt(body)
+        && let ExprKind::MethodCall(seg, recv, [value], _) = push.kind
+        && seg.ident.as_str() == "push"
+        && path_to_local_id(recv, binding_id)
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(push.hir_id)
+        && is_diag_item_method(cx, method_id, ty_name)
+        // the accumulator must not be used by the iterator or the added parts
+        && !is_local_used(cx, arg, binding_id)
+        && !is_local_used(cx, value, binding_id)
+        && !has_control_flow(value)
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let iter = make_iterator_snippet(cx, arg, &mut app);
+        let pat_snip = snippet_with_applicability(cx, pat.span, "..", &mut app);
+        let value_snip = snippet_with_applicability(cx, value.span, "..", &mut app);
+        let binding = if is_mutated_after_loop(cx, expr, binding_id) {
+            format!("mut {ident}")
+        } else {
+            ident.to_string()
+        };
+        let ty = if let Some(ty) = local.ty {
+            snippet_with_applicability(cx, ty.span, "..", &mut app).into_owned()
+        } else if let ExprKind::Call(func, _) = init_expr.kind
+            && let ExprKind::Path(QPath::TypeRelative(ty, _)) = func.kind
+        {
+            snippet_with_applicability(cx, ty.span, "..", &mut app).into_owned()
+        } else {
+            return;
+        };
+
+        // `PathBuf` can be collected from anything it can push, but `OsString` only from
+        // `OsString`, `&OsStr` and `Cow<OsStr>`
+        let (msg, sugg) = if ty_name == sym::PathBuf || is_os_str_part(cx, cx.typeck_results().expr_ty(value)) {
+            let map = if matches!(pat.kind, PatKind::Binding(_, id, _, None) if path_to_local_id(value, id)) {
+                String::new()
+            } else {
+                format!(".map(|{pat_snip}| {value_snip})")
+            };
+            (
+                "manual implementation of `Iterator::collect`",
+                format!("let {binding}: {ty} = {iter}{map}.collect();"),
+            )
+        } else {
+            let init = snippet_with_applicability(cx, init_expr.span, "..", &mut app);
+            let closure = format!("|mut {ident}, {pat_snip}| {{ {ident}.push({value_snip}); {ident} }}");
+            (
+                "manual implementation of `Iterator::fold`",
+                format!("let {binding}: {ty} = {iter}.fold({init}, {closure});"),
+            )
+        };
+        span_lint_and_sugg(cx, MANUAL_PATH_COLLECT, local.span.to(span), msg, "try", sugg, app);
+    }
+}
+
+/// Checks if an `OsString` can be collected from an iterator over values of the type.
+fn is_os_str_part<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match ty.kind() {
+        ty::Ref(_, inner, _) => is_type_diagnostic_item(cx, *inner, sym::OsStr),
+        ty::Adt(adt, substs) if cx.tcx.is_diagnostic_item(sym::Cow, adt.did()) => {
+            is_type_diagnostic_item(cx, substs.type_at(0), sym::OsStr)
+        },
+        _ => is_type_diagnostic_item(cx, ty, sym::OsString),
+    }
+}
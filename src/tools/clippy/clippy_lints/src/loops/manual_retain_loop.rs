@@ -0,0 +1,253 @@
+use super::utils::{has_control_flow, preceding_local};
+use super::MANUAL_RETAIN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{snippet_opt, snippet_with_applicability};
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{
+    eq_expr_value, get_parent_expr, higher, is_integer_const, path_to_local, path_to_local_id, peel_blocks_with_stmt,
+};
+use core::ops::ControlFlow;
+use rustc_ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BindingAnnotation, BorrowKind, Expr, ExprKind, HirId, Mutability, Pat, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span, Symbol};
+
+/// Checks for `let mut i = 0; while i < v.len() { if cond { v.remove(i); } else { i += 1; } }`.
+pub(super) fn check_while<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    condition: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+) {
+    let Some(local) = preceding_local(cx, expr) else {
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::MUT, index_id, _, None) = local.pat.kind
+        && local.els.is_none()
+        && local.span.ctxt() == expr.span.ctxt()
+        && let Some(init) = local.init
+        && is_integer_const(cx, init, 0)
+        && let ExprKind::Binary(op, index, len) = condition.kind
+        && op.node == BinOpKind::Lt
+        && path_to_local_id(index, index_id)
+        && let Some(vec) = vec_len_recv(cx, len)
+        && let Some(higher::If { cond, then, r#else: Some(els) }) = higher::If::hir(peel_blocks_with_stmt(body))
+        && let then = peel_blocks_with_stmt(then)
+        && let els = peel_blocks_with_stmt(els)
+        && let Some((method, removes_if_cond)) = match (removal(cx, then, vec, index_id), removal(cx, els, vec, index_id)) {
+            (Some(method), None) if is_increment(cx, els, index_id) => Some((method, true)),
+            (None, Some(method)) if is_increment(cx, then, index_id) => Some((method, false)),
+            _ => None,
+        }
+        && !local_used_after_expr(cx, index_id, expr)
+    {
+        emit_lint(cx, local.span.to(expr.span), vec, cond, index_id, method, removes_if_cond);
+    }
+}
+
+/// Checks for `for i in (0..v.len()).rev() { if cond { v.remove(i); } }`.
+pub(super) fn check_for<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    span: Span,
+) {
+    if let PatKind::Binding(BindingAnnotation::NONE, index_id, _, None) = pat.kind
+        && let ExprKind::MethodCall(rev, range, [], _) = arg.kind
+        && rev.ident.as_str() == "rev"
+        && let Some(higher::Range {
+            start: Some(start),
+            end: Some(len),
+            limits: RangeLimits::HalfOpen,
+        }) = higher::Range::hir(range)
+        && is_integer_const(cx, start, 0)
+        && let Some(vec) = vec_len_recv(cx, len)
+        && let Some(higher::If { cond, then, r#else: None }) = higher::If::hir(peel_blocks_with_stmt(body))
+        && let Some(method) = removal(cx, peel_blocks_with_stmt(then), vec, index_id)
+    {
+        emit_lint(cx, span, vec, cond, index_id, method, true);
+    }
+}
+
+fn emit_lint(
+    cx: &LateContext<'_>,
+    span: Span,
+    vec: &Expr<'_>,
+    mut cond: &Expr<'_>,
+    index_id: HirId,
+    method: &str,
+    mut removes_if_cond: bool,
+) {
+    if let ExprKind::Unary(UnOp::Not, inner) = cond.kind {
+        cond = inner;
+        removes_if_cond = !removes_if_cond;
+    }
+    if has_control_flow(cond) {
+        return;
+    }
+    let Some(param) = ["x", "item", "elem"]
+        .into_iter()
+        .map(Symbol::intern)
+        .find(|&name| !uses_local_named(cx, cond, name))
+    else {
+        return;
+    };
+    // `!(a == b)` is written as `a != b`, as `nonminimal_bool` would suggest
+    let negated_op = if removes_if_cond { negated_cmp(cx, cond) } else { None };
+    let Some(pred) = predicate(cx, vec, cond, index_id, param, negated_op) else {
+        return;
+    };
+    let pred = if !removes_if_cond || negated_op.is_some() {
+        pred
+    } else if matches!(cond.kind, ExprKind::Binary(..) | ExprKind::Cast(..)) {
+        format!("!({pred})")
+    } else {
+        format!("!{pred}")
+    };
+
+    let mut app = if method == "swap_remove" {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+    let vec = snippet_with_applicability(cx, vec.span, "..", &mut app);
+    span_lint_and_then(
+        cx,
+        MANUAL_RETAIN_LOOP,
+        span,
+        "manual implementation of `Vec::retain`",
+        |diag| {
+            if method == "swap_remove" {
+                diag.note("`retain` keeps the order of the remaining elements, unlike `swap_remove`");
+            }
+            diag.span_suggestion(span, "use `retain`", format!("{vec}.retain(|{param}| {pred});"), app);
+        },
+    );
+}
+
+/// Gets `v` in `v.len()`, where `v` is a `Vec`.
+fn vec_len_recv<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.name == sym::len
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), sym::Vec)
+    {
+        Some(recv)
+    } else {
+        None
+    }
+}
+
+/// Gets the name of the method if `e` is `v.remove(i)` or `v.swap_remove(i)`.
+fn removal(cx: &LateContext<'_>, e: &Expr<'_>, vec: &Expr<'_>, index_id: HirId) -> Option<&'static str> {
+    if let ExprKind::MethodCall(path, recv, [index], _) = e.kind
+        && path_to_local_id(index, index_id)
+        && eq_expr_value(cx, recv, vec)
+    {
+        match path.ident.as_str() {
+            "remove" => Some("remove"),
+            "swap_remove" => Some("swap_remove"),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Checks if `e` is `i += 1`.
+fn is_increment(cx: &LateContext<'_>, e: &Expr<'_>, index_id: HirId) -> bool {
+    matches!(e.kind, ExprKind::AssignOp(op, lhs, rhs)
+        if op.node == BinOpKind::Add && path_to_local_id(lhs, index_id) && is_integer_const(cx, rhs, 1))
+}
+
+fn uses_local_named(cx: &LateContext<'_>, e: &Expr<'_>, name: Symbol) -> bool {
+    for_each_expr(e, |e| match path_to_local(e) {
+        Some(id) if cx.tcx.hir().name(id) == name => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
+
+/// Gets the span of the operator of the comparison `cond` and the operator negating it, if the
+/// operands are totally ordered.
+fn negated_cmp(cx: &LateContext<'_>, cond: &Expr<'_>) -> Option<(Span, &'static str)> {
+    let ExprKind::Binary(op, lhs, _) = cond.kind else {
+        return None;
+    };
+    let negated = match op.node {
+        BinOpKind::Eq => "!=",
+        BinOpKind::Ne => "==",
+        BinOpKind::Lt => ">=",
+        BinOpKind::Gt => "<=",
+        BinOpKind::Le => ">",
+        BinOpKind::Ge => "<",
+        _ => return None,
+    };
+    let ord = cx.tcx.get_diagnostic_item(sym::Ord)?;
+    implements_trait(cx, cx.typeck_results().expr_ty(lhs), ord, &[]).then_some((op.span, negated))
+}
+
+/// Turns the condition into the body of the closure passed to `retain`, replacing `v[i]` by the
+/// element, and the operator of the comparison by `negated_op` if given. Returns `None` if the
+/// condition uses the index or the `Vec` in any other way.
+fn predicate(
+    cx: &LateContext<'_>,
+    vec: &Expr<'_>,
+    cond: &Expr<'_>,
+    index_id: HirId,
+    param: Symbol,
+    negated_op: Option<(Span, &str)>,
+) -> Option<String> {
+    let mut replacements = Vec::new();
+    let uses_other = for_each_expr(cond, |e| {
+        if let ExprKind::Index(base, index) = e.kind
+            && path_to_local_id(index, index_id)
+            && eq_expr_value(cx, base, vec)
+        {
+            // `&v[i]` is the element itself, and methods and fields dereference it automatically
+            let replacement = match get_parent_expr(cx, e) {
+                Some(
+                    parent @ Expr {
+                        kind: ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _),
+                        ..
+                    },
+                ) => (parent.span, param.to_string()),
+                Some(Expr {
+                    kind: ExprKind::MethodCall(_, base, ..) | ExprKind::Field(base, _),
+                    ..
+                }) if base.hir_id == e.hir_id => (e.span, param.to_string()),
+                _ => (e.span, format!("*{param}")),
+            };
+            replacements.push(replacement);
+            ControlFlow::Continue(Descend::No)
+        } else if path_to_local_id(e, index_id) || eq_expr_value(cx, e, vec) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(Descend::Yes)
+        }
+    })
+    .is_some();
+    if uses_other || replacements.is_empty() {
+        return None;
+    }
+    replacements.extend(negated_op.map(|(span, op)| (span, op.to_string())));
+    replacements.sort_by_key(|(span, _)| span.lo());
+
+    let snip = snippet_opt(cx, cond.span)?;
+    let mut pred = String::with_capacity(snip.len());
+    let mut pos = 0;
+    for (span, replacement) in replacements {
+        if span.ctxt() != cond.span.ctxt() {
+            return None;
+        }
+        let start = (span.lo() - cond.span.lo()).0 as usize;
+        pred.push_str(snip.get(pos..start)?);
+        pred.push_str(&replacement);
+        pos = (span.hi() - cond.span.lo()).0 as usize;
+    }
+    pred.push_str(snip.get(pos..)?);
+    Some(pred)
+}
@@ -0,0 +1,134 @@
+use super::utils::{has_control_flow, is_mutated_after_loop, make_iterator_snippet, preceding_local};
+use super::MANUAL_SEARCH_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_copy;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{higher, is_res_lang_ctor, is_trait_method, path_res, path_to_local_id, peel_blocks_with_stmt};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, LangItem, Mutability, Pat, PatKind, Stmt, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    span: Span,
+) {
+    let Some(local) = preceding_local(cx, expr) else {
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::MUT, found_id, found, None) = local.pat.kind
+        && local.els.is_none()
+        && local.span.ctxt() == span.ctxt()
+        && let Some(init) = local.init
+        && is_res_lang_ctor(cx, path_res(cx, init), LangItem::OptionNone)
+        && let Some(for_loop) = higher::ForLoop::hir(expr)
+        && let Some(higher::If { cond, then, r#else: None }) = higher::If::hir(peel_blocks_with_stmt(body))
+        && let ExprKind::Block(then, _) = then.kind
+        && let Some((assign, brk)) = assign_and_break(then)
+        && let ExprKind::Assign(place, some, _) = assign.kind
+        && path_to_local_id(place, found_id)
+        && let ExprKind::Call(ctor, [value]) = some.kind
+        && is_res_lang_ctor(cx, path_res(cx, ctor), LangItem::OptionSome)
+        && let ExprKind::Break(destination, None) = brk.kind
+        && destination.target_id == Ok(for_loop.loop_id)
+        && !is_local_used(cx, arg, found_id)
+        && !is_local_used(cx, cond, found_id)
+        && !has_control_flow(cond)
+        && !is_mutated_after_loop(cx, expr, found_id)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let (iter, method, param, suffix) = if let PatKind::Tuple([index_pat, item_pat], _) = pat.kind
+            && let PatKind::Binding(_, index_id, _, None) = index_pat.kind
+            && path_to_local_id(value, index_id)
+            && let ExprKind::MethodCall(seg, recv, [], _) = arg.kind
+            && seg.ident.name == sym!(enumerate)
+            && is_trait_method(cx, arg, sym::Iterator)
+            && !is_local_used(cx, cond, index_id)
+        {
+            // `for (i, x) in iter.enumerate() { if pred { found = Some(i); break; } }`
+            (
+                Sugg::hir_with_applicability(cx, recv, "..", &mut app)
+                    .maybe_par()
+                    .to_string(),
+                "position",
+                snippet_with_applicability(cx, item_pat.span, "..", &mut app).into_owned(),
+                "",
+            )
+        } else if let PatKind::Binding(BindingAnnotation::NONE, item_id, _, None)
+            | PatKind::Ref(
+                &Pat {
+                    kind: PatKind::Binding(BindingAnnotation::NONE, item_id, _, None),
+                    ..
+                },
+                Mutability::Not,
+            ) = pat.kind
+            && path_to_local_id(value, item_id)
+        {
+            // `for x in iter { if pred { found = Some(x); break; } }`
+            let iter = make_iterator_snippet(cx, arg, &mut app);
+            let pat_snip = snippet_with_applicability(cx, pat.span, "..", &mut app);
+            let is_ref = matches!(pat.kind, PatKind::Ref(..));
+            if is_copy(cx, cx.typeck_results().expr_ty(value)) {
+                (iter, "find", format!("&{pat_snip}"), if is_ref { ".copied()" } else { "" })
+            } else {
+                app = Applicability::MaybeIncorrect;
+                (iter, "find", pat_snip.into_owned(), "")
+            }
+        } else {
+            return;
+        };
+
+        let ty = local.ty.map_or(String::new(), |ty| {
+            format!(": {}", snippet_with_applicability(cx, ty.span, "..", &mut app))
+        });
+        let cond = snippet_with_applicability(cx, cond.span, "..", &mut app);
+        let lint_span = local.span.to(span);
+        span_lint_and_then(
+            cx,
+            MANUAL_SEARCH_LOOP,
+            lint_span,
+            &format!("manual implementation of `Iterator::{method}`"),
+            |diag| {
+                if app == Applicability::MaybeIncorrect {
+                    diag.note("you may need to dereference some variables");
+                }
+                diag.span_suggestion(
+                    lint_span,
+                    "replace with an iterator",
+                    format!("let {found}{ty} = {iter}.{method}(|{param}| {cond}){suffix};"),
+                    app,
+                );
+            },
+        );
+    }
+}
+
+/// Gets the assignment and the `break` in `{ found = Some(x); break; }`.
+fn assign_and_break<'tcx>(block: &'tcx Block<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    match (block.stmts, block.expr) {
+        (
+            [Stmt {
+                kind: StmtKind::Semi(assign),
+                ..
+            }, Stmt {
+                kind: StmtKind::Semi(brk),
+                ..
+            }],
+            None,
+        )
+        | (
+            [Stmt {
+                kind: StmtKind::Semi(assign),
+                ..
+            }],
+            Some(brk),
+        ) => Some((assign, brk)),
+        _ => None,
+    }
+}
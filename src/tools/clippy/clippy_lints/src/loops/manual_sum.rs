@@ -0,0 +1,112 @@
+use super::utils::{has_control_flow, is_mutated_after_loop, make_iterator_snippet, preceding_local};
+use super::MANUAL_SUM;
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{path_to_local_id, peel_blocks_with_stmt};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BindingAnnotation, Expr, ExprKind, Mutability, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+    span: Span,
+) {
+    let Some(local) = preceding_local(cx, expr) else {
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::MUT, acc_id, acc, None) = local.pat.kind
+        && local.els.is_none()
+        && local.span.ctxt() == span.ctxt()
+        && let Some(init) = local.init
+        && let ExprKind::AssignOp(op, lhs, value) = peel_blocks_with_stmt(body).kind
+        && path_to_local_id(lhs, acc_id)
+        && let Some((method, identity)) = match op.node {
+            BinOpKind::Add => Some(("sum", 0)),
+            BinOpKind::Mul => Some(("product", 1)),
+            _ => None,
+        }
+        && let acc_ty = cx.typeck_results().pat_ty(local.pat)
+        && acc_ty.is_numeric()
+        && cx.typeck_results().expr_ty(value).peel_refs() == acc_ty
+        && is_constant_value(cx, init, identity)
+        // the accumulated value is computed from the item, a constant would count the items
+        && pat_bindings_used(cx, pat, value)
+        && !is_local_used(cx, arg, acc_id)
+        && !is_local_used(cx, value, acc_id)
+        && !has_control_flow(value)
+        && !is_mutated_after_loop(cx, expr, acc_id)
+    {
+        let mut app = if acc_ty.is_floating_point() {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        let iter = make_iterator_snippet(cx, arg, &mut app);
+        // `Sum` and `Product` are implemented for the references to numbers as well
+        let is_item = match pat.kind {
+            PatKind::Binding(BindingAnnotation::NONE, id, _, None)
+            | PatKind::Ref(
+                &Pat {
+                    kind: PatKind::Binding(BindingAnnotation::NONE, id, _, None),
+                    ..
+                },
+                Mutability::Not,
+            ) => path_to_local_id(value, id),
+            _ => false,
+        };
+        let map = if is_item {
+            String::new()
+        } else {
+            format!(
+                ".map(|{}| {})",
+                snippet_with_applicability(cx, pat.span, "..", &mut app),
+                snippet_with_applicability(cx, value.span, "..", &mut app),
+            )
+        };
+        let lint_span = local.span.to(span);
+        span_lint_and_then(
+            cx,
+            MANUAL_SUM,
+            lint_span,
+            &format!("manual implementation of `Iterator::{method}`"),
+            |diag| {
+                if acc_ty.is_floating_point() {
+                    diag.note(format!(
+                        "`{method}` doesn't guarantee to combine floating-point numbers in the order of the loop, \
+                        which may change the result slightly"
+                    ));
+                }
+                diag.span_suggestion(
+                    lint_span,
+                    "replace with an iterator",
+                    format!("let {acc}: {acc_ty} = {iter}{map}.{method}();"),
+                    app,
+                );
+            },
+        );
+    }
+}
+
+/// Checks if `e` is the constant `value`, as an integer or a floating-point number.
+fn is_constant_value(cx: &LateContext<'_>, e: &Expr<'_>, value: u8) -> bool {
+    match constant(cx, cx.typeck_results(), e) {
+        Some(Constant::Int(v)) => v == u128::from(value),
+        Some(Constant::F32(v)) => f64::from(v).to_bits() == f64::from(value).to_bits(),
+        Some(Constant::F64(v)) => v.to_bits() == f64::from(value).to_bits(),
+        _ => false,
+    }
+}
+
+/// Checks if any binding of `pat` is used in `e`.
+fn pat_bindings_used(cx: &LateContext<'_>, pat: &Pat<'_>, e: &Expr<'_>) -> bool {
+    let mut used = false;
+    pat.each_binding_or_first(&mut |_, id, _, _| used |= is_local_used(cx, e, id));
+    used
+}
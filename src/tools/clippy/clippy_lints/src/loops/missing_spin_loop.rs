@@ -30,27 +30,36 @@ fn unpack_cond<'tcx>(cond: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
     }
 }
 
-pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+/// Checks for `while` loops with an empty body, waiting on an `AtomicBool`.
+pub(super) fn is_empty_spin_loop<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'_>, body: &'tcx Expr<'_>) -> bool {
     if_chain! {
         if let ExprKind::Block(Block { stmts: [], expr: None, ..}, _) = body.kind;
         if let ExprKind::MethodCall(method, callee, ..) = unpack_cond(cond).kind;
         if [sym::load, sym::compare_exchange, sym::compare_exchange_weak].contains(&method.ident.name);
         if let ty::Adt(def, _substs) = cx.typeck_results().expr_ty(callee).kind();
-        if cx.tcx.is_diagnostic_item(sym::AtomicBool, def.did());
         then {
-            span_lint_and_sugg(
-                cx,
-                MISSING_SPIN_LOOP,
-                body.span,
-                "busy-waiting loop should at least have a spin loop hint",
-                "try this",
-                (if is_no_std_crate(cx) {
-                    "{ core::hint::spin_loop() }"
-                } else {
-                    "{ std::hint::spin_loop() }"
-                }).into(),
-                Applicability::MachineApplicable
-            );
+            cx.tcx.is_diagnostic_item(sym::AtomicBool, def.did())
+        } else {
+            false
         }
     }
 }
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    if is_empty_spin_loop(cx, cond, body) {
+        span_lint_and_sugg(
+            cx,
+            MISSING_SPIN_LOOP,
+            body.span,
+            "busy-waiting loop should at least have a spin loop hint",
+            "try this",
+            (if is_no_std_crate(cx) {
+                "{ core::hint::spin_loop() }"
+            } else {
+                "{ std::hint::spin_loop() }"
+            })
+            .into(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
@@ -1,23 +1,35 @@
+mod busy_wait_loop;
+mod cartesian_product_loop;
 mod empty_loop;
 mod explicit_counter_loop;
 mod explicit_into_iter_loop;
 mod explicit_iter_loop;
+mod extend_without_reserve;
 mod for_kv_map;
 mod iter_next_loop;
+mod len_mutated_in_loop;
+mod loop_invariant_computation;
+mod manual_collect;
 mod manual_find;
 mod manual_flatten;
 mod manual_memcpy;
+mod manual_path_collect;
+mod manual_retain_loop;
+mod manual_search_loop;
+mod manual_sum;
 mod manual_while_let_some;
 mod missing_spin_loop;
 mod mut_range_bound;
 mod needless_range_loop;
 mod never_loop;
 mod same_item_push;
+mod sequential_await_in_loop;
 mod single_element_loop;
 mod utils;
 mod while_immutable_condition;
 mod while_let_loop;
 mod while_let_on_iterator;
+mod while_range_loop;
 
 use clippy_utils::higher;
 use clippy_utils::msrvs::Msrv;
@@ -33,7 +45,8 @@
     /// slices that could be optimized by having a memcpy.
     ///
     /// ### Why is this bad?
-    /// It is not as fast as a memcpy.
+    /// It is not as fast as a memcpy. `copy_from_slice` is suggested for `Copy` items, and
+    /// `clone_from_slice` for the others.
     ///
     /// ### Example
     /// ```rust
@@ -59,7 +72,8 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for looping over the range of `0..len` of some
-    /// collection just to get the values by index.
+    /// collection just to get the values by index. Two collections
+    /// indexed in parallel can be iterated together with `zip`.
     ///
     /// ### Why is this bad?
     /// Just iterating the collection itself makes the intent
@@ -417,12 +431,13 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks whether a for loop is being used to push a constant
-    /// value into a Vec.
+    /// value, or a value which doesn't change in the loop, into a Vec.
     ///
     /// ### Why is this bad?
     /// This kind of operation can be expressed more succinctly with
     /// `vec![item; SIZE]` or `vec.resize(NEW_SIZE, item)` and using these alternatives may also
-    /// have better performance.
+    /// have better performance. Values which aren't `Clone` can be created by
+    /// `vec.extend(std::iter::repeat_with(|| item).take(SIZE))`.
     ///
     /// ### Example
     /// ```rust
@@ -607,6 +622,414 @@
     "checking for emptiness of a `Vec` in the loop condition and popping an element in the body"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an empty `Vec`, `HashMap`, `BTreeMap` or `HashSet` which is directly filled
+    /// by a `for` loop that only pushes or inserts into it.
+    ///
+    /// ### Why is this bad?
+    /// The collection can be created with `Iterator::collect` instead, which is shorter and can
+    /// reserve the needed capacity up front. When the collection is created with `with_capacity`,
+    /// `Extend::extend` can be used instead.
+    ///
+    /// ### Known problems
+    /// The suggested closure can change the meaning of the code when the added value borrows
+    /// from the loop pattern, or when the collection's type can no longer be inferred.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut squares = Vec::new();
+    /// for i in 0..10 {
+    ///     squares.push(i * i);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let squares: Vec<_> = (0..10).map(|i| i * i).collect();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_COLLECT,
+    pedantic,
+    "creating an empty collection and filling it in a loop instead of collecting an iterator"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an empty `Vec` or `String` which is filled by a `for` loop adding a known number
+    /// of elements in each iteration, while the number of iterations is known before the loop.
+    ///
+    /// ### Why is this bad?
+    /// The collection has to grow its allocation repeatedly while it's filled, even though the
+    /// needed capacity can be computed up front.
+    ///
+    /// Loops which may skip adding to the collection, through an `if`, `continue`, `break`,
+    /// `return` or `?`, aren't linted. Neither are calls to `extend` with an iterator whose
+    /// length is only known at runtime, since `extend` already reserves its size hint.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let points = [(1, 2), (3, 4)];
+    /// let mut coords = Vec::new();
+    /// for (x, y) in points {
+    ///     coords.extend([x, y]);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let points = [(1, 2), (3, 4)];
+    /// let mut coords = Vec::with_capacity(points.len() * 2);
+    /// for (x, y) in points {
+    ///     coords.extend([x, y]);
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub EXTEND_WITHOUT_RESERVE,
+    pedantic,
+    "filling an empty collection in a loop without reserving the known capacity"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a local set to `None` before a `for` loop, which is only assigned the first
+    /// item, or its index, matching a condition before breaking out of the loop.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::find` and `Iterator::position` do the same and make the intent clearer.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let names = ["a", "b"];
+    /// let mut found = None;
+    /// for (i, name) in names.iter().enumerate() {
+    ///     if name.len() > 1 {
+    ///         found = Some(i);
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let names = ["a", "b"];
+    /// let found = names.iter().position(|name| name.len() > 1);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_SEARCH_LOOP,
+    complexity,
+    "searching an item in a loop which breaks once it's found instead of using `find` or `position`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `for` loop whose body is only another `for` loop, over an iterator which
+    /// doesn't depend on the item of the outer loop.
+    ///
+    /// ### Why is this bad?
+    /// The loops iterate over the cartesian product of both iterators, which can be written as
+    /// a single iterator over the pairs with `flat_map`, or with the `iproduct!` macro of the
+    /// `itertools` crate.
+    ///
+    /// ### Example
+    /// ```rust
+    /// for x in 0..3 {
+    ///     for y in 0..3 {
+    ///         println!("{x}, {y}");
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// for (x, y) in (0..3).flat_map(|x| (0..3).map(move |y| (x, y))) {
+    ///     println!("{x}, {y}");
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CARTESIAN_PRODUCT_LOOP,
+    pedantic,
+    "nested loops over independent iterators instead of a single loop over their product"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `for` loop which only adds (or multiplies) a value computed from each item
+    /// to a mutable accumulator, initialized to `0` (or `1`) right before the loop.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::sum` and `Iterator::product` express the intent more clearly, and don't need
+    /// the accumulator to be mutable.
+    ///
+    /// ### Known problems
+    /// For floating-point numbers, `sum` and `product` don't guarantee to combine the items in
+    /// the order of the loop, which can change the result slightly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let prices = [3, 5, 8];
+    /// let mut total = 0;
+    /// for price in prices {
+    ///     total += price * 2;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let prices = [3, 5, 8];
+    /// let total: i32 = prices.into_iter().map(|price| price * 2).sum();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_SUM,
+    pedantic,
+    "accumulating a sum or a product in a loop instead of using `sum` or `product`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for expensive computations in the body of a loop which don't depend on the loop:
+    /// `Regex::new`, `format!`, `collect` and `env::var` whose arguments are neither declared nor
+    /// changed in the loop, and whose result is only borrowed.
+    ///
+    /// ### Why is this bad?
+    /// The same value is computed again on every iteration. Computing it once before the loop
+    /// avoids compiling the regex, allocating or reading the environment over and over.
+    ///
+    /// ### Known problems
+    /// The environment may be changed by other threads while the loop runs, reading it once
+    /// misses these changes.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let lines = ["a1", "b"];
+    /// # let prefix = "a";
+    /// for line in lines {
+    ///     if line.starts_with(&format!("{prefix}1")) {
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let lines = ["a1", "b"];
+    /// # let prefix = "a";
+    /// let start = format!("{prefix}1");
+    /// for line in lines {
+    ///     if line.starts_with(&start) {
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub LOOP_INVARIANT_COMPUTATION,
+    pedantic,
+    "computing a value which doesn't depend on the loop in every iteration"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `while` loops over a counter declared right before them, which is compared to
+    /// an upper bound and incremented at the end of every iteration.
+    ///
+    /// ### Why is this bad?
+    /// A `for` loop over a range states the bounds of the counter in one place, and can't
+    /// forget to increment it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// let mut i = 0;
+    /// while i < v.len() {
+    ///     println!("{}", v[i]);
+    ///     i += 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// for i in 0..v.len() {
+    ///     println!("{}", v[i]);
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub WHILE_RANGE_LOOP,
+    pedantic,
+    "a `while` loop incrementing a counter up to a bound, which could be a `for` loop over a range"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops waiting for an atomic to change, which neither give a spin loop hint nor
+    /// call anything which may block, e.g. `while !flag.load(Ordering::Acquire) {}` or
+    /// `loop { if flag.load(Ordering::Acquire) { break; } }`.
+    ///
+    /// ### Why is this bad?
+    /// The loop keeps the processor busy while it waits. `std::hint::spin_loop()` lets the
+    /// processor save power and yield to other hyper-threads, and parking the thread or waiting
+    /// on a `Condvar` doesn't spin at all.
+    ///
+    /// ### Known problems
+    /// Calls to functions outside of the standard library are assumed to block.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # let ready = AtomicBool::new(false);
+    /// let mut spins = 0;
+    /// while !ready.load(Ordering::Acquire) {
+    ///     spins += 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # let ready = AtomicBool::new(false);
+    /// let mut spins = 0;
+    /// while !ready.load(Ordering::Acquire) {
+    ///     spins += 1;
+    ///     std::hint::spin_loop();
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub BUSY_WAIT_LOOP,
+    restriction,
+    "a loop waiting for an atomic to change without a spin loop hint or a blocking call"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops removing the elements of a `Vec` which match a condition one by one, by
+    /// their index.
+    ///
+    /// ### Why is this bad?
+    /// `Vec::remove` shifts all the following elements, which makes the loop quadratic, and
+    /// keeping the index right while the elements move is easy to get wrong. `Vec::retain` does
+    /// the same in a single pass.
+    ///
+    /// ### Known problems
+    /// Removing with `swap_remove` changes the order of the remaining elements, which `retain`
+    /// doesn't.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// let mut i = 0;
+    /// while i < v.len() {
+    ///     if v[i] % 2 == 0 {
+    ///         v.remove(i);
+    ///     } else {
+    ///         i += 1;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// v.retain(|x| !(*x % 2 == 0));
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_RETAIN_LOOP,
+    complexity,
+    "removing the elements of a `Vec` matching a condition by their index, instead of using `retain`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops bounded by the length of a `Vec` or a `VecDeque`, which add or remove
+    /// elements of it in their body:
+    /// * `while i < v.len()` loops adding elements, or removing elements while `i` is
+    ///   incremented in every iteration.
+    /// * `for i in 0..v.len()` loops removing elements.
+    ///
+    /// ### Why is this bad?
+    /// The condition of a `while` loop reads the length again in every iteration, so the
+    /// added elements are visited as well, and the loop may never end. Removing an element moves
+    /// the next one to its index, which is then skipped. The range of a `for` loop is only
+    /// evaluated once, so indexing with it after removing elements may go out of bounds.
+    ///
+    /// ### Known problems
+    /// Visiting the added elements may be intended, e.g. when the `Vec` is used as a work list.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # let mut v = vec![1, 2, 3];
+    /// for i in 0..v.len() {
+    ///     if v[i] == 2 {
+    ///         v.remove(i);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// v.retain(|&x| x != 2);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub LEN_MUTATED_IN_LOOP,
+    pedantic,
+    "a loop bounded by the length of a collection which is changed in the loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an empty `PathBuf` or `OsString` which is directly built by a `for` loop that
+    /// only pushes the parts of an iterator into it.
+    ///
+    /// ### Why is this bad?
+    /// `PathBuf` can be collected from an iterator of path components, and `OsString` from an
+    /// iterator of `OsString`s or `&OsStr`s, which is shorter and doesn't need a mutable binding.
+    /// Other parts can be pushed into an `OsString` with `Iterator::fold`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// let parts = ["usr", "local", "bin"];
+    /// let mut path = PathBuf::new();
+    /// for part in parts {
+    ///     path.push(part);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// let parts = ["usr", "local", "bin"];
+    /// let path: PathBuf = parts.into_iter().collect();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_PATH_COLLECT,
+    pedantic,
+    "creating an empty `PathBuf` or `OsString` and pushing into it in a loop instead of collecting an iterator"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops in async code whose body awaits a future built from the loop item,
+    /// which doesn't depend on the previous iterations.
+    ///
+    /// ### Why is this bad?
+    /// Each future only starts once the previous one has completed. Independent futures, such as
+    /// requests to a server, can run concurrently, e.g. with `futures::future::join_all` or a
+    /// stream with `buffered`, which is often much faster.
+    ///
+    /// ### Known problems
+    /// The futures may still have to run one after another, e.g. to limit the load on a server or
+    /// because they share state through interior mutability.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # async fn fetch(url: &str) -> String { String::new() }
+    /// # async fn f(urls: &[&str]) {
+    /// let mut pages = Vec::new();
+    /// for url in urls {
+    ///     pages.push(fetch(url).await);
+    /// }
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let pages = futures::future::join_all(urls.iter().map(|url| fetch(url))).await;
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SEQUENTIAL_AWAIT_IN_LOOP,
+    pedantic,
+    "awaiting independent futures one after another in a loop"
+}
+
 pub struct Loops {
     msrv: Msrv,
 }
@@ -634,7 +1057,19 @@ pub fn new(msrv: Msrv) -> Self {
     SINGLE_ELEMENT_LOOP,
     MISSING_SPIN_LOOP,
     MANUAL_FIND,
-    MANUAL_WHILE_LET_SOME
+    MANUAL_WHILE_LET_SOME,
+    MANUAL_COLLECT,
+    EXTEND_WITHOUT_RESERVE,
+    MANUAL_SEARCH_LOOP,
+    CARTESIAN_PRODUCT_LOOP,
+    MANUAL_SUM,
+    LOOP_INVARIANT_COMPUTATION,
+    WHILE_RANGE_LOOP,
+    BUSY_WAIT_LOOP,
+    MANUAL_RETAIN_LOOP,
+    LEN_MUTATED_IN_LOOP,
+    MANUAL_PATH_COLLECT,
+    SEQUENTIAL_AWAIT_IN_LOOP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -673,10 +1108,12 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         // check for `loop { if let {} else break }` that could be `while let`
         // (also matches an explicit "match" instead of "if let")
         // (even if the "match" or "if let" is used for declaration)
-        if let ExprKind::Loop(block, _, LoopSource::Loop, _) = expr.kind {
+        if let ExprKind::Loop(block, _, LoopSource::Loop, span) = expr.kind {
             // also check for empty `loop {}` statements, skipping those in #[panic_handler]
             empty_loop::check(cx, expr, block);
             while_let_loop::check(cx, expr, block);
+            loop_invariant_computation::check(cx, expr, block);
+            busy_wait_loop::check(cx, expr, None, block, span);
         }
 
         while_let_on_iterator::check(cx, expr);
@@ -685,6 +1122,14 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             while_immutable_condition::check(cx, condition, body);
             missing_spin_loop::check(cx, condition, body);
             manual_while_let_some::check(cx, condition, body, span);
+            loop_invariant_computation::check(cx, expr, body);
+            while_range_loop::check(cx, expr, condition, body);
+            manual_retain_loop::check_while(cx, expr, condition, body);
+            len_mutated_in_loop::check_while(cx, condition, body);
+            // empty loops waiting on an `AtomicBool` are linted by `missing_spin_loop`
+            if !missing_spin_loop::is_empty_spin_loop(cx, condition, body) {
+                busy_wait_loop::check(cx, expr, Some(condition), body, span);
+            }
         }
     }
 
@@ -713,6 +1158,16 @@ fn check_for_loop<'tcx>(
         same_item_push::check(cx, pat, arg, body, expr);
         manual_flatten::check(cx, pat, arg, body, span);
         manual_find::check(cx, pat, arg, body, span, expr);
+        manual_collect::check(cx, pat, arg, body, expr, span);
+        manual_path_collect::check(cx, pat, arg, body, expr, span);
+        manual_search_loop::check(cx, pat, arg, body, expr, span);
+        cartesian_product_loop::check(cx, pat, arg, body, expr, span);
+        manual_sum::check(cx, pat, arg, body, expr, span);
+        loop_invariant_computation::check(cx, expr, body);
+        manual_retain_loop::check_for(cx, pat, arg, body, span);
+        len_mutated_in_loop::check_for(cx, arg, body);
+        sequential_await_in_loop::check(cx, pat, body, expr);
+        extend_without_reserve::check(cx, arg, body, expr, span);
     }
 
     fn check_for_loop_arg(&self, cx: &LateContext<'_>, _: &Pat<'_>, arg: &Expr<'_>) {
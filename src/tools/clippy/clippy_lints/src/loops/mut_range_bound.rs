@@ -138,7 +138,7 @@ pub fn is_found(cx: &LateContext<'_>, hir_id: HirId) -> bool {
             break_after_expr: false,
         };
 
-        get_enclosing_block(cx, hir_id).map_or(false, |block| {
+        get_enclosing_block(cx, hir_id).is_some_and(|block| {
             visitor.visit_block(block);
             visitor.break_after_expr
         })
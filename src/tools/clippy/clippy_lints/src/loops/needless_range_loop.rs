@@ -56,13 +56,8 @@ pub(super) fn check<'tcx>(
                     .expect("already checked that we have exactly 1 element");
 
                 // ensure that the indexed variable was declared before the loop, see #601
-                if let Some(indexed_extent) = indexed_extent {
-                    let parent_def_id = cx.tcx.hir().get_parent_item(expr.hir_id);
-                    let region_scope_tree = cx.tcx.region_scope_tree(parent_def_id);
-                    let pat_extent = region_scope_tree.var_scope(pat.hir_id.local_id).unwrap();
-                    if region_scope_tree.is_subscope_of(indexed_extent, pat_extent) {
-                        return;
-                    }
+                if !is_declared_before_loop(cx, expr, pat, indexed_extent) {
+                    return;
                 }
 
                 // don't lint if the container that is indexed does not have .iter() method
@@ -181,11 +176,98 @@ pub(super) fn check<'tcx>(
                         },
                     );
                 }
+            } else if visitor.indexed_indirectly.is_empty() && visitor.indexed_directly.len() == 2 {
+                check_zip(cx, visitor, pat, ident.name, start, *end, limits, arg, expr);
             }
         }
     }
 }
 
+/// Checks for two sequences indexed in parallel, e.g. `for i in 0..a.len() { f(a[i], b[i]) }`,
+/// which can be iterated with `zip`.
+#[expect(clippy::too_many_arguments)]
+fn check_zip<'tcx>(
+    cx: &LateContext<'tcx>,
+    visitor: VarVisitor<'_, 'tcx>,
+    pat: &'tcx Pat<'_>,
+    var: Symbol,
+    start: &Expr<'_>,
+    end: Option<&Expr<'_>>,
+    limits: ast::RangeLimits,
+    arg: &Expr<'_>,
+    expr: &Expr<'_>,
+) {
+    if visitor.nonindex || !is_integer_const(cx, start, 0) || limits != ast::RangeLimits::HalfOpen {
+        return;
+    }
+    let Some(end) = end else {
+        return;
+    };
+    let mut indexed: Vec<_> = visitor.indexed_directly.into_iter().collect();
+    if indexed.iter().any(|&(name, (extent, ty))| {
+        !is_declared_before_loop(cx, expr, pat, extent)
+            || has_iter_method(cx, ty).is_none()
+            || visitor.referenced.contains(&name)
+    }) {
+        return;
+    }
+    // the sequence whose length bounds the loop is iterated, the other one is zipped to it
+    let Some(first) = indexed.iter().position(|&(name, _)| is_len_call(end, name)) else {
+        return;
+    };
+    indexed.swap(0, first);
+    let [(first, _), (second, (_, second_ty))] = indexed[..] else {
+        return;
+    };
+
+    let method = if visitor.indexed_mut.contains(&first) {
+        "iter_mut"
+    } else {
+        "iter"
+    };
+    let zipped = match (second_ty.kind(), visitor.indexed_mut.contains(&second)) {
+        (ty::Ref(..), true) => format!("{second}.iter_mut()"),
+        (ty::Ref(..), false) => format!("{second}.iter()"),
+        (_, true) => format!("&mut {second}"),
+        (_, false) => format!("&{second}"),
+    };
+    span_lint_and_then(
+        cx,
+        NEEDLESS_RANGE_LOOP,
+        arg.span,
+        &format!("the loop variable `{var}` is only used to index `{first}` and `{second}`"),
+        |diag| {
+            diag.note(format!(
+                "`zip` stops at the end of the shorter one, while indexing panics if `{second}` is shorter than `{first}`"
+            ));
+            multispan_sugg(
+                diag,
+                "consider zipping the iterators",
+                vec![
+                    (pat.span, format!("(<{first}_item>, <{second}_item>)")),
+                    (arg.span, format!("{first}.{method}().zip({zipped})")),
+                ],
+            );
+        },
+    );
+}
+
+fn is_declared_before_loop(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    pat: &Pat<'_>,
+    indexed_extent: Option<region::Scope>,
+) -> bool {
+    if let Some(indexed_extent) = indexed_extent {
+        let parent_def_id = cx.tcx.hir().get_parent_item(expr.hir_id);
+        let region_scope_tree = cx.tcx.region_scope_tree(parent_def_id);
+        let pat_extent = region_scope_tree.var_scope(pat.hir_id.local_id).unwrap();
+        !region_scope_tree.is_subscope_of(indexed_extent, pat_extent)
+    } else {
+        true
+    }
+}
+
 fn is_len_call(expr: &Expr<'_>, var: Symbol) -> bool {
     if_chain! {
         if let ExprKind::MethodCall(method, recv, [], _) = expr.kind;
@@ -1,9 +1,17 @@
+use super::utils::collection_init;
 use super::SAME_ITEM_PUSH;
+use clippy_utils::consts::{constant_full_int, FullInt};
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::path_to_local;
+use clippy_utils::side_effects::{expr_has_side_effects, expr_side_effects};
 use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use clippy_utils::{get_enclosing_block, higher, is_integer_const, path_to_local};
+use core::ops::ControlFlow;
 use if_chain::if_chain;
+use rustc_ast::RangeLimits;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
@@ -18,25 +26,10 @@
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     pat: &'tcx Pat<'_>,
-    _: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
     body: &'tcx Expr<'_>,
-    _: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
 ) {
-    fn emit_lint(cx: &LateContext<'_>, vec: &Expr<'_>, pushed_item: &Expr<'_>, ctxt: SyntaxContext) {
-        let mut app = Applicability::Unspecified;
-        let vec_str = snippet_with_context(cx, vec.span, ctxt, "", &mut app).0;
-        let item_str = snippet_with_context(cx, pushed_item.span, ctxt, "", &mut app).0;
-
-        span_lint_and_help(
-            cx,
-            SAME_ITEM_PUSH,
-            vec.span,
-            "it looks like the same item is being pushed into this Vec",
-            None,
-            &format!("try using vec![{item_str};SIZE] or {vec_str}.resize(NEW_SIZE, {item_str})"),
-        );
-    }
-
     if !matches!(pat.kind, PatKind::Wild) {
         return;
     }
@@ -47,59 +40,166 @@ fn emit_lint(cx: &LateContext<'_>, vec: &Expr<'_>, pushed_item: &Expr<'_>, ctxt:
     if_chain! {
         if same_item_push_visitor.should_lint();
         if let Some((vec, pushed_item, ctxt)) = same_item_push_visitor.vec_push;
-        let vec_ty = cx.typeck_results().expr_ty(vec);
-        let ty = vec_ty.walk().nth(1).unwrap().expect_ty();
-        if cx
-            .tcx
-            .lang_items()
-            .clone_trait()
-            .map_or(false, |id| implements_trait(cx, ty, id, &[]));
+        if is_invariant(cx, body, pushed_item);
         then {
-            // Make sure that the push does not involve possibly mutating values
-            match pushed_item.kind {
-                ExprKind::Path(ref qpath) => {
-                    match cx.qpath_res(qpath, pushed_item.hir_id) {
-                        // immutable bindings that are initialized with literal or constant
-                        Res::Local(hir_id) => {
-                            let node = cx.tcx.hir().get(hir_id);
-                            if_chain! {
-                                if let Node::Pat(pat) = node;
-                                if let PatKind::Binding(bind_ann, ..) = pat.kind;
-                                if !matches!(bind_ann, BindingAnnotation(_, Mutability::Mut));
-                                let parent_node = cx.tcx.hir().parent_id(hir_id);
-                                if let Some(Node::Local(parent_let_expr)) = cx.tcx.hir().find(parent_node);
-                                if let Some(init) = parent_let_expr.init;
-                                then {
-                                    match init.kind {
-                                        // immutable bindings that are initialized with literal
-                                        ExprKind::Lit(..) => emit_lint(cx, vec, pushed_item, ctxt),
-                                        // immutable bindings that are initialized with constant
-                                        ExprKind::Path(ref path) => {
-                                            if let Res::Def(DefKind::Const, ..) = cx.qpath_res(path, init.hir_id) {
-                                                emit_lint(cx, vec, pushed_item, ctxt);
-                                            }
-                                        }
-                                        _ => {},
-                                    }
-                                }
-                            }
-                        },
-                        // constant
-                        Res::Def(DefKind::Const, ..) => emit_lint(cx, vec, pushed_item, ctxt),
-                        _ => {},
-                    }
+            let vec_ty = cx.typeck_results().expr_ty(vec);
+            let ty = vec_ty.walk().nth(1).unwrap().expect_ty();
+            let is_clone = cx
+                .tcx
+                .lang_items()
+                .clone_trait()
+                .is_some_and(|id| implements_trait(cx, ty, id, &[]));
+
+            let mut app = Applicability::Unspecified;
+            let vec_str = snippet_with_context(cx, vec.span, ctxt, "", &mut app).0;
+            let item_str = snippet_with_context(cx, pushed_item.span, ctxt, "", &mut app).0;
+            let help = match iteration_count(cx, arg, ctxt) {
+                Some(count) if !is_clone => {
+                    format!("try using `{vec_str}.extend(std::iter::repeat_with(|| {item_str}).take({count}))`")
+                },
+                Some(count) if path_to_local(vec).is_some_and(|id| is_empty_before_loop(cx, id, expr)) => {
+                    format!("try initializing the Vec with `vec![{item_str}; {count}]` instead")
                 },
-                ExprKind::Lit(..) => emit_lint(cx, vec, pushed_item, ctxt),
-                _ => {},
+                Some(count) => format!("try using `{vec_str}.resize({vec_str}.len() + {count}, {item_str})`"),
+                None if is_clone => format!("try using vec![{item_str};SIZE] or {vec_str}.resize(NEW_SIZE, {item_str})"),
+                None => return,
+            };
+            span_lint_and_help(
+                cx,
+                SAME_ITEM_PUSH,
+                vec.span,
+                "it looks like the same item is being pushed into this Vec",
+                None,
+                &help,
+            );
+        }
+    }
+}
+
+/// Checks if the pushed item is the same in every iteration.
+fn is_invariant<'tcx>(cx: &LateContext<'tcx>, body: &Expr<'_>, pushed_item: &'tcx Expr<'_>) -> bool {
+    let is_constant = match pushed_item.kind {
+        ExprKind::Lit(..) => true,
+        ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, pushed_item.hir_id) {
+            Res::Local(hir_id) => is_constant_binding(cx, hir_id),
+            Res::Def(DefKind::Const, ..) => true,
+            _ => false,
+        },
+        _ => false,
+    };
+    if is_constant {
+        return true;
+    }
+
+    // Evaluating the item again has to give the same value: it doesn't do anything besides
+    // allocating, and the locals it uses aren't declared or changed by the loop.
+    let effects = expr_side_effects(cx, pushed_item);
+    if effects.mutates || effects.does_io || effects.control_flow || effects.calls_unknown {
+        return false;
+    }
+    let Some(mutated) = mutated_variables(body, cx) else {
+        return false;
+    };
+    for_each_expr(pushed_item, |e| match path_to_local(e) {
+        Some(id) if mutated.contains(&id) || body.span.contains(cx.tcx.hir().span(id)) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_none()
+}
+
+/// Checks for immutable bindings that are initialized with a literal or a constant.
+fn is_constant_binding(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let node = cx.tcx.hir().get(hir_id);
+    if_chain! {
+        if let Node::Pat(pat) = node;
+        if let PatKind::Binding(bind_ann, ..) = pat.kind;
+        if !matches!(bind_ann, BindingAnnotation(_, Mutability::Mut));
+        let parent_node = cx.tcx.hir().parent_id(hir_id);
+        if let Some(Node::Local(parent_let_expr)) = cx.tcx.hir().find(parent_node);
+        if let Some(init) = parent_let_expr.init;
+        then {
+            match init.kind {
+                ExprKind::Lit(..) => true,
+                ExprKind::Path(ref path) => matches!(cx.qpath_res(path, init.hir_id), Res::Def(DefKind::Const, ..)),
+                _ => false,
+            }
+        } else {
+            false
+        }
+    }
+}
+
+/// Gets the number of iterations of a loop over a range, as a `usize`.
+fn iteration_count(cx: &LateContext<'_>, arg: &Expr<'_>, ctxt: SyntaxContext) -> Option<String> {
+    let higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits,
+    } = higher::Range::hir(arg)?
+    else {
+        return None;
+    };
+    let extra = u128::from(limits == RangeLimits::Closed);
+    let typeck = cx.typeck_results();
+    match (constant_full_int(cx, typeck, start), constant_full_int(cx, typeck, end)) {
+        (Some(FullInt::U(start)), Some(FullInt::U(end))) => Some((end + extra).saturating_sub(start).to_string()),
+        (Some(FullInt::S(start)), Some(FullInt::S(end))) => {
+            Some(u128::try_from(end - start).map_or(0, |count| count + extra).to_string())
+        },
+        _ => {
+            let mut app = Applicability::Unspecified;
+            let end_sugg = Sugg::hir_with_context(cx, end, ctxt, "..", &mut app);
+            let mut count = if is_integer_const(cx, start, 0) {
+                end_sugg
+            } else {
+                end_sugg - Sugg::hir_with_context(cx, start, ctxt, "..", &mut app)
+            };
+            if limits == RangeLimits::Closed {
+                count = count + Sugg::NonParen("1".into());
             }
+            if typeck.expr_ty(end) != cx.tcx.types.usize {
+                count = count.as_ty("usize");
+            }
+            Some(count.to_string())
+        },
+    }
+}
+
+/// Checks if the `Vec` is declared empty in the same block as the loop, and isn't used in between.
+fn is_empty_before_loop(cx: &LateContext<'_>, vec_id: HirId, loop_expr: &Expr<'_>) -> bool {
+    let Some(block) = get_enclosing_block(cx, loop_expr.hir_id) else {
+        return false;
+    };
+    let mut stmts = block
+        .stmts
+        .iter()
+        .skip_while(|stmt| !matches!(stmt.kind, StmtKind::Local(local) if local.pat.hir_id == vec_id));
+    let Some(Stmt {
+        kind: StmtKind::Local(local),
+        ..
+    }) = stmts.next()
+    else {
+        return false;
+    };
+    if !local.init.is_some_and(|init| collection_init(cx, init).is_some()) {
+        return false;
+    }
+    for stmt in stmts {
+        match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) if e.hir_id == loop_expr.hir_id => return true,
+            _ if is_local_used(cx, stmt, vec_id) => return false,
+            _ => {},
         }
     }
+    false
 }
 
 // Scans the body of the for loop and determines whether lint should be given
 struct SameItemPushVisitor<'a, 'tcx> {
     non_deterministic_expr: bool,
     multiple_pushes: bool,
+    // the loop does something besides pushing, which replacing it would lose
+    other_effects: bool,
     // this field holds the last vec push operation visited, which should be the only push seen
     vec_push: Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, SyntaxContext)>,
     cx: &'a LateContext<'tcx>,
@@ -111,6 +211,7 @@ fn new(cx: &'a LateContext<'tcx>) -> Self {
         Self {
             non_deterministic_expr: false,
             multiple_pushes: false,
+            other_effects: false,
             vec_push: None,
             cx,
             used_locals: FxHashSet::default(),
@@ -121,6 +222,7 @@ fn should_lint(&self) -> bool {
         if_chain! {
             if !self.non_deterministic_expr;
             if !self.multiple_pushes;
+            if !self.other_effects;
             if let Some((vec, _, _)) = self.vec_push;
             if let Some(hir_id) = path_to_local(vec);
             then {
@@ -151,6 +253,10 @@ fn visit_block(&mut self, b: &'tcx Block<'_>) {
         for stmt in b.stmts {
             self.visit_stmt(stmt);
         }
+        if let Some(expr) = b.expr {
+            self.other_effects = true;
+            self.visit_expr(expr);
+        }
     }
 
     fn visit_stmt(&mut self, s: &'tcx Stmt<'_>) {
@@ -158,8 +264,14 @@ fn visit_stmt(&mut self, s: &'tcx Stmt<'_>) {
         if vec_push_option.is_none() {
             // Current statement is not a push so visit inside
             match &s.kind {
-                StmtKind::Expr(expr) | StmtKind::Semi(expr) => self.visit_expr(expr),
-                _ => {},
+                StmtKind::Expr(expr) | StmtKind::Semi(expr) => {
+                    self.other_effects = true;
+                    self.visit_expr(expr);
+                },
+                StmtKind::Local(local) => {
+                    self.other_effects |= local.init.is_some_and(|init| expr_has_side_effects(self.cx, init));
+                },
+                StmtKind::Item(_) => {},
             }
         } else {
             // Current statement is a push ...check whether another
@@ -0,0 +1,89 @@
+use super::SEQUENTIAL_AWAIT_IN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::path_to_local;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::{for_each_expr, for_each_expr_with_closures, Descend};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirIdSet, MatchSource, Pat};
+use rustc_lint::LateContext;
+
+/// Checks for `for x in xs { f(x).await; }` where the awaited future only depends on the loop
+/// item, so the futures of all iterations could run concurrently.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>, body: &'tcx Expr<'_>, expr: &'tcx Expr<'_>) {
+    // a single `.await` in the body, other ones may have to happen in order
+    let mut awaits = awaited_futures(body);
+    if let (Some((await_expr, future)), None) = (awaits.next(), awaits.next())
+        && !await_expr.span.from_expansion()
+        && !has_early_exit(body)
+        && let Some(mutated) = mutated_variables(body, cx)
+        && is_independent(cx, future, pat, expr, &mutated)
+    {
+        span_lint_and_help(
+            cx,
+            SEQUENTIAL_AWAIT_IN_LOOP,
+            await_expr.span,
+            "the future awaited in each iteration of the loop only depends on the loop item",
+            None,
+            "if the futures can run concurrently, use `futures::future::join_all` or \
+            `try_join_all`, or a stream with `buffered`",
+        );
+    }
+}
+
+/// Gets the `.await` expressions of the loop body along with the futures they await, without
+/// looking into async blocks and closures.
+fn awaited_futures<'tcx>(body: &'tcx Expr<'_>) -> impl Iterator<Item = (&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let mut awaits = Vec::new();
+    for_each_expr(body, |e| {
+        if let ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar) = e.kind
+            && let ExprKind::Call(_, [future]) = scrutinee.kind
+        {
+            awaits.push((e, future));
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    awaits.into_iter()
+}
+
+/// Checks for a `return`, `break` or `continue`, after which the sequential version would stop
+/// creating futures.
+fn has_early_exit(e: &Expr<'_>) -> bool {
+    for_each_expr(e, |e| match e.kind {
+        ExprKind::Ret(_) | ExprKind::Break(..) | ExprKind::Continue(_) => ControlFlow::Break(()),
+        // the desugaring of `.await` contains a `break`, and `?` is handled by `try_join_all`
+        ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar | MatchSource::TryDesugar) => {
+            if has_early_exit(scrutinee) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(Descend::No)
+            }
+        },
+        _ => ControlFlow::Continue(Descend::Yes),
+    })
+    .is_some()
+}
+
+/// Checks if the future uses the loop item, and otherwise only locals which are neither declared
+/// nor changed by the loop, so it doesn't depend on the previous iterations.
+fn is_independent(
+    cx: &LateContext<'_>,
+    future: &Expr<'_>,
+    pat: &Pat<'_>,
+    loop_expr: &Expr<'_>,
+    mutated: &HirIdSet,
+) -> bool {
+    let mut uses_item = false;
+    let depends_on_loop = for_each_expr_with_closures(cx, future, |e| {
+        if let Some(id) = path_to_local(e) {
+            let decl = cx.tcx.hir().span(id);
+            if pat.span.contains(decl) {
+                uses_item = true;
+            } else if mutated.contains(&id) || (loop_expr.span.contains(decl) && !future.span.contains(decl)) {
+                return ControlFlow::Break(());
+            }
+        }
+        ControlFlow::Continue(())
+    })
+    .is_some();
+    uses_item && !depends_on_loop
+}
@@ -1,10 +1,17 @@
+use clippy_utils::higher::VecArgs;
 use clippy_utils::ty::{has_iter_method, implements_trait};
-use clippy_utils::{get_parent_expr, is_integer_const, path_to_local, path_to_local_id, sugg};
+use clippy_utils::usage::is_potentially_mutated;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_enclosing_block, get_parent_expr, is_integer_const, path_to_local, path_to_local_id, sugg};
+use core::ops::ControlFlow;
 use if_chain::if_chain;
 use rustc_ast::ast::{LitIntType, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{walk_expr, walk_local, walk_pat, walk_stmt, Visitor};
-use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, HirId, HirIdMap, Local, Mutability, Pat, PatKind, Stmt};
+use rustc_hir::{
+    BinOpKind, BorrowKind, Expr, ExprKind, HirId, HirIdMap, Local, MatchSource, Mutability, Node, Pat, PatKind, QPath,
+    Stmt, StmtKind,
+};
 use rustc_hir_analysis::hir_ty_to_ty;
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
@@ -317,9 +324,10 @@ fn visit_pat(&mut self, pat: &'tcx Pat<'_>) {
 /// If `arg` was the argument to a `for` loop, return the "cleanest" way of writing the
 /// actual `Iterator` that the loop uses.
 pub(super) fn make_iterator_snippet(cx: &LateContext<'_>, arg: &Expr<'_>, applic_ref: &mut Applicability) -> String {
-    let impls_iterator = cx.tcx.get_diagnostic_item(sym::Iterator).map_or(false, |id| {
-        implements_trait(cx, cx.typeck_results().expr_ty(arg), id, &[])
-    });
+    let impls_iterator = cx
+        .tcx
+        .get_diagnostic_item(sym::Iterator)
+        .is_some_and(|id| implements_trait(cx, cx.typeck_results().expr_ty(arg), id, &[]));
     if impls_iterator {
         format!(
             "{}",
@@ -351,3 +359,96 @@ pub(super) fn make_iterator_snippet(cx: &LateContext<'_>, arg: &Expr<'_>, applic
         }
     }
 }
+
+/// How an empty collection preceding a loop is initialized, see [`collection_init`].
+pub(super) enum Init {
+    /// `Vec::new()` or `vec![]`
+    Empty,
+    /// `Vec::with_capacity(_)`, the capacity is kept by extending the collection instead.
+    WithCapacity,
+}
+
+/// Returns the `let` statement directly preceding the loop.
+pub(super) fn preceding_local<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<&'tcx Local<'tcx>> {
+    let mut parents = cx.tcx.hir().parent_iter(expr.hir_id);
+    let (block, loop_stmt_id) = match parents.next()? {
+        (stmt_id, Node::Stmt(_)) => match parents.next()? {
+            (_, Node::Block(block)) => (block, Some(stmt_id)),
+            _ => return None,
+        },
+        (_, Node::Block(block)) => (block, None),
+        _ => return None,
+    };
+    let preceding_stmts = match loop_stmt_id {
+        Some(id) => &block.stmts[..block.stmts.iter().position(|stmt| stmt.hir_id == id)?],
+        None => block.stmts,
+    };
+    if let [.., stmt] = preceding_stmts
+        && let StmtKind::Local(local) = stmt.kind
+    {
+        Some(local)
+    } else {
+        None
+    }
+}
+
+/// Returns how the collection is initialized, if it's created without any elements.
+pub(super) fn collection_init(cx: &LateContext<'_>, init: &Expr<'_>) -> Option<Init> {
+    if let Some(VecArgs::Vec([])) = VecArgs::hir(cx, init) {
+        return Some(Init::Empty);
+    }
+    if init.span.from_expansion() {
+        return None;
+    }
+    if let ExprKind::Call(func, args) = init.kind
+        && let ExprKind::Path(QPath::TypeRelative(_, seg)) = func.kind
+    {
+        match (seg.ident.as_str(), args) {
+            ("new", []) => Some(Init::Empty),
+            ("with_capacity", [_]) => Some(Init::WithCapacity),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Checks if the expression would behave differently when moved into a closure.
+pub(super) fn has_control_flow(expr: &Expr<'_>) -> bool {
+    for_each_expr(expr, |e| {
+        if matches!(
+            e.kind,
+            ExprKind::Ret(_)
+                | ExprKind::Break(..)
+                | ExprKind::Continue(_)
+                | ExprKind::Yield(..)
+                | ExprKind::Match(_, _, MatchSource::TryDesugar)
+        ) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Checks if the local `id` may be mutated in the statements following the loop.
+pub(super) fn is_mutated_after_loop<'tcx>(cx: &LateContext<'tcx>, loop_expr: &Expr<'_>, id: HirId) -> bool {
+    let Some(block) = get_enclosing_block(cx, loop_expr.hir_id) else {
+        return true;
+    };
+    let stmts_after = block
+        .stmts
+        .iter()
+        .position(|stmt| matches!(stmt.kind, StmtKind::Expr(e) | StmtKind::Semi(e) if e.hir_id == loop_expr.hir_id))
+        .map_or(&[][..], |pos| &block.stmts[pos + 1..]);
+    stmts_after
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            StmtKind::Local(local) => local.init,
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            StmtKind::Item(_) => None,
+        })
+        .chain(block.expr.filter(|e| e.hir_id != loop_expr.hir_id))
+        .any(|e| is_potentially_mutated(id, e, cx))
+}
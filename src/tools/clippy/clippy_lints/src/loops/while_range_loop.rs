@@ -0,0 +1,176 @@
+use super::utils::preceding_local;
+use super::WHILE_RANGE_LOOP;
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::path_to_local_id;
+use clippy_utils::source::{snippet_opt, snippet_with_applicability};
+use clippy_utils::usage::{is_potentially_mutated, local_used_after_expr, mutated_variables};
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BinOpKind, BindingAnnotation, Block, Expr, ExprKind, HirId, HirIdSet, PatKind, Stmt, StmtKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+/// Checks for `let mut i = start; while i < end { ..; i += step; }`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    condition: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+) {
+    let Some(local) = preceding_local(cx, expr) else {
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::MUT, counter_id, counter, None) = local.pat.kind
+        && local.els.is_none()
+        && local.span.ctxt() == expr.span.ctxt()
+        && let Some(start) = local.init
+        && cx.typeck_results().pat_ty(local.pat).is_integral()
+        && let Some((end, inclusive)) = upper_bound(condition, counter_id)
+        && let ExprKind::Block(block, None) = body.kind
+        && !block.span.from_expansion()
+        && let Some((other_stmts, increment, increment_span)) = split_increment(block)
+        && let ExprKind::AssignOp(op, lhs, step) = increment.kind
+        && op.node == BinOpKind::Add
+        && path_to_local_id(lhs, counter_id)
+        && !other_stmts.is_empty()
+        && let Some(mutated) = mutated_variables(body, cx)
+        && is_invariant(cx, end, &mutated)
+        && is_invariant(cx, step, &mutated)
+        && !is_local_used(cx, end, counter_id)
+        && !is_local_used(cx, step, counter_id)
+        && !other_stmts.iter().any(|e| is_potentially_mutated(counter_id, e, cx))
+        // skipping the increment has no equivalent in a `for` loop
+        && !has_continue(expr.hir_id, body)
+        && !local_used_after_expr(cx, counter_id, expr)
+        && let Some(body_snip) = snippet_opt(cx, block.span)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let step_by = match constant_full_int(cx, cx.typeck_results(), step) {
+            Some(FullInt::S(1) | FullInt::U(1)) => String::new(),
+            Some(FullInt::S(step)) if step > 0 => format!(".step_by({step})"),
+            Some(FullInt::U(step)) if step > 0 => format!(".step_by({step})"),
+            Some(_) => return,
+            None => {
+                // the step isn't known to be positive, and `step_by` takes a `usize`
+                app = Applicability::MaybeIncorrect;
+                let step_snip = snippet_with_applicability(cx, step.span, "..", &mut app);
+                if cx.typeck_results().expr_ty(step) == cx.tcx.types.usize {
+                    format!(".step_by({step_snip})")
+                } else {
+                    format!(".step_by({step_snip} as usize)")
+                }
+            },
+        };
+        let start = snippet_with_applicability(cx, start.span, "..", &mut app);
+        let end = snippet_with_applicability(cx, end.span, "..", &mut app);
+        let dots = if inclusive { "..=" } else { ".." };
+        let range = if step_by.is_empty() {
+            format!("{start}{dots}{end}")
+        } else {
+            format!("({start}{dots}{end}){step_by}")
+        };
+        let label = match expr.kind {
+            ExprKind::Loop(_, Some(label), ..) => format!("{}: ", label.ident),
+            _ => String::new(),
+        };
+
+        // remove the increment along with the whitespace before it
+        let increment_start = (increment.span.lo() - block.span.lo()).0 as usize;
+        let increment_end = (increment_span.hi() - block.span.lo()).0 as usize;
+        let body = format!(
+            "{}{}",
+            body_snip[..increment_start].trim_end(),
+            &body_snip[increment_end..]
+        );
+
+        span_lint_and_sugg(
+            cx,
+            WHILE_RANGE_LOOP,
+            local.span.to(expr.span),
+            "this `while` loop counts over a range",
+            "use a `for` loop",
+            format!("{label}for {counter} in {range} {body}"),
+            app,
+        );
+    }
+}
+
+/// Gets the upper bound of `counter < end` or `counter <= end`, and whether it's inclusive.
+fn upper_bound<'tcx>(condition: &'tcx Expr<'tcx>, counter_id: HirId) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    let ExprKind::Binary(op, lhs, rhs) = condition.kind else {
+        return None;
+    };
+    match op.node {
+        BinOpKind::Lt if path_to_local_id(lhs, counter_id) => Some((rhs, false)),
+        BinOpKind::Le if path_to_local_id(lhs, counter_id) => Some((rhs, true)),
+        BinOpKind::Gt if path_to_local_id(rhs, counter_id) => Some((lhs, false)),
+        BinOpKind::Ge if path_to_local_id(rhs, counter_id) => Some((lhs, true)),
+        _ => None,
+    }
+}
+
+/// Splits the loop body into the expressions of its other statements and the final increment,
+/// which has to be the last statement so it's done unconditionally. The span of the increment
+/// includes its semicolon.
+fn split_increment<'tcx>(block: &'tcx Block<'tcx>) -> Option<(Vec<&'tcx Expr<'tcx>>, &'tcx Expr<'tcx>, Span)> {
+    let (stmts, increment, span) = match (block.stmts, block.expr) {
+        (stmts, Some(e)) => (stmts, e, e.span),
+        (
+            [stmts @ .., Stmt {
+                kind: StmtKind::Semi(e),
+                span,
+                ..
+            }],
+            None,
+        ) => (stmts, *e, *span),
+        _ => return None,
+    };
+    if increment.span.ctxt() != block.span.ctxt() {
+        return None;
+    }
+    let mut exprs = Vec::with_capacity(stmts.len());
+    for stmt in stmts {
+        match stmt.kind {
+            StmtKind::Local(local) => {
+                if local.els.is_some() {
+                    return None;
+                }
+                exprs.extend(local.init);
+            },
+            StmtKind::Expr(e) | StmtKind::Semi(e) => exprs.push(e),
+            StmtKind::Item(_) => {},
+        }
+    }
+    Some((exprs, increment, span))
+}
+
+/// Checks if the bound or the step of the loop stays the same in every iteration: it's made of
+/// literals, constants, locals which aren't mutated in the loop, and their lengths.
+fn is_invariant(cx: &LateContext<'_>, e: &Expr<'_>, mutated: &HirIdSet) -> bool {
+    match e.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, e.hir_id) {
+            Res::Local(id) => !mutated.contains(&id),
+            Res::Def(DefKind::Const | DefKind::AssocConst | DefKind::ConstParam, _) => true,
+            _ => false,
+        },
+        ExprKind::Field(base, _) | ExprKind::Cast(base, _) | ExprKind::Unary(UnOp::Deref | UnOp::Neg, base) => {
+            is_invariant(cx, base, mutated)
+        },
+        ExprKind::Binary(_, lhs, rhs) => is_invariant(cx, lhs, mutated) && is_invariant(cx, rhs, mutated),
+        ExprKind::MethodCall(path, recv, [], _) if path.ident.name == sym::len => is_invariant(cx, recv, mutated),
+        _ => false,
+    }
+}
+
+/// Checks for a `continue` targeting the loop.
+fn has_continue(loop_id: HirId, body: &Expr<'_>) -> bool {
+    for_each_expr(body, |e| match e.kind {
+        ExprKind::Continue(destination) if destination.target_id == Ok(loop_id) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
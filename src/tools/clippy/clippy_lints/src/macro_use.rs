@@ -156,7 +156,7 @@ fn check_crate_post(&mut self, cx: &LateContext<'_>) {
                     [root, item] => {
                         if !check_dup.contains(&(*item).to_string()) {
                             used.entry(((*root).to_string(), span, hir_id))
-                                .or_insert_with(Vec::new)
+                                .or_default()
                                 .push((*item).to_string());
                             check_dup.push((*item).to_string());
                         }
@@ -174,13 +174,13 @@ fn check_crate_post(&mut self, cx: &LateContext<'_>) {
                                 })
                                 .collect::<Vec<_>>();
                             used.entry(((*root).to_string(), span, hir_id))
-                                .or_insert_with(Vec::new)
+                                .or_default()
                                 .push(filtered.join("::"));
                             check_dup.extend(filtered);
                         } else {
                             let rest = rest.to_vec();
                             used.entry(((*root).to_string(), span, hir_id))
-                                .or_insert_with(Vec::new)
+                                .or_default()
                                 .push(rest.join("::"));
                             check_dup.extend(rest.iter().map(ToString::to_string));
                         }
@@ -0,0 +1,173 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::get_parent_expr;
+use clippy_utils::macros::{find_format_args, root_macro_call_first_node};
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use rustc_ast::{FormatArgsPiece, LitKind};
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for paths built with `format!` or by concatenating strings with a path separator
+    /// following a value, such as `format!("{dir}/{file}")` or `dir.to_string() + "/" + file`,
+    /// when the result is passed to a function taking a path or converted to a `Path` or
+    /// `PathBuf`.
+    ///
+    /// ### Why is this bad?
+    /// The separator is platform specific, and a `String` can't hold paths which aren't valid
+    /// UTF-8. `Path::join` and `PathBuf::push` use the separator of the platform and work with
+    /// any path.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::path::Path;
+    /// # let dir = "dir";
+    /// # let file = "file";
+    /// let path = Path::new(&format!("{dir}/{file}")).to_path_buf();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::Path;
+    /// # let dir = "dir";
+    /// # let file = "file";
+    /// let path = Path::new(dir).join(file);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_PATH_JOIN,
+    pedantic,
+    "building a path with `format!` or string concatenation instead of `Path::join`"
+}
+declare_lint_pass!(ManualPathJoin => [MANUAL_PATH_JOIN]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualPathJoin {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(macro_call) = root_macro_call_first_node(cx, expr) {
+            if !cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id) || !is_path_arg(cx, expr) {
+                return;
+            }
+            find_format_args(cx, expr, macro_call.expn, |format_args| {
+                // a separator following a value, a constant prefix such as in `tests/{file}` is fine
+                let mut pieces = format_args.template.iter();
+                if pieces.any(|piece| matches!(piece, FormatArgsPiece::Placeholder(_)))
+                    && pieces
+                        .any(|piece| matches!(piece, FormatArgsPiece::Literal(lit) if contains_separator(lit.as_str())))
+                {
+                    lint(cx, macro_call.span, "building a path with `format!`");
+                }
+            });
+        } else if let ExprKind::Binary(op, ..) = expr.kind
+            && op.node == BinOpKind::Add
+            && !expr.span.from_expansion()
+            && is_type_lang_item(cx, cx.typeck_results().expr_ty(expr), LangItem::String)
+            // only lint the whole concatenation
+            && !get_parent_expr(cx, expr).is_some_and(|parent| matches!(
+                parent.kind,
+                ExprKind::Binary(op, lhs, _) if op.node == BinOpKind::Add && lhs.hir_id == expr.hir_id
+            ))
+            && is_path_arg(cx, expr)
+        {
+            let mut operands = Vec::new();
+            let mut current = expr;
+            while let ExprKind::Binary(op, lhs, rhs) = current.kind
+                && op.node == BinOpKind::Add
+            {
+                operands.push(rhs);
+                current = lhs;
+            }
+            operands.push(current);
+
+            // as for `format!`, a separator following a value
+            let mut operands = operands.into_iter().rev();
+            if operands.any(|e| !matches!(e.kind, ExprKind::Lit(_)))
+                && operands.any(|e| {
+                    if let ExprKind::Lit(lit) = e.kind
+                        && let LitKind::Str(s, _) = lit.node
+                    {
+                        contains_separator(s.as_str())
+                    } else {
+                        false
+                    }
+                })
+            {
+                lint(cx, expr.span, "building a path by concatenating strings");
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, span: Span, msg: &str) {
+    span_lint_and_help(
+        cx,
+        MANUAL_PATH_JOIN,
+        span,
+        msg,
+        None,
+        "use `Path::join` or `PathBuf::push` to add components to the path",
+    );
+}
+
+fn contains_separator(s: &str) -> bool {
+    s.contains(['/', '\\'])
+}
+
+/// Checks if the value of `e` is used as a path: passed to a function taking `impl AsRef<Path>`,
+/// like `File::open` and the `fs` functions, or converted to a `Path` or `PathBuf`.
+fn is_path_arg<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> bool {
+    let mut arg = e;
+    let mut parent = get_parent_expr(cx, e);
+    if let Some(addr_of) = parent
+        && let ExprKind::AddrOf(BorrowKind::Ref, _, _) = addr_of.kind
+    {
+        arg = addr_of;
+        parent = get_parent_expr(cx, addr_of);
+    }
+    let Some(parent) = parent else {
+        return false;
+    };
+    let (fn_id, param_pos) = match parent.kind {
+        ExprKind::Call(callee, args) if let ExprKind::Path(ref qpath) = callee.kind => (
+            cx.qpath_res(qpath, callee.hir_id).opt_def_id(),
+            args.iter().position(|x| x.hir_id == arg.hir_id),
+        ),
+        ExprKind::MethodCall(_, recv, args, _) => (
+            cx.typeck_results().type_dependent_def_id(parent.hir_id),
+            if recv.hir_id == arg.hir_id {
+                Some(0)
+            } else {
+                args.iter().position(|x| x.hir_id == arg.hir_id).map(|pos| pos + 1)
+            },
+        ),
+        _ => return false,
+    };
+    let (Some(fn_id), Some(param_pos)) = (fn_id, param_pos) else {
+        return false;
+    };
+
+    // `Path::new`, `PathBuf::from` and `into`
+    let ret_ty = cx.typeck_results().expr_ty(parent).peel_refs();
+    if is_type_diagnostic_item(cx, ret_ty, sym::Path) || is_type_diagnostic_item(cx, ret_ty, sym::PathBuf) {
+        return true;
+    }
+
+    let sig = cx.tcx.fn_sig(fn_id).skip_binder().skip_binder();
+    if let Some(&param_ty) = sig.inputs().get(param_pos)
+        && let ty::Param(param) = param_ty.kind()
+        && let Some(as_ref_id) = cx.tcx.get_diagnostic_item(sym::AsRef)
+    {
+        cx.tcx.predicates_of(fn_id).predicates.iter().any(|(pred, _)| {
+            if let ty::ClauseKind::Trait(tr) = pred.kind().skip_binder()
+                && tr.def_id() == as_ref_id
+                && tr.self_ty().is_param(param.index)
+            {
+                is_type_diagnostic_item(cx, tr.trait_ref.substs.type_at(1), sym::Path)
+            } else {
+                false
+            }
+        })
+    } else {
+        false
+    }
+}
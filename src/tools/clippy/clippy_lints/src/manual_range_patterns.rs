@@ -1,4 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::numeric_literal::NumericLiteralBuilder;
+use clippy_utils::source::snippet_opt;
 use rustc_ast::LitKind;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
@@ -46,6 +48,17 @@ fn expr_as_u128(expr: &Expr<'_>) -> Option<u128> {
     }
 }
 
+/// Returns the style of the literal, used to format the bounds of the suggested range.
+fn lit_style(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<NumericLiteralBuilder> {
+    if let ExprKind::Lit(lit) = expr.kind
+        && let Some(src) = snippet_opt(cx, lit.span)
+    {
+        NumericLiteralBuilder::from_lit(&src, &lit.node)
+    } else {
+        None
+    }
+}
+
 impl LateLintPass<'_> for ManualRangePatterns {
     fn check_pat(&mut self, cx: &LateContext<'_>, pat: &'_ rustc_hir::Pat<'_>) {
         if in_external_macro(cx.sess(), pat.span) {
@@ -60,20 +73,23 @@ fn check_pat(&mut self, cx: &LateContext<'_>, pat: &'_ rustc_hir::Pat<'_>) {
             let mut max = 0;
             let mut numbers_found = FxHashSet::default();
             let mut ranges_found = Vec::new();
+            let mut style = None;
 
             for pat in pats {
                 if let PatKind::Lit(lit) = pat.kind
                     && let Some(num) = expr_as_u128(lit)
                 {
                     numbers_found.insert(num);
+                    style = style.or_else(|| lit_style(cx, lit));
 
                     min = min.min(num);
                     max = max.max(num);
-                } else if let PatKind::Range(Some(left), Some(right), end) = pat.kind
-                    && let Some(left) = expr_as_u128(left)
+                } else if let PatKind::Range(Some(left_expr), Some(right), end) = pat.kind
+                    && let Some(left) = expr_as_u128(left_expr)
                     && let Some(right) = expr_as_u128(right)
                     && right >= left
                 {
+                    style = style.or_else(|| lit_style(cx, left_expr));
                     min = min.min(left);
                     max = max.max(right);
                     ranges_found.push(left..=match end {
@@ -108,13 +124,14 @@ fn check_pat(&mut self, cx: &LateContext<'_>, pat: &'_ rustc_hir::Pat<'_>) {
             };
 
             if contains_whole_range {
+                let style = style.unwrap_or_default();
                 span_lint_and_sugg(
                     cx,
                     MANUAL_RANGE_PATTERNS,
                     pat.span,
                     "this OR pattern can be rewritten using a range",
                     "try",
-                    format!("{min}..={max}"),
+                    format!("{}..={}", style.format_int(min), style.format_int(max)),
                     Applicability::MachineApplicable,
                 );
             }
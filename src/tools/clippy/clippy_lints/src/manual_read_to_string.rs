@@ -0,0 +1,203 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher::{get_vec_init_kind, VecInitKind};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{
+    get_enclosing_block, get_parent_expr, get_parent_node, is_trait_method, match_def_path, path_def_id, path_to_local,
+    path_to_local_id, paths,
+};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{
+    BindingAnnotation, BorrowKind, Expr, ExprKind, LangItem, MatchSource, Mutability, Node, PatKind, QPath,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bytes read with `Read::read_to_end` or `fs::read` only to be converted to a
+    /// `String` with `String::from_utf8`.
+    ///
+    /// ### Why is this bad?
+    /// `Read::read_to_string` and `fs::read_to_string` do both at once, without the intermediate
+    /// `Vec` and its second error to handle.
+    ///
+    /// ### Known problems
+    /// Invalid UTF-8 is reported as an `io::Error` of kind `InvalidData` instead of a
+    /// `FromUtf8Error`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::io::Read;
+    /// fn read(mut reader: impl Read) -> Result<String, Box<dyn std::error::Error>> {
+    ///     let mut bytes = Vec::new();
+    ///     reader.read_to_end(&mut bytes)?;
+    ///     Ok(String::from_utf8(bytes)?)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::io::Read;
+    /// fn read(mut reader: impl Read) -> Result<String, Box<dyn std::error::Error>> {
+    ///     let mut bytes = String::new();
+    ///     reader.read_to_string(&mut bytes)?;
+    ///     Ok(bytes)
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_READ_TO_STRING,
+    complexity,
+    "reading bytes and converting them with `String::from_utf8` instead of using `read_to_string`"
+}
+declare_lint_pass!(ManualReadToString => [MANUAL_READ_TO_STRING]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualReadToString {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(fun, [bytes]) = expr.kind
+            && !expr.span.from_expansion()
+            && path_def_id(cx, fun).is_some_and(|id| match_def_path(cx, id, &paths::STRING_FROM_UTF8))
+            // the `FromUtf8Error` has to be handled right away, it's replaced by the `io::Error`
+            && let Some(handled) = handled_result_parent(cx, expr)
+        {
+            // the span of `e?` is marked as a desugaring
+            let handled_span = expr.span.with_hi(handled.span.hi());
+            let mut app = Applicability::MaybeIncorrect;
+            let mut sugg = Vec::new();
+            if let Some(read_fn_span) = unhandled_result(cx, bytes).and_then(|read| fs_read_path(cx, read)) {
+                // `String::from_utf8(fs::read(path)?)?`
+                let read_fn = snippet_with_applicability(cx, read_fn_span, "..", &mut app);
+                let rest = snippet_with_applicability(cx, bytes.span.with_lo(read_fn_span.hi()), "..", &mut app);
+                sugg.push((handled_span, format!("{}{rest}", read_to_string_fn(&read_fn))));
+            } else if let Some(local_id) = path_to_local(bytes)
+                && let Some(Node::Local(local)) = get_parent_node(cx.tcx, local_id)
+                && let Some(init) = local.init
+                && let Some(block) = get_enclosing_block(cx, local.hir_id)
+            {
+                let mut uses = Vec::new();
+                let _: Option<()> = for_each_expr_with_closures(cx, block, |e| {
+                    if path_to_local_id(e, local_id) {
+                        uses.push(e);
+                    }
+                    ControlFlow::Continue(())
+                });
+
+                if let Some(read_fn_span) = unhandled_result(cx, init).and_then(|read| fs_read_path(cx, read))
+                    && let [_] = uses[..]
+                {
+                    // `let bytes = fs::read(path)?;`
+                    let read_fn = snippet_with_applicability(cx, read_fn_span, "..", &mut app);
+                    sugg.push((read_fn_span, read_to_string_fn(&read_fn)));
+                } else if let PatKind::Binding(BindingAnnotation::MUT, ..) = local.pat.kind
+                    && matches!(
+                        get_vec_init_kind(cx, init),
+                        Some(VecInitKind::New | VecInitKind::Default)
+                    )
+                    && let [first, second] = uses[..]
+                    && let Some(read_to_end) = [first, second].into_iter().find_map(|e| read_to_end_method(cx, e))
+                {
+                    // `let mut bytes = Vec::new(); reader.read_to_end(&mut bytes)?;`
+                    sugg.push((init.span, "String::new()".to_owned()));
+                    sugg.push((read_to_end, "read_to_string".to_owned()));
+                } else {
+                    return;
+                }
+                if let Some(ty) = local.ty {
+                    sugg.push((ty.span, "String".to_owned()));
+                }
+                let name = snippet_with_applicability(cx, bytes.span, "..", &mut app);
+                sugg.push((handled_span, name.into_owned()));
+            } else {
+                return;
+            }
+
+            span_lint_and_then(
+                cx,
+                MANUAL_READ_TO_STRING,
+                handled_span,
+                "converting the bytes read to a `String` with `String::from_utf8`",
+                |diag| {
+                    diag.multipart_suggestion("read them into a `String` with `read_to_string`", sugg, app);
+                },
+            );
+        }
+    }
+}
+
+/// Gets the expression handling the `Result` of `e` with `?`, `unwrap` or `expect`.
+fn handled_result_parent<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    let parent = get_parent_expr(cx, e)?;
+    match parent.kind {
+        ExprKind::MethodCall(path, recv, ..)
+            if recv.hir_id == e.hir_id && matches!(path.ident.as_str(), "unwrap" | "expect") =>
+        {
+            Some(parent)
+        },
+        // `Try::branch(e)` in the desugaring of `e?`
+        ExprKind::Call(fun, [arg])
+            if arg.hir_id == e.hir_id
+                && matches!(fun.kind, ExprKind::Path(QPath::LangItem(LangItem::TryTraitBranch, ..))) =>
+        {
+            get_parent_expr(cx, parent)
+                .filter(|try_expr| matches!(try_expr.kind, ExprKind::Match(_, _, MatchSource::TryDesugar)))
+        },
+        _ => None,
+    }
+}
+
+/// Gets the `Result` handled with `?`, `unwrap` or `expect` in `e`.
+fn unhandled_result<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match e.kind {
+        ExprKind::Match(scrutinee, _, MatchSource::TryDesugar) => match scrutinee.kind {
+            ExprKind::Call(_, [result]) => Some(result),
+            _ => None,
+        },
+        ExprKind::MethodCall(path, recv, ..)
+            if matches!(path.ident.as_str(), "unwrap" | "expect")
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result) =>
+        {
+            Some(recv)
+        },
+        _ => None,
+    }
+}
+
+/// If `e` is `fs::read(path)`, returns the span of `fs::read`.
+fn fs_read_path(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<Span> {
+    if let ExprKind::Call(fun, [_]) = e.kind
+        && !fun.span.from_expansion()
+        && path_def_id(cx, fun).is_some_and(|id| match_def_path(cx, id, &paths::STD_FS_READ))
+    {
+        Some(fun.span)
+    } else {
+        None
+    }
+}
+
+/// Turns the path to `fs::read` into one to `fs::read_to_string`, which may not be imported if
+/// `read` is.
+fn read_to_string_fn(read_fn: &str) -> String {
+    if read_fn.ends_with("::read") {
+        format!("{read_fn}_to_string")
+    } else {
+        "std::fs::read_to_string".to_owned()
+    }
+}
+
+/// If `e` is borrowed in `reader.read_to_end(&mut e)`, returns the span of `read_to_end`.
+fn read_to_end_method(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<Span> {
+    let addr_of = get_parent_expr(cx, e)?;
+    let call = get_parent_expr(cx, addr_of)?;
+    if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, _) = addr_of.kind
+        && let ExprKind::MethodCall(path, _, [arg], _) = call.kind
+        && arg.hir_id == addr_of.hir_id
+        && path.ident.name == sym!(read_to_end)
+        && is_trait_method(cx, call, sym::IoRead)
+    {
+        Some(path.ident.span)
+    } else {
+        None
+    }
+}
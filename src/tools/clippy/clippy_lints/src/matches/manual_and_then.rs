@@ -0,0 +1,192 @@
+use super::manual_utils::{check_with, try_parse_pattern, OptionPat, SomeExpr};
+use super::MANUAL_AND_THEN;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, type_is_unsafe_function};
+use clippy_utils::{
+    can_move_expr_to_closure, is_else_clause, is_res_lang_ctor, path_res, path_to_local, path_to_local_id, peel_blocks,
+};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{Arm, BindingAnnotation, Expr, ExprKind, HirId, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, SyntaxContext};
+
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+) {
+    if let [arm1, arm2] = arms
+        && arm1.guard.is_none()
+        && arm2.guard.is_none()
+    {
+        check(cx, expr, scrutinee, arm1.pat, arm1.body, Some(arm2.pat), arm2.body);
+    }
+}
+
+pub(super) fn check_if_let<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    let_pat: &'tcx Pat<'_>,
+    let_expr: &'tcx Expr<'_>,
+    then_expr: &'tcx Expr<'_>,
+    else_expr: &'tcx Expr<'_>,
+) {
+    check(cx, expr, let_expr, let_pat, then_expr, None, else_expr);
+}
+
+fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    then_pat: &'tcx Pat<'_>,
+    then_body: &'tcx Expr<'_>,
+    else_pat: Option<&'tcx Pat<'_>>,
+    else_body: &'tcx Expr<'_>,
+) {
+    if let Some(sugg_info) = check_with(
+        cx,
+        expr,
+        scrutinee,
+        then_pat,
+        then_body,
+        else_pat,
+        else_body,
+        get_and_then_expr,
+    ) {
+        span_lint_and_sugg(
+            cx,
+            MANUAL_AND_THEN,
+            expr.span,
+            "manual implementation of `Option::and_then`",
+            "try this",
+            if sugg_info.needs_brackets {
+                format!(
+                    "{{ {}{}.and_then({}) }}",
+                    sugg_info.scrutinee_str, sugg_info.as_ref_str, sugg_info.body_str
+                )
+            } else {
+                format!(
+                    "{}{}.and_then({})",
+                    sugg_info.scrutinee_str, sugg_info.as_ref_str, sugg_info.body_str
+                )
+            },
+            sugg_info.app,
+        );
+    } else {
+        check_or_else(cx, expr, scrutinee, then_pat, then_body, else_pat, else_body);
+    }
+}
+
+// Checks for an expression returning an `Option` which isn't built directly from `Some` or `None`.
+// Those are linted by `manual_map` and `manual_filter` instead.
+fn get_and_then_expr<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    expr: &'tcx Expr<'_>,
+    ctxt: SyntaxContext,
+) -> Option<SomeExpr<'tcx>> {
+    let inner = peel_blocks(expr);
+    if expr.span.ctxt() != ctxt
+        || !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Option)
+        || is_option_ctor(cx, inner)
+        || path_to_local(inner).is_some()
+    {
+        return None;
+    }
+    if let PatKind::Binding(_, id, ..) = pat.kind
+        && is_filter_cond(cx, id, inner)
+    {
+        return None;
+    }
+    Some(SomeExpr::new_no_negated(expr, false))
+}
+
+/// Checks for `Some(x) => Some(x), None => <expr>`, which is `Option::or_else`.
+fn check_or_else<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    then_pat: &'tcx Pat<'_>,
+    then_body: &'tcx Expr<'_>,
+    else_pat: Option<&'tcx Pat<'_>>,
+    else_body: &'tcx Expr<'_>,
+) {
+    let ctxt = expr.span.ctxt();
+    let (some_pat, some_body, none_body) = match (
+        try_parse_pattern(cx, then_pat, ctxt),
+        else_pat.map_or(Some(OptionPat::Wild), |p| try_parse_pattern(cx, p, ctxt)),
+    ) {
+        (Some(OptionPat::Some { pattern, ref_count: 0 }), Some(OptionPat::None | OptionPat::Wild)) => {
+            (pattern, then_body, else_body)
+        },
+        (Some(OptionPat::None | OptionPat::Wild), Some(OptionPat::Some { pattern, ref_count: 0 })) => {
+            (pattern, else_body, then_body)
+        },
+        _ => return,
+    };
+    let typeck = cx.typeck_results();
+    let scrutinee_ty = typeck.expr_ty(scrutinee);
+    // The value is moved out of the `Option`, so it can't be behind a reference.
+    if !is_type_diagnostic_item(cx, scrutinee_ty, sym::Option) || typeck.expr_ty(expr) != scrutinee_ty {
+        return;
+    }
+    let PatKind::Binding(BindingAnnotation::NONE, id, _, None) = some_pat.kind else {
+        return;
+    };
+    if let ExprKind::Call(ctor, [arg]) = peel_blocks(some_body).kind
+        && is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome)
+        && path_to_local_id(arg, id)
+        && !is_option_ctor(cx, peel_blocks(none_body))
+        && none_body.span.ctxt() == ctxt
+        && typeck.expr_adjustments(none_body).is_empty()
+        && let Some(captures) = can_move_expr_to_closure(cx, none_body)
+        // The scrutinee is moved into `or_else` before the closure is run.
+        && path_to_local(scrutinee).map_or(true, |id| !captures.contains_key(&id))
+    {
+        let mut app = Applicability::MachineApplicable;
+        let scrutinee_str = Sugg::hir_with_context(cx, scrutinee, ctxt, "..", &mut app).maybe_par();
+        let body_str = if let ExprKind::Call(func, []) = peel_blocks(none_body).kind
+            && func.span.ctxt() == ctxt
+            && !type_is_unsafe_function(cx, typeck.expr_ty(func).peel_refs())
+        {
+            snippet_with_context(cx, func.span, ctxt, "..", &mut app).0.into_owned()
+        } else {
+            format!("|| {}", Sugg::hir_with_context(cx, none_body, ctxt, "..", &mut app))
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_AND_THEN,
+            expr.span,
+            "manual implementation of `Option::or_else`",
+            "try this",
+            if else_pat.is_none() && is_else_clause(cx.tcx, expr) {
+                format!("{{ {scrutinee_str}.or_else({body_str}) }}")
+            } else {
+                format!("{scrutinee_str}.or_else({body_str})")
+            },
+            app,
+        );
+    }
+}
+
+fn is_option_ctor(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Call(ctor, _) => is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome),
+        _ => is_res_lang_ctor(cx, path_res(cx, e), OptionNone),
+    }
+}
+
+// Checks for `if <cond> { Some(x) } else { None }`, which is linted by `manual_filter`.
+fn is_filter_cond(cx: &LateContext<'_>, binding: HirId, e: &Expr<'_>) -> bool {
+    let is_some_binding = |e: &Expr<'_>| {
+        matches!(peel_blocks(e).kind, ExprKind::Call(ctor, [arg])
+            if is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome) && path_to_local_id(arg, binding))
+    };
+    let is_none = |e: &Expr<'_>| is_res_lang_ctor(cx, path_res(cx, peel_blocks(e)), OptionNone);
+    matches!(e.kind, ExprKind::If(_, then, Some(els))
+        if is_some_binding(then) && is_none(els) || is_none(then) && is_some_binding(els))
+}
@@ -0,0 +1,207 @@
+use std::cmp::Ordering::{self, Equal, Greater, Less};
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::{eq_expr_value, is_trait_method, match_def_path, paths, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::{Arm, BorrowKind, Expr, ExprKind, Mutability, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::MANUAL_MIN_MAX;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    ex: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+    msrv: &Msrv,
+) {
+    if arms.iter().any(|arm| arm.guard.is_some()) {
+        return;
+    }
+    if let Some((recv, arg)) = ord_cmp_args(cx, ex) {
+        check_min_max(cx, expr, recv, arg, arms);
+    } else if let ExprKind::Tup([first, second]) = ex.kind
+        && let Some((x, lo)) = ord_cmp_args(cx, first)
+        && let Some((other_x, hi)) = ord_cmp_args(cx, second)
+        && eq_expr_value(cx, x, other_x)
+        && msrv.meets(msrvs::CLAMP)
+    {
+        check_clamp(cx, expr, x, lo, hi, arms);
+    }
+}
+
+/// Checks for `match a.cmp(&b) { .. }` which returns either `a` or `b` the same way `Ord::min` or
+/// `Ord::max` does.
+fn check_min_max<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+) {
+    let [Some(less), Some(equal), Some(greater)] = [Less, Equal, Greater].map(|ord| arm_body(cx, arms, &[ord])) else {
+        return;
+    };
+    let is_recv = |e: &Expr<'_>| eq_expr_value(cx, e, recv);
+    let is_arg = |e: &Expr<'_>| eq_expr_value(cx, e, arg);
+
+    // `a.min(b)` returns `a` if both are equal, while `a.max(b)` returns `b`.
+    let (method, recv, arg) = if is_recv(less) && is_arg(greater) {
+        if is_recv(equal) {
+            ("min", recv, arg)
+        } else if is_arg(equal) {
+            ("min", arg, recv)
+        } else {
+            return;
+        }
+    } else if is_arg(less) && is_recv(greater) {
+        if is_arg(equal) {
+            ("max", recv, arg)
+        } else if is_recv(equal) {
+            ("max", arg, recv)
+        } else {
+            return;
+        }
+    } else {
+        return;
+    };
+    if !are_ord_values(cx, &[recv, arg]) {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        MANUAL_MIN_MAX,
+        expr.span,
+        &format!("this `match` reimplements `Ord::{method}`"),
+        |diag| {
+            let ctxt = expr.span.ctxt();
+            let mut app = Applicability::MachineApplicable;
+            let recv = Sugg::hir_with_context(cx, recv, ctxt, "..", &mut app).maybe_par();
+            let arg = snippet_with_context(cx, arg.span, ctxt, "..", &mut app).0;
+            if !is_copy(cx, cx.typeck_results().expr_ty(expr)) {
+                // Both values are moved by the method call, not only the returned one.
+                app = Applicability::MaybeIncorrect;
+            }
+            diag.span_suggestion(expr.span, "try", format!("{recv}.{method}({arg})"), app);
+        },
+    );
+}
+
+/// Checks for `match (x.cmp(&lo), x.cmp(&hi)) { .. }` which returns `lo`, `hi` or `x` the same
+/// way `Ord::clamp` does.
+fn check_clamp<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    x: &'tcx Expr<'_>,
+    lo: &'tcx Expr<'_>,
+    hi: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+) {
+    // Assuming `lo <= hi`, these are the only possible results of comparing `x` to both bounds.
+    let cases = [
+        ([Less, Less], lo),
+        ([Equal, Less], x),
+        ([Equal, Equal], x),
+        ([Greater, Less], x),
+        ([Greater, Equal], x),
+        ([Greater, Greater], hi),
+    ];
+    if !cases
+        .iter()
+        .all(|(ords, expected)| arm_body(cx, arms, ords).is_some_and(|body| eq_expr_value(cx, body, expected)))
+        || !are_ord_values(cx, &[x, lo, hi])
+    {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        MANUAL_MIN_MAX,
+        expr.span,
+        "this `match` reimplements `Ord::clamp`",
+        |diag| {
+            let ctxt = expr.span.ctxt();
+            // `clamp` panics if `lo > hi`, which the `match` doesn't.
+            let mut app = Applicability::MaybeIncorrect;
+            let x = Sugg::hir_with_context(cx, x, ctxt, "..", &mut app).maybe_par();
+            let lo = snippet_with_context(cx, lo.span, ctxt, "..", &mut app).0;
+            let hi = snippet_with_context(cx, hi.span, ctxt, "..", &mut app).0;
+            diag.span_suggestion(expr.span, "try", format!("{x}.clamp({lo}, {hi})"), app);
+            diag.note("`clamp` will panic if the lower bound is greater than the upper bound");
+        },
+    );
+}
+
+/// Gets the receiver and argument of an `Ord::cmp` call, e.g. `a` and `b` in `a.cmp(&b)`.
+fn ord_cmp_args<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(path, recv, [arg], _) = e.kind
+        && path.ident.name == sym::cmp
+        && is_trait_method(cx, e, sym::Ord)
+        && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, arg) = arg.kind
+    {
+        Some((recv, arg))
+    } else {
+        None
+    }
+}
+
+/// Checks that all the values have the same type, and that the type implements `Ord`.
+fn are_ord_values(cx: &LateContext<'_>, values: &[&Expr<'_>]) -> bool {
+    let typeck = cx.typeck_results();
+    let ty = typeck.expr_ty(values[0]);
+    values.iter().all(|e| typeck.expr_ty(e) == ty)
+        && cx
+            .tcx
+            .get_diagnostic_item(sym::Ord)
+            .is_some_and(|id| implements_trait(cx, ty, id, &[]))
+}
+
+/// Gets the body of the first arm which matches the result of the comparisons. Returns `None` if
+/// no arm matches or if an arm's pattern isn't understood.
+fn arm_body<'tcx>(cx: &LateContext<'_>, arms: &'tcx [Arm<'_>], ords: &[Ordering]) -> Option<&'tcx Expr<'tcx>> {
+    for arm in arms {
+        if pat_matches(cx, arm.pat, ords)? {
+            return Some(peel_blocks(arm.body));
+        }
+    }
+    None
+}
+
+fn pat_matches(cx: &LateContext<'_>, pat: &Pat<'_>, ords: &[Ordering]) -> Option<bool> {
+    match (pat.kind, ords) {
+        (PatKind::Wild, _) => Some(true),
+        (PatKind::Or(pats), _) => pats
+            .iter()
+            .try_fold(false, |acc, pat| Some(acc | pat_matches(cx, pat, ords)?)),
+        (PatKind::Tuple(pats, dotdot), _) if dotdot.as_opt_usize().is_none() && pats.len() == ords.len() => pats
+            .iter()
+            .zip(ords)
+            .try_fold(true, |acc, (pat, ord)| Some(acc & pat_matches(cx, pat, &[*ord])?)),
+        (PatKind::Path(ref qpath), [ord]) => Some(ordering_variant(cx, cx.qpath_res(qpath, pat.hir_id))? == *ord),
+        _ => None,
+    }
+}
+
+/// Gets the `Ordering` the path resolves to, if it's one of its variants.
+fn ordering_variant(cx: &LateContext<'_>, res: Res) -> Option<Ordering> {
+    let Res::Def(DefKind::Ctor(CtorOf::Variant, CtorKind::Const), ctor_id) = res else {
+        return None;
+    };
+    let variant_id = cx.tcx.parent(ctor_id);
+    if !match_def_path(cx, cx.tcx.parent(variant_id), &paths::CMP_ORDERING) {
+        return None;
+    }
+    match cx.tcx.item_name(variant_id).as_str() {
+        "Less" => Some(Less),
+        "Equal" => Some(Equal),
+        "Greater" => Some(Greater),
+        _ => None,
+    }
+}
@@ -0,0 +1,113 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{snippet_block_with_context, snippet_with_context};
+use clippy_utils::span_contains_comment;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BindingAnnotation, BlockCheckMode, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::ty;
+use rustc_span::SyntaxContext;
+
+use super::MANUAL_SPLIT_FIRST_LAST;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, ex: &'tcx Expr<'_>, arms: &'tcx [Arm<'_>]) {
+    let [arm1, arm2] = arms else {
+        return;
+    };
+    if arm1.guard.is_some() || arm2.guard.is_some() {
+        return;
+    }
+    let ty::Ref(_, slice_ty, mutbl) = *cx.typeck_results().expr_ty(ex).kind() else {
+        return;
+    };
+    if !slice_ty.is_slice() {
+        return;
+    }
+    let (some_arm, none_arm) = if is_empty_slice_pat(arm2.pat) {
+        (arm1, arm2)
+    } else if is_empty_slice_pat(arm1.pat) {
+        (arm2, arm1)
+    } else {
+        return;
+    };
+
+    let PatKind::Slice(before, Some(rest), after) = some_arm.pat.kind else {
+        return;
+    };
+    let (method, elem) = match (before, after) {
+        ([elem], []) => ("split_first", elem),
+        ([], [elem]) => ("split_last", elem),
+        _ => return,
+    };
+    // Both bindings need to use the default binding mode so they bind the same references as the
+    // method returns.
+    let PatKind::Binding(BindingAnnotation::NONE, _, rest_ident, Some(_)) = rest.kind else {
+        return;
+    };
+    if !matches!(
+        elem.kind,
+        PatKind::Wild | PatKind::Binding(BindingAnnotation::NONE, _, _, None)
+    ) {
+        return;
+    }
+    let method = if mutbl.is_mut() {
+        format!("{method}_mut")
+    } else {
+        method.to_owned()
+    };
+
+    let ctxt = expr.span.ctxt();
+    let mut app = Applicability::MachineApplicable;
+    let scrutinee = Sugg::hir_with_context(cx, ex, ctxt, "..", &mut app).maybe_par();
+    let elem = snippet_with_context(cx, elem.span, ctxt, "..", &mut app).0;
+    let then = body_snippet(cx, expr, some_arm.body, ctxt, &mut app);
+    let els = if is_empty_block(cx, none_arm.body) {
+        String::new()
+    } else {
+        format!(" else {}", body_snippet(cx, expr, none_arm.body, ctxt, &mut app))
+    };
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_SPLIT_FIRST_LAST,
+        expr.span,
+        &format!("this `match` reimplements `{method}`"),
+        "try",
+        format!("if let Some(({elem}, {rest_ident})) = {scrutinee}.{method}() {then}{els}"),
+        app,
+    );
+}
+
+fn is_empty_slice_pat(pat: &Pat<'_>) -> bool {
+    matches!(pat.kind, PatKind::Wild | PatKind::Slice([], None, []))
+}
+
+fn is_empty_block(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if let ExprKind::Block(block, None) = e.kind {
+        block.stmts.is_empty()
+            && block.expr.is_none()
+            && block.rules == BlockCheckMode::DefaultBlock
+            && !span_contains_comment(cx.sess().source_map(), e.span)
+    } else {
+        false
+    }
+}
+
+/// Gets the snippet of an arm body as a block.
+fn body_snippet(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    body: &Expr<'_>,
+    ctxt: SyntaxContext,
+    app: &mut Applicability,
+) -> String {
+    let snip = snippet_block_with_context(cx, body.span, ctxt, "..", Some(expr.span), app).0;
+    if let ExprKind::Block(block, None) = body.kind
+        && block.rules == BlockCheckMode::DefaultBlock
+        && body.span.ctxt() == ctxt
+    {
+        snip.into_owned()
+    } else {
+        format!("{{ {snip} }}")
+    }
+}
@@ -0,0 +1,55 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::side_effects::expr_has_side_effects;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{is_lint_allowed, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Expr, PatKind};
+use rustc_lint::LateContext;
+
+use super::MATCH_ALL_ARMS_SAME;
+
+/// Returns `true` if the lint was emitted.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>, ex: &'tcx Expr<'tcx>, arms: &[Arm<'tcx>]) -> bool {
+    if is_lint_allowed(cx, MATCH_ALL_ARMS_SAME, expr.hir_id) {
+        return false;
+    }
+    let [first, rest @ ..] = arms else {
+        return false;
+    };
+    if rest.is_empty()
+        || arms.iter().any(|arm| {
+            arm.guard.is_some()
+                || arm.span.from_expansion()
+                // Even an unused binding can move out of the scrutinee.
+                || !arm.pat.walk_short(|pat| !matches!(pat.kind, PatKind::Binding(..)))
+        })
+        || !rest.iter().all(|arm| SpanlessEq::new(cx).eq_expr(first.body, arm.body))
+    {
+        return false;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let ctxt = expr.span.ctxt();
+    let body = snippet_with_context(cx, first.body.span, ctxt, "..", &mut app).0;
+    let sugg = if !expr_has_side_effects(cx, ex) {
+        body.into_owned()
+    } else {
+        // The scrutinee is now dropped before the body is evaluated instead of afterwards.
+        if cx.typeck_results().expr_ty(ex).needs_drop(cx.tcx, cx.param_env) {
+            app = Applicability::MaybeIncorrect;
+        }
+        let scrutinee = snippet_with_context(cx, ex.span, ctxt, "..", &mut app).0;
+        format!("{{ let _ = {scrutinee}; {body} }}")
+    };
+
+    span_lint_and_sugg(
+        cx,
+        MATCH_ALL_ARMS_SAME,
+        expr.span,
+        "all arms of this `match` have the same body",
+        "try",
+        sugg,
+        app,
+    );
+    true
+}
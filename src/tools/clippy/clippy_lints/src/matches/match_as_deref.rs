@@ -0,0 +1,136 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, peel_mid_ty_refs};
+use clippy_utils::{is_lint_allowed, is_res_lang_ctor, path_res, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{
+    Arm, BindingAnnotation, BorrowKind, ByRef, Expr, ExprKind, HirId, LangItem, Mutability, PatKind, UnOp,
+};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::MATCH_AS_DEREF;
+
+/// Returns `true` if the lint was emitted.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, ex: &'tcx Expr<'_>, arms: &'tcx [Arm<'_>]) -> bool {
+    if is_lint_allowed(cx, MATCH_AS_DEREF, expr.hir_id) {
+        return false;
+    }
+    let [arm1, arm2] = arms else {
+        return false;
+    };
+    if arm1.guard.is_some() || arm2.guard.is_some() {
+        return false;
+    }
+    let some_arm = if is_none_arm(cx, arm1) {
+        arm2
+    } else if is_none_arm(cx, arm2) {
+        arm1
+    } else {
+        return false;
+    };
+
+    // The `Option` itself, without the borrow added to match on it.
+    let recv = match ex.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, recv) => recv,
+        _ => ex,
+    };
+    let typeck = cx.typeck_results();
+    let (opt_ty, _) = peel_mid_ty_refs(typeck.expr_ty(recv));
+    let ty::Adt(_, substs) = opt_ty.kind() else {
+        return false;
+    };
+    if !is_type_diagnostic_item(cx, opt_ty, sym::Option) {
+        return false;
+    }
+
+    let PatKind::TupleStruct(ref qpath, [pat], _) = some_arm.pat.kind else {
+        return false;
+    };
+    if !is_res_lang_ctor(cx, cx.qpath_res(qpath, some_arm.pat.hir_id), LangItem::OptionSome) {
+        return false;
+    }
+    let PatKind::Binding(BindingAnnotation(by_ref, _), id, ident, None) = pat.kind else {
+        return false;
+    };
+    // The value has to be bound by reference, either explicitly or by the default binding mode.
+    let ty::Ref(_, bound_ty, bound_mutbl) = *typeck.node_type(pat.hir_id).kind() else {
+        return false;
+    };
+    if bound_ty != substs.type_at(0) {
+        return false;
+    }
+
+    let ExprKind::Call(ctor, [arg]) = peel_blocks(some_arm.body).kind else {
+        return false;
+    };
+    if !is_res_lang_ctor(cx, path_res(cx, ctor), LangItem::OptionSome)
+        // The value would be coerced to the expected type otherwise.
+        || !typeck.expr_adjustments(arg).is_empty()
+    {
+        return false;
+    }
+    let (mutbl, derefs) = if path_to_local_id(arg, id) {
+        // `Some(ref v) => Some(v)` is linted by `match_as_ref`.
+        if by_ref == ByRef::Yes {
+            return false;
+        }
+        (bound_mutbl, 1)
+    } else if let ExprKind::AddrOf(BorrowKind::Ref, mutbl, inner) = arg.kind
+        && let Some(derefs) = count_derefs(cx, inner, id)
+    {
+        (mutbl, derefs)
+    } else {
+        return false;
+    };
+
+    let ctxt = expr.span.ctxt();
+    let mut app = Applicability::MachineApplicable;
+    let recv = Sugg::hir_with_context(cx, recv, ctxt, "..", &mut app).maybe_par();
+    let (method, sugg) = match (derefs, mutbl) {
+        (1, Mutability::Not) => ("as_ref", format!("{recv}.as_ref()")),
+        (1, Mutability::Mut) => ("as_mut", format!("{recv}.as_mut()")),
+        (2, Mutability::Not) => ("as_deref", format!("{recv}.as_deref()")),
+        (2, Mutability::Mut) => ("as_deref_mut", format!("{recv}.as_deref_mut()")),
+        (_, mutbl) => {
+            let method = if mutbl.is_mut() { "as_mut" } else { "as_ref" };
+            let arg = snippet_with_context(cx, arg.span, ctxt, "..", &mut app).0;
+            (method, format!("{recv}.{method}().map(|{ident}| {arg})"))
+        },
+    };
+
+    span_lint_and_sugg(
+        cx,
+        MATCH_AS_DEREF,
+        expr.span,
+        &format!("this `match` reimplements `Option::{method}`"),
+        "try",
+        sugg,
+        app,
+    );
+    true
+}
+
+fn is_none_arm(cx: &LateContext<'_>, arm: &Arm<'_>) -> bool {
+    matches!(
+        arm.pat.kind,
+        PatKind::Path(ref qpath) if is_res_lang_ctor(cx, cx.qpath_res(qpath, arm.pat.hir_id), LangItem::OptionNone)
+    ) && is_res_lang_ctor(cx, path_res(cx, peel_blocks(arm.body)), LangItem::OptionNone)
+}
+
+/// Counts the dereferences of the local in an expression like `**x`. Returns `None` if the
+/// expression isn't only dereferences of the local, or if any of them is of a raw pointer.
+fn count_derefs(cx: &LateContext<'_>, e: &Expr<'_>, id: HirId) -> Option<usize> {
+    let mut count = 0;
+    let mut e = e;
+    while let ExprKind::Unary(UnOp::Deref, inner) = e.kind {
+        if cx.typeck_results().expr_ty(inner).is_unsafe_ptr() {
+            return None;
+        }
+        count += 1;
+        e = inner;
+    }
+    (count > 0 && path_to_local_id(e, id)).then_some(count)
+}
@@ -89,10 +89,12 @@ fn find_matches_sugg<'a, 'b, I>(
         if let Some(b1) = find_bool_lit(&last_expr.kind);
         if b0 != b1;
         if first_guard.is_none() || iter.len() == 0;
+        // `matches!` only supports `if` guards.
+        if !matches!(first_guard, Some(Guard::IfLet(_)));
         if first_attrs.is_empty();
         if iter
             .all(|arm| {
-                find_bool_lit(&arm.2.kind).map_or(false, |b| b == b0) && arm.3.is_none() && arm.0.is_empty()
+                find_bool_lit(&arm.2.kind).is_some_and(|b| b == b0) && arm.3.is_none() && arm.0.is_empty()
             });
         then {
             if let Some(last_pat) = last_pat_opt {
@@ -109,9 +111,9 @@ fn find_matches_sugg<'a, 'b, I>(
                 }
             }
 
-            // The suggestion may be incorrect, because some arms can have `cfg` attributes
-            // evaluated into `false` and so such arms will be stripped before.
-            let mut applicability = Applicability::MaybeIncorrect;
+            // Arms with `cfg` attributes are checked for before getting here, so the suggestion
+            // can be applied as is.
+            let mut applicability = Applicability::MachineApplicable;
             let pat = {
                 use itertools::Itertools as _;
                 iter_without_last
@@ -0,0 +1,88 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::pat_overlap::PatSummary;
+use clippy_utils::ty::{get_discriminant_value, EnumValue};
+use clippy_utils::SpanlessEq;
+use rustc_arena::DroplessArena;
+use rustc_hir::def::{CtorKind, CtorOf, DefKind};
+use rustc_hir::{Arm, Expr, ExprKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::AdtDef;
+
+use super::MATCH_LOOKUP_TABLE;
+
+/// The minimum number of arms a `match` needs before it's worth replacing with a lookup table.
+const MIN_ARMS: usize = 4;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
+    let [first, ..] = arms else {
+        return;
+    };
+    let Some(adt) = cx.typeck_results().expr_ty(ex).peel_refs().ty_adt_def() else {
+        return;
+    };
+    if arms.len() < MIN_ARMS || !adt.is_enum() || !has_index_discriminants(cx, adt) {
+        return;
+    }
+    let body_ty = cx.typeck_results().expr_ty(first.body);
+    if !arms.iter().all(|arm| {
+        arm.guard.is_none()
+            && !arm.span.from_expansion()
+            && is_literal(arm.body)
+            && cx.typeck_results().expr_ty(arm.body) == body_ty
+    }) || arms.iter().all(|arm| SpanlessEq::new(cx).eq_expr(first.body, arm.body))
+    {
+        return;
+    }
+
+    let arena = DroplessArena::default();
+    let pats: Vec<_> = arms
+        .iter()
+        .map(|arm| PatSummary::from_pat(cx, &arena, arm.pat))
+        .collect();
+    if pats.iter().all(|pat| is_unit_variants(cx, pat))
+        && pats
+            .iter()
+            .enumerate()
+            .all(|(i, pat)| pats[i + 1..].iter().all(|other| !pat.may_overlap(other)))
+    {
+        span_lint_and_help(
+            cx,
+            MATCH_LOOKUP_TABLE,
+            expr.span,
+            "this `match` only maps unit variants to literals",
+            None,
+            "consider indexing a `const` array of the values with the variant's discriminant, or casting the \
+            variant if the values are its discriminants",
+        );
+    }
+}
+
+/// Checks if the enum has no fields and the discriminants of its variants are `0, 1, 2, ..` in
+/// declaration order, so a variant cast to `usize` indexes an array of one value per variant.
+fn has_index_discriminants(cx: &LateContext<'_>, adt: AdtDef<'_>) -> bool {
+    adt.is_payloadfree()
+        && adt
+            .variants()
+            .indices()
+            .all(|i| match get_discriminant_value(cx.tcx, adt, i) {
+                EnumValue::Unsigned(x) => x == i.as_u32().into(),
+                EnumValue::Signed(x) => x == i.as_u32().into(),
+            })
+}
+
+fn is_literal(e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Unary(UnOp::Neg, e) => matches!(e.kind, ExprKind::Lit(_)),
+        _ => false,
+    }
+}
+
+/// Checks if the pattern only matches unit enum variants by name.
+fn is_unit_variants(cx: &LateContext<'_>, pat: &PatSummary<'_>) -> bool {
+    match *pat {
+        PatSummary::Path(Some(id)) => matches!(cx.tcx.def_kind(id), DefKind::Ctor(CtorOf::Variant, CtorKind::Const)),
+        PatSummary::Or(pats) => pats.iter().all(|pat| is_unit_variants(cx, pat)),
+        _ => false,
+    }
+}
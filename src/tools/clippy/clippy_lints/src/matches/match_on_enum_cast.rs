@@ -0,0 +1,123 @@
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{get_discriminant_value, EnumValue};
+use clippy_utils::{clip, sext};
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, AdtDef, Ty};
+use rustc_span::Symbol;
+
+use super::MATCH_ON_ENUM_CAST;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, ex: &'tcx Expr<'_>, arms: &'tcx [Arm<'_>]) {
+    let ExprKind::Cast(enum_expr, _) = ex.kind else {
+        return;
+    };
+    let typeck = cx.typeck_results();
+    let ty::Adt(adt, _) = *typeck.expr_ty(enum_expr).kind() else {
+        return;
+    };
+    if !adt.is_enum() || !adt.is_payloadfree() || adt.variants().is_empty() {
+        return;
+    }
+    let cast_ty = typeck.expr_ty(ex);
+    let Some(discriminants) = cast_discriminants(cx, adt, cast_ty) else {
+        return;
+    };
+
+    // The variants each arm's pattern matches, or `None` for the wildcard arm.
+    let Some(arm_variants) = arms
+        .iter()
+        .map(|arm| match arm.pat.kind {
+            PatKind::Wild => Some(None),
+            _ => pat_variants(cx, arm.pat, &discriminants).map(Some),
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    let Some(wild_idx) = arm_variants.iter().position(Option::is_none) else {
+        return;
+    };
+    // Any arm after the wildcard arm is unreachable.
+    if wild_idx + 1 != arms.len() {
+        return;
+    }
+
+    let enum_name = cx.tcx.item_name(adt.did());
+    span_lint_and_then(
+        cx,
+        MATCH_ON_ENUM_CAST,
+        expr.span,
+        "this `match` is on the discriminant of an enum instead of the enum itself",
+        |diag| {
+            // The variant paths assume the enum is in scope.
+            let mut app = Applicability::MaybeIncorrect;
+            let scrutinee = Sugg::hir_with_context(cx, enum_expr, expr.span.ctxt(), "..", &mut app);
+            let mut sugg = vec![(ex.span, scrutinee.to_string())];
+            for (arm, variants) in arms.iter().zip(&arm_variants) {
+                if let Some(variants) = variants {
+                    let pat = variants
+                        .iter()
+                        .map(|name| format!("{enum_name}::{name}"))
+                        .collect::<Vec<_>>()
+                        .join(" | ");
+                    sugg.push((arm.pat.span, pat));
+                }
+            }
+            // The wildcard arm becomes unreachable once all the variants are matched by the other arms.
+            let covered = arm_variants.iter().flatten().map(Vec::len).sum::<usize>();
+            if covered == discriminants.len()
+                && wild_idx > 0
+                && arms.iter().all(|arm| arm.guard.is_none())
+                && (adt.did().is_local() || !adt.is_variant_list_non_exhaustive())
+            {
+                let removal = arms[wild_idx].span.with_lo(arms[wild_idx - 1].span.hi());
+                sugg.push((removal, String::new()));
+            }
+            diag.multipart_suggestion("match on the enum instead", sugg, app);
+        },
+    );
+}
+
+/// Gets the name and the value after the cast of each variant's discriminant. Returns `None` if
+/// the cast maps multiple variants to the same value.
+#[expect(clippy::cast_sign_loss)]
+fn cast_discriminants(cx: &LateContext<'_>, adt: AdtDef<'_>, cast_ty: Ty<'_>) -> Option<Vec<(Symbol, FullInt)>> {
+    let mut discriminants: Vec<(Symbol, FullInt)> = Vec::with_capacity(adt.variants().len());
+    for (i, variant) in adt.variants().iter_enumerated() {
+        let bits = match get_discriminant_value(cx.tcx, adt, i) {
+            EnumValue::Signed(x) => x as u128,
+            EnumValue::Unsigned(x) => x,
+        };
+        let value = match *cast_ty.kind() {
+            ty::Int(ity) => FullInt::S(sext(cx.tcx, bits, ity)),
+            ty::Uint(uty) => FullInt::U(clip(cx.tcx, bits, uty)),
+            _ => return None,
+        };
+        if discriminants.iter().any(|&(_, other)| other == value) {
+            return None;
+        }
+        discriminants.push((variant.name, value));
+    }
+    Some(discriminants)
+}
+
+/// Gets the variants matched by a pattern of integer literals. Returns `None` if any of the values
+/// isn't the discriminant of a variant.
+fn pat_variants(cx: &LateContext<'_>, pat: &Pat<'_>, discriminants: &[(Symbol, FullInt)]) -> Option<Vec<Symbol>> {
+    match pat.kind {
+        PatKind::Lit(lit) => {
+            let value = constant_full_int(cx, cx.typeck_results(), lit)?;
+            let &(name, _) = discriminants.iter().find(|&&(_, discr)| discr == value)?;
+            Some(vec![name])
+        },
+        PatKind::Or(pats) => pats.iter().try_fold(Vec::new(), |mut names, pat| {
+            names.extend(pat_variants(cx, pat, discriminants)?);
+            Some(names)
+        }),
+        _ => None,
+    }
+}
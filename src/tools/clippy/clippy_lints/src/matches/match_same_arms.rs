@@ -1,23 +1,29 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::source::snippet;
-use clippy_utils::{is_lint_allowed, path_to_local, search_same, SpanlessEq, SpanlessHash};
-use core::cmp::Ordering;
-use core::iter;
-use core::slice;
+use clippy_utils::pat_overlap::PatSummary;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    both, is_lint_allowed, path_to_local, search_same, span_contains_comment, SpanlessEq, SpanlessHash,
+};
+use core::ops::ControlFlow;
 use rustc_arena::DroplessArena;
-use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
-use rustc_hir::def_id::DefId;
-use rustc_hir::{Arm, Expr, ExprKind, HirId, HirIdMap, HirIdMapEntry, HirIdSet, Pat, PatKind, RangeEnd};
+use rustc_hir::{Arm, Expr, Guard, HirId, HirIdMap, HirIdMapEntry, HirIdSet, Node, Pat, PatKind};
 use rustc_lint::builtin::NON_EXHAUSTIVE_OMITTED_PATTERNS;
-use rustc_lint::LateContext;
-use rustc_middle::ty;
-use rustc_span::Symbol;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::symbol::Ident;
+use rustc_span::{Span, Symbol};
 
 use super::MATCH_SAME_ARMS;
 
 #[expect(clippy::too_many_lines)]
-pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    arms: &'tcx [Arm<'_>],
+    min_complexity: u64,
+    allow_renamed_bindings: bool,
+    allow_unused_bindings: bool,
+) {
     let hash = |&(_, arm): &(usize, &Arm<'_>)| -> u64 {
         let mut h = SpanlessHash::new(cx);
         h.hash_expr(arm.body);
@@ -25,10 +31,7 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
     };
 
     let arena = DroplessArena::default();
-    let normalized_pats: Vec<_> = arms
-        .iter()
-        .map(|a| NormalizedPat::from_pat(cx, &arena, a.pat))
-        .collect();
+    let normalized_pats: Vec<_> = arms.iter().map(|a| PatSummary::from_pat(cx, &arena, a.pat)).collect();
 
     // The furthest forwards a pattern can move without semantic changes
     let forwards_blocking_idxs: Vec<_> = normalized_pats
@@ -38,7 +41,7 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
             normalized_pats[i + 1..]
                 .iter()
                 .enumerate()
-                .find_map(|(j, other)| pat.has_overlapping_values(other).then_some(i + 1 + j))
+                .find_map(|(j, other)| pat.may_overlap(other).then_some(i + 1 + j))
                 .unwrap_or(normalized_pats.len())
         })
         .collect();
@@ -54,55 +57,50 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                 .rev()
                 .zip(forwards_blocking_idxs[..i].iter().copied().rev())
                 .skip_while(|&(_, forward_block)| forward_block > i)
-                .find_map(|((j, other), forward_block)| {
-                    (forward_block == i || pat.has_overlapping_values(other)).then_some(j)
-                })
+                .find_map(|((j, other), forward_block)| (forward_block == i || pat.may_overlap(other)).then_some(j))
                 .unwrap_or(0)
         })
         .collect();
 
+    // Arms which allow the lint themselves aren't paired with any other arm.
+    let exempt_arms: Vec<bool> = arms
+        .iter()
+        .map(|arm| is_lint_allowed(cx, MATCH_SAME_ARMS, arm.hir_id))
+        .collect();
+
     let eq = |&(lindex, lhs): &(usize, &Arm<'_>), &(rindex, rhs): &(usize, &Arm<'_>)| -> bool {
         let min_index = usize::min(lindex, rindex);
         let max_index = usize::max(lindex, rindex);
 
-        let mut local_map: HirIdMap<HirId> = HirIdMap::default();
-        let eq_fallback = |a: &Expr<'_>, b: &Expr<'_>| {
-            if_chain! {
-                if let Some(a_id) = path_to_local(a);
-                if let Some(b_id) = path_to_local(b);
-                let entry = match local_map.entry(a_id) {
-                    HirIdMapEntry::Vacant(entry) => entry,
-                    // check if using the same bindings as before
-                    HirIdMapEntry::Occupied(entry) => return *entry.get() == b_id,
-                };
-                // the names technically don't have to match; this makes the lint more conservative
-                if cx.tcx.hir().name(a_id) == cx.tcx.hir().name(b_id);
-                if cx.typeck_results().expr_ty(a) == cx.typeck_results().expr_ty(b);
-                if pat_contains_local(lhs.pat, a_id);
-                if pat_contains_local(rhs.pat, b_id);
-                then {
-                    entry.insert(b_id);
-                    true
-                } else {
-                    false
-                }
-            }
-        };
-        // Arms with a guard are ignored, those can’t always be merged together
-        // If both arms overlap with an arm in between then these can't be merged either.
-        !(backwards_blocking_idxs[max_index] > min_index && forwards_blocking_idxs[min_index] < max_index)
-                && lhs.guard.is_none()
-                && rhs.guard.is_none()
-                && SpanlessEq::new(cx)
-                    .expr_fallback(eq_fallback)
-                    .eq_expr(lhs.body, rhs.body)
-                // these checks could be removed to allow unused bindings
-                && bindings_eq(lhs.pat, local_map.keys().copied().collect())
-                && bindings_eq(rhs.pat, local_map.values().copied().collect())
+        !exempt_arms[lindex]
+            && !exempt_arms[rindex]
+            // If both arms overlap with an arm in between then these can't be merged.
+            && !(backwards_blocking_idxs[max_index] > min_index && forwards_blocking_idxs[min_index] < max_index)
+            && arm_bindings_map(cx, lhs, rhs, allow_renamed_bindings, allow_unused_bindings).is_some()
     };
 
     let indexed_arms: Vec<(usize, &Arm<'_>)> = arms.iter().enumerate().collect();
+    // The arms already edited by a suggestion. Suggestions touching them again would overlap, so those
+    // pairs only get a help message.
+    let mut suggested_arms = vec![false; arms.len()];
     for (&(i, arm1), &(j, arm2)) in search_same(&indexed_arms, hash, eq) {
+        // Both bodies are the same, so only one of them needs to be checked.
+        let complexity = body_complexity(arm1.body);
+        if complexity < min_complexity {
+            continue;
+        }
+        let complexity_note = (min_complexity > 0).then(|| {
+            format!(
+                "the arm bodies have a complexity of {complexity}, which is at least the configured \
+                `match-same-arms-min-complexity` of {min_complexity}"
+            )
+        });
+        let suggest = !suggested_arms[i] && !suggested_arms[j];
+        if suggest {
+            suggested_arms[i] = true;
+            suggested_arms[j] = true;
+        }
+
         if matches!(arm2.pat.kind, PatKind::Wild) {
             if !cx.tcx.features().non_exhaustive_omitted_patterns_lint
                 || is_lint_allowed(cx, NON_EXHAUSTIVE_OMITTED_PATTERNS, arm2.hir_id)
@@ -113,19 +111,43 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                     arm1.span,
                     "this match arm has an identical body to the `_` wildcard arm",
                     |diag| {
-                        diag.span_suggestion(arm1.span, "try removing the arm", "", Applicability::MaybeIncorrect)
-                            .help("or try changing either arm body")
+                        diag.help("or try changing either arm body")
                             .span_note(arm2.span, "`_` wildcard arm here");
+                        if let Some(note) = complexity_note {
+                            diag.note(note);
+                        }
+                        if suggest {
+                            diag.span_suggestion_verbose(
+                                arm_removal_span(arms, i),
+                                "try removing the arm",
+                                "",
+                                Applicability::MaybeIncorrect,
+                            );
+                        } else {
+                            diag.help("try removing the arm");
+                        }
                     },
                 );
             }
         } else {
             let back_block = backwards_blocking_idxs[j];
-            let (keep_arm, move_arm) = if back_block < i || (back_block == 0 && forwards_blocking_idxs[i] <= j) {
-                (arm1, arm2)
-            } else {
-                (arm2, arm1)
-            };
+            let (keep_arm, (move_idx, move_arm)) =
+                if back_block < i || (back_block == 0 && forwards_blocking_idxs[i] <= j) {
+                    (arm1, (j, arm2))
+                } else {
+                    (arm2, (i, arm1))
+                };
+            let bindings_map = arm_bindings_map(cx, keep_arm, move_arm, allow_renamed_bindings, allow_unused_bindings)
+                .unwrap_or_default();
+            // The bindings of the arm being moved which need to be renamed to the name of the binding in
+            // the kept arm before both patterns can be merged.
+            let renames: Vec<_> = bindings_map
+                .iter()
+                .map(|(&keep_id, &move_id)| (move_id, cx.tcx.hir().name(move_id), cx.tcx.hir().name(keep_id)))
+                .filter(|&(_, old_name, new_name)| old_name != new_name)
+                .collect();
+            let keep_unused = unused_bindings(cx, keep_arm.pat, bindings_map.keys().copied());
+            let move_unused = unused_bindings(cx, move_arm.pat, bindings_map.values().copied());
 
             span_lint_and_then(
                 cx,
@@ -133,269 +155,230 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                 keep_arm.span,
                 "this match arm has an identical body to another arm",
                 |diag| {
-                    let move_pat_snip = snippet(cx, move_arm.pat.span, "<pat2>");
-                    let keep_pat_snip = snippet(cx, keep_arm.pat.span, "<pat1>");
+                    // Merging arms with guards is only correct as long as the guard doesn't depend on which
+                    // pattern matched, so leave those to the user.
+                    let mut app = if keep_arm.guard.is_none() && move_arm.guard.is_none() {
+                        Applicability::MachineApplicable
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+                    let move_pat_snip =
+                        pat_snippet_without_bindings(cx, move_arm.pat, &move_unused, "<pat2>", &mut app);
+                    let keep_pat_snip =
+                        pat_snippet_without_bindings(cx, keep_arm.pat, &keep_unused, "<pat1>", &mut app);
+                    let removal_span = arm_removal_span(arms, move_idx);
+                    if span_contains_comment(cx.sess().source_map(), removal_span) {
+                        app = Applicability::MaybeIncorrect;
+                    }
 
-                    diag.span_suggestion(
-                        keep_arm.pat.span,
+                    diag.help("or try changing either arm body")
+                        .span_note(move_arm.span, "other arm here");
+                    if let Some(note) = complexity_note {
+                        diag.note(note);
+                    }
+                    if !renames.is_empty() {
+                        // The patterns can't be merged until both arms bind the same names.
+                        let msg = "rename the bindings so both arms can be merged";
+                        if suggest && let Some(edits) = binding_rename_edits(cx, move_arm, &renames) {
+                            diag.multipart_suggestion(msg, edits, Applicability::MachineApplicable);
+                        } else {
+                            diag.help(msg);
+                        }
+                        return;
+                    }
+                    if !suggest {
+                        diag.help("try merging the arm patterns");
+                        return;
+                    }
+                    diag.multipart_suggestion(
                         "try merging the arm patterns",
-                        format!("{keep_pat_snip} | {move_pat_snip}"),
-                        Applicability::MaybeIncorrect,
-                    )
-                    .help("or try changing either arm body")
-                    .span_note(move_arm.span, "other arm here");
+                        vec![
+                            (keep_arm.pat.span, format!("{keep_pat_snip} | {move_pat_snip}")),
+                            (removal_span, String::new()),
+                        ],
+                        app,
+                    );
                 },
             );
         }
     }
 }
 
-#[derive(Clone, Copy)]
-enum NormalizedPat<'a> {
-    Wild,
-    Struct(Option<DefId>, &'a [(Symbol, Self)]),
-    Tuple(Option<DefId>, &'a [Self]),
-    Or(&'a [Self]),
-    Path(Option<DefId>),
-    LitStr(Symbol),
-    LitBytes(&'a [u8]),
-    LitInt(u128),
-    LitBool(bool),
-    Range(PatRange),
-    /// A slice pattern. If the second value is `None`, then this matches an exact size. Otherwise
-    /// the first value contains everything before the `..` wildcard pattern, and the second value
-    /// contains everything afterwards. Note that either side, or both sides, may contain zero
-    /// patterns.
-    Slice(&'a [Self], Option<&'a [Self]>),
+/// Checks if the guards and bodies of both arms are equal. If so, returns the map from the bindings
+/// of `lhs` to the bindings of `rhs` they correspond to.
+fn arm_bindings_map(
+    cx: &LateContext<'_>,
+    lhs: &Arm<'_>,
+    rhs: &Arm<'_>,
+    allow_renamed_bindings: bool,
+    allow_unused_bindings: bool,
+) -> Option<HirIdMap<HirId>> {
+    let mut local_map: HirIdMap<HirId> = HirIdMap::default();
+    let eq_fallback = |a: &Expr<'_>, b: &Expr<'_>| {
+        if_chain! {
+            if let Some(a_id) = path_to_local(a);
+            if let Some(b_id) = path_to_local(b);
+            let entry = match local_map.entry(a_id) {
+                HirIdMapEntry::Vacant(entry) => entry,
+                // check if using the same bindings as before
+                HirIdMapEntry::Occupied(entry) => return *entry.get() == b_id,
+            };
+            // the names technically don't have to match; this makes the lint more conservative
+            if allow_renamed_bindings || cx.tcx.hir().name(a_id) == cx.tcx.hir().name(b_id);
+            if cx.typeck_results().expr_ty(a) == cx.typeck_results().expr_ty(b);
+            if pat_contains_local(lhs.pat, a_id);
+            if pat_contains_local(rhs.pat, b_id);
+            then {
+                entry.insert(b_id);
+                true
+            } else {
+                false
+            }
+        }
+    };
+    // Arms with a guard can only be merged together if both guards are the same.
+    let eq = {
+        let mut spanless_eq = SpanlessEq::new(cx).modulo_adjustments(true).expr_fallback(eq_fallback);
+        let mut inter_expr = spanless_eq.inter_expr();
+        both(&lhs.guard, &rhs.guard, |l, r| inter_expr.eq_guard(l, r)) && inter_expr.eq_expr(lhs.body, rhs.body)
+    };
+    // Unused bindings can be replaced with `_` in the merged pattern, so they don't need a counterpart
+    // in the other arm.
+    (eq && (allow_unused_bindings
+        || bindings_eq(lhs.pat, local_map.keys().copied().collect())
+            && bindings_eq(rhs.pat, local_map.values().copied().collect())))
+    .then_some(local_map)
 }
 
-#[derive(Clone, Copy)]
-struct PatRange {
-    start: u128,
-    end: u128,
-    bounds: RangeEnd,
+/// Gets the bindings in the pattern which don't share a name with any of the `used` bindings. Each
+/// entry holds the binding's id, the span of the whole binding pattern, its name and the span of its
+/// sub-pattern.
+fn unused_bindings(
+    cx: &LateContext<'_>,
+    pat: &Pat<'_>,
+    used: impl Iterator<Item = HirId>,
+) -> Vec<(HirId, Span, Ident, Option<Span>)> {
+    let used: Vec<_> = used.map(|id| cx.tcx.hir().name(id)).collect();
+    let mut unused = Vec::new();
+    pat.walk_always(|p| {
+        if let PatKind::Binding(_, id, ident, sub) = p.kind
+            && !used.contains(&ident.name)
+        {
+            unused.push((id, p.span, ident, sub.map(|sub| sub.span)));
+        }
+    });
+    unused.sort_by_key(|&(_, span, ..)| span.lo());
+    unused
 }
-impl PatRange {
-    fn contains(&self, x: u128) -> bool {
-        x >= self.start
-            && match self.bounds {
-                RangeEnd::Included => x <= self.end,
-                RangeEnd::Excluded => x < self.end,
-            }
-    }
 
-    fn overlaps(&self, other: &Self) -> bool {
-        // Note: Empty ranges are impossible, so this is correct even though it would return true if an
-        // empty exclusive range were to reside within an inclusive range.
-        (match self.bounds {
-            RangeEnd::Included => self.end >= other.start,
-            RangeEnd::Excluded => self.end > other.start,
-        } && match other.bounds {
-            RangeEnd::Included => self.start <= other.end,
-            RangeEnd::Excluded => self.start < other.end,
-        })
+/// Gets the snippet of the pattern with each of the `unused` bindings replaced by `_`, or by their
+/// sub-pattern if they have one.
+fn pat_snippet_without_bindings(
+    cx: &LateContext<'_>,
+    pat: &Pat<'_>,
+    unused: &[(HirId, Span, Ident, Option<Span>)],
+    default: &str,
+    app: &mut Applicability,
+) -> String {
+    let mut snip = snippet_with_applicability(cx, pat.span, default, app).into_owned();
+    if unused.is_empty() {
+        return snip;
     }
-}
-
-/// Iterates over the pairs of fields with matching names.
-fn iter_matching_struct_fields<'a>(
-    left: &'a [(Symbol, NormalizedPat<'a>)],
-    right: &'a [(Symbol, NormalizedPat<'a>)],
-) -> impl Iterator<Item = (&'a NormalizedPat<'a>, &'a NormalizedPat<'a>)> + 'a {
-    struct Iter<'a>(
-        slice::Iter<'a, (Symbol, NormalizedPat<'a>)>,
-        slice::Iter<'a, (Symbol, NormalizedPat<'a>)>,
-    );
-    impl<'a> Iterator for Iter<'a> {
-        type Item = (&'a NormalizedPat<'a>, &'a NormalizedPat<'a>);
-        fn next(&mut self) -> Option<Self::Item> {
-            // Note: all the fields in each slice are sorted by symbol value.
-            let mut left = self.0.next()?;
-            let mut right = self.1.next()?;
-            loop {
-                match left.0.cmp(&right.0) {
-                    Ordering::Equal => return Some((&left.1, &right.1)),
-                    Ordering::Less => left = self.0.next()?,
-                    Ordering::Greater => right = self.1.next()?,
-                }
-            }
+    if pat.span.from_expansion() {
+        *app = Applicability::MaybeIncorrect;
+        return snip;
+    }
+    // Replace from the back so the offsets of the earlier bindings stay valid.
+    for &(id, span, ident, sub_span) in unused.iter().rev() {
+        if span.from_expansion() || !pat.span.contains(span) {
+            *app = Applicability::MaybeIncorrect;
+            continue;
         }
+        let (span, replacement) = match (sub_span, cx.tcx.hir().find_parent(id)) {
+            (Some(sub_span), _) => (span.until(sub_span), String::new()),
+            // Shorthand fields need to keep the field name.
+            (None, Some(Node::PatField(field))) if field.is_shorthand => (span, format!("{ident}: _")),
+            (None, _) => (span, "_".into()),
+        };
+        let lo = (span.lo() - pat.span.lo()).to_usize();
+        let hi = (span.hi() - pat.span.lo()).to_usize();
+        snip.replace_range(lo..hi, &replacement);
     }
-    Iter(left.iter(), right.iter())
+    snip
 }
 
-#[expect(clippy::similar_names)]
-impl<'a> NormalizedPat<'a> {
-    fn from_pat(cx: &LateContext<'_>, arena: &'a DroplessArena, pat: &'a Pat<'_>) -> Self {
-        match pat.kind {
-            PatKind::Wild | PatKind::Binding(.., None) => Self::Wild,
-            PatKind::Binding(.., Some(pat)) | PatKind::Box(pat) | PatKind::Ref(pat, _) => {
-                Self::from_pat(cx, arena, pat)
-            },
-            PatKind::Struct(ref path, fields, _) => {
-                let fields =
-                    arena.alloc_from_iter(fields.iter().map(|f| (f.ident.name, Self::from_pat(cx, arena, f.pat))));
-                fields.sort_by_key(|&(name, _)| name);
-                Self::Struct(cx.qpath_res(path, pat.hir_id).opt_def_id(), fields)
-            },
-            PatKind::TupleStruct(ref path, pats, wild_idx) => {
-                let Some(adt) = cx.typeck_results().pat_ty(pat).ty_adt_def() else {
-                    return Self::Wild
-                };
-                let (var_id, variant) = if adt.is_enum() {
-                    match cx.qpath_res(path, pat.hir_id).opt_def_id() {
-                        Some(x) => (Some(x), adt.variant_with_ctor_id(x)),
-                        None => return Self::Wild,
-                    }
-                } else {
-                    (None, adt.non_enum_variant())
-                };
-                let (front, back) = match wild_idx.as_opt_usize() {
-                    Some(i) => pats.split_at(i),
-                    None => (pats, [].as_slice()),
-                };
-                let pats = arena.alloc_from_iter(
-                    front
-                        .iter()
-                        .map(|pat| Self::from_pat(cx, arena, pat))
-                        .chain(iter::repeat_with(|| Self::Wild).take(variant.fields.len() - pats.len()))
-                        .chain(back.iter().map(|pat| Self::from_pat(cx, arena, pat))),
-                );
-                Self::Tuple(var_id, pats)
-            },
-            PatKind::Or(pats) => Self::Or(arena.alloc_from_iter(pats.iter().map(|pat| Self::from_pat(cx, arena, pat)))),
-            PatKind::Path(ref path) => Self::Path(cx.qpath_res(path, pat.hir_id).opt_def_id()),
-            PatKind::Tuple(pats, wild_idx) => {
-                let field_count = match cx.typeck_results().pat_ty(pat).kind() {
-                    ty::Tuple(subs) => subs.len(),
-                    _ => return Self::Wild,
-                };
-                let (front, back) = match wild_idx.as_opt_usize() {
-                    Some(i) => pats.split_at(i),
-                    None => (pats, [].as_slice()),
-                };
-                let pats = arena.alloc_from_iter(
-                    front
-                        .iter()
-                        .map(|pat| Self::from_pat(cx, arena, pat))
-                        .chain(iter::repeat_with(|| Self::Wild).take(field_count - pats.len()))
-                        .chain(back.iter().map(|pat| Self::from_pat(cx, arena, pat))),
-                );
-                Self::Tuple(None, pats)
-            },
-            PatKind::Lit(e) => match &e.kind {
-                // TODO: Handle negative integers. They're currently treated as a wild match.
-                ExprKind::Lit(lit) => match lit.node {
-                    LitKind::Str(sym, _) => Self::LitStr(sym),
-                    LitKind::ByteStr(ref bytes, _) | LitKind::CStr(ref bytes, _) => Self::LitBytes(bytes),
-                    LitKind::Byte(val) => Self::LitInt(val.into()),
-                    LitKind::Char(val) => Self::LitInt(val.into()),
-                    LitKind::Int(val, _) => Self::LitInt(val),
-                    LitKind::Bool(val) => Self::LitBool(val),
-                    LitKind::Float(..) | LitKind::Err => Self::Wild,
-                },
-                _ => Self::Wild,
-            },
-            PatKind::Range(start, end, bounds) => {
-                // TODO: Handle negative integers. They're currently treated as a wild match.
-                let start = match start {
-                    None => 0,
-                    Some(e) => match &e.kind {
-                        ExprKind::Lit(lit) => match lit.node {
-                            LitKind::Int(val, _) => val,
-                            LitKind::Char(val) => val.into(),
-                            LitKind::Byte(val) => val.into(),
-                            _ => return Self::Wild,
-                        },
-                        _ => return Self::Wild,
-                    },
-                };
-                let (end, bounds) = match end {
-                    None => (u128::MAX, RangeEnd::Included),
-                    Some(e) => match &e.kind {
-                        ExprKind::Lit(lit) => match lit.node {
-                            LitKind::Int(val, _) => (val, bounds),
-                            LitKind::Char(val) => (val.into(), bounds),
-                            LitKind::Byte(val) => (val.into(), bounds),
-                            _ => return Self::Wild,
-                        },
-                        _ => return Self::Wild,
-                    },
-                };
-                Self::Range(PatRange { start, end, bounds })
-            },
-            PatKind::Slice(front, wild_pat, back) => Self::Slice(
-                arena.alloc_from_iter(front.iter().map(|pat| Self::from_pat(cx, arena, pat))),
-                wild_pat.map(|_| &*arena.alloc_from_iter(back.iter().map(|pat| Self::from_pat(cx, arena, pat)))),
-            ),
+/// Builds the edits renaming the bindings of `arm`. Each entry in `renames` holds the binding's id
+/// along with its current and new name. Returns `None` if any use of a binding comes from a macro.
+fn binding_rename_edits(
+    cx: &LateContext<'_>,
+    arm: &Arm<'_>,
+    renames: &[(HirId, Symbol, Symbol)],
+) -> Option<Vec<(Span, String)>> {
+    let mut edits = Vec::new();
+    let mut from_expansion = false;
+    let mut push_edit = |id: HirId, span: Span, old_name: Symbol, new_name: Symbol| {
+        if span.from_expansion() {
+            from_expansion = true;
+            return;
         }
-    }
-
-    /// Checks if two patterns overlap in the values they can match assuming they are for the same
-    /// type.
-    fn has_overlapping_values(&self, other: &Self) -> bool {
-        match (*self, *other) {
-            (Self::Wild, _) | (_, Self::Wild) => true,
-            (Self::Or(pats), ref other) | (ref other, Self::Or(pats)) => {
-                pats.iter().any(|pat| pat.has_overlapping_values(other))
-            },
-            (Self::Struct(lpath, lfields), Self::Struct(rpath, rfields)) => {
-                if lpath != rpath {
-                    return false;
-                }
-                iter_matching_struct_fields(lfields, rfields).all(|(lpat, rpat)| lpat.has_overlapping_values(rpat))
-            },
-            (Self::Tuple(lpath, lpats), Self::Tuple(rpath, rpats)) => {
-                if lpath != rpath {
-                    return false;
-                }
-                lpats
-                    .iter()
-                    .zip(rpats.iter())
-                    .all(|(lpat, rpat)| lpat.has_overlapping_values(rpat))
-            },
-            (Self::Path(x), Self::Path(y)) => x == y,
-            (Self::LitStr(x), Self::LitStr(y)) => x == y,
-            (Self::LitBytes(x), Self::LitBytes(y)) => x == y,
-            (Self::LitInt(x), Self::LitInt(y)) => x == y,
-            (Self::LitBool(x), Self::LitBool(y)) => x == y,
-            (Self::Range(ref x), Self::Range(ref y)) => x.overlaps(y),
-            (Self::Range(ref range), Self::LitInt(x)) | (Self::LitInt(x), Self::Range(ref range)) => range.contains(x),
-            (Self::Slice(lpats, None), Self::Slice(rpats, None)) => {
-                lpats.len() == rpats.len() && lpats.iter().zip(rpats.iter()).all(|(x, y)| x.has_overlapping_values(y))
-            },
-            (Self::Slice(pats, None), Self::Slice(front, Some(back)))
-            | (Self::Slice(front, Some(back)), Self::Slice(pats, None)) => {
-                // Here `pats` is an exact size match. If the combined lengths of `front` and `back` are greater
-                // then the minimum length required will be greater than the length of `pats`.
-                if pats.len() < front.len() + back.len() {
-                    return false;
-                }
-                pats[..front.len()]
-                    .iter()
-                    .zip(front.iter())
-                    .chain(pats[pats.len() - back.len()..].iter().zip(back.iter()))
-                    .all(|(x, y)| x.has_overlapping_values(y))
+        // Shorthand fields need to keep the field name.
+        let is_shorthand = match cx.tcx.hir().find_parent(id) {
+            Some(Node::PatField(field)) => field.is_shorthand,
+            Some(Node::ExprField(field)) => field.is_shorthand,
+            _ => false,
+        };
+        edits.push((
+            span,
+            if is_shorthand {
+                format!("{old_name}: {new_name}")
+            } else {
+                new_name.to_string()
             },
-            (Self::Slice(lfront, Some(lback)), Self::Slice(rfront, Some(rback))) => lfront
-                .iter()
-                .zip(rfront.iter())
-                .chain(lback.iter().rev().zip(rback.iter().rev()))
-                .all(|(x, y)| x.has_overlapping_values(y)),
+        ));
+    };
 
-            // Enums can mix unit variants with tuple/struct variants. These can never overlap.
-            (Self::Path(_), Self::Tuple(..) | Self::Struct(..))
-            | (Self::Tuple(..) | Self::Struct(..), Self::Path(_)) => false,
+    // Every alternative of an or-pattern has its own binding, so match them by name.
+    arm.pat.walk_always(|pat| {
+        if let PatKind::Binding(_, _, ident, _) = pat.kind
+            && let Some(&(_, old_name, new_name)) = renames.iter().find(|&&(_, name, _)| name == ident.name)
+        {
+            push_edit(pat.hir_id, ident.span, old_name, new_name);
+        }
+    });
+    let guard = arm.guard.as_ref().map(|guard| match guard {
+        Guard::If(e) => *e,
+        Guard::IfLet(l) => l.init,
+    });
+    for e in guard.into_iter().chain([arm.body]) {
+        let _: Option<!> = for_each_expr(e, |e| {
+            if let Some(id) = path_to_local(e)
+                && let Some(&(_, old_name, new_name)) = renames.iter().find(|&&(binding_id, ..)| binding_id == id)
+            {
+                push_edit(e.hir_id, e.span, old_name, new_name);
+            }
+            ControlFlow::Continue(())
+        });
+    }
+    (!from_expansion).then_some(edits)
+}
 
-            // Tuples can be matched like a struct.
-            (Self::Tuple(x, _), Self::Struct(y, _)) | (Self::Struct(x, _), Self::Tuple(y, _)) => {
-                // TODO: check fields here.
-                x == y
-            },
+/// Counts the number of expression nodes in an arm body.
+fn body_complexity<'tcx>(body: &'tcx Expr<'tcx>) -> u64 {
+    let mut complexity = 0;
+    let _: Option<!> = for_each_expr(body, |_| {
+        complexity += 1;
+        ControlFlow::Continue(())
+    });
+    complexity
+}
 
-            // TODO: Lit* with Path, Range with Path, LitBytes with Slice
-            _ => true,
-        }
+/// Gets the span to remove so that the arm at `idx` is deleted along with its trailing comma and the
+/// whitespace separating it from the next arm.
+fn arm_removal_span(arms: &[Arm<'_>], idx: usize) -> Span {
+    match arms.get(idx + 1) {
+        Some(next) => arms[idx].span.until(next.span),
+        // The last arm keeps its trailing comma, so remove everything after the previous arm instead.
+        None => arms[idx].span.with_lo(arms[idx - 1].span.hi()),
     }
 }
 
@@ -0,0 +1,113 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{indent_of, snippet_with_context};
+use clippy_utils::{get_parent_node, span_contains_comment, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Block, BlockCheckMode, Expr, ExprKind, Node, StmtKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::{Span, SyntaxContext};
+
+use super::MATCH_SHARED_TRAILING_STMT;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arms: &'tcx [Arm<'_>]) {
+    if arms.len() < 2 || !cx.typeck_results().expr_ty(expr).is_unit() {
+        return;
+    }
+    let ctxt = expr.span.ctxt();
+    // The statement is moved after the `match`, which is only possible if the `match` is a statement
+    // itself.
+    let insert_at = match get_parent_node(cx.tcx, expr.hir_id) {
+        Some(Node::Stmt(stmt)) if matches!(stmt.kind, StmtKind::Semi(_) | StmtKind::Expr(_)) => stmt.span,
+        Some(Node::Block(block)) if block.expr.is_some_and(|e| e.hir_id == expr.hir_id) => expr.span,
+        _ => return,
+    };
+    if insert_at.ctxt() != ctxt {
+        return;
+    }
+
+    let Some(trailing) = arms
+        .iter()
+        .map(|arm| trailing_expr(arm, ctxt))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    let (first, _) = &trailing[0];
+    if !trailing[1..]
+        .iter()
+        .all(|(trailing, _)| SpanlessEq::new(cx).eq_expr(first.expr, trailing.expr))
+        // Otherwise all arms are left empty, which other lints handle better.
+        || trailing.iter().all(|(trailing, _)| trailing.prev_span.is_none())
+    {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        MATCH_SHARED_TRAILING_STMT,
+        expr.span,
+        "all arms of this `match` end with the same statement",
+        |diag| {
+            let sm = cx.sess().source_map();
+            let mut app = Applicability::MachineApplicable;
+            let mut sugg = Vec::with_capacity(arms.len() + 1);
+            for (trailing, block) in &trailing {
+                let span = match trailing.prev_span {
+                    Some(prev) => trailing.span.with_lo(prev.hi()),
+                    None => block.span,
+                };
+                if span_contains_comment(sm, span) {
+                    app = Applicability::MaybeIncorrect;
+                }
+                if block.stmts.iter().any(|stmt| matches!(stmt.kind, StmtKind::Local(_))) {
+                    // The locals of the arm are now dropped before the statement is run.
+                    app = Applicability::MaybeIncorrect;
+                }
+                let replacement = if trailing.prev_span.is_some() {
+                    String::new()
+                } else {
+                    "{}".into()
+                };
+                sugg.push((span, replacement));
+            }
+            let stmt = snippet_with_context(cx, first.expr.span, ctxt, "..", &mut app).0;
+            let indent = " ".repeat(indent_of(cx, insert_at).unwrap_or(0));
+            sugg.push((insert_at.shrink_to_hi(), format!("\n{indent}{stmt};")));
+            diag.multipart_suggestion("move the statement after the `match`", sugg, app);
+        },
+    );
+}
+
+struct Trailing<'tcx> {
+    /// The expression of the statement, without the semicolon.
+    expr: &'tcx Expr<'tcx>,
+    /// The span of the statement, including the semicolon.
+    span: Span,
+    /// The span of the statement before it in the same block, if there is one.
+    prev_span: Option<Span>,
+}
+
+/// Gets the last statement of an arm's block, or its trailing expression if it has one.
+fn trailing_expr<'tcx>(arm: &Arm<'tcx>, ctxt: SyntaxContext) -> Option<(Trailing<'tcx>, &'tcx Block<'tcx>)> {
+    let ExprKind::Block(block, None) = arm.body.kind else {
+        return None;
+    };
+    if block.rules != BlockCheckMode::DefaultBlock || block.span.ctxt() != ctxt {
+        return None;
+    }
+    let (expr, span, prev) = if let Some(e) = block.expr {
+        (e, e.span, block.stmts.last())
+    } else {
+        let [rest @ .., last] = block.stmts else {
+            return None;
+        };
+        let StmtKind::Semi(e) = last.kind else {
+            return None;
+        };
+        (e, last.span, rest.last())
+    };
+    let prev_span = prev.map(|stmt| stmt.span);
+    if span.ctxt() != ctxt || prev_span.is_some_and(|span| span.ctxt() != ctxt) {
+        return None;
+    }
+    Some((Trailing { expr, span, prev_span }, block))
+}
@@ -1,6 +1,8 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::pat_overlap::{is_variant_exhaustive, PatSummary};
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{is_refutable, peel_hir_pat_refs, recurse_or_patterns};
+use clippy_utils::{peel_hir_pat_refs, recurse_or_patterns};
+use rustc_arena::DroplessArena;
 use rustc_errors::Applicability;
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::{Arm, Expr, PatKind, PathSegment, QPath, Ty, TyKind};
@@ -11,7 +13,7 @@
 use super::{MATCH_WILDCARD_FOR_SINGLE_VARIANTS, WILDCARD_ENUM_MATCH_ARM};
 
 #[expect(clippy::too_many_lines)]
-pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
+pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &Expr<'tcx>, arms: &[Arm<'tcx>]) {
     let ty = cx.typeck_results().expr_ty(ex).peel_refs();
     let adt_def = match ty.kind() {
         ty::Adt(adt_def, _)
@@ -25,26 +27,26 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
 
     // First pass - check for violation, but don't do much book-keeping because this is hopefully
     // the uncommon case, and the book-keeping is slightly expensive.
-    let mut wildcard_span = None;
+    let mut wildcard = None;
     let mut wildcard_ident = None;
     let mut has_non_wild = false;
-    for arm in arms {
+    for (i, arm) in arms.iter().enumerate() {
         match peel_hir_pat_refs(arm.pat).0.kind {
-            PatKind::Wild if arm.guard.is_none() => wildcard_span = Some(arm.pat.span),
-            PatKind::Binding(_, _, ident, None) => {
-                wildcard_span = Some(arm.pat.span);
+            PatKind::Wild if arm.guard.is_none() => wildcard = Some((i, arm.pat.span)),
+            PatKind::Binding(_, _, ident, None) if arm.guard.is_none() => {
+                wildcard = Some((i, arm.pat.span));
                 wildcard_ident = Some(ident);
             },
             _ => has_non_wild = true,
         }
     }
-    let wildcard_span = match wildcard_span {
+    let (wildcard_idx, wildcard_span) = match wildcard {
         Some(x) if has_non_wild => x,
         _ => return,
     };
 
     // Accumulate the variants which should be put in place of the wildcard because they're not
-    // already covered.
+    // already covered by an earlier arm.
     let is_external = adt_def.did().as_local().is_none();
     let has_external_hidden = is_external && adt_def.variants().iter().any(|x| is_hidden(cx, x));
     let mut missing_variants: Vec<_> = adt_def
@@ -54,42 +56,17 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
         .collect();
 
     let mut path_prefix = CommonPrefixSearcher::None;
-    for arm in arms {
-        // Guards mean that this case probably isn't exhaustively covered. Technically
-        // this is incorrect, as we should really check whether each variant is exhaustively
-        // covered by the set of guards that cover it, but that's really hard to do.
+    for arm in &arms[..wildcard_idx] {
         recurse_or_patterns(arm.pat, |pat| {
             let path = match &peel_hir_pat_refs(pat).0.kind {
-                PatKind::Path(path) => {
-                    let id = match cx.qpath_res(path, pat.hir_id) {
-                        Res::Def(
-                            DefKind::Const | DefKind::ConstParam | DefKind::AnonConst | DefKind::InlineConst,
-                            _,
-                        ) => return,
-                        Res::Def(_, id) => id,
-                        _ => return,
-                    };
-                    if arm.guard.is_none() {
-                        missing_variants.retain(|e| e.ctor_def_id() != Some(id));
-                    }
-                    path
-                },
-                PatKind::TupleStruct(path, patterns, ..) => {
-                    if let Some(id) = cx.qpath_res(path, pat.hir_id).opt_def_id() {
-                        if arm.guard.is_none() && patterns.iter().all(|p| !is_refutable(cx, p)) {
-                            missing_variants.retain(|e| e.ctor_def_id() != Some(id));
-                        }
-                    }
-                    path
-                },
-                PatKind::Struct(path, patterns, ..) => {
-                    if let Some(id) = cx.qpath_res(path, pat.hir_id).opt_def_id() {
-                        if arm.guard.is_none() && patterns.iter().all(|p| !is_refutable(cx, p.pat)) {
-                            missing_variants.retain(|e| e.def_id != id);
-                        }
-                    }
-                    path
+                PatKind::Path(path) => match cx.qpath_res(path, pat.hir_id) {
+                    Res::Def(DefKind::Const | DefKind::ConstParam | DefKind::AnonConst | DefKind::InlineConst, _) => {
+                        return;
+                    },
+                    Res::Def(..) => path,
+                    _ => return,
                 },
+                PatKind::TupleStruct(path, ..) | PatKind::Struct(path, ..) => path,
                 _ => return,
             };
             match path {
@@ -106,6 +83,25 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
         });
     }
 
+    // Guards mean that this case probably isn't exhaustively covered. Technically
+    // this is incorrect, as we should really check whether each variant is exhaustively
+    // covered by the set of guards that cover it, but that's really hard to do.
+    let arena = DroplessArena::default();
+    let arm_pats: Vec<_> = arms[..wildcard_idx]
+        .iter()
+        .filter(|arm| arm.guard.is_none())
+        .map(|arm| PatSummary::from_pat(cx, &arena, arm.pat))
+        .collect();
+    missing_variants.retain(|v| !is_variant_exhaustive(cx, ty, v, &arm_pats));
+
+    // The suggestion can only be applied as is if the variants can be named the same way the other
+    // arms name them, and if no binding has to be repeated for each variant.
+    let app = if wildcard_ident.is_none() && matches!(path_prefix, CommonPrefixSearcher::Path(_)) {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+
     let format_suggestion = |variant: &VariantDef| {
         format!(
             "{}{}{}{}",
@@ -145,7 +141,7 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
             "wildcard matches only a single variant and will also match any future added variants",
             "try this",
             format_suggestion(x),
-            Applicability::MaybeIncorrect,
+            app,
         ),
         variants => {
             let mut suggestions: Vec<_> = variants.iter().copied().map(format_suggestion).collect();
@@ -163,7 +159,7 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
                 message,
                 "try this",
                 suggestions.join(" | "),
-                Applicability::MaybeIncorrect,
+                app,
             );
         },
     };
@@ -1,10 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_note;
 use clippy_utils::macros::{is_panic, root_macro_call};
+use clippy_utils::pat_overlap::{is_exhaustive, PatSummary};
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::is_local_used;
 use clippy_utils::{in_constant, is_wild, peel_blocks_with_stmt};
+use rustc_arena::DroplessArena;
 use rustc_hir::{Arm, Expr, PatKind};
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::symbol::{kw, sym};
 
 use super::MATCH_WILD_ERR_ARM;
@@ -16,13 +19,24 @@ pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &Expr<'tcx>, arms: &[Arm<'
     }
 
     let ex_ty = cx.typeck_results().expr_ty(ex).peel_refs();
-    if is_type_diagnostic_item(cx, ex_ty, sym::Result) {
+    if is_type_diagnostic_item(cx, ex_ty, sym::Result)
+        && let ty::Adt(_, substs) = ex_ty.kind()
+    {
+        let err_ty = substs.type_at(1);
         for arm in arms {
             if let PatKind::TupleStruct(ref path, inner, _) = arm.pat.kind {
                 let path_str = rustc_hir_pretty::to_string(rustc_hir_pretty::NO_ANN, |s| s.print_qpath(path, false));
                 if path_str == "Err" {
                     let mut matching_wild = inner.iter().any(is_wild);
                     let mut ident_bind_name = kw::Underscore;
+                    if !matching_wild {
+                        // Looking for patterns without bindings matching every error, e.g.
+                        // `Err(Error::A | Error::B)`
+                        let arena = DroplessArena::default();
+                        let pats: Vec<_> = inner.iter().map(|pat| PatSummary::from_pat(cx, &arena, pat)).collect();
+                        matching_wild =
+                            inner.iter().all(|pat| !pat.contains_bindings()) && is_exhaustive(cx, err_ty, &pats);
+                    }
                     if !matching_wild {
                         // Looking for unused bindings (i.e.: `_e`)
                         for pat in inner {
@@ -1,22 +1,34 @@
 mod collapsible_match;
 mod infallible_destructuring_match;
+mod manual_and_then;
 mod manual_filter;
 mod manual_map;
+mod manual_min_max;
+mod manual_split_first_last;
 mod manual_unwrap_or;
 mod manual_utils;
+mod match_all_arms_same;
+mod match_as_deref;
 mod match_as_ref;
 mod match_bool;
 mod match_like_matches;
+mod match_lookup_table;
+mod match_on_enum_cast;
 mod match_on_vec_items;
 mod match_ref_pats;
 mod match_same_arms;
+mod match_shared_trailing_stmt;
 mod match_single_binding;
 mod match_str_case_mismatch;
 mod match_wild_enum;
 mod match_wild_err_arm;
 mod needless_match;
+mod nested_match;
+mod nested_match_same_scrutinee;
 mod overlapping_arms;
+mod redundant_match_guard;
 mod redundant_pattern_match;
+mod redundant_ref_binding;
 mod rest_pat_in_fully_bound_struct;
 mod significant_drop_in_scrutinee;
 mod single_match;
@@ -936,17 +948,443 @@
     "reimplementation of `filter`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` expressions where every arm has the same body and no arm has a guard or
+    /// binds anything.
+    ///
+    /// ### Why is this bad?
+    /// The `match` doesn't do anything besides evaluating the scrutinee. Either it can be replaced
+    /// with the body, or one of the arms was meant to do something else.
+    ///
+    /// ### Known problems
+    /// A `match` like this is sometimes used on purpose so that adding a new enum variant causes a
+    /// compile error.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # enum Foo { A, B }
+    /// # let foo = Foo::A;
+    /// let x = match foo {
+    ///     Foo::A => 1,
+    ///     Foo::B => 1,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x = 1;
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MATCH_ALL_ARMS_SAME,
+    pedantic,
+    "`match` where all the arms have the same body"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` expressions on an enum where every arm matches unit variants and
+    /// evaluates to a literal of the same type.
+    ///
+    /// Only enums without fields whose discriminants are `0, 1, 2, ..` in declaration order are
+    /// checked, since the variants of other enums can't index an array.
+    ///
+    /// ### Why is this bad?
+    /// A long `match` like this is a lookup table written out the long way. Indexing a `const`
+    /// array with the variant's discriminant is shorter, or the variant can be cast directly if
+    /// the values are its discriminants.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # #[derive(Clone, Copy)]
+    /// # enum Color { Red, Green, Blue, Black }
+    /// # let color = Color::Red;
+    /// let weight = match color {
+    ///     Color::Red => 3,
+    ///     Color::Green => 5,
+    ///     Color::Blue => 1,
+    ///     Color::Black => 8,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # #[derive(Clone, Copy)]
+    /// # enum Color { Red, Green, Blue, Black }
+    /// # let color = Color::Red;
+    /// const WEIGHTS: [u32; 4] = [3, 5, 1, 8];
+    /// let weight = WEIGHTS[color as usize];
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MATCH_LOOKUP_TABLE,
+    pedantic,
+    "`match` on unit variants which only evaluates to literals"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for match guards which are always true for the values matched by the arm's
+    /// pattern.
+    ///
+    /// ### Why is this bad?
+    /// The guard re-tests what the pattern already guarantees, which makes the arm harder to read.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let x: u32 = 0;
+    /// match x {
+    ///     n @ 0..=9 if (0..10).contains(&n) => println!("{n} is a single digit"),
+    ///     _ => println!("multiple digits"),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x: u32 = 0;
+    /// match x {
+    ///     n @ 0..=9 => println!("{n} is a single digit"),
+    ///     _ => println!("multiple digits"),
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub REDUNDANT_MATCH_GUARD,
+    complexity,
+    "match guard which is implied by the arm's pattern"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match`es on the result of `Ord::cmp` which only pick the smaller or the greater
+    /// of the compared values, or clamp a value between two bounds.
+    ///
+    /// ### Why is this bad?
+    /// `min`, `max` and `clamp` state the intent directly.
+    ///
+    /// ### Known problems
+    /// `clamp` panics if the lower bound is greater than the upper bound, while the `match` doesn't.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # let (a, b) = (1, 2);
+    /// let smallest = match a.cmp(&b) {
+    ///     Ordering::Less | Ordering::Equal => a,
+    ///     Ordering::Greater => b,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = (1, 2);
+    /// let smallest = a.min(b);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_MIN_MAX,
+    complexity,
+    "`match` on `Ord::cmp` which reimplements `min`, `max` or `clamp`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` arms whose body is another `match` on a different local.
+    ///
+    /// ### Why is this bad?
+    /// Matching on both values at once shows every combination of cases at the same level, rather
+    /// than spreading them over several levels of nesting.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (a, b) = (Some(1), true);
+    /// let x = match a {
+    ///     Some(_) => match b {
+    ///         true => 1,
+    ///         false => 2,
+    ///     },
+    ///     None => 3,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = (Some(1), true);
+    /// let x = match (a, b) {
+    ///     (Some(_), true) => 1,
+    ///     (Some(_), false) => 2,
+    ///     (None, _) => 3,
+    /// };
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub NESTED_MATCH,
+    pedantic,
+    "`match` nested in an arm of another `match` on a different local"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match`es on a slice with one arm for the empty slice, and one arm which splits
+    /// off the first or the last element.
+    ///
+    /// ### Why is this bad?
+    /// `split_first` and `split_last` do the same, and name what is being done.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v: &[u32] = &[];
+    /// match v {
+    ///     [first, rest @ ..] => println!("{first} followed by {rest:?}"),
+    ///     [] => println!("empty"),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v: &[u32] = &[];
+    /// if let Some((first, rest)) = v.split_first() {
+    ///     println!("{first} followed by {rest:?}");
+    /// } else {
+    ///     println!("empty");
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_SPLIT_FIRST_LAST,
+    pedantic,
+    "`match` on a slice which reimplements `split_first` or `split_last`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match`es where every arm ends with the same statement.
+    ///
+    /// ### Why is this bad?
+    /// The statement is run regardless of which arm is taken, so it can be moved after the `match`.
+    /// Keeping a copy in each arm makes it easy to miss one when it needs to change.
+    ///
+    /// ### Known problems
+    /// The locals of each arm are dropped before the moved statement is run, instead of after it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # enum Event { Click, Key(char) }
+    /// # fn click() {}
+    /// # fn key(_: char) {}
+    /// # fn log_event() {}
+    /// # let event = Event::Click;
+    /// match event {
+    ///     Event::Click => {
+    ///         click();
+    ///         log_event();
+    ///     },
+    ///     Event::Key(c) => {
+    ///         key(c);
+    ///         log_event();
+    ///     },
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # enum Event { Click, Key(char) }
+    /// # fn click() {}
+    /// # fn key(_: char) {}
+    /// # fn log_event() {}
+    /// # let event = Event::Click;
+    /// match event {
+    ///     Event::Click => {
+    ///         click();
+    ///     },
+    ///     Event::Key(c) => {
+    ///         key(c);
+    ///     },
+    /// }
+    /// log_event();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MATCH_SHARED_TRAILING_STMT,
+    pedantic,
+    "every arm of a `match` ends with the same statement"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match`es which borrow the value of an `Option`, and possibly dereference it,
+    /// the same way `Option::as_ref`, `Option::as_deref` or their mutable versions do.
+    ///
+    /// ### Why is this bad?
+    /// The methods are shorter and easier to read.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let opt: Option<String> = None;
+    /// let s: Option<&str> = match &opt {
+    ///     Some(s) => Some(&**s),
+    ///     None => None,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let opt: Option<String> = None;
+    /// let s: Option<&str> = opt.as_deref();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MATCH_AS_DEREF,
+    complexity,
+    "a `match` on an `Option` which could be `as_ref()` or `as_deref()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match`es nested in an arm of another `match` on the same value.
+    ///
+    /// ### Why is this bad?
+    /// The outer pattern already matched the value, so the inner `match` has to repeat what is
+    /// already known, usually with `unreachable!()` arms. Binding the needed parts of the value in
+    /// the outer pattern is shorter and can't get out of sync.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let x = Some(1);
+    /// match x {
+    ///     Some(_) => match x {
+    ///         Some(y) => println!("{y}"),
+    ///         None => unreachable!(),
+    ///     },
+    ///     None => println!("none"),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = Some(1);
+    /// match x {
+    ///     Some(y) => println!("{y}"),
+    ///     None => println!("none"),
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub NESTED_MATCH_SAME_SCRUTINEE,
+    complexity,
+    "a `match` nested in an arm of another `match` on the same value"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `match` which could be implemented using `Option::and_then` or
+    /// `Option::or_else`.
+    ///
+    /// ### Why is this bad?
+    /// Using the method is clearer and more concise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # fn parse(s: &str) -> Option<u32> { s.parse().ok() }
+    /// # fn fallback() -> Option<&'static str> { None }
+    /// # let opt = Some("1");
+    /// let _ = match opt {
+    ///     Some(s) => parse(s),
+    ///     None => None,
+    /// };
+    /// let _ = match opt {
+    ///     Some(s) => Some(s),
+    ///     None => fallback(),
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn parse(s: &str) -> Option<u32> { s.parse().ok() }
+    /// # fn fallback() -> Option<&'static str> { None }
+    /// # let opt = Some("1");
+    /// let _ = opt.and_then(parse);
+    /// let _ = opt.or_else(fallback);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_AND_THEN,
+    pedantic,
+    "reimplementation of `Option::and_then` or `Option::or_else`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `ref` and `ref mut` bindings in patterns which already bind by reference due
+    /// to the default binding mode, e.g. when matching on a reference with a non-reference
+    /// pattern.
+    ///
+    /// ### Why is this bad?
+    /// The keyword doesn't change how the value is bound, and suggests to the reader that the
+    /// value would be moved without it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let x = Some(String::new());
+    /// match &x {
+    ///     Some(ref s) => println!("{s}"),
+    ///     None => {},
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x = Some(String::new());
+    /// match &x {
+    ///     Some(s) => println!("{s}"),
+    ///     None => {},
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub REDUNDANT_REF_BINDING,
+    pedantic,
+    "`ref` binding in a pattern which already binds by reference"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match`es on a fieldless enum cast to an integer, where every arm matches the
+    /// discriminant of one of the variants.
+    ///
+    /// ### Why is this bad?
+    /// Matching on the enum itself names the variants instead of their discriminants, which keeps
+    /// working when the discriminants change. It also lets the compiler check the `match` is
+    /// exhaustive, so a wildcard arm for values which can't occur isn't needed.
+    ///
+    /// ### Example
+    /// ```rust
+    /// enum Color { Red, Green, Blue }
+    /// # let color = Color::Red;
+    /// let name = match color as u8 {
+    ///     0 => "red",
+    ///     1 => "green",
+    ///     2 => "blue",
+    ///     _ => unreachable!(),
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// enum Color { Red, Green, Blue }
+    /// # let color = Color::Red;
+    /// let name = match color {
+    ///     Color::Red => "red",
+    ///     Color::Green => "green",
+    ///     Color::Blue => "blue",
+    /// };
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MATCH_ON_ENUM_CAST,
+    complexity,
+    "a `match` on an enum cast to an integer instead of on the enum"
+}
+
 #[derive(Default)]
 pub struct Matches {
     msrv: Msrv,
+    match_same_arms_min_complexity: u64,
+    match_same_arms_allow_renamed_bindings: bool,
+    match_same_arms_allow_unused_bindings: bool,
     infallible_destructuring_match_linted: bool,
 }
 
 impl Matches {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
+    pub fn new(
+        msrv: Msrv,
+        match_same_arms_min_complexity: u64,
+        match_same_arms_allow_renamed_bindings: bool,
+        match_same_arms_allow_unused_bindings: bool,
+    ) -> Self {
         Self {
             msrv,
+            match_same_arms_min_complexity,
+            match_same_arms_allow_renamed_bindings,
+            match_same_arms_allow_unused_bindings,
             ..Matches::default()
         }
     }
@@ -978,6 +1416,18 @@ pub fn new(msrv: Msrv) -> Self {
     TRY_ERR,
     MANUAL_MAP,
     MANUAL_FILTER,
+    MATCH_ALL_ARMS_SAME,
+    MATCH_LOOKUP_TABLE,
+    REDUNDANT_MATCH_GUARD,
+    MANUAL_MIN_MAX,
+    NESTED_MATCH,
+    MANUAL_SPLIT_FIRST_LAST,
+    MATCH_SHARED_TRAILING_STMT,
+    MATCH_AS_DEREF,
+    NESTED_MATCH_SAME_SCRUTINEE,
+    MANUAL_AND_THEN,
+    REDUNDANT_REF_BINDING,
+    MATCH_ON_ENUM_CAST,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
@@ -1004,6 +1454,8 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 // These don't depend on a relationship between multiple arms
                 match_wild_err_arm::check(cx, ex, arms);
                 wild_in_or_pats::check(cx, arms);
+                redundant_match_guard::check(cx, arms);
+                redundant_ref_binding::check(cx, arms);
             }
 
             if source == MatchSource::TryDesugar {
@@ -1012,24 +1464,44 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
 
             if !from_expansion && !contains_cfg_arm(cx, expr, ex, arms) {
                 if source == MatchSource::Normal {
-                    if !(self.msrv.meets(msrvs::MATCHES_MACRO) && match_like_matches::check_match(cx, expr, ex, arms)) {
-                        match_same_arms::check(cx, arms);
+                    if !(self.msrv.meets(msrvs::MATCHES_MACRO) && match_like_matches::check_match(cx, expr, ex, arms))
+                        && !match_all_arms_same::check(cx, expr, ex, arms)
+                    {
+                        match_same_arms::check(
+                            cx,
+                            arms,
+                            self.match_same_arms_min_complexity,
+                            self.match_same_arms_allow_renamed_bindings,
+                            self.match_same_arms_allow_unused_bindings,
+                        );
                     }
 
                     redundant_pattern_match::check_match(cx, expr, ex, arms);
                     single_match::check(cx, ex, arms, expr);
                     match_bool::check(cx, ex, arms, expr);
+                    match_lookup_table::check(cx, expr, ex, arms);
+                    manual_min_max::check(cx, expr, ex, arms, &self.msrv);
+                    nested_match::check(cx, expr, ex, arms);
+                    nested_match_same_scrutinee::check(cx, expr, ex, arms);
+                    manual_split_first_last::check(cx, expr, ex, arms);
+                    match_shared_trailing_stmt::check(cx, expr, arms);
                     overlapping_arms::check(cx, ex, arms);
                     match_wild_enum::check(cx, ex, arms);
                     match_as_ref::check(cx, ex, arms, expr);
+                    let as_deref_linted = match_as_deref::check(cx, expr, ex, arms);
                     needless_match::check_match(cx, ex, arms, expr);
+                    match_on_enum_cast::check(cx, expr, ex, arms);
                     match_on_vec_items::check(cx, ex);
                     match_str_case_mismatch::check(cx, ex, arms);
 
                     if !in_constant(cx, expr.hir_id) {
                         manual_unwrap_or::check(cx, expr, ex, arms);
-                        manual_map::check_match(cx, expr, ex, arms);
+                        // Both lints suggest to replace the same `match`.
+                        if !as_deref_linted {
+                            manual_map::check_match(cx, expr, ex, arms);
+                        }
                         manual_filter::check_match(cx, ex, arms, expr);
+                        manual_and_then::check_match(cx, expr, ex, arms);
                     }
 
                     if self.infallible_destructuring_match_linted {
@@ -1064,6 +1536,14 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                             if_let.if_then,
                             else_expr,
                         );
+                        manual_and_then::check_if_let(
+                            cx,
+                            expr,
+                            if_let.let_pat,
+                            if_let.let_expr,
+                            if_let.if_then,
+                            else_expr,
+                        );
                     }
                 }
                 redundant_pattern_match::check_if_let(
@@ -1074,6 +1554,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     if_let.if_else.is_some(),
                 );
                 needless_match::check_if_let(cx, expr, &if_let);
+                redundant_ref_binding::check_pat(cx, if_let.let_pat);
             }
         } else if !from_expansion {
             redundant_pattern_match::check(cx, expr);
@@ -0,0 +1,115 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{indent_of, snippet_block_with_context, snippet_with_context};
+use clippy_utils::ty::is_copy;
+use clippy_utils::{path_to_local, peel_blocks, span_contains_comment};
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Expr, ExprKind, HirId, MatchSource, Pat, PatKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::Symbol;
+use std::borrow::Cow;
+
+use super::NESTED_MATCH;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, ex: &'tcx Expr<'_>, arms: &'tcx [Arm<'_>]) {
+    let Some(outer_id) = path_to_local(ex) else {
+        return;
+    };
+    let ctxt = expr.span.ctxt();
+
+    // The scrutinee of the nested `match`es, and the arms nested in each of the outer arms.
+    let mut inner_scrutinee = None;
+    let mut nested_arms = Vec::with_capacity(arms.len());
+    for arm in arms {
+        if arm.guard.is_some() {
+            return;
+        }
+        let body = peel_blocks(arm.body);
+        let inner = if let ExprKind::Match(inner_ex, inner_arms, MatchSource::Normal) = body.kind
+            && body.span.ctxt() == ctxt
+            && let Some(inner_id) = path_to_local(inner_ex)
+            && inner_id != outer_id
+        {
+            match inner_scrutinee {
+                None => inner_scrutinee = Some((inner_id, inner_ex)),
+                Some((id, _)) if id == inner_id => {},
+                Some(_) => return,
+            }
+            // The inner scrutinee has to be independent of the outer arm, and both patterns have to be
+            // combinable into a single tuple pattern.
+            if inner_arms.iter().any(|arm| arm.guard.is_some())
+                || binds_local(arm.pat, inner_id)
+                || inner_arms.iter().any(|inner| shares_binding_names(arm.pat, inner.pat))
+            {
+                return;
+            }
+            Some(inner_arms)
+        } else {
+            None
+        };
+        nested_arms.push(inner);
+    }
+    let Some((_, inner_ex)) = inner_scrutinee else {
+        return;
+    };
+
+    let mut app = if span_contains_comment(cx.sess().source_map(), expr.span) {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+    let typeck = cx.typeck_results();
+    if !is_copy(cx, typeck.expr_ty(ex)) || !is_copy(cx, typeck.expr_ty(inner_ex)) {
+        // The tuple moves both values, while the `match`es only move what their bindings move.
+        app = Applicability::MaybeIncorrect;
+    }
+
+    let indent = " ".repeat(indent_of(cx, expr.span).unwrap_or(0));
+    let mut new_arms = Vec::new();
+    for (arm, inner_arms) in arms.iter().zip(nested_arms) {
+        let outer_pat = snippet_with_context(cx, arm.pat.span, ctxt, "..", &mut app).0;
+        let rows: Vec<(Cow<'_, str>, &Expr<'_>)> = match inner_arms {
+            Some(inner_arms) => inner_arms
+                .iter()
+                .map(|inner| {
+                    (
+                        snippet_with_context(cx, inner.pat.span, ctxt, "..", &mut app).0,
+                        inner.body,
+                    )
+                })
+                .collect(),
+            None => vec![("_".into(), arm.body)],
+        };
+        for (inner_pat, body) in rows {
+            let body = snippet_block_with_context(cx, body.span, ctxt, "..", Some(arm.span), &mut app).0;
+            new_arms.push(format!("{indent}    ({outer_pat}, {inner_pat}) => {body},"));
+        }
+    }
+    let outer_scrutinee = snippet_with_context(cx, ex.span, ctxt, "..", &mut app).0;
+    let inner_scrutinee = snippet_with_context(cx, inner_ex.span, ctxt, "..", &mut app).0;
+
+    span_lint_and_sugg(
+        cx,
+        NESTED_MATCH,
+        expr.span,
+        "this `match` and the `match` nested in it can be combined into a single `match`",
+        "try",
+        format!(
+            "match ({outer_scrutinee}, {inner_scrutinee}) {{\n{}\n{indent}}}",
+            new_arms.join("\n")
+        ),
+        app,
+    );
+}
+
+fn binds_local(pat: &Pat<'_>, id: HirId) -> bool {
+    !pat.walk_short(|p| !matches!(p.kind, PatKind::Binding(_, binding_id, ..) if binding_id == id))
+}
+
+/// Checks if both patterns bind a value to the same name, which isn't allowed in a tuple pattern.
+fn shares_binding_names(left: &Pat<'_>, right: &Pat<'_>) -> bool {
+    let mut names: Vec<Symbol> = Vec::new();
+    left.each_binding_or_first(&mut |_, _, _, ident| names.push(ident.name));
+    let mut shared = false;
+    right.each_binding_or_first(&mut |_, _, _, ident| shared |= names.contains(&ident.name));
+    shared
+}
@@ -0,0 +1,29 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{peel_blocks, SpanlessEq};
+use rustc_hir::{Arm, Expr, ExprKind, MatchSource};
+use rustc_lint::LateContext;
+
+use super::NESTED_MATCH_SAME_SCRUTINEE;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
+    let ctxt = expr.span.ctxt();
+    for arm in arms {
+        let body = peel_blocks(arm.body);
+        if let ExprKind::Match(inner_ex, _, MatchSource::Normal) = body.kind
+            && body.span.ctxt() == ctxt
+            // Evaluating the scrutinee again has to give the same value.
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(ex, inner_ex)
+        {
+            span_lint_and_then(
+                cx,
+                NESTED_MATCH_SAME_SCRUTINEE,
+                body.span,
+                "this `match` is on the same value as the `match` it's nested in",
+                |diag| {
+                    diag.span_note(arm.pat.span, "the value was already matched by this pattern");
+                    diag.help("bind the parts of the value that are needed in the outer pattern instead");
+                },
+            );
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use clippy_utils::consts::{constant, constant_full_int, miri_to_const, FullInt};
+use clippy_utils::consts::{constant, miri_to_const, Constant, FullInt};
 use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::pat_overlap::PatRange;
 use core::cmp::Ordering;
 use rustc_hir::{Arm, Expr, PatKind, RangeEnd};
 use rustc_lint::LateContext;
@@ -10,8 +11,9 @@
 use super::MATCH_OVERLAPPING_ARM;
 
 pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &'tcx Expr<'_>, arms: &'tcx [Arm<'_>]) {
-    if arms.len() >= 2 && cx.typeck_results().expr_ty(ex).is_integral() {
-        let ranges = all_ranges(cx, arms, cx.typeck_results().expr_ty(ex));
+    let ty = cx.typeck_results().expr_ty(ex);
+    if arms.len() >= 2 && (ty.is_integral() || ty.is_char()) {
+        let ranges = all_ranges(cx, arms, ty);
         if !ranges.is_empty() {
             if let Some((start, end)) = overlapping(&ranges) {
                 span_lint_and_note(
@@ -28,7 +30,7 @@ pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &'tcx Expr<'_>, arms: &'tc
 }
 
 /// Gets the ranges for each range pattern arm. Applies `ty` bounds for open ranges.
-fn all_ranges<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>], ty: Ty<'tcx>) -> Vec<SpannedRange<FullInt>> {
+fn all_ranges<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>], ty: Ty<'tcx>) -> Vec<SpannedRange> {
     arms.iter()
         .filter_map(|arm| {
             if let Arm { pat, guard: None, .. } = *arm {
@@ -55,23 +57,25 @@ fn all_ranges<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>], ty: Ty<'tcx>)
                             miri_to_const(cx, max_constant)?
                         },
                     };
-                    let lhs_val = lhs_const.int_value(cx, ty)?;
-                    let rhs_val = rhs_const.int_value(cx, ty)?;
-                    let rhs_bound = match range_end {
-                        RangeEnd::Included => EndBound::Included(rhs_val),
-                        RangeEnd::Excluded => EndBound::Excluded(rhs_val),
-                    };
                     return Some(SpannedRange {
                         span: pat.span,
-                        node: (lhs_val, rhs_bound),
+                        range: PatRange {
+                            start: range_value(cx, &lhs_const, ty)?,
+                            end: range_value(cx, &rhs_const, ty)?,
+                            bounds: range_end,
+                        },
                     });
                 }
 
                 if let PatKind::Lit(value) = pat.kind {
-                    let value = constant_full_int(cx, cx.typeck_results(), value)?;
+                    let value = range_value(cx, &constant(cx, cx.typeck_results(), value)?, ty)?;
                     return Some(SpannedRange {
                         span: pat.span,
-                        node: (value, EndBound::Included(value)),
+                        range: PatRange {
+                            start: value,
+                            end: value,
+                            bounds: RangeEnd::Included,
+                        },
                     });
                 }
             }
@@ -80,22 +84,26 @@ fn all_ranges<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>], ty: Ty<'tcx>)
         .collect()
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum EndBound<T> {
-    Included(T),
-    Excluded(T),
+/// Gets the value of an integer or `char` constant as a `u128` which orders the same way as the
+/// values of the type. `char`s are compared by their code point.
+fn range_value(cx: &LateContext<'_>, value: &Constant<'_>, ty: Ty<'_>) -> Option<u128> {
+    match *value {
+        Constant::Char(c) => Some(c.into()),
+        _ => match value.int_value(cx, ty)? {
+            // Flipping the sign bit keeps the order of the signed values.
+            FullInt::S(value) => Some(value as u128 ^ (1 << 127)),
+            FullInt::U(value) => Some(value),
+        },
+    }
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct SpannedRange<T> {
+struct SpannedRange {
     pub span: Span,
-    pub node: (T, EndBound<T>),
+    pub range: PatRange,
 }
 
-fn overlapping<T>(ranges: &[SpannedRange<T>]) -> Option<(&SpannedRange<T>, &SpannedRange<T>)>
-where
-    T: Copy + Ord,
-{
+fn overlapping(ranges: &[SpannedRange]) -> Option<(&SpannedRange, &SpannedRange)> {
     #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
     enum BoundKind {
         EndExcluded,
@@ -104,15 +112,15 @@ enum BoundKind {
     }
 
     #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-    struct RangeBound<'a, T>(T, BoundKind, &'a SpannedRange<T>);
+    struct RangeBound<'a>(u128, BoundKind, &'a SpannedRange);
 
-    impl<'a, T: Copy + Ord> PartialOrd for RangeBound<'a, T> {
+    impl<'a> PartialOrd for RangeBound<'a> {
         fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
             Some(self.cmp(other))
         }
     }
 
-    impl<'a, T: Copy + Ord> Ord for RangeBound<'a, T> {
+    impl<'a> Ord for RangeBound<'a> {
         fn cmp(&self, RangeBound(other_value, other_kind, _): &Self) -> Ordering {
             let RangeBound(self_value, self_kind, _) = *self;
             (self_value, self_kind).cmp(&(*other_value, *other_kind))
@@ -121,11 +129,11 @@ fn cmp(&self, RangeBound(other_value, other_kind, _): &Self) -> Ordering {
 
     let mut values = Vec::with_capacity(2 * ranges.len());
 
-    for r @ SpannedRange { node: (start, end), .. } in ranges {
-        values.push(RangeBound(*start, BoundKind::Start, r));
-        values.push(match end {
-            EndBound::Excluded(val) => RangeBound(*val, BoundKind::EndExcluded, r),
-            EndBound::Included(val) => RangeBound(*val, BoundKind::EndIncluded, r),
+    for r in ranges {
+        values.push(RangeBound(r.range.start, BoundKind::Start, r));
+        values.push(match r.range.bounds {
+            RangeEnd::Excluded => RangeBound(r.range.end, BoundKind::EndExcluded, r),
+            RangeEnd::Included => RangeBound(r.range.end, BoundKind::EndIncluded, r),
         });
     }
 
@@ -160,35 +168,22 @@ fn cmp(&self, RangeBound(other_value, other_kind, _): &Self) -> Ordering {
 fn test_overlapping() {
     use rustc_span::source_map::DUMMY_SP;
 
-    let sp = |s, e| SpannedRange {
+    let sp = |start, end, bounds| SpannedRange {
         span: DUMMY_SP,
-        node: (s, e),
+        range: PatRange { start, end, bounds },
     };
+    let inc = RangeEnd::Included;
 
-    assert_eq!(None, overlapping::<u8>(&[]));
-    assert_eq!(None, overlapping(&[sp(1, EndBound::Included(4))]));
-    assert_eq!(
-        None,
-        overlapping(&[sp(1, EndBound::Included(4)), sp(5, EndBound::Included(6))])
-    );
-    assert_eq!(
-        None,
-        overlapping(&[
-            sp(1, EndBound::Included(4)),
-            sp(5, EndBound::Included(6)),
-            sp(10, EndBound::Included(11))
-        ],)
-    );
+    assert_eq!(None, overlapping(&[]));
+    assert_eq!(None, overlapping(&[sp(1, 4, inc)]));
+    assert_eq!(None, overlapping(&[sp(1, 4, inc), sp(5, 6, inc)]));
+    assert_eq!(None, overlapping(&[sp(1, 4, inc), sp(5, 6, inc), sp(10, 11, inc)]));
     assert_eq!(
-        Some((&sp(1, EndBound::Included(4)), &sp(3, EndBound::Included(6)))),
-        overlapping(&[sp(1, EndBound::Included(4)), sp(3, EndBound::Included(6))])
+        Some((&sp(1, 4, inc), &sp(3, 6, inc))),
+        overlapping(&[sp(1, 4, inc), sp(3, 6, inc)])
     );
     assert_eq!(
-        Some((&sp(5, EndBound::Included(6)), &sp(6, EndBound::Included(11)))),
-        overlapping(&[
-            sp(1, EndBound::Included(4)),
-            sp(5, EndBound::Included(6)),
-            sp(6, EndBound::Included(11))
-        ],)
+        Some((&sp(5, 6, inc), &sp(6, 11, inc))),
+        overlapping(&[sp(1, 4, inc), sp(5, 6, inc), sp(6, 11, inc)])
     );
 }
@@ -0,0 +1,185 @@
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::pat_overlap::{PatRange, PatSummary};
+use clippy_utils::{higher, is_res_lang_ctor, path_to_local};
+use rustc_arena::DroplessArena;
+use rustc_ast::ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
+use rustc_hir::{Arm, BinOpKind, BorrowKind, Expr, ExprKind, Guard, HirId, Pat, PatKind, RangeEnd};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::REDUNDANT_MATCH_GUARD;
+
+pub(super) fn check(cx: &LateContext<'_>, arms: &[Arm<'_>]) {
+    for arm in arms {
+        let Some(Guard::If(cond)) = arm.guard else {
+            continue;
+        };
+        if arm.span.from_expansion() || cond.span.from_expansion() {
+            continue;
+        }
+        if is_implied_by_pat(cx, arm.pat, cond) {
+            span_lint_and_sugg(
+                cx,
+                REDUNDANT_MATCH_GUARD,
+                arm.pat.span.shrink_to_hi().with_hi(cond.span.hi()),
+                "this guard is always true for values matched by the arm's pattern",
+                "remove the guard",
+                String::new(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+fn is_implied_by_pat(cx: &LateContext<'_>, pat: &Pat<'_>, cond: &Expr<'_>) -> bool {
+    match cond.kind {
+        ExprKind::MethodCall(path, receiver, [], _) => {
+            let Some(sub_pat) = path_to_local(receiver).and_then(|id| bound_sub_pat(pat, id)) else {
+                return false;
+            };
+            let lang_item = match path.ident.as_str() {
+                "is_some" => OptionSome,
+                "is_none" => OptionNone,
+                "is_ok" => ResultOk,
+                "is_err" => ResultErr,
+                _ => return false,
+            };
+            match sub_pat.kind {
+                PatKind::TupleStruct(ref qpath, ..) | PatKind::Path(ref qpath) => {
+                    is_res_lang_ctor(cx, cx.qpath_res(qpath, sub_pat.hir_id), lang_item)
+                },
+                _ => false,
+            }
+        },
+        ExprKind::MethodCall(path, receiver, [arg], _) if path.ident.name == sym!(contains) => {
+            let ExprKind::AddrOf(BorrowKind::Ref, _, arg) = arg.kind else {
+                return false;
+            };
+            let Some(range) = higher::Range::hir(receiver) else {
+                return false;
+            };
+            let start = match range.start {
+                Some(start) => lower_bound(cx, start),
+                None => Some(0),
+            };
+            let end = match range.end {
+                Some(end) => upper_bound(cx, end),
+                None => Some(u128::MAX),
+            };
+            let bounds = match range.limits {
+                RangeLimits::Closed => RangeEnd::Included,
+                RangeLimits::HalfOpen if range.end.is_some() => RangeEnd::Excluded,
+                RangeLimits::HalfOpen => RangeEnd::Included,
+            };
+            match (start, end) {
+                (Some(start), Some(end)) => is_local_contained_in(cx, pat, arg, &PatRange { start, end, bounds }),
+                _ => false,
+            }
+        },
+        ExprKind::Binary(op, lhs, rhs) => {
+            // Normalize the comparison so the local is on the left.
+            let (op, local, bound) = if path_to_local(lhs).is_some() {
+                (op.node, lhs, rhs)
+            } else {
+                let op = match op.node {
+                    BinOpKind::Lt => BinOpKind::Gt,
+                    BinOpKind::Le => BinOpKind::Ge,
+                    BinOpKind::Gt => BinOpKind::Lt,
+                    BinOpKind::Ge => BinOpKind::Le,
+                    _ => return false,
+                };
+                (op, rhs, lhs)
+            };
+            let range = match op {
+                BinOpKind::Lt => upper_bound(cx, bound).map(|end| PatRange {
+                    start: 0,
+                    end,
+                    bounds: RangeEnd::Excluded,
+                }),
+                BinOpKind::Le => upper_bound(cx, bound).map(|end| PatRange {
+                    start: 0,
+                    end,
+                    bounds: RangeEnd::Included,
+                }),
+                BinOpKind::Gt => lower_bound(cx, bound)
+                    .and_then(|start| start.checked_add(1))
+                    .map(|start| PatRange {
+                        start,
+                        end: u128::MAX,
+                        bounds: RangeEnd::Included,
+                    }),
+                BinOpKind::Ge => lower_bound(cx, bound).map(|start| PatRange {
+                    start,
+                    end: u128::MAX,
+                    bounds: RangeEnd::Included,
+                }),
+                _ => None,
+            };
+            range.is_some_and(|range| is_local_contained_in(cx, pat, local, &range))
+        },
+        _ => false,
+    }
+}
+
+/// Checks if the local is bound to a sub-pattern which only matches values inside the range.
+fn is_local_contained_in(cx: &LateContext<'_>, pat: &Pat<'_>, local: &Expr<'_>, range: &PatRange) -> bool {
+    // Values are only compared as unsigned integers, which negative values can't be mapped to.
+    if !matches!(cx.typeck_results().expr_ty(local).kind(), ty::Uint(_)) {
+        return false;
+    }
+    let Some(sub_pat) = path_to_local(local).and_then(|id| bound_sub_pat(pat, id)) else {
+        return false;
+    };
+    let arena = DroplessArena::default();
+    PatSummary::from_pat(cx, &arena, sub_pat).is_contained_in(range)
+}
+
+/// Gets the value of a lower bound. Negative values are clamped to zero.
+fn lower_bound(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    match constant_full_int(cx, cx.typeck_results(), e)? {
+        FullInt::U(x) => Some(x),
+        FullInt::S(x) => Some(u128::try_from(x).unwrap_or(0)),
+    }
+}
+
+/// Gets the value of an upper bound. Negative values aren't supported.
+fn upper_bound(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    match constant_full_int(cx, cx.typeck_results(), e)? {
+        FullInt::U(x) => Some(x),
+        FullInt::S(x) => u128::try_from(x).ok(),
+    }
+}
+
+/// Gets the sub-pattern the local is bound to, e.g. `1..=5` in `x @ 1..=5`. Returns `None` if the
+/// local isn't bound with a sub-pattern, or if it's bound in multiple alternatives of an
+/// or-pattern.
+fn bound_sub_pat<'a>(pat: &'a Pat<'a>, id: HirId) -> Option<&'a Pat<'a>> {
+    let mut name = None;
+    pat.walk_short(|p| match p.kind {
+        PatKind::Binding(_, binding_id, ident, _) if binding_id == id => {
+            name = Some(ident.name);
+            false
+        },
+        _ => true,
+    });
+    let name = name?;
+
+    let mut sub_pat = None;
+    let mut count = 0;
+    pat.walk_always(|p| {
+        if let PatKind::Binding(_, _, ident, sub) = p.kind
+            && ident.name == name
+        {
+            count += 1;
+            sub_pat = sub;
+        }
+    });
+    if count == 1 {
+        sub_pat
+    } else {
+        None
+    }
+}
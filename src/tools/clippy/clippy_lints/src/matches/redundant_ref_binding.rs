@@ -0,0 +1,73 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BindingAnnotation, ByRef, Mutability, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::REDUNDANT_REF_BINDING;
+
+pub(super) fn check(cx: &LateContext<'_>, arms: &[Arm<'_>]) {
+    for arm in arms {
+        check_pat(cx, arm.pat);
+    }
+}
+
+pub(super) fn check_pat(cx: &LateContext<'_>, pat: &Pat<'_>) {
+    walk(cx, pat, None);
+}
+
+/// Walks the pattern, tracking the default binding mode: `None` for moving the value, or the
+/// mutability of the reference the value is bound by.
+fn walk(cx: &LateContext<'_>, pat: &Pat<'_>, mut default_mode: Option<Mutability>) {
+    // Matching a reference with a non-reference pattern dereferences it, and switches the default
+    // binding mode to bind by reference.
+    if let Some(adjustments) = cx.typeck_results().pat_adjustments().get(pat.hir_id) {
+        for ty in adjustments {
+            if let ty::Ref(_, _, mutbl) = *ty.kind() {
+                default_mode = match default_mode {
+                    Some(Mutability::Not) => Some(Mutability::Not),
+                    _ => Some(mutbl),
+                };
+            }
+        }
+    }
+
+    match pat.kind {
+        PatKind::Binding(BindingAnnotation(by_ref, mutbl), _, ident, sub) => {
+            if by_ref == ByRef::Yes && default_mode == Some(mutbl) && !pat.span.from_expansion() {
+                let keyword = if mutbl.is_mut() { "ref mut" } else { "ref" };
+                span_lint_and_sugg(
+                    cx,
+                    REDUNDANT_REF_BINDING,
+                    pat.span.until(ident.span),
+                    &format!("`{keyword}` is redundant as the value is already bound by reference"),
+                    &format!("remove `{keyword}`"),
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            }
+            if let Some(sub) = sub {
+                walk(cx, sub, default_mode);
+            }
+        },
+        // An explicit reference pattern resets the default binding mode.
+        PatKind::Ref(inner, _) => walk(cx, inner, None),
+        PatKind::Box(inner) => walk(cx, inner, default_mode),
+        PatKind::Struct(_, fields, _) => {
+            for field in fields {
+                walk(cx, field.pat, default_mode);
+            }
+        },
+        PatKind::TupleStruct(_, pats, _) | PatKind::Tuple(pats, _) | PatKind::Or(pats) => {
+            for pat in pats {
+                walk(cx, pat, default_mode);
+            }
+        },
+        PatKind::Slice(before, mid, after) => {
+            for pat in before.iter().chain(mid).chain(after) {
+                walk(cx, pat, default_mode);
+            }
+        },
+        PatKind::Wild | PatKind::Path(_) | PatKind::Lit(_) | PatKind::Range(..) => {},
+    }
+}
@@ -1,7 +1,7 @@
 use crate::FxHashSet;
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::{indent_of, snippet};
-use clippy_utils::{get_attr, is_lint_allowed};
+use clippy_utils::{is_lint_allowed, ClippyAttrs};
 use rustc_errors::{Applicability, Diagnostic};
 use rustc_hir::intravisit::{walk_expr, Visitor};
 use rustc_hir::{Arm, Expr, ExprKind, MatchSource};
@@ -126,7 +126,10 @@ fn has_seen_type(&mut self, ty: Ty<'tcx>) -> bool {
 
     fn has_sig_drop_attr(&mut self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
         if let Some(adt) = ty.ty_adt_def() {
-            if get_attr(cx.sess(), cx.tcx.get_attrs_unchecked(adt.did()), "has_significant_drop").count() > 0 {
+            if ClippyAttrs::parse(cx.sess(), cx.tcx.get_attrs_unchecked(adt.did()))
+                .has_significant_drop
+                .is_some()
+            {
                 return true;
             }
         }
@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::{expr_block, get_source_text, snippet};
+use clippy_utils::source::{expr_block, get_source_text, snippet, sugg_with_comments};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item, peel_mid_ty_refs};
 use clippy_utils::{is_lint_allowed, is_unit_expr, is_wild, peel_blocks, peel_hir_pat_refs, peel_n_hir_expr_refs};
 use core::cmp::max;
@@ -135,6 +135,10 @@ fn report_single_pattern(
             (msg, sugg)
         }
     };
+    // Keep the comments between the arms, they would be removed with the `match` otherwise.
+    let mut kept = vec![ex.span, arms[0].pat.span, arms[0].body.span];
+    kept.extend(els.map(|els| els.span));
+    let sugg = sugg_with_comments(cx, expr.span, &kept, sugg);
 
     span_lint_and_sugg(cx, lint, expr.span, msg, "try this", sugg, app);
 }
@@ -1,4 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::source::{indent_of, reindent_multiline};
 use clippy_utils::ty::is_type_lang_item;
@@ -17,6 +18,7 @@ pub(super) fn check<'tcx>(
     call_span: Span,
     recv: &'tcx Expr<'_>,
     arg: &'tcx Expr<'_>,
+    msrv: &Msrv,
 ) {
     if let ExprKind::MethodCall(path_segment, ..) = recv.kind {
         if matches!(
@@ -53,12 +55,17 @@ pub(super) fn check<'tcx>(
                             recv_source = format!("&{recv_source}");
                         }
 
+                        let ext_check = if msrv.meets(msrvs::OPTION_IS_SOME_AND) {
+                            "is_some_and("
+                        } else {
+                            "map_or(false, "
+                        };
                         let suggestion_source = reindent_multiline(
                             format!(
                                 "std::path::Path::new({})
                                     .extension()
-                                    .map_or(false, |ext| ext.eq_ignore_ascii_case(\"{}\"))",
-                                recv_source, ext_str.strip_prefix('.').unwrap()).into(),
+                                    .{}|ext| ext.eq_ignore_ascii_case(\"{}\"))",
+                                recv_source, ext_check, ext_str.strip_prefix('.').unwrap()).into(),
                             true,
                             Some(indent_of(cx, call_span).unwrap_or(0) + 4)
                         );
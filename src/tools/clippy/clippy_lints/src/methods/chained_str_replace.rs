@@ -0,0 +1,109 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::{eq_expr_value, get_parent_expr};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+
+use super::no_effect_replace::lit_string_value;
+use super::{method_call, CHAINED_STR_REPLACE};
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, msrv: &Msrv) {
+    // only lint the whole chain, from its last call
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && let Some(("replace", recv, [_, _], _, _)) = method_call(parent)
+        && recv.hir_id == expr.hir_id
+        && is_str_replace(cx, parent)
+    {
+        return;
+    }
+
+    // the calls of the chain, from the first to the last, as `(from, to)` pairs
+    let mut calls = Vec::new();
+    let mut current = expr;
+    let mut first_span = expr.span;
+    while let Some(("replace", recv, [from, to], span, _)) = method_call(current)
+        && is_str_replace(cx, current)
+    {
+        calls.push((from, to));
+        first_span = span;
+        current = recv;
+    }
+    calls.reverse();
+    if calls.len() < 2 {
+        return;
+    }
+
+    // `collapsible_str_replace` suggests a single call replacing all the `char`s
+    if msrv.meets(msrvs::PATTERN_TRAIT_CHAR_ARRAY)
+        && calls.iter().all(|(from, to)| {
+            cx.typeck_results().expr_ty(from).peel_refs().is_char() && eq_expr_value(cx, to, calls[0].1)
+        })
+    {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        CHAINED_STR_REPLACE,
+        expr.span.with_lo(first_span.lo()),
+        "chained `str::replace` calls",
+        |diag| {
+            let mut overlaps = false;
+            for (i, (_, to)) in calls.iter().enumerate() {
+                let Some(replacement) = lit_value(to) else {
+                    continue;
+                };
+                for (from, _) in &calls[i + 1..] {
+                    if let Some(pattern) = lit_value(from)
+                        && can_match_replacement(&replacement, &pattern)
+                    {
+                        overlaps = true;
+                        diag.span_note(
+                            from.span,
+                            "this pattern can match text produced by an earlier replacement",
+                        );
+                        diag.span_note(to.span, "which is this replacement");
+                    }
+                }
+            }
+            if overlaps {
+                diag.help("review the order of the replacements, the result depends on it");
+            } else {
+                diag.help(
+                    "each call scans the whole string and allocates a new `String`, \
+                    consider replacing all patterns in a single pass",
+                );
+            }
+        },
+    );
+}
+
+fn is_str_replace(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .is_some_and(|impl_id| cx.tcx.type_of(impl_id).subst_identity().is_str())
+}
+
+fn lit_value(expr: &Expr<'_>) -> Option<String> {
+    if let ExprKind::Lit(lit) = expr.kind {
+        lit_string_value(&lit.node)
+    } else {
+        None
+    }
+}
+
+/// Checks if `pattern` can match text containing `replacement`, either inside of it, across one
+/// of its ends, or across the text joined by removing the replaced text.
+fn can_match_replacement(replacement: &str, pattern: &str) -> bool {
+    if pattern.is_empty() {
+        return false;
+    }
+    if replacement.is_empty() {
+        return pattern.chars().nth(1).is_some();
+    }
+    replacement.contains(pattern)
+        || (1..pattern.len())
+            .filter(|&i| pattern.is_char_boundary(i))
+            .any(|i| replacement.ends_with(&pattern[..i]) || replacement.starts_with(&pattern[i..]))
+}
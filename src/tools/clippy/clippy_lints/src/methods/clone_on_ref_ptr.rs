@@ -1,5 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::paths;
+use clippy_utils::resolve_method_call_target;
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::ty::{is_type_diagnostic_item, match_type};
 use rustc_errors::Applicability;
@@ -20,7 +21,10 @@ pub(super) fn check(
     if !(args.is_empty() && method_name == sym::clone) {
         return;
     }
-    let obj_ty = cx.typeck_results().expr_ty(receiver).peel_refs();
+    let Some(target) = resolve_method_call_target(cx, expr) else {
+        return;
+    };
+    let obj_ty = target.self_ty;
 
     if let ty::Adt(_, subst) = obj_ty.kind() {
         let caller_type = if is_type_diagnostic_item(cx, obj_ty, sym::Rc) {
@@ -0,0 +1,62 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::side_effects::expr_side_effects;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{is_trait_method, path_to_local, resolve_method_call_target};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Expr, Node, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::DOUBLE_ENDED_ITERATOR_LAST;
+
+/// lint use of `last()` on a `DoubleEndedIterator` which doesn't override it
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, call_span: Span) {
+    if !is_trait_method(cx, expr, sym::Iterator) || call_span.from_expansion() {
+        return;
+    }
+    // iterators overriding `last` usually implement it with `next_back` already
+    let Some(last_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+        return;
+    };
+    let Some(target) = resolve_method_call_target(cx, expr) else {
+        return;
+    };
+    if target.is_overridable
+        || target.def_id != last_id
+        || !cx
+            .tcx
+            .get_diagnostic_item(sym::DoubleEndedIterator)
+            .is_some_and(|id| implements_trait(cx, target.self_ty, id, &[]))
+    {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        DOUBLE_ENDED_ITERATOR_LAST,
+        call_span,
+        "called `Iterator::last` on a `DoubleEndedIterator`; this will needlessly iterate the entire iterator",
+        |diag| {
+            // `next_back()` skips over the remaining items, so any side effects they have are lost
+            let effects = expr_side_effects(cx, recv);
+            let mut app = if effects.does_io || effects.mutates || effects.calls_unknown {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            if let Some(id) = path_to_local(recv)
+                && let Node::Pat(pat) = cx.tcx.hir().get(id)
+                && let PatKind::Binding(ann, _, _, _) = pat.kind
+                && ann != BindingAnnotation::MUT
+            {
+                app = Applicability::Unspecified;
+                diag.span_help(
+                    pat.span,
+                    format!("for this change `{}` has to be mutable", snippet(cx, pat.span, "..")),
+                );
+            }
+            diag.span_suggestion(call_span, "use `next_back()` instead", "next_back()", app);
+        },
+    );
+}
@@ -1,14 +1,15 @@
 use crate::methods::DRAIN_COLLECT;
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::is_range_full;
-use clippy_utils::source::snippet;
+use clippy_utils::source::{snippet, snippet_with_context};
 use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{higher, is_range_full};
 use rustc_errors::Applicability;
 use rustc_hir::Expr;
 use rustc_hir::ExprKind;
 use rustc_hir::LangItem;
 use rustc_hir::Path;
 use rustc_hir::QPath;
+use rustc_hir::RangeLimits;
 use rustc_lint::LateContext;
 use rustc_middle::query::Key;
 use rustc_middle::ty;
@@ -54,6 +55,18 @@ fn check_collections(cx: &LateContext<'_>, expr: Ty<'_>, recv: Ty<'_>) -> Option
         .or_else(|| types_match_diagnostic_item(cx, expr, recv, sym::BinaryHeap).then_some("BinaryHeap"))
 }
 
+/// Checks the collections with a `split_off` method, which drains a tail of the collection
+/// into a new one of the same type.
+fn check_split_off(cx: &LateContext<'_>, expr: Ty<'_>, recv: Ty<'_>) -> Option<&'static str> {
+    types_match_diagnostic_item(cx, expr, recv, sym::Vec)
+        .then_some("Vec")
+        .or_else(|| types_match_diagnostic_item(cx, expr, recv, sym::VecDeque).then_some("VecDeque"))
+        .or_else(|| {
+            (is_type_lang_item(cx, expr, LangItem::String) && is_type_lang_item(cx, recv, LangItem::String))
+                .then_some("String")
+        })
+}
+
 pub(super) fn check(cx: &LateContext<'_>, args: &[Expr<'_>], expr: &Expr<'_>, recv: &Expr<'_>) {
     let expr_ty = cx.typeck_results().expr_ty(expr);
     let recv_ty = cx.typeck_results().expr_ty(recv);
@@ -81,5 +94,27 @@ pub(super) fn check(cx: &LateContext<'_>, args: &[Expr<'_>], expr: &Expr<'_>, re
             sugg,
             Applicability::MachineApplicable,
         );
+    } else if let [arg] = args
+        && let Some(typename) = check_split_off(cx, expr_ty, recv_ty_no_refs)
+        && let Some(higher::Range {
+            start: Some(start),
+            end: None,
+            limits: RangeLimits::HalfOpen,
+        }) = higher::Range::hir(arg)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let recv = snippet_with_context(cx, recv.span, ctxt, "<expr>", &mut applicability).0;
+        let start = snippet_with_context(cx, start.span, ctxt, "..", &mut applicability).0;
+
+        span_lint_and_sugg(
+            cx,
+            DRAIN_COLLECT,
+            expr.span,
+            &format!("you seem to be trying to move the tail of a `{typename}` into a new `{typename}`"),
+            "consider using `split_off`",
+            format!("{recv}.split_off({start})"),
+            applicability,
+        );
     }
 }
@@ -0,0 +1,54 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_trait_method;
+use clippy_utils::side_effects::expr_side_effects;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::implements_trait;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::FILTER_LAST;
+
+/// lint use of `filter().last()` for `DoubleEndedIterator`s
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    filter_recv: &'tcx hir::Expr<'_>,
+    filter_arg: &'tcx hir::Expr<'_>,
+) {
+    let filter_recv_ty = cx.typeck_results().expr_ty(filter_recv);
+    if cx
+        .tcx
+        .get_diagnostic_item(sym::DoubleEndedIterator)
+        .is_some_and(|double_ended_iterator| implements_trait(cx, filter_recv_ty, double_ended_iterator, &[]))
+        && is_trait_method(cx, recv, sym::Iterator)
+        && is_trait_method(cx, expr, sym::Iterator)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        // The closures in the chain are called for fewer elements and in the reverse order, and a
+        // local iterator needs to be declared as mutable.
+        let effects = expr_side_effects(cx, recv);
+        if effects.does_io
+            || effects.mutates
+            || effects.calls_unknown
+            || !matches!(
+                filter_recv.kind,
+                hir::ExprKind::MethodCall(..) | hir::ExprKind::Call(..)
+            )
+        {
+            applicability = Applicability::MaybeIncorrect;
+        }
+        let filter_snippet = snippet_with_applicability(cx, filter_arg.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            FILTER_LAST,
+            expr.span.with_lo(filter_recv.span.hi()),
+            "called `filter(..).last()` on a `DoubleEndedIterator`",
+            "search from the back instead",
+            format!(".rfind({filter_snippet})"),
+            applicability,
+        );
+    }
+}
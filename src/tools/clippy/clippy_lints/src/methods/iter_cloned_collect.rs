@@ -1,10 +1,13 @@
 use crate::methods::utils::derefs_to_slice;
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
 use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_expr_path_def_path, is_trait_method, paths};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::sym;
 
 use super::ITER_CLONED_COLLECT;
@@ -16,16 +19,89 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir:
         if let Some(to_replace) = expr.span.trim_start(slice.span.source_callsite());
 
         then {
+            let (receiver, method) = receiver_and_method(cx, slice);
             span_lint_and_sugg(
                 cx,
                 ITER_CLONED_COLLECT,
                 to_replace,
-                &format!("called `iter().{method_name}().collect()` on a slice to create a `Vec`. Calling `to_vec()` is both faster and \
-                more readable"),
+                &format!("called `iter().{method_name}().collect()` on {receiver} to create a `Vec`. Calling `{method}()` \
+                is both faster and more readable"),
                 "try",
-                ".to_vec()".to_string(),
+                format!(".{method}()"),
                 Applicability::MachineApplicable,
             );
         }
     }
 }
+
+/// Checks for `vec.into_iter().collect::<Vec<_>>()`, which gives back the same `Vec`.
+pub(super) fn check_into_iter(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>, vec: &hir::Expr<'_>) {
+    let vec_ty = cx.typeck_results().expr_ty(vec);
+    if is_type_diagnostic_item(cx, vec_ty, sym::Vec)
+        && cx.typeck_results().expr_ty(expr) == vec_ty
+        && is_trait_method(cx, recv, sym::IntoIterator)
+        && let Some(to_replace) = expr.span.trim_start(vec.span.source_callsite())
+    {
+        span_lint_and_sugg(
+            cx,
+            ITER_CLONED_COLLECT,
+            to_replace,
+            "called `into_iter().collect()` on a `Vec` to create the same `Vec`",
+            "remove the calls",
+            String::new(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Checks for `Vec::from_iter(slice.iter().cloned())`.
+pub(super) fn check_from_iter(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    func: &hir::Expr<'_>,
+    args: &[hir::Expr<'_>],
+) {
+    if_chain! {
+        if let [arg] = args;
+        if is_expr_path_def_path(cx, func, &paths::FROM_ITERATOR_METHOD);
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec);
+        if let hir::ExprKind::MethodCall(path, recv, [], _) = arg.kind;
+        if let method_name @ ("cloned" | "copied") = path.ident.as_str();
+        if is_trait_method(cx, arg, sym::Iterator);
+        if let Some(slice) = derefs_to_slice(cx, recv, cx.typeck_results().expr_ty(recv));
+
+        then {
+            let (receiver, method) = receiver_and_method(cx, slice);
+            let mut app = Applicability::MachineApplicable;
+            let snippet = snippet_with_context(cx, slice.span, expr.span.ctxt(), "..", &mut app).0;
+            span_lint_and_sugg(
+                cx,
+                ITER_CLONED_COLLECT,
+                expr.span,
+                &format!("called `Vec::from_iter` with `iter().{method_name}()` on {receiver}. Calling `{method}()` \
+                is both faster and more readable"),
+                "try",
+                format!("{snippet}.{method}()"),
+                app,
+            );
+        }
+    }
+}
+
+/// Describes the receiver and returns the method copying it into a `Vec`: a `Vec` place can be
+/// `clone`d as is, anything else goes through `to_vec`.
+fn receiver_and_method(cx: &LateContext<'_>, slice: &hir::Expr<'_>) -> (&'static str, &'static str) {
+    let slice_ty = cx.typeck_results().expr_ty(slice);
+    let slice_ty = if let ty::Ref(_, inner, _) = slice_ty.kind() {
+        *inner
+    } else {
+        slice_ty
+    };
+    if is_type_diagnostic_item(cx, slice_ty, sym::Vec)
+        && matches!(slice.kind, hir::ExprKind::Path(_) | hir::ExprKind::Field(..))
+    {
+        ("a `Vec`", "clone")
+    } else {
+        ("a slice", "to_vec")
+    }
+}
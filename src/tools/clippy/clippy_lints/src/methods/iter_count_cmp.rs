@@ -0,0 +1,111 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_trait_method;
+use clippy_utils::side_effects::expr_side_effects;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::{method_call, needless_as_bytes, ITER_COUNT_CMP};
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    lhs: &'tcx Expr<'tcx>,
+    rhs: &'tcx Expr<'tcx>,
+) {
+    let Some((count_recv, n, (min, at_least))) =
+        count_cmp(cx, lhs, rhs, op).or_else(|| count_cmp(cx, rhs, lhs, flip(op)?))
+    else {
+        return;
+    };
+    let method = match min {
+        1 => "next()",
+        2 => "nth(1)",
+        _ => return,
+    };
+    // `needless_as_bytes` suggests `is_empty` for strings.
+    if min == 1
+        && let Some(("chars", chars_recv, [], _, _)) = method_call(count_recv)
+        && needless_as_bytes::check_chars_count(cx, expr, count_recv, chars_recv, !at_least)
+    {
+        return;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    // The closures in the chain are called for fewer elements, and a local iterator needs to be
+    // declared as mutable.
+    let effects = expr_side_effects(cx, count_recv);
+    if effects.does_io
+        || effects.mutates
+        || effects.calls_unknown
+        || !matches!(count_recv.kind, ExprKind::MethodCall(..) | ExprKind::Call(..))
+    {
+        app = Applicability::MaybeIncorrect;
+    }
+    let recv = Sugg::hir_with_context(cx, count_recv, expr.span.ctxt(), "..", &mut app).maybe_par();
+    let check = if at_least { "is_some" } else { "is_none" };
+
+    span_lint_and_sugg(
+        cx,
+        ITER_COUNT_CMP,
+        expr.span,
+        &format!("counting all elements of an iterator to compare the count to `{n}`"),
+        "only consume the elements needed for the comparison",
+        format!("{recv}.{method}.{check}()"),
+        app,
+    );
+}
+
+/// Matches `count_expr <op> n` where `count_expr` is a call to `Iterator::count`. Returns the
+/// receiver of `count`, `n` and the result of `min_count`.
+fn count_cmp<'tcx>(
+    cx: &LateContext<'tcx>,
+    count_expr: &'tcx Expr<'tcx>,
+    other: &Expr<'_>,
+    op: BinOpKind,
+) -> Option<(&'tcx Expr<'tcx>, u128, (u128, bool))> {
+    let ("count", recv, [], _, _) = method_call(count_expr)? else {
+        return None;
+    };
+    if !is_trait_method(cx, count_expr, sym::Iterator) {
+        return None;
+    }
+    // `iter_count` suggests using the collection's length instead.
+    if let Some(("iter" | "iter_mut" | "into_iter", _, [], _, _)) = method_call(recv) {
+        return None;
+    }
+    let Some(Constant::Int(n)) = constant(cx, cx.typeck_results(), other) else {
+        return None;
+    };
+    Some((recv, n, min_count(op, n)?))
+}
+
+/// Returns the number of elements `count() <op> n` requires the iterator to have, and whether the
+/// comparison checks for at least, or for less than this number of elements.
+fn min_count(op: BinOpKind, n: u128) -> Option<(u128, bool)> {
+    match op {
+        BinOpKind::Eq if n == 0 => Some((1, false)),
+        BinOpKind::Ne if n == 0 => Some((1, true)),
+        BinOpKind::Gt => Some((n.checked_add(1)?, true)),
+        BinOpKind::Ge => Some((n, true)),
+        BinOpKind::Lt => Some((n, false)),
+        BinOpKind::Le => Some((n.checked_add(1)?, false)),
+        _ => None,
+    }
+}
+
+/// Returns the operator to use when swapping the operands.
+fn flip(op: BinOpKind) -> Option<BinOpKind> {
+    match op {
+        BinOpKind::Eq | BinOpKind::Ne => Some(op),
+        BinOpKind::Lt => Some(BinOpKind::Gt),
+        BinOpKind::Le => Some(BinOpKind::Ge),
+        BinOpKind::Gt => Some(BinOpKind::Lt),
+        BinOpKind::Ge => Some(BinOpKind::Le),
+        _ => None,
+    }
+}
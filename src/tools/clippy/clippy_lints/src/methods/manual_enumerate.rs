@@ -0,0 +1,83 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{get_parent_expr, higher, is_integer_const, is_trait_method};
+use rustc_ast::ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, UintTy};
+use rustc_span::{sym, Span};
+
+use super::MANUAL_ENUMERATE;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, zip_arg: &'tcx Expr<'_>, zip_span: Span) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && !zip_span.from_expansion()
+        // `0..` or `0..n`
+        && let Some(higher::Range { start: Some(start), end, limits: RangeLimits::HalfOpen }) = higher::Range::hir(zip_arg)
+        && is_integer_const(cx, start, 0)
+        // `enumerate` counts with `usize`s
+        && *cx.typeck_results().expr_ty(start).kind() == ty::Uint(UintTy::Usize)
+        // `0..x.len()` is handled by `RANGE_ZIP_WITH_LEN`
+        && !end.is_some_and(|end| matches!(end.kind, ExprKind::MethodCall(path, _, [], _) if path.ident.name == sym::len))
+    {
+        let mut app = Applicability::MachineApplicable;
+        let enumerate = match end {
+            Some(end) => format!(
+                "take({}).enumerate()",
+                snippet_with_applicability(cx, end.span, "..", &mut app)
+            ),
+            None => "enumerate()".to_string(),
+        };
+        let span = zip_span.with_hi(expr.span.hi());
+
+        span_lint_and_then(
+            cx,
+            MANUAL_ENUMERATE,
+            span,
+            "zipping an iterator with a range starting at zero",
+            |diag| {
+                diag.note("`enumerate` yields the index first, so the order of the tuple is flipped");
+                if let Some((first, second)) = following_closure_tuple(cx, expr) {
+                    let first_snippet = snippet_with_applicability(cx, first, "..", &mut app);
+                    let second_snippet = snippet_with_applicability(cx, second, "..", &mut app);
+                    diag.multipart_suggestion(
+                        "use `enumerate` and swap the destructured values",
+                        vec![
+                            (span, enumerate),
+                            (first, second_snippet.into_owned()),
+                            (second, first_snippet.into_owned()),
+                        ],
+                        app,
+                    );
+                } else {
+                    diag.span_suggestion(span, "use `enumerate`", enumerate, Applicability::MaybeIncorrect);
+                }
+            },
+        );
+    }
+}
+
+/// Returns the spans of the two values of the tuple destructured by the closure passed to the
+/// iterator method called right after `expr`, e.g. `x` and `i` in `.map(|(x, i)| ..)`.
+fn following_closure_tuple(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(Span, Span)> {
+    let parent = get_parent_expr(cx, expr)?;
+    if let ExprKind::MethodCall(_, recv, [arg], _) = parent.kind
+        && recv.hir_id == expr.hir_id
+        && is_trait_method(cx, parent, sym::Iterator)
+        && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let [param] = cx.tcx.hir().body(body).params
+    {
+        let pat = match param.pat.kind {
+            PatKind::Ref(pat, _) => pat,
+            _ => param.pat,
+        };
+        if let PatKind::Tuple([first, second], dotdot) = pat.kind
+            && dotdot.as_opt_usize().is_none()
+            && !pat.span.from_expansion()
+        {
+            return Some((first.span, second.span));
+        }
+    }
+    None
+}
@@ -0,0 +1,167 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{higher, is_res_lang_ctor, is_trait_method, path_res, path_to_local_id, peel_blocks};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{BinOpKind, BindingAnnotation, Closure, Expr, ExprKind, HirId, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, Span};
+
+use super::unnecessary_sort_by::mirrored_exprs;
+use super::MANUAL_OPTION_FOLD;
+
+/// The search a `fold(None, |acc, x| if cond { Some(x) } else { acc })` closure implements,
+/// depending on `cond`.
+enum Search<'tcx> {
+    /// `acc.is_none() && pred`
+    Find(&'tcx Expr<'tcx>),
+    /// `acc.map_or(true, |m| key(x) >= key(m))`, without a key if the items are compared
+    Max(Option<&'tcx Expr<'tcx>>),
+    /// `acc.map_or(true, |m| key(x) < key(m))`, without a key if the items are compared
+    Min(Option<&'tcx Expr<'tcx>>),
+}
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    init: &'tcx Expr<'_>,
+    acc: &'tcx Expr<'_>,
+    fold_span: Span,
+) {
+    if !is_trait_method(cx, expr, sym::Iterator)
+        || !is_res_lang_ctor(cx, path_res(cx, init), OptionNone)
+        || fold_span.from_expansion()
+    {
+        return;
+    }
+    let ExprKind::Closure(&Closure { body, .. }) = acc.kind else {
+        return;
+    };
+    let body = cx.tcx.hir().body(body);
+    if let [acc_param, item_param] = body.params
+        && let PatKind::Binding(_, acc_id, _, None) = acc_param.pat.kind
+        && let PatKind::Binding(BindingAnnotation::NONE, item_id, item_ident, None) = item_param.pat.kind
+        && let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(els),
+        }) = higher::If::hir(peel_blocks(body.value))
+        && path_to_local_id(peel_blocks(els), acc_id)
+        && let ExprKind::Call(some, [some_arg]) = peel_blocks(then).kind
+        && is_res_lang_ctor(cx, path_res(cx, some), OptionSome)
+        && path_to_local_id(some_arg, item_id)
+        && let Some(search) = classify(cx, cond, acc_id, item_id, item_ident)
+    {
+        let item_ty = cx.typeck_results().pat_ty(item_param.pat);
+        let is_ord = |e: &Expr<'_>| {
+            cx.tcx
+                .get_diagnostic_item(sym::Ord)
+                .is_some_and(|id| implements_trait(cx, cx.typeck_results().expr_ty(e), id, &[]))
+        };
+        let mut app = Applicability::MachineApplicable;
+        let (method, sugg) = match search {
+            // `find` passes a reference to the predicate
+            Search::Find(pred) if is_copy(cx, item_ty) => (
+                "find",
+                format!(
+                    "find(|&{item_ident}| {})",
+                    snippet_with_applicability(cx, pred.span, "..", &mut app)
+                ),
+            ),
+            Search::Max(None) if is_ord(some_arg) => ("max", "max()".to_string()),
+            Search::Min(None) if is_ord(some_arg) => ("min", "min()".to_string()),
+            Search::Max(Some(key)) | Search::Min(Some(key)) if is_ord(key) => {
+                let method = if matches!(search, Search::Max(_)) {
+                    "max_by_key"
+                } else {
+                    "min_by_key"
+                };
+                // the key is computed from a reference to the item
+                app = Applicability::MaybeIncorrect;
+                let key = snippet_with_applicability(cx, key.span, "..", &mut app);
+                (method, format!("{method}(|{item_ident}| {key})"))
+            },
+            _ => return,
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_OPTION_FOLD,
+            fold_span.with_hi(expr.span.hi()),
+            &format!("this `.fold` reimplements `Iterator::{method}`"),
+            &format!("use `{method}` instead"),
+            sugg,
+            app,
+        );
+    }
+}
+
+fn classify<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+    acc_id: HirId,
+    item_id: HirId,
+    item_ident: Ident,
+) -> Option<Search<'tcx>> {
+    match cond.kind {
+        ExprKind::Binary(op, left, pred) if op.node == BinOpKind::And => {
+            if let ExprKind::MethodCall(path, recv, [], _) = left.kind
+                && path.ident.name == sym!(is_none)
+                && path_to_local_id(recv, acc_id)
+                && !is_local_used(cx, pred, acc_id)
+            {
+                Some(Search::Find(pred))
+            } else {
+                None
+            }
+        },
+        ExprKind::MethodCall(path, recv, [default, map], _)
+            if path.ident.name == sym!(map_or)
+                && path_to_local_id(recv, acc_id)
+                && matches!(default.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Bool(true))) =>
+        {
+            let ExprKind::Closure(&Closure { body, .. }) = map.kind else {
+                return None;
+            };
+            let body = cx.tcx.hir().body(body);
+            let [param] = body.params else {
+                return None;
+            };
+            let PatKind::Binding(_, _, current_ident, None) = param.pat.kind else {
+                return None;
+            };
+            let ExprKind::Binary(op, left, right) = peel_blocks(body.value).kind else {
+                return None;
+            };
+            // `key(x) op key(m)`, or `key(m) op key(x)` with the operator flipped
+            let (key, op) = if mirrored_exprs(left, &item_ident, right, &current_ident) {
+                (left, op.node)
+            } else if mirrored_exprs(left, &current_ident, right, &item_ident) {
+                let flipped = match op.node {
+                    BinOpKind::Lt => BinOpKind::Gt,
+                    BinOpKind::Le => BinOpKind::Ge,
+                    BinOpKind::Gt => BinOpKind::Lt,
+                    BinOpKind::Ge => BinOpKind::Le,
+                    _ => return None,
+                };
+                (right, flipped)
+            } else {
+                return None;
+            };
+            if is_local_used(cx, key, acc_id) {
+                return None;
+            }
+            let key = (!path_to_local_id(key, item_id)).then_some(key);
+            // `max` and `max_by_key` return the last maximum, `min` and `min_by_key` the first minimum
+            match op {
+                BinOpKind::Ge => Some(Search::Max(key)),
+                BinOpKind::Lt => Some(Search::Min(key)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
@@ -6,6 +6,7 @@
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
 use rustc_lint::LateContext;
 use rustc_middle::mir::Mutability;
 use rustc_middle::ty;
@@ -19,58 +20,85 @@ pub(super) fn check(cx: &LateContext<'_>, e: &hir::Expr<'_>, recv: &hir::Expr<'_
     if_chain! {
         if let Some(method_id) = cx.typeck_results().type_dependent_def_id(e.hir_id);
         if cx.tcx.impl_of_method(method_id)
-            .map_or(false, |id| is_type_diagnostic_item(cx, cx.tcx.type_of(id).subst_identity(), sym::Option))
+            .is_some_and(|id| is_type_diagnostic_item(cx, cx.tcx.type_of(id).subst_identity(), sym::Option))
             || is_diag_trait_item(cx, method_id, sym::Iterator);
-        if let hir::ExprKind::Closure(&hir::Closure{ body, .. }) = arg.kind;
         then {
-            let closure_body = cx.tcx.hir().body(body);
-            let closure_expr = peel_blocks(closure_body.value);
-            match closure_body.params[0].pat.kind {
-                hir::PatKind::Ref(inner, hir::Mutability::Not) => if let hir::PatKind::Binding(
-                    hir::BindingAnnotation::NONE, .., name, None
-                ) = inner.kind {
-                    if ident_eq(name, closure_expr) {
-                        lint_explicit_closure(cx, e.span, recv.span, true, msrv);
-                    }
-                },
-                hir::PatKind::Binding(hir::BindingAnnotation::NONE, .., name, None) => {
-                    match closure_expr.kind {
-                        hir::ExprKind::Unary(hir::UnOp::Deref, inner) => {
-                            if ident_eq(name, inner) {
-                                if let ty::Ref(.., Mutability::Not) = cx.typeck_results().expr_ty(inner).kind() {
-                                    lint_explicit_closure(cx, e.span, recv.span, true, msrv);
-                                }
+            match arg.kind {
+                hir::ExprKind::Closure(&hir::Closure { body, .. }) => {
+                    let closure_body = cx.tcx.hir().body(body);
+                    let closure_expr = peel_blocks(closure_body.value);
+                    match closure_body.params[0].pat.kind {
+                        hir::PatKind::Ref(inner, hir::Mutability::Not) => if let hir::PatKind::Binding(
+                            hir::BindingAnnotation::NONE, .., name, None
+                        ) = inner.kind {
+                            if ident_eq(name, closure_expr) {
+                                lint_explicit_closure(cx, e.span, recv.span, true, msrv);
                             }
                         },
-                        hir::ExprKind::MethodCall(method, obj, [], _) => if_chain! {
-                            if ident_eq(name, obj) && method.ident.name == sym::clone;
-                            if let Some(fn_id) = cx.typeck_results().type_dependent_def_id(closure_expr.hir_id);
-                            if let Some(trait_id) = cx.tcx.trait_of_item(fn_id);
-                            if cx.tcx.lang_items().clone_trait().map_or(false, |id| id == trait_id);
-                            // no autoderefs
-                            if !cx.typeck_results().expr_adjustments(obj).iter()
-                                .any(|a| matches!(a.kind, Adjust::Deref(Some(..))));
-                            then {
-                                let obj_ty = cx.typeck_results().expr_ty(obj);
-                                if let ty::Ref(_, ty, mutability) = obj_ty.kind() {
-                                    if matches!(mutability, Mutability::Not) {
-                                        let copy = is_copy(cx, *ty);
-                                        lint_explicit_closure(cx, e.span, recv.span, copy, msrv);
+                        hir::PatKind::Binding(hir::BindingAnnotation::NONE, .., name, None) => {
+                            match closure_expr.kind {
+                                hir::ExprKind::Unary(hir::UnOp::Deref, inner) => {
+                                    if ident_eq(name, inner)
+                                        && let ty::Ref(.., Mutability::Not) = cx.typeck_results().expr_ty(inner).kind()
+                                    {
+                                        lint_explicit_closure(cx, e.span, recv.span, true, msrv);
                                     }
-                                } else {
-                                    lint_needless_cloning(cx, e.span, recv.span);
-                                }
+                                },
+                                hir::ExprKind::MethodCall(method, obj, [], _) => if_chain! {
+                                    if ident_eq(name, obj) && method.ident.name == sym::clone;
+                                    if let Some(fn_id) = cx.typeck_results().type_dependent_def_id(closure_expr.hir_id);
+                                    if let Some(trait_id) = cx.tcx.trait_of_item(fn_id);
+                                    if cx.tcx.lang_items().clone_trait().is_some_and(|id| id == trait_id);
+                                    // no autoderefs
+                                    if !cx.typeck_results().expr_adjustments(obj).iter()
+                                        .any(|a| matches!(a.kind, Adjust::Deref(Some(..))));
+                                    then {
+                                        let obj_ty = cx.typeck_results().expr_ty(obj);
+                                        if let ty::Ref(_, ty, mutability) = obj_ty.kind() {
+                                            if matches!(mutability, Mutability::Not) {
+                                                let copy = is_copy(cx, *ty);
+                                                lint_explicit_closure(cx, e.span, recv.span, copy, msrv);
+                                            }
+                                        } else {
+                                            lint_needless_cloning(cx, e.span, recv.span);
+                                        }
+                                    }
+                                },
+                                _ => {},
                             }
                         },
                         _ => {},
                     }
                 },
+                hir::ExprKind::Path(ref qpath) => check_path(cx, e, recv, arg, qpath, msrv),
                 _ => {},
             }
         }
     }
 }
 
+/// Checks `map` calls given a path to `Clone::clone` or `ToOwned::to_owned`.
+fn check_path(
+    cx: &LateContext<'_>,
+    e: &hir::Expr<'_>,
+    recv: &hir::Expr<'_>,
+    arg: &hir::Expr<'_>,
+    qpath: &hir::QPath<'_>,
+    msrv: &Msrv,
+) {
+    if let Res::Def(DefKind::AssocFn, fn_id) = cx.qpath_res(qpath, arg.hir_id)
+        && let Some(trait_id) = cx.tcx.trait_of_item(fn_id)
+        && let ty::FnDef(_, substs) = cx.typeck_results().expr_ty(arg).kind()
+        && let self_ty = substs.type_at(0)
+        // `ToOwned` is only implemented by cloning for sized types, e.g. `str::to_owned` creates a `String`
+        && (cx.tcx.lang_items().clone_trait() == Some(trait_id)
+            || (cx.tcx.is_diagnostic_item(sym::ToOwned, trait_id) && self_ty.is_sized(cx.tcx, cx.param_env)))
+    {
+        let using = format!("`{}::{}`", cx.tcx.item_name(trait_id), cx.tcx.item_name(fn_id));
+        lint_dedicated_method(cx, e.span, recv.span, is_copy(cx, self_ty), msrv, &using);
+    }
+}
+
 fn ident_eq(name: Ident, path: &hir::Expr<'_>) -> bool {
     if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = path.kind {
         path.segments.len() == 1 && path.segments[0].ident == name
@@ -92,19 +120,23 @@ fn lint_needless_cloning(cx: &LateContext<'_>, root: Span, receiver: Span) {
 }
 
 fn lint_explicit_closure(cx: &LateContext<'_>, replace: Span, root: Span, is_copy: bool, msrv: &Msrv) {
+    lint_dedicated_method(cx, replace, root, is_copy, msrv, "an explicit closure");
+}
+
+fn lint_dedicated_method(cx: &LateContext<'_>, replace: Span, root: Span, is_copy: bool, msrv: &Msrv, using: &str) {
     let mut applicability = Applicability::MachineApplicable;
 
-    let (message, sugg_method) = if is_copy && msrv.meets(msrvs::ITERATOR_COPIED) {
-        ("you are using an explicit closure for copying elements", "copied")
+    let (action, sugg_method) = if is_copy && msrv.meets(msrvs::ITERATOR_COPIED) {
+        ("copying", "copied")
     } else {
-        ("you are using an explicit closure for cloning elements", "cloned")
+        ("cloning", "cloned")
     };
 
     span_lint_and_sugg(
         cx,
         MAP_CLONE,
         replace,
-        message,
+        &format!("you are using {using} for {action} elements"),
         &format!("consider calling the dedicated `{sugg_method}` method"),
         format!(
             "{}.{sugg_method}()",
@@ -54,7 +54,7 @@ pub(super) fn check<'tcx>(
         let unwrap_snippet = snippet(cx, unwrap_arg.span, "..");
         // lint, with note if neither arg is > 1 line and both map() and
         // unwrap_or_else() have the same span
-        let multiline = map_snippet.lines().count() > 1 || unwrap_snippet.lines().count() > 1;
+        let multiline = map_snippet.lines().nth(1).is_some() || unwrap_snippet.lines().nth(1).is_some();
         let same_span = map_arg.span.ctxt() == unwrap_arg.span.ctxt();
         if same_span && !multiline {
             let var_snippet = snippet(cx, recv.span, "..");
@@ -3,6 +3,7 @@
 mod bytes_count_to_len;
 mod bytes_nth;
 mod case_sensitive_file_extension_comparisons;
+mod chained_str_replace;
 mod chars_cmp;
 mod chars_cmp_with_unwrap;
 mod chars_last_cmp;
@@ -14,12 +15,14 @@
 mod clone_on_ref_ptr;
 mod cloned_instead_of_copied;
 mod collapsible_str_replace;
+mod double_ended_iterator_last;
 mod drain_collect;
 mod err_expect;
 mod expect_fun_call;
 mod expect_used;
 mod extend_with_drain;
 mod filetype_is_file;
+mod filter_last;
 mod filter_map;
 mod filter_map_identity;
 mod filter_map_next;
@@ -37,6 +40,7 @@
 mod is_digit_ascii_radix;
 mod iter_cloned_collect;
 mod iter_count;
+mod iter_count_cmp;
 mod iter_kv_map;
 mod iter_next_slice;
 mod iter_nth;
@@ -46,8 +50,10 @@
 mod iter_skip_next;
 mod iter_with_drain;
 mod iterator_step_by_zero;
+mod manual_enumerate;
 mod manual_next_back;
 mod manual_ok_or;
+mod manual_option_fold;
 mod manual_saturating_arithmetic;
 mod manual_str_repeat;
 mod manual_try_fold;
@@ -58,6 +64,8 @@
 mod map_identity;
 mod map_unwrap_or;
 mod mut_mutex_lock;
+mod naive_string_reversal;
+mod needless_as_bytes;
 mod needless_collect;
 mod needless_option_as_deref;
 mod needless_option_take;
@@ -81,13 +89,18 @@
 mod single_char_pattern;
 mod single_char_push_string;
 mod skip_while_next;
+mod slice_contains_in_loop;
+mod sort_by_partial_cmp;
 mod stable_sort_primitive;
 mod str_splitn;
 mod string_extend_chars;
 mod suspicious_command_arg_space;
+mod suspicious_iterator_adapter;
 mod suspicious_map;
 mod suspicious_splitn;
 mod suspicious_to_owned;
+mod take_while_count;
+mod to_string_in_push_str;
 mod uninit_assumed_init;
 mod unit_hash;
 mod unnecessary_filter_map;
@@ -96,12 +109,14 @@
 mod unnecessary_join;
 mod unnecessary_lazy_eval;
 mod unnecessary_literal_unwrap;
+mod unnecessary_map_or;
 mod unnecessary_sort_by;
 mod unnecessary_to_owned;
 mod unwrap_or_else_default;
 mod unwrap_used;
 mod useless_asref;
 mod utils;
+mod vec_front_modification;
 mod vec_resize_to_zero;
 mod verbose_file_reads;
 mod wrong_self_convention;
@@ -471,29 +486,47 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `_.unwrap_or_else(Default::default)` on `Option` and
-    /// `Result` values.
+    /// Checks for usage of `_.unwrap_or_else(Default::default)` and
+    /// `_.unwrap_or(Default::default())` on `Option` and `Result` values, and
+    /// the equivalent `or_insert_with`/`or_insert` calls on `HashMap` and
+    /// `BTreeMap` entries. `Option::get_or_insert_with` is also checked when
+    /// the `option_get_or_insert_default` feature is enabled, as are
+    /// `_.or_else(|| Some(Default::default()))` and `_.or(Ok(Vec::new()))`.
+    ///
+    /// Besides `Default::default`, the constructors of the standard library
+    /// types such as `Vec::new` and `String::from("")` are recognized, and
+    /// `T::new` for other types implementing `Default`, although nothing
+    /// guarantees that it constructs the same value.
     ///
     /// ### Why is this bad?
-    /// Readability, these can be written as `_.unwrap_or_default`, which is
-    /// simpler and more concise.
+    /// Readability, these can be written as `_.unwrap_or_default()`,
+    /// `_.or_default()` and `_.get_or_insert_default()`, which are simpler and
+    /// more concise.
     ///
     /// ### Examples
     /// ```rust
+    /// # use std::collections::HashMap;
     /// # let x = Some(1);
+    /// # let mut map = HashMap::<u32, Vec<u32>>::new();
     /// x.unwrap_or_else(Default::default);
-    /// x.unwrap_or_else(u32::default);
+    /// x.unwrap_or(u32::default());
+    /// x.or_else(|| Some(Default::default()));
+    /// map.entry(42).or_insert_with(Vec::new);
     /// ```
     ///
     /// Use instead:
     /// ```rust
+    /// # use std::collections::HashMap;
     /// # let x = Some(1);
+    /// # let mut map = HashMap::<u32, Vec<u32>>::new();
     /// x.unwrap_or_default();
+    /// Some(x.unwrap_or_default());
+    /// map.entry(42).or_default();
     /// ```
     #[clippy::version = "1.56.0"]
     pub UNWRAP_OR_ELSE_DEFAULT,
     style,
-    "using `.unwrap_or_else(Default::default)`, which is more succinctly expressed as `.unwrap_or_default()`"
+    "using `.unwrap_or_else(Default::default)` and similar, which is more succinctly expressed as `.unwrap_or_default()`"
 }
 
 declare_clippy_lint! {
@@ -1408,26 +1441,33 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for the use of `.cloned().collect()` on slice to
-    /// create a `Vec`.
+    /// Checks for the use of `.cloned().collect()` or `.copied().collect()` on
+    /// slice to create a `Vec`, including through `Vec::from_iter`, and for
+    /// `.into_iter().collect()` on a `Vec` giving back the same `Vec`.
     ///
     /// ### Why is this bad?
-    /// `.to_vec()` is clearer
+    /// `.to_vec()` is clearer, and a `Vec` can simply be `.clone()`d
     ///
     /// ### Example
     /// ```rust
     /// let s = [1, 2, 3, 4, 5];
     /// let s2: Vec<isize> = s[..].iter().cloned().collect();
+    /// let v = vec![1, 2, 3];
+    /// let v2: Vec<isize> = Vec::from_iter(v.iter().copied());
+    /// let v3: Vec<isize> = v.into_iter().collect();
     /// ```
     /// The better use would be:
     /// ```rust
     /// let s = [1, 2, 3, 4, 5];
     /// let s2: Vec<isize> = s.to_vec();
+    /// let v = vec![1, 2, 3];
+    /// let v2: Vec<isize> = v.clone();
+    /// let v3: Vec<isize> = v;
     /// ```
     #[clippy::version = "pre 1.29.0"]
     pub ITER_CLONED_COLLECT,
     style,
-    "using `.cloned().collect()` on slice to create a `Vec`"
+    "using `.cloned().collect()` on slice or `.into_iter().collect()` on `Vec` to create a `Vec`"
 }
 
 declare_clippy_lint! {
@@ -2170,7 +2210,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `str::splitn(2, _)`
+    /// Checks for usage of `str::splitn(2, _)` and `str::rsplitn(2, _)`, and of
+    /// `str::splitn(3, _)` collected into a `Vec` which is then indexed.
     ///
     /// ### Why is this bad?
     /// `split_once` is both clearer in intent and slightly more efficient.
@@ -2184,6 +2225,11 @@
     /// let mut parts = s.splitn(2, '=');
     /// let key = parts.next()?;
     /// let value = parts.next()?;
+    ///
+    /// let parts: Vec<&str> = s.splitn(3, '=').collect();
+    /// let key = parts[0];
+    /// let value = parts[1];
+    /// let rest = parts[2];
     /// ```
     ///
     /// Use instead:
@@ -2193,11 +2239,15 @@
     /// let value = s.split_once('=')?.1;
     ///
     /// let (key, value) = s.split_once('=')?;
+    ///
+    /// let (key, parts) = s.split_once('=').unwrap();
+    /// let (value, rest) = parts.split_once('=').unwrap();
     /// ```
     ///
     /// ### Limitations
-    /// The multiple statement variant currently only detects `iter.next()?`/`iter.next().unwrap()`
-    /// in two separate `let` statements that immediately follow the `splitn()`
+    /// The multiple statement variants currently only detect `iter.next()?`/`iter.next().unwrap()`
+    /// in two separate `let` statements, or `parts[0]`/`parts[1]`/`parts[2]` with a literal pattern
+    /// in three separate `let` statements, that immediately follow the `splitn()`
     #[clippy::version = "1.57.0"]
     pub MANUAL_SPLIT_ONCE,
     complexity,
@@ -2614,9 +2664,10 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `map(|x| x.clone())` or
-    /// dereferencing closures for `Copy` types, on `Iterator` or `Option`,
-    /// and suggests `cloned()` or `copied()` instead
+    /// Checks for usage of `map(|x| x.clone())`, `map(Clone::clone)`,
+    /// `map(ToOwned::to_owned)` on sized types or dereferencing closures for
+    /// `Copy` types, on `Iterator` or `Option`, and suggests `cloned()` or
+    /// `copied()` instead
     ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely
@@ -3254,6 +3305,8 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for calls to `.drain()` that clear the collection, immediately followed by a call to `.collect()`.
+    /// Calls to `.drain(n..)` that remove the tail of a `Vec`, `VecDeque` or `String` into a new
+    /// one of the same type are checked as well.
     ///
     /// > "Collection" in this context refers to any type with a `drain` method:
     /// > `Vec`, `VecDeque`, `BinaryHeap`, `HashSet`,`HashMap`, `String`
@@ -3263,6 +3316,9 @@
     /// When using `mem::take`, the old collection is replaced with an empty one and ownership of
     /// the old collection is returned.
     ///
+    /// Similarly, `split_off(n)` moves the tail in one go instead of moving every element
+    /// through an iterator.
+    ///
     /// ### Known issues
     /// `mem::take(&mut vec)` is almost equivalent to `vec.drain(..).collect()`, except that
     /// it also moves the **capacity**. The user might have explicitly written it this way
@@ -3273,6 +3329,10 @@
     /// fn remove_all(v: &mut Vec<i32>) -> Vec<i32> {
     ///     v.drain(..).collect()
     /// }
+    ///
+    /// fn remove_tail(v: &mut Vec<i32>, n: usize) -> Vec<i32> {
+    ///     v.drain(n..).collect()
+    /// }
     /// ```
     /// Use instead:
     /// ```rust
@@ -3280,6 +3340,10 @@
     /// fn remove_all(v: &mut Vec<i32>) -> Vec<i32> {
     ///     mem::take(v)
     /// }
+    ///
+    /// fn remove_tail(v: &mut Vec<i32>, n: usize) -> Vec<i32> {
+    ///     v.split_off(n)
+    /// }
     /// ```
     #[clippy::version = "1.71.0"]
     pub DRAIN_COLLECT,
@@ -3316,11 +3380,286 @@
     "checks for usage of `Iterator::fold` with a type that implements `Try`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `_.filter(_).last()` on a `DoubleEndedIterator`.
+    ///
+    /// ### Why is this bad?
+    /// `last` consumes the whole iterator to find the last matching element, while `rfind`
+    /// searches from the back and stops at the first match.
+    ///
+    /// ### Known problems
+    /// The predicate is called on fewer elements and in the reverse order, which changes the
+    /// behavior if it has side effects.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let vec = vec![1];
+    /// vec.iter().filter(|x| **x == 0).last();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # let vec = vec![1];
+    /// vec.iter().rfind(|x| **x == 0);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub FILTER_LAST,
+    perf,
+    "using `filter(p).last()` on a `DoubleEndedIterator`, which is more efficiently expressed as `.rfind(p)`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons of `Iterator::count` against `0`, `1` or `2`.
+    ///
+    /// ### Why is this bad?
+    /// `count` consumes the whole iterator, while the comparison only needs to know whether
+    /// the iterator has at least one or two elements.
+    ///
+    /// ### Known problems
+    /// The closures in the iterator chain are called for fewer elements, which changes the
+    /// behavior if they have side effects.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let text = "";
+    /// let blank = text.split_whitespace().count() == 0;
+    /// let multiline = text.lines().count() >= 2;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let text = "";
+    /// let blank = text.split_whitespace().next().is_none();
+    /// let multiline = text.lines().nth(1).is_some();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ITER_COUNT_CMP,
+    perf,
+    "comparing the `count` of an iterator to a small constant"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `_.map_or(false, _)` on `Option` and `Result` values, and of
+    /// `_.map(_).unwrap_or(false)` on `Result` values.
+    ///
+    /// The negated forms, `_.map_or(true, |x| !_)` and `_.map(|x| !_).unwrap_or(true)`, are
+    /// checked as well. Other closures given to `_.map_or(true, _)` aren't linted, as they
+    /// would need to be negated.
+    ///
+    /// ### Why is this bad?
+    /// Readability, these can be written more concisely as `_.is_some_and(_)` and
+    /// `_.is_ok_and(_)`, or their negation.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let option = Some(1);
+    /// # let result: Result<usize, ()> = Ok(1);
+    /// option.map_or(false, |a| a > 10);
+    /// result.map(|a| a > 10).unwrap_or(false);
+    /// option.map_or(true, |a| !a.is_power_of_two());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let option = Some(1);
+    /// # let result: Result<usize, ()> = Ok(1);
+    /// option.is_some_and(|a| a > 10);
+    /// result.is_ok_and(|a| a > 10);
+    /// !option.is_some_and(|a| a.is_power_of_two());
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub UNNECESSARY_MAP_OR,
+    style,
+    "using `.map_or(false, _)`, which is more succinctly expressed as `.is_some_and(_)` or `.is_ok_and(_)`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `sort_by` and `sort_unstable_by` comparators unwrapping the result of
+    /// `partial_cmp`, like `|a, b| a.partial_cmp(b).unwrap()`.
+    ///
+    /// ### Why is this bad?
+    /// The sort panics as soon as two elements can't be compared, e.g. when a `NaN` shows up in
+    /// a slice of floats. Floats can be sorted with `total_cmp` instead, which orders every value,
+    /// and `Ord` types don't need `partial_cmp` in the first place.
+    ///
+    /// ### Known problems
+    /// `total_cmp` orders `-0.0` before `0.0`, where `partial_cmp` considers them equal.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut floats = vec![1.0_f64, 0.5];
+    /// # let mut ints = vec![1, 2];
+    /// floats.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// ints.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut floats = vec![1.0_f64, 0.5];
+    /// # let mut ints = vec![1, 2];
+    /// floats.sort_by(f64::total_cmp);
+    /// ints.sort();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SORT_BY_PARTIAL_CMP,
+    suspicious,
+    "unwrapping the result of `partial_cmp` in a `sort_by` comparator"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `_.iter().take_while(p).count()` on slices.
+    ///
+    /// ### Why is this bad?
+    /// Counting the leading elements matching `p` looks for the index of the first element not
+    /// matching it, which `position` says more directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let vec = vec![1, 2, 0];
+    /// let boundary = vec.iter().take_while(|&x| *x != 0).count();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let vec = vec![1, 2, 0];
+    /// let boundary = vec.iter().position(|x| *x == 0).unwrap_or(vec.len());
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub TAKE_WHILE_COUNT,
+    pedantic,
+    "using `take_while(p).count()` on a slice iterator, which is more clearly expressed with `position`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for zipping an iterator with an ascending `usize` range starting at zero, like
+    /// `_.zip(0..)` or `_.zip(0..n)`.
+    ///
+    /// ### Why is this bad?
+    /// The code is better expressed with `enumerate`, with `take(n)` for a bounded range.
+    ///
+    /// ### Known problems
+    /// `enumerate` yields the index first, so the order of the tuples is flipped. Patterns
+    /// destructuring them are only adjusted in a closure passed to the following method.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v = vec![1_usize, 2];
+    /// let _ = v.iter().zip(0..).map(|(x, i)| x * i);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1_usize, 2];
+    /// let _ = v.iter().enumerate().map(|(i, x)| x * i);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_ENUMERATE,
+    style,
+    "zipping an iterator with a range starting at zero when `enumerate()` would do"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `fold(None, ..)` calls whose closure keeps the first item matching a predicate,
+    /// or the maximum or minimum item.
+    ///
+    /// ### Why is this bad?
+    /// Readability, these are the `find`, `max`, `min`, `max_by_key` and `min_by_key` methods of
+    /// `Iterator`, and `find` also stops at the first match.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v = vec!["a", "bc"];
+    /// let first = v.iter().fold(None, |acc, x| {
+    ///     if acc.is_none() && x.len() > 1 { Some(x) } else { acc }
+    /// });
+    /// let longest = v.iter().fold(None, |acc, x| {
+    ///     if acc.map_or(true, |m| x.len() >= m.len()) { Some(x) } else { acc }
+    /// });
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec!["a", "bc"];
+    /// let first = v.iter().find(|&x| x.len() > 1);
+    /// let longest = v.iter().max_by_key(|x| x.len());
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_OPTION_FOLD,
+    complexity,
+    "using `fold(None, ..)` to reimplement `find`, `max`, `min`, `max_by_key` or `min_by_key`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the result of `to_string()` being appended to a `String`, with `push_str` or
+    /// `+=`.
+    ///
+    /// ### Why is this bad?
+    /// `to_string()` allocates a new `String` which is immediately dropped after being appended.
+    /// A `char` can be pushed as is, and other values can be written to the `String` directly
+    /// with `write!`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut s = String::new();
+    /// for (i, c) in ['a', 'b'].into_iter().enumerate() {
+    ///     s.push_str(&c.to_string());
+    ///     s += &i.to_string();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt::Write as _;
+    ///
+    /// let mut s = String::new();
+    /// for (i, c) in ['a', 'b'].into_iter().enumerate() {
+    ///     s.push(c);
+    ///     let _ = write!(s, "{i}");
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub TO_STRING_IN_PUSH_STR,
+    pedantic,
+    "appending the result of `to_string()` to a `String`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Iterator::last` being called on a `DoubleEndedIterator` which doesn't provide
+    /// its own implementation of `last`.
+    ///
+    /// ### Why is this bad?
+    /// The default implementation of `last` consumes the whole iterator to find its last element,
+    /// while `next_back` takes it from the back directly.
+    ///
+    /// ### Known problems
+    /// Unlike `last`, `next_back` doesn't evaluate the adapters for the other elements, e.g. the
+    /// closure passed to `map` is only called once.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let last = v.iter().map(|x| x * 2).last();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let last = v.iter().map(|x| x * 2).next_back();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DOUBLE_ENDED_ITERATOR_LAST,
+    perf,
+    "using `last()` on a `DoubleEndedIterator`, which is more efficiently expressed as `next_back()`"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
     allow_expect_in_tests: bool,
     allow_unwrap_in_tests: bool,
+    naive_string_reversal_all_rev: bool,
+    vec_front_modification_min_iterations: u64,
 }
 
 impl Methods {
@@ -3330,16 +3669,224 @@ pub fn new(
         msrv: Msrv,
         allow_expect_in_tests: bool,
         allow_unwrap_in_tests: bool,
+        naive_string_reversal_all_rev: bool,
+        vec_front_modification_min_iterations: u64,
     ) -> Self {
         Self {
             avoid_breaking_exported_api,
             msrv,
             allow_expect_in_tests,
             allow_unwrap_in_tests,
+            naive_string_reversal_all_rev,
+            vec_front_modification_min_iterations,
         }
     }
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `as_bytes().len()` and `as_bytes().is_empty()` called on a `str` or `String`,
+    /// and for `chars().count()` compared to zero.
+    ///
+    /// ### Why is this bad?
+    /// `len` and `is_empty` can be called on the string directly, and they don't need to decode
+    /// the whole string as `chars().count()` does.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let s = "foo";
+    /// let len = s.as_bytes().len();
+    /// let empty = s.chars().count() == 0;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let s = "foo";
+    /// let len = s.len();
+    /// let empty = s.is_empty();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub NEEDLESS_AS_BYTES,
+    complexity,
+    "calling `len` or `is_empty` through `as_bytes`, or counting `chars` to check for emptiness"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for chains of two or more `str::replace` calls, and notes when a later pattern
+    /// can match text produced by an earlier replacement.
+    ///
+    /// ### Why is this bad?
+    /// Every call scans the whole string and allocates a new `String`. A later call also sees
+    /// the output of the earlier ones, so the result depends on the order of the calls, which is
+    /// easy to get wrong when a pattern overlaps with an earlier replacement.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let text = "";
+    /// // `&` has to be escaped first, otherwise `&lt;` would become `&amp;lt;`
+    /// let escaped = text.replace('<', "&lt;").replace('&', "&amp;");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let text = "";
+    /// let mut escaped = String::with_capacity(text.len());
+    /// for c in text.chars() {
+    ///     match c {
+    ///         '<' => escaped.push_str("&lt;"),
+    ///         '&' => escaped.push_str("&amp;"),
+    ///         c => escaped.push(c),
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CHAINED_STR_REPLACE,
+    pedantic,
+    "chained `str::replace` calls, which scan the string once per call and can replace each other's output"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `contains` calls on a `Vec` or slice inside a loop, when the loop doesn't
+    /// iterate over the searched collection nor change it.
+    ///
+    /// ### Why is this bad?
+    /// `contains` compares the value to every element, so searching in every iteration of the
+    /// loop takes quadratic time. Collecting the elements into a `HashSet`, or sorting them to use
+    /// `binary_search`, once before the loop makes each lookup cheap.
+    ///
+    /// ### Known problems
+    /// Building the lookup structure isn't worth it for small collections or few iterations.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (allowed, requests) = (vec![1, 2], vec![3, 4]);
+    /// for request in &requests {
+    ///     if allowed.contains(request) {
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # let (allowed, requests) = (vec![1, 2], vec![3, 4]);
+    /// let allowed: HashSet<_> = allowed.into_iter().collect();
+    /// for request in &requests {
+    ///     if allowed.contains(request) {
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SLICE_CONTAINS_IN_LOOP,
+    pedantic,
+    "searching a `Vec` or slice with `contains` on every iteration of a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for strings reversed with `chars().rev().collect::<String>()`.
+    ///
+    /// With the `naive-string-reversal-all-rev` configuration, every `chars().rev()` on a string
+    /// is linted, e.g. in palindrome checks.
+    ///
+    /// ### Why is this bad?
+    /// `chars` yields Unicode scalar values, not what a user perceives as characters. Reversing
+    /// them breaks up grapheme clusters made of several scalar values, such as a letter followed
+    /// by a combining accent, or emoji sequences.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let reversed: String = "noe\u{308}l".chars().rev().collect();
+    /// // the diaeresis now is on the `l`
+    /// assert_eq!(reversed, "l\u{308}eon");
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use unicode_segmentation::UnicodeSegmentation;
+    ///
+    /// let reversed: String = "noe\u{308}l".graphemes(true).rev().collect();
+    /// assert_eq!(reversed, "le\u{308}on");
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub NAIVE_STRING_REVERSAL,
+    pedantic,
+    "reversing a string by its `char`s, which breaks up grapheme clusters"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterator adapters called with constant arguments that make them useless or
+    /// surprising: `take(0)`, `skip(usize::MAX)`, `step_by(1)`, and `cycle().take(n)` where `n`
+    /// is the known length of the iterator, such as for arrays or constant ranges.
+    ///
+    /// ### Why is this bad?
+    /// These are almost always bugs or leftovers of debugging: `take(0)` never yields anything,
+    /// `step_by(1)` does nothing, and `cycle().take(n)` stopping after a single pass over the
+    /// items doesn't need `cycle`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// for x in [1, 2, 3].iter().cycle().take(3) {
+    ///     println!("{x}");
+    /// }
+    /// for i in (0..10).step_by(1) {
+    ///     println!("{i}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// for x in [1, 2, 3].iter() {
+    ///     println!("{x}");
+    /// }
+    /// for i in 0..10 {
+    ///     println!("{i}");
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SUSPICIOUS_ITERATOR_ADAPTER,
+    suspicious,
+    "iterator adapters with constant arguments making them useless, like `take(0)` or `step_by(1)`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `insert(0, _)` and `remove(0)` on a `Vec` in a loop which the `Vec` outlives.
+    ///
+    /// ### Why is this bad?
+    /// Inserting or removing the first element moves all other elements of the `Vec`, so doing it
+    /// on every iteration of a loop takes quadratic time. A `VecDeque` can add and remove elements
+    /// at both ends in constant time, and a `Vec` can be filled or drained from the back instead
+    /// and reversed once.
+    ///
+    /// ### Known problems
+    /// Moving a few elements is cheap. Loops known to run fewer times than the
+    /// `vec-front-modification-min-iterations` configuration, such as loops over small arrays or
+    /// constant ranges, aren't linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let lines = vec!["a", "b"];
+    /// let mut reversed = Vec::new();
+    /// for line in lines {
+    ///     reversed.insert(0, line);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let lines = vec!["a", "b"];
+    /// let mut reversed = Vec::new();
+    /// for line in lines {
+    ///     reversed.push(line);
+    /// }
+    /// reversed.reverse();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub VEC_FRONT_MODIFICATION,
+    perf,
+    "inserting at or removing from the front of a `Vec` in a loop"
+}
+
 impl_lint_pass!(Methods => [
     UNWRAP_USED,
     EXPECT_USED,
@@ -3448,6 +3995,21 @@ pub fn new(
     UNNECESSARY_LITERAL_UNWRAP,
     DRAIN_COLLECT,
     MANUAL_TRY_FOLD,
+    FILTER_LAST,
+    ITER_COUNT_CMP,
+    UNNECESSARY_MAP_OR,
+    SORT_BY_PARTIAL_CMP,
+    TAKE_WHILE_COUNT,
+    MANUAL_ENUMERATE,
+    MANUAL_OPTION_FOLD,
+    TO_STRING_IN_PUSH_STR,
+    DOUBLE_ENDED_ITERATOR_LAST,
+    NEEDLESS_AS_BYTES,
+    CHAINED_STR_REPLACE,
+    SLICE_CONTAINS_IN_LOOP,
+    NAIVE_STRING_REVERSAL,
+    SUSPICIOUS_ITERATOR_ADAPTER,
+    VEC_FRONT_MODIFICATION,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -3474,9 +4036,11 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         match expr.kind {
             hir::ExprKind::Call(func, args) => {
                 from_iter_instead_of_collect::check(cx, expr, args, func);
+                iter_cloned_collect::check_from_iter(cx, expr, func, args);
             },
             hir::ExprKind::MethodCall(method_call, receiver, args, _) => {
                 let method_span = method_call.ident.span;
+                unwrap_or_else_default::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
                 or_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
                 expect_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
                 clone_on_copy::check(cx, expr, method_call.ident.name, receiver, args);
@@ -3487,14 +4051,22 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
                 single_char_pattern::check(cx, expr, method_call.ident.name, receiver, args);
                 unnecessary_to_owned::check(cx, expr, method_call.ident.name, receiver, args, &self.msrv);
             },
-            hir::ExprKind::Binary(op, lhs, rhs) if op.node == hir::BinOpKind::Eq || op.node == hir::BinOpKind::Ne => {
-                let mut info = BinaryExprInfo {
-                    expr,
-                    chain: lhs,
-                    other: rhs,
-                    eq: op.node == hir::BinOpKind::Eq,
-                };
-                lint_binary_expr_with_method_call(cx, &mut info);
+            hir::ExprKind::Binary(op, lhs, rhs) => {
+                if op.node == hir::BinOpKind::Eq || op.node == hir::BinOpKind::Ne {
+                    let mut info = BinaryExprInfo {
+                        expr,
+                        chain: lhs,
+                        other: rhs,
+                        eq: op.node == hir::BinOpKind::Eq,
+                    };
+                    lint_binary_expr_with_method_call(cx, &mut info);
+                }
+                iter_count_cmp::check(cx, expr, op.node, lhs, rhs);
+            },
+            hir::ExprKind::AssignOp(op, lhs, rhs) => {
+                if op.node == hir::BinOpKind::Add {
+                    to_string_in_push_str::check_add_assign(cx, expr, lhs, rhs);
+                }
             },
             _ => (),
         }
@@ -3669,6 +4241,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         Some((name @ ("cloned" | "copied"), recv2, [], _, _)) => {
                             iter_cloned_collect::check(cx, name, expr, recv2);
                         },
+                        Some(("into_iter", recv2, [], _, _)) => {
+                            iter_cloned_collect::check_into_iter(cx, expr, recv, recv2);
+                        },
                         Some(("map", m_recv, [m_arg], _, _)) => {
                             map_collect_result_unit::check(cx, expr, m_recv, m_arg);
                         },
@@ -3683,6 +4258,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         _ => {},
                     }
                 },
+                ("contains", [_]) => slice_contains_in_loop::check(cx, expr, recv),
                 ("count", []) if is_trait_method(cx, expr, sym::Iterator) => match method_call(recv) {
                     Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(cx, expr, recv, recv2, true, false),
                     Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
@@ -3691,6 +4267,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     Some(("map", _, [arg], _, _)) => suspicious_map::check(cx, expr, recv, arg),
                     Some(("filter", recv2, [arg], _, _)) => bytecount::check(cx, expr, recv2, arg),
                     Some(("bytes", recv2, [], _, _)) => bytes_count_to_len::check(cx, expr, recv, recv2),
+                    Some(("take_while", recv2, [arg], span2, _)) => {
+                        take_while_count::check(cx, expr, recv, recv2, arg, span2);
+                    },
                     _ => {},
                 },
                 ("drain", ..) => {
@@ -3705,7 +4284,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("ends_with", [arg]) => {
                     if let ExprKind::MethodCall(.., span) = expr.kind {
-                        case_sensitive_file_extension_comparisons::check(cx, expr, span, recv, arg);
+                        case_sensitive_file_extension_comparisons::check(cx, expr, span, recv, arg, &self.msrv);
                     }
                 },
                 ("expect", [_]) => {
@@ -3743,6 +4322,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("fold", [init, acc]) => {
                     manual_try_fold::check(cx, expr, init, acc, call_span, &self.msrv);
                     unnecessary_fold::check(cx, expr, init, acc, span);
+                    manual_option_fold::check(cx, expr, init, acc, span);
                 },
                 ("for_each", [_]) => {
                     if let Some(("inspect", _, [_], span2, _)) = method_call(recv) {
@@ -3761,6 +4341,16 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("is_digit", [radix]) => is_digit_ascii_radix::check(cx, expr, recv, radix, &self.msrv),
                 ("is_none", []) => check_is_some_is_none(cx, expr, recv, false),
                 ("is_some", []) => check_is_some_is_none(cx, expr, recv, true),
+                ("insert", [index, _]) => {
+                    vec_front_modification::check(
+                        cx,
+                        expr,
+                        recv,
+                        index,
+                        name,
+                        self.vec_front_modification_min_iterations,
+                    );
+                },
                 ("iter" | "iter_mut" | "into_iter", []) => {
                     iter_on_single_or_empty_collections::check(cx, expr, name, recv);
                 },
@@ -3770,10 +4360,23 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     }
                 },
                 ("last", []) | ("skip", [_]) => {
-                    if let Some((name2, recv2, args2, _span2, _)) = method_call(recv) {
-                        if let ("cloned", []) = (name2, args2) {
+                    if let ("skip", [arg]) = (name, args) {
+                        suspicious_iterator_adapter::check_skip(cx, expr, arg);
+                    }
+                    match method_call(recv) {
+                        Some(("cloned", recv2, [], _, _)) => {
                             iter_overeager_cloned::check(cx, expr, recv, recv2, false, false);
-                        }
+                        },
+                        Some(("filter", recv2, [arg], _, _)) if name == "last" => {
+                            filter_last::check(cx, expr, recv, recv2, arg);
+                        },
+                        _ if name == "last" => double_ended_iterator_last::check(cx, expr, recv, call_span),
+                        _ => {},
+                    }
+                },
+                (name @ ("len" | "is_empty"), []) => {
+                    if let Some(("as_bytes", recv2, [], _, _)) = method_call(recv) {
+                        needless_as_bytes::check(cx, expr, name, recv, recv2);
                     }
                 },
                 ("lock", []) => {
@@ -3806,6 +4409,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("map_or", [def, map]) => {
                     option_map_or_none::check(cx, expr, recv, def, map);
                     manual_ok_or::check(cx, expr, recv, def, map);
+                    unnecessary_map_or::check(cx, expr, recv, def, map, span, &self.msrv);
                 },
                 ("next", []) => {
                     if let Some((name2, recv2, args2, _, _)) = method_call(recv) {
@@ -3815,7 +4419,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                             ("filter_map", [arg]) => filter_map_next::check(cx, expr, recv2, arg, &self.msrv),
                             ("iter", []) => iter_next_slice::check(cx, expr, recv2),
                             ("skip", [arg]) => iter_skip_next::check(cx, expr, recv2, arg),
-                            ("skip_while", [_]) => skip_while_next::check(cx, expr),
+                            ("skip_while", [arg]) => skip_while_next::check(cx, expr, recv2, arg),
                             ("rev", [])=> manual_next_back::check(cx, expr, recv, recv2),
                             _ => {},
                         }
@@ -3840,12 +4444,25 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("push", [arg]) => {
                     path_buf_push_overwrite::check(cx, expr, arg);
                 },
+                ("push_str", [arg]) => {
+                    to_string_in_push_str::check(cx, expr, recv, arg);
+                },
                 ("read_to_end", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_END_MSG);
                 },
                 ("read_to_string", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_STRING_MSG);
                 },
+                ("remove", [index]) => {
+                    vec_front_modification::check(
+                        cx,
+                        expr,
+                        recv,
+                        index,
+                        name,
+                        self.vec_front_modification_min_iterations,
+                    );
+                },
                 ("repeat", [arg]) => {
                     repeat_once::check(cx, expr, recv, arg);
                 },
@@ -3859,10 +4476,18 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     {
                         collapsible_str_replace::check(cx, expr, arg1, arg2);
                     }
+                    if name == "replace" {
+                        chained_str_replace::check(cx, expr, &self.msrv);
+                    }
                 },
                 ("resize", [count_arg, default_arg]) => {
                     vec_resize_to_zero::check(cx, expr, count_arg, default_arg, span);
                 },
+                ("rev", []) => {
+                    if let Some(("chars", _, [], chars_span, _)) = method_call(recv) {
+                        naive_string_reversal::check(cx, expr, recv, chars_span, self.naive_string_reversal_all_rev);
+                    }
+                },
                 ("seek", [arg]) => {
                     if self.msrv.meets(msrvs::SEEK_FROM_CURRENT) {
                         seek_from_current::check(cx, expr, recv, arg);
@@ -3876,9 +4501,11 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("sort_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, false);
+                    sort_by_partial_cmp::check(cx, expr, recv, arg, false, &self.msrv);
                 },
                 ("sort_unstable_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, true);
+                    sort_by_partial_cmp::check(cx, expr, recv, arg, true, &self.msrv);
                 },
                 ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
                     if let Some(Constant::Int(count)) = constant(cx, cx.typeck_results(), count_arg) {
@@ -3891,8 +4518,12 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         suspicious_splitn::check(cx, name, expr, recv, count);
                     }
                 },
-                ("step_by", [arg]) => iterator_step_by_zero::check(cx, expr, arg),
-                ("take", [_arg]) => {
+                ("step_by", [arg]) => {
+                    iterator_step_by_zero::check(cx, expr, arg);
+                    suspicious_iterator_adapter::check_step_by(cx, expr, recv, arg);
+                },
+                ("take", [arg]) => {
+                    suspicious_iterator_adapter::check_take(cx, expr, recv, arg);
                     if let Some((name2, recv2, args2, _span2, _)) = method_call(recv) {
                         if let ("cloned", []) = (name2, args2) {
                             iter_overeager_cloned::check(cx, expr, recv, recv2, false, false);
@@ -3941,6 +4572,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         },
                         Some(("map", m_recv, [m_arg], span, _)) => {
                             option_map_unwrap_or::check(cx, expr, m_recv, m_arg, recv, u_arg, span, &self.msrv);
+                            unnecessary_map_or::check_map_unwrap_or(cx, expr, m_recv, m_arg, u_arg, span, &self.msrv);
                         },
                         Some(("then_some", t_recv, [t_arg], _, _)) => {
                             obfuscated_if_else::check(cx, expr, t_recv, t_arg, u_arg);
@@ -3957,7 +4589,6 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         Some(("map", recv, [map_arg], _, _))
                             if map_unwrap_or::check(cx, expr, recv, map_arg, u_arg, &self.msrv) => {},
                         _ => {
-                            unwrap_or_else_default::check(cx, expr, recv, u_arg);
                             unnecessary_lazy_eval::check(cx, expr, recv, u_arg, "unwrap_or");
                         },
                     }
@@ -3969,6 +4600,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     {
                         range_zip_with_len::check(cx, expr, iter_recv, arg);
                     }
+                    manual_enumerate::check(cx, expr, arg, span);
                 },
                 _ => {},
             }
@@ -4114,7 +4746,7 @@ fn matches_value<'a>(cx: &LateContext<'a>, parent_ty: Ty<'a>, ty: Ty<'a>) -> boo
                 ty.boxed_ty() == parent_ty
             } else if is_type_diagnostic_item(cx, ty, sym::Rc) || is_type_diagnostic_item(cx, ty, sym::Arc) {
                 if let ty::Adt(_, substs) = ty.kind() {
-                    substs.types().next().map_or(false, |t| t == parent_ty)
+                    substs.types().next().is_some_and(|t| t == parent_ty)
                 } else {
                     false
                 }
@@ -0,0 +1,38 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{get_parent_expr, is_trait_method};
+use rustc_hir::{Expr, LangItem};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::{method_call, NAIVE_STRING_REVERSAL};
+
+/// Checks for `s.chars().rev()`, `expr` being the `rev` call.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, chars: &Expr<'_>, chars_span: Span, all_rev: bool) {
+    let is_str_chars = cx
+        .typeck_results()
+        .type_dependent_def_id(chars.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .is_some_and(|impl_id| cx.tcx.type_of(impl_id).subst_identity().is_str());
+    if !is_str_chars || !is_trait_method(cx, expr, sym::Iterator) {
+        return;
+    }
+
+    let (span, msg) = if let Some(parent) = get_parent_expr(cx, expr)
+        && let Some(("collect", _, [], _, _)) = method_call(parent)
+        && is_type_lang_item(cx, cx.typeck_results().expr_ty(parent), LangItem::String)
+    {
+        (chars_span.with_hi(parent.span.hi()), "reversing a string by its `char`s")
+    } else if all_rev {
+        (chars_span.with_hi(expr.span.hi()), "iterating over the `char`s of a string in reverse")
+    } else {
+        return;
+    };
+    span_lint_and_then(cx, NAIVE_STRING_REVERSAL, span, msg, |diag| {
+        diag.note(
+            "`chars` yields Unicode scalar values, reversing them also reverses the ones making up a \
+            single grapheme cluster, e.g. `\"e\\u{301}\"` (é) becomes `\"\\u{301}e\"`",
+        );
+        diag.help("if the string can contain such text, reverse its grapheme clusters instead");
+    });
+}
@@ -0,0 +1,76 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, LangItem};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+
+use super::NEEDLESS_AS_BYTES;
+
+/// Checks for `s.as_bytes().len()` and `s.as_bytes().is_empty()`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    name: &str,
+    recv: &'tcx Expr<'_>,
+    as_bytes_recv: &'tcx Expr<'_>,
+) {
+    // only the inherent slice methods, the byte slice is dropped right after the call
+    if is_method_of(cx, expr, |ty| ty.is_slice())
+        && is_method_of(cx, recv, |ty| {
+            ty.is_str() || is_type_lang_item(cx, ty, LangItem::String)
+        })
+    {
+        let mut app = Applicability::MachineApplicable;
+        let sugg = Sugg::hir_with_context(cx, as_bytes_recv, expr.span.ctxt(), "..", &mut app).maybe_par();
+        span_lint_and_sugg(
+            cx,
+            NEEDLESS_AS_BYTES,
+            expr.span,
+            "needless call to `as_bytes`",
+            &format!("`{name}()` can be called directly on strings"),
+            format!("{sugg}.{name}()"),
+            app,
+        );
+    }
+}
+
+/// Checks for `s.chars().count()` compared to zero, `is_empty` tells whether the comparison
+/// checks for an empty string. Returns whether the comparison was linted.
+pub(super) fn check_chars_count<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    chars: &'tcx Expr<'_>,
+    chars_recv: &'tcx Expr<'_>,
+    is_empty: bool,
+) -> bool {
+    if !is_method_of(cx, chars, |ty| ty.is_str()) {
+        return false;
+    }
+    let mut app = Applicability::MachineApplicable;
+    let sugg = Sugg::hir_with_context(cx, chars_recv, expr.span.ctxt(), "..", &mut app).maybe_par();
+    let sugg = if is_empty {
+        format!("{sugg}.is_empty()")
+    } else {
+        format!("!{sugg}.is_empty()")
+    };
+    span_lint_and_sugg(
+        cx,
+        NEEDLESS_AS_BYTES,
+        expr.span,
+        "counting the `char`s of a string to check whether it is empty",
+        "use `is_empty()`",
+        sugg,
+        app,
+    );
+    true
+}
+
+/// Checks if the method called by `expr` is implemented on a type matching `f`.
+fn is_method_of<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, f: impl FnOnce(Ty<'tcx>) -> bool) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .is_some_and(|impl_id| f(cx.tcx.type_of(impl_id).subst_identity()))
+}
@@ -2,7 +2,7 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::source::{snippet, snippet_with_applicability};
 use clippy_utils::sugg::Sugg;
-use clippy_utils::ty::{is_type_diagnostic_item, make_normalized_projection, make_projection};
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item, make_normalized_projection, make_projection};
 use clippy_utils::{
     can_move_expr_to_closure, get_enclosing_block, get_parent_node, is_trait_method, path_to_local, path_to_local_id,
     CaptureKind,
@@ -12,7 +12,8 @@
 use rustc_errors::{Applicability, MultiSpan};
 use rustc_hir::intravisit::{walk_block, walk_expr, Visitor};
 use rustc_hir::{
-    BindingAnnotation, Block, Expr, ExprKind, HirId, HirIdSet, Local, Mutability, Node, PatKind, Stmt, StmtKind,
+    BindingAnnotation, Block, Expr, ExprKind, HirId, HirIdSet, LangItem, Local, Mutability, Node, PatKind, Stmt,
+    StmtKind,
 };
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
@@ -48,15 +49,34 @@ pub(super) fn check<'tcx>(
                                 )
                             {
                                 "count()".into()
+                            } else if is_type_lang_item(cx, collect_ty, LangItem::String) {
+                                // the length of a `String` is its length in bytes
+                                match string_item(cx, iter_expr) {
+                                    Some(StringItem::Char) => "map(char::len_utf8).sum::<usize>()".into(),
+                                    Some(StringItem::CharRef) => "map(|c| c.len_utf8()).sum::<usize>()".into(),
+                                    Some(StringItem::Str) => "map(str::len).sum::<usize>()".into(),
+                                    Some(StringItem::String) => "map(|s| s.len()).sum::<usize>()".into(),
+                                    None => return,
+                                }
                             } else {
                                 return;
                             }
                         },
-                        "is_empty"
+                        "is_empty" => {
                             if is_is_empty_sig(cx, parent.hir_id)
-                                && iterates_same_ty(cx, cx.typeck_results().expr_ty(iter_expr), collect_ty) =>
-                        {
-                            "next().is_none()".into()
+                                && iterates_same_ty(cx, cx.typeck_results().expr_ty(iter_expr), collect_ty)
+                            {
+                                "next().is_none()".into()
+                            } else if is_type_lang_item(cx, collect_ty, LangItem::String) {
+                                // empty strings don't add anything to the collected `String`
+                                match string_item(cx, iter_expr) {
+                                    Some(StringItem::Char | StringItem::CharRef) => "next().is_none()".into(),
+                                    Some(StringItem::Str | StringItem::String) => "all(|s| s.is_empty())".into(),
+                                    None => return,
+                                }
+                            } else {
+                                return;
+                            }
                         },
                         "contains" => {
                             if is_contains_sig(cx, parent.hir_id, iter_expr)
@@ -200,7 +220,7 @@ fn check_collect_into_intoiterator<'tcx>(
 
 /// Checks if the given method call matches the expected signature of `([&[mut]] self) -> bool`
 fn is_is_empty_sig(cx: &LateContext<'_>, call_id: HirId) -> bool {
-    cx.typeck_results().type_dependent_def_id(call_id).map_or(false, |id| {
+    cx.typeck_results().type_dependent_def_id(call_id).is_some_and(|id| {
         let sig = cx.tcx.fn_sig(id).subst_identity().skip_binder();
         sig.inputs().len() == 1 && sig.output().is_bool()
     })
@@ -224,6 +244,36 @@ fn iterates_same_ty<'tcx>(cx: &LateContext<'tcx>, iter_ty: Ty<'tcx>, collect_ty:
     }
 }
 
+/// The items of an iterator collected into a `String`.
+enum StringItem {
+    /// `char`
+    Char,
+    /// `&char`
+    CharRef,
+    /// `&str`
+    Str,
+    /// `String`
+    String,
+}
+
+fn string_item<'tcx>(cx: &LateContext<'tcx>, iter_expr: &Expr<'_>) -> Option<StringItem> {
+    let iter_trait = cx.tcx.get_diagnostic_item(sym::Iterator)?;
+    let item_ty = make_normalized_projection(
+        cx.tcx,
+        cx.param_env,
+        iter_trait,
+        Symbol::intern("Item"),
+        [cx.typeck_results().expr_ty(iter_expr)],
+    )?;
+    match *item_ty.kind() {
+        ty::Char => Some(StringItem::Char),
+        ty::Ref(_, ty, _) if ty.is_char() => Some(StringItem::CharRef),
+        ty::Ref(_, ty, _) if ty.is_str() => Some(StringItem::Str),
+        _ if is_type_lang_item(cx, item_ty, LangItem::String) => Some(StringItem::String),
+        _ => None,
+    }
+}
+
 /// Checks if the given method call matches the expected signature of
 /// `([&[mut]] self, &<iter_ty as Iterator>::Item) -> bool`
 fn is_contains_sig(cx: &LateContext<'_>, call_id: HirId, iter_expr: &Expr<'_>) -> bool {
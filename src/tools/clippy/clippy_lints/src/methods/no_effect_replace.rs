@@ -37,7 +37,7 @@ pub(super) fn check<'tcx>(
     }
 }
 
-fn lit_string_value(node: &LitKind) -> Option<String> {
+pub(super) fn lit_string_value(node: &LitKind) -> Option<String> {
     match node {
         LitKind::Char(value) => Some(value.to_string()),
         LitKind::Str(value, _) => Some(value.as_str().to_owned()),
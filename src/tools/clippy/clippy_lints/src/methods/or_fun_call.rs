@@ -1,16 +1,16 @@
+use clippy_utils::contains_return;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::eager_or_lazy::switch_to_lazy_eval;
 use clippy_utils::source::snippet_with_context;
-use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
-use clippy_utils::{contains_return, is_trait_item, last_path_segment};
+use clippy_utils::ty::is_type_diagnostic_item;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
 use rustc_span::source_map::Span;
-use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_span::symbol::{sym, Symbol};
 
-use super::OR_FUN_CALL;
+use super::{unwrap_or_else_default, OR_FUN_CALL};
 
 /// Checks for the `OR_FUN_CALL` lint.
 #[allow(clippy::too_many_lines)]
@@ -22,58 +22,6 @@ pub(super) fn check<'tcx>(
     receiver: &'tcx hir::Expr<'_>,
     args: &'tcx [hir::Expr<'_>],
 ) {
-    /// Checks for `unwrap_or(T::new())`, `unwrap_or(T::default())`,
-    /// `or_insert(T::new())` or `or_insert(T::default())`.
-    #[allow(clippy::too_many_arguments)]
-    fn check_unwrap_or_default(
-        cx: &LateContext<'_>,
-        name: &str,
-        fun: &hir::Expr<'_>,
-        arg: &hir::Expr<'_>,
-        or_has_args: bool,
-        span: Span,
-        method_span: Span,
-    ) -> bool {
-        let is_default_default = || is_trait_item(cx, fun, sym::Default);
-
-        let implements_default = |arg, default_trait_id| {
-            let arg_ty = cx.typeck_results().expr_ty(arg);
-            implements_trait(cx, arg_ty, default_trait_id, &[])
-        };
-
-        if_chain! {
-            if !or_has_args;
-            if let Some(sugg) = match name {
-                "unwrap_or" => Some("unwrap_or_default"),
-                "or_insert" => Some("or_default"),
-                _ => None,
-            };
-            if let hir::ExprKind::Path(ref qpath) = fun.kind;
-            if let Some(default_trait_id) = cx.tcx.get_diagnostic_item(sym::Default);
-            let path = last_path_segment(qpath).ident.name;
-            // needs to target Default::default in particular or be *::new and have a Default impl
-            // available
-            if (matches!(path, kw::Default) && is_default_default())
-                || (matches!(path, sym::new) && implements_default(arg, default_trait_id));
-
-            then {
-                span_lint_and_sugg(
-                    cx,
-                    OR_FUN_CALL,
-                    method_span.with_hi(span.hi()),
-                    &format!("use of `{name}` followed by a call to `{path}`"),
-                    "try this",
-                    format!("{sugg}()"),
-                    Applicability::MachineApplicable,
-                );
-
-                true
-            } else {
-                false
-            }
-        }
-    }
-
     /// Checks for `*or(foo())`.
     #[allow(clippy::too_many_arguments)]
     fn check_general_case<'tcx>(
@@ -167,9 +115,9 @@ fn check_general_case<'tcx>(
         let inner_arg = extract_inner_arg(arg);
         match inner_arg.kind {
             hir::ExprKind::Call(fun, or_args) => {
-                let or_has_args = !or_args.is_empty();
-                if !check_unwrap_or_default(cx, name, fun, arg, or_has_args, expr.span, method_span) {
-                    let fun_span = if or_has_args { None } else { Some(fun.span) };
+                // `unwrap_or(T::default())` and similar are linted by `UNWRAP_OR_ELSE_DEFAULT`
+                if unwrap_or_else_default::default_method(cx, name, receiver, arg).is_none() {
+                    let fun_span = if or_args.is_empty() { Some(fun.span) } else { None };
                     check_general_case(cx, name, method_span, receiver, arg, None, expr.span, fun_span);
                 }
             },
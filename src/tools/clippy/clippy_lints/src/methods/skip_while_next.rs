@@ -1,22 +1,49 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::is_trait_method;
+use clippy_utils::source::{snippet, snippet_with_applicability};
+use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
 use rustc_span::sym;
 
+use super::utils::negated_predicate;
 use super::SKIP_WHILE_NEXT;
 
 /// lint use of `skip_while().next()` for `Iterators`
-pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    skip_while_arg: &'tcx hir::Expr<'_>,
+) {
     // lint if caller of `.skip_while().next()` is an Iterator
     if is_trait_method(cx, expr, sym::Iterator) {
-        span_lint_and_help(
-            cx,
-            SKIP_WHILE_NEXT,
-            expr.span,
-            "called `skip_while(<p>).next()` on an `Iterator`",
-            None,
-            "this is more succinctly expressed by calling `.find(!<p>)` instead",
-        );
+        let msg = "called `skip_while(<p>).next()` on an `Iterator`";
+        let mut applicability = Applicability::MachineApplicable;
+        if snippet(cx, skip_while_arg.span, "..").lines().nth(1).is_none()
+            && let Some(pred) = negated_predicate(cx, skip_while_arg, false, &mut applicability)
+        {
+            span_lint_and_sugg(
+                cx,
+                SKIP_WHILE_NEXT,
+                expr.span,
+                msg,
+                "this is more succinctly expressed by calling `.find(!<p>)` instead",
+                format!(
+                    "{}.find({pred})",
+                    snippet_with_applicability(cx, recv.span, "..", &mut applicability)
+                ),
+                applicability,
+            );
+        } else {
+            span_lint_and_help(
+                cx,
+                SKIP_WHILE_NEXT,
+                expr.span,
+                msg,
+                None,
+                "this is more succinctly expressed by calling `.find(!<p>)` instead",
+            );
+        }
     }
 }
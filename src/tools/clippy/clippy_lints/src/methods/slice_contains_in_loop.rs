@@ -0,0 +1,73 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::usage::is_potentially_mutated;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{enclosing_loop_or_closure_boundary, path_to_local, ControlFlowBoundary};
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::utils::loop_header;
+use super::SLICE_CONTAINS_IN_LOOP;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>) {
+    let is_slice_contains = cx
+        .typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .is_some_and(|impl_id| cx.tcx.type_of(impl_id).subst_identity().is_slice());
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    if !is_slice_contains || !(recv_ty.is_slice() || is_type_diagnostic_item(cx, recv_ty, sym::Vec)) {
+        return;
+    }
+    let Some(local_id) = path_to_local(recv) else {
+        return;
+    };
+    let Some(ControlFlowBoundary::Loop(loop_expr)) = enclosing_loop_or_closure_boundary(cx, expr.hir_id) else {
+        return;
+    };
+    // The collection has to exist before the loop and stay the same during it for a lookup
+    // structure to be built once, and the number of iterations shouldn't depend on it.
+    if loop_expr.span.contains(cx.tcx.hir().span(local_id))
+        || is_potentially_mutated(local_id, loop_expr, cx)
+        || loop_header(cx, loop_expr).is_some_and(|header| is_local_used(cx, header, local_id))
+    {
+        return;
+    }
+
+    let elem_ty = match recv_ty.kind() {
+        ty::Slice(elem_ty) => *elem_ty,
+        ty::Adt(_, substs) => substs.type_at(0),
+        _ => return,
+    };
+    let implements = |trait_name| {
+        cx.tcx
+            .get_diagnostic_item(trait_name)
+            .is_some_and(|id| implements_trait(cx, elem_ty, id, &[]))
+    };
+    let hashable = implements(sym::Hash) && implements(sym::Eq);
+    let ordered = implements(sym::Ord);
+    if !hashable && !ordered {
+        return;
+    }
+
+    let name = cx.tcx.hir().name(local_id);
+    span_lint_and_then(
+        cx,
+        SLICE_CONTAINS_IN_LOOP,
+        expr.span,
+        &format!("`{name}` is searched linearly on every iteration of the loop"),
+        |diag| {
+            if hashable {
+                diag.help(format!("collect `{name}` into a `HashSet` before the loop"));
+            }
+            if ordered {
+                diag.help(format!(
+                    "{}sort `{name}` before the loop and use `binary_search`",
+                    if hashable { "or " } else { "" }
+                ));
+            }
+        },
+    );
+}
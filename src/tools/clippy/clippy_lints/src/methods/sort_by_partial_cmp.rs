@@ -0,0 +1,117 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{is_trait_method, path_to_local_id, peel_blocks};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+use super::unnecessary_sort_by::mirrored_exprs;
+use super::SORT_BY_PARTIAL_CMP;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+    is_unstable: bool,
+    msrv: &Msrv,
+) {
+    if_chain! {
+        if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+        if let Some(impl_id) = cx.tcx.impl_of_method(method_id);
+        if cx.tcx.type_of(impl_id).subst_identity().is_slice();
+        if let ExprKind::Closure(&Closure { body, .. }) = arg.kind;
+        if let closure_body = cx.tcx.hir().body(body);
+        if let [left_param, right_param] = closure_body.params;
+        if let PatKind::Binding(_, left_id, left_ident, None) = left_param.pat.kind;
+        if let PatKind::Binding(_, right_id, right_ident, None) = right_param.pat.kind;
+        let unwrap = peel_blocks(closure_body.value);
+        if let ExprKind::MethodCall(unwrap_path, partial_cmp, unwrap_args, _) = unwrap.kind;
+        if matches!((unwrap_path.ident.as_str(), unwrap_args), ("unwrap", []) | ("expect", [_]));
+        if let ExprKind::MethodCall(cmp_path, left_expr, [right_expr], _) = partial_cmp.kind;
+        if cmp_path.ident.name == sym::partial_cmp;
+        if is_trait_method(cx, partial_cmp, sym::PartialOrd);
+        if !unwrap.span.from_expansion();
+        then {
+            let (first_id, reverse) = if mirrored_exprs(left_expr, &left_ident, right_expr, &right_ident) {
+                (left_id, false)
+            } else if mirrored_exprs(left_expr, &right_ident, right_expr, &left_ident) {
+                (right_id, true)
+            } else {
+                return;
+            };
+            // The closure compares the elements themselves, not some key of theirs
+            let is_elem = !reverse && path_to_local_id(left_expr, first_id);
+            let left_ty = cx.typeck_results().expr_ty(left_expr);
+            let sort = if is_unstable { "sort_unstable" } else { "sort" };
+            let msg = format!("unwrapping the result of `partial_cmp` in the `{sort}_by` comparator");
+            let mut app = Applicability::MachineApplicable;
+
+            if let ty::Float(float_ty) = left_ty.peel_refs().kind() {
+                if !msrv.meets(msrvs::TOTAL_CMP) {
+                    return;
+                }
+                // `total_cmp` orders `-0.0` before `0.0` and doesn't panic on `NaN`s
+                app = Applicability::MaybeIncorrect;
+                if is_elem && matches!(left_ty.kind(), ty::Ref(_, inner, _) if inner.is_floating_point()) {
+                    span_lint_and_sugg(
+                        cx,
+                        SORT_BY_PARTIAL_CMP,
+                        arg.span,
+                        &msg,
+                        "use `total_cmp` instead",
+                        format!("{}::total_cmp", float_ty.name_str()),
+                        app,
+                    );
+                    return;
+                }
+                lint_cmp_method(cx, unwrap.span, left_expr, right_expr, "total_cmp", &msg, app);
+            } else if cx
+                .tcx
+                .get_diagnostic_item(sym::Ord)
+                .is_some_and(|id| implements_trait(cx, left_ty.peel_refs(), id, &[]))
+            {
+                if is_elem {
+                    span_lint_and_sugg(
+                        cx,
+                        SORT_BY_PARTIAL_CMP,
+                        expr.span,
+                        &msg,
+                        &format!("the elements are `Ord`, use `{sort}` instead"),
+                        format!("{}.{sort}()", snippet_with_applicability(cx, recv.span, "..", &mut app)),
+                        app,
+                    );
+                    return;
+                }
+                lint_cmp_method(cx, unwrap.span, left_expr, right_expr, "cmp", &msg, app);
+            }
+        }
+    }
+}
+
+fn lint_cmp_method(
+    cx: &LateContext<'_>,
+    span: Span,
+    left_expr: &Expr<'_>,
+    right_expr: &Expr<'_>,
+    method: &str,
+    msg: &str,
+    mut app: Applicability,
+) {
+    let left = snippet_with_applicability(cx, left_expr.span, "..", &mut app);
+    let right = snippet_with_applicability(cx, right_expr.span, "..", &mut app);
+    span_lint_and_sugg(
+        cx,
+        SORT_BY_PARTIAL_CMP,
+        span,
+        msg,
+        &format!("use `{method}` instead"),
+        format!("{left}.{method}({right})"),
+        app,
+    );
+}
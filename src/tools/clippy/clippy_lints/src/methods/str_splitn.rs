@@ -2,9 +2,10 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::usage::local_used_after_expr;
 use clippy_utils::visitors::{for_each_expr_with_closures, Descend};
-use clippy_utils::{is_diag_item_method, match_def_path, path_to_local_id, paths};
+use clippy_utils::{is_diag_item_method, is_trait_method, match_def_path, path_to_local_id, paths};
 use core::ops::ControlFlow;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -42,6 +43,9 @@ pub(super) fn check(
         None if manual => {
             check_manual_split_once_indirect(cx, method_name, expr, self_arg, pat_arg);
         },
+        None if count == 3 && msrv.meets(msrvs::STR_SPLIT_ONCE) => {
+            check_manual_split_once_indexed(cx, method_name, expr, self_arg, pat_arg);
+        },
         _ => {},
     }
 }
@@ -191,6 +195,137 @@ fn check_manual_split_once_indirect(
     Some(())
 }
 
+/// checks for
+///
+/// ```
+/// let parts: Vec<&str> = "a.b.c".splitn(3, '.').collect();
+/// let a = parts[0];
+/// let b = parts[1];
+/// let c = parts[2];
+/// ```
+fn check_manual_split_once_indexed(
+    cx: &LateContext<'_>,
+    method_name: &str,
+    expr: &Expr<'_>,
+    self_arg: &Expr<'_>,
+    pat_arg: &Expr<'_>,
+) -> Option<()> {
+    let ctxt = expr.span.ctxt();
+    let mut parents = cx.tcx.hir().parent_iter(expr.hir_id);
+    // The pattern is used twice in the suggestion.
+    if let ExprKind::Lit(_) = pat_arg.kind
+        && let (_, Node::Expr(collect_expr)) = parents.next()?
+        && let ExprKind::MethodCall(collect_name, _, [], _) = collect_expr.kind
+        && collect_name.ident.as_str() == "collect"
+        && is_trait_method(cx, collect_expr, sym::Iterator)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(collect_expr), sym::Vec)
+        && let (_, Node::Local(local)) = parents.next()?
+        && let PatKind::Binding(BindingAnnotation::NONE, parts_binding_id, parts_ident, None) = local.pat.kind
+        && let (parts_stmt_id, Node::Stmt(_)) = parents.next()?
+        && let (_, Node::Block(enclosing_block)) = parents.next()?
+
+        && let mut stmts = enclosing_block
+            .stmts
+            .iter()
+            .skip_while(|stmt| stmt.hir_id != parts_stmt_id)
+            .skip(1)
+
+        && let first = indexed_usage(cx, stmts.next()?, parts_binding_id, ctxt)?
+        && let second = indexed_usage(cx, stmts.next()?, parts_binding_id, ctxt)?
+        && let third = indexed_usage(cx, stmts.next()?, parts_binding_id, ctxt)?
+        && let mut names = [None; 3]
+        && [&first, &second, &third].into_iter().all(|usage| {
+            names
+                .get_mut(usage.index)
+                .is_some_and(|name| name.replace(usage.name).is_none())
+        })
+        && let [Some(n0), Some(n1), Some(n2)] = names
+        && n0 != n1
+        && n1 != n2
+        && n0 != n2
+        && !local_used_after_expr(cx, parts_binding_id, third.init_expr)
+    {
+        let (r, first_let, second_let) = if method_name == "splitn" {
+            ("", format!("({n0}, {parts_ident})"), format!("({n1}, {n2})"))
+        } else {
+            ("r", format!("({parts_ident}, {n0})"), format!("({n2}, {n1})"))
+        };
+        let msg = format!("manual implementation of `{r}split_once`");
+
+        let mut app = Applicability::MachineApplicable;
+        let self_snip = snippet_with_context(cx, self_arg.span, ctxt, "..", &mut app).0;
+        let pat_snip = snippet_with_context(cx, pat_arg.span, ctxt, "..", &mut app).0;
+
+        span_lint_and_then(cx, MANUAL_SPLIT_ONCE, local.span, &msg, |diag| {
+            diag.span_label(first.span, "first usage here");
+            diag.span_label(second.span, "second usage here");
+            diag.span_label(third.span, "third usage here");
+
+            diag.span_suggestion_verbose(
+                local.span,
+                format!("try `{r}split_once`"),
+                format!("let {first_let} = {self_snip}.{r}split_once({pat_snip}).unwrap();"),
+                app,
+            );
+            diag.span_suggestion(
+                first.span,
+                format!("and `{r}split_once` the remainder"),
+                format!("let {second_let} = {parts_ident}.{r}split_once({pat_snip}).unwrap();"),
+                app,
+            );
+
+            let remove_msg = format!("remove the `{parts_ident}` usages");
+            diag.span_suggestion(second.span, remove_msg.clone(), "", app);
+            diag.span_suggestion(third.span, remove_msg, "", app);
+        });
+    }
+
+    Some(())
+}
+
+#[derive(Debug)]
+struct IndexedUsage<'a> {
+    index: usize,
+    name: Symbol,
+    span: Span,
+    init_expr: &'a Expr<'a>,
+}
+
+/// returns `Some(IndexedUsage)` for e.g.
+///
+/// ```ignore
+/// let name = binding[1];
+/// ```
+fn indexed_usage<'tcx>(
+    cx: &LateContext<'tcx>,
+    stmt: &Stmt<'tcx>,
+    binding: HirId,
+    ctxt: SyntaxContext,
+) -> Option<IndexedUsage<'tcx>> {
+    if let StmtKind::Local(&Local {
+        pat: Pat {
+            kind: PatKind::Binding(BindingAnnotation::NONE, _, ident, None),
+            ..
+        },
+        init: Some(init_expr),
+        ..
+    }) = stmt.kind
+        && init_expr.span.ctxt() == ctxt
+        && let ExprKind::Index(base, index_expr) = init_expr.kind
+        && path_to_local_id(base, binding)
+        && let Some(Constant::Int(index)) = constant(cx, cx.typeck_results(), index_expr)
+    {
+        Some(IndexedUsage {
+            index: usize::try_from(index).ok()?,
+            name: ident.name,
+            span: stmt.span,
+            init_expr,
+        })
+    } else {
+        None
+    }
+}
+
 #[derive(Debug)]
 struct IndirectUsage<'a> {
     name: Symbol,
@@ -367,7 +502,7 @@ fn parse_iter_usage<'tcx>(
                     && cx
                         .typeck_results()
                         .type_dependent_def_id(e.hir_id)
-                        .map_or(false, |id| is_diag_item_method(cx, id, sym::Option)) =>
+                        .is_some_and(|id| is_diag_item_method(cx, id, sym::Option)) =>
             {
                 (Some(UnwrapKind::Unwrap), e.span)
             },
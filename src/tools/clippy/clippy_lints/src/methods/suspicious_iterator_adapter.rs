@@ -0,0 +1,93 @@
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::is_trait_method;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::utils::known_len;
+use super::{method_call, SUSPICIOUS_ITERATOR_ADAPTER};
+
+/// Checks for `iter.take(0)` and `iter.cycle().take(n)` where `n` is the known length of `iter`.
+pub(super) fn check_take<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, arg: &Expr<'_>) {
+    if !is_trait_method(cx, expr, sym::Iterator) {
+        return;
+    }
+    let Some(FullInt::U(n)) = constant_full_int(cx, cx.typeck_results(), arg) else {
+        return;
+    };
+    if n == 0 {
+        span_lint_and_help(
+            cx,
+            SUSPICIOUS_ITERATOR_ADAPTER,
+            expr.span,
+            "`take(0)` never yields any items",
+            None,
+            "if an empty iterator is intended, use `std::iter::empty()`",
+        );
+        return;
+    }
+
+    let Some(("cycle", cycle_recv, [], _, _)) = method_call(recv) else {
+        return;
+    };
+    if !is_trait_method(cx, recv, sym::Iterator) {
+        return;
+    }
+    if known_len(cx, cycle_recv) != Some(n) {
+        return;
+    }
+    // removing the adapters changes the type of the iterator
+    let mut app = Applicability::MaybeIncorrect;
+    span_lint_and_sugg(
+        cx,
+        SUSPICIOUS_ITERATOR_ADAPTER,
+        expr.span,
+        &format!("`cycle().take({n})` yields the {n} items of the iterator only once"),
+        "remove `cycle` and `take`",
+        Sugg::hir_with_applicability(cx, cycle_recv, "..", &mut app)
+            .maybe_par()
+            .to_string(),
+        app,
+    );
+}
+
+/// Checks for `iter.skip(usize::MAX)`.
+pub(super) fn check_skip(cx: &LateContext<'_>, expr: &Expr<'_>, arg: &Expr<'_>) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && let Some(FullInt::U(n)) = constant_full_int(cx, cx.typeck_results(), arg)
+        && n == cx.tcx.data_layout.pointer_size.unsigned_int_max()
+    {
+        span_lint_and_help(
+            cx,
+            SUSPICIOUS_ITERATOR_ADAPTER,
+            expr.span,
+            "`skip(usize::MAX)` only yields items of iterators longer than `usize::MAX`",
+            None,
+            "if an empty iterator is intended, use `std::iter::empty()`",
+        );
+    }
+}
+
+/// Checks for `iter.step_by(1)`.
+pub(super) fn check_step_by(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && let Some(FullInt::U(1)) = constant_full_int(cx, cx.typeck_results(), arg)
+    {
+        // removing the adapter changes the type of the iterator
+        let mut app = Applicability::MaybeIncorrect;
+        span_lint_and_sugg(
+            cx,
+            SUSPICIOUS_ITERATOR_ADAPTER,
+            expr.span,
+            "`step_by(1)` has no effect",
+            "remove `step_by`",
+            Sugg::hir_with_applicability(cx, recv, "..", &mut app)
+                .maybe_par()
+                .to_string(),
+            app,
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_trait_method;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::utils::{derefs_to_slice, negated_predicate};
+use super::TAKE_WHILE_COUNT;
+
+/// lint use of `slice.iter().take_while().count()`
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    iter: &'tcx Expr<'_>,
+    take_while_arg: &'tcx Expr<'_>,
+    take_while_span: Span,
+) {
+    if is_trait_method(cx, recv, sym::Iterator)
+        && !take_while_span.from_expansion()
+        && let Some(slice) = derefs_to_slice(cx, iter, cx.typeck_results().expr_ty(iter))
+        // the slice is used twice in the suggestion
+        && matches!(slice.kind, ExprKind::Path(_) | ExprKind::Field(..))
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let Some(pred) = negated_predicate(cx, take_while_arg, true, &mut applicability) else {
+            return;
+        };
+        let slice = snippet_with_applicability(cx, slice.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            TAKE_WHILE_COUNT,
+            take_while_span.with_hi(expr.span.hi()),
+            "called `take_while(<p>).count()` on a slice iterator",
+            "use `position` with the negated predicate instead",
+            format!("position({pred}).unwrap_or({slice}.len())"),
+            applicability,
+        );
+    }
+}
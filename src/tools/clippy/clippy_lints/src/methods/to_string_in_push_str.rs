@@ -0,0 +1,83 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{match_def_path, path_to_local, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, LangItem, Mutability};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::TO_STRING_IN_PUSH_STR;
+use crate::format_push_string::write_sugg;
+
+/// Checks for `s.push_str(&x.to_string())`.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
+    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && match_def_path(cx, method_id, &paths::PUSH_STR)
+    {
+        lint(cx, expr, recv, arg, "push_str");
+    }
+}
+
+/// Checks for `s += &x.to_string()`.
+pub(super) fn check_add_assign(cx: &LateContext<'_>, expr: &Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+    if is_type_lang_item(cx, cx.typeck_results().expr_ty(lhs), LangItem::String) {
+        lint(cx, expr, lhs, rhs, "+=");
+    }
+}
+
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, string: &Expr<'_>, arg: &Expr<'_>, appended_with: &str) {
+    let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, to_string) = arg.kind else {
+        return;
+    };
+    let ExprKind::MethodCall(path, value, [], _) = to_string.kind else {
+        return;
+    };
+    if path.ident.as_str() != "to_string"
+        || !cx
+            .typeck_results()
+            .type_dependent_def_id(to_string.hir_id)
+            .is_some_and(|id| match_def_path(cx, id, &paths::TO_STRING_METHOD))
+    {
+        return;
+    }
+
+    let msg = &format!("`to_string()` result appended to a `String` using `{appended_with}`");
+    let mut app = Applicability::MachineApplicable;
+    let string_snip = snippet_with_context(cx, string.span, expr.span.ctxt(), "..", &mut app).0;
+    let value_ty = cx.typeck_results().expr_ty(value);
+    if value_ty.is_char() || matches!(value_ty.kind(), ty::Ref(_, inner, _) if inner.is_char()) {
+        let mut c = Sugg::hir_with_context(cx, value, expr.span.ctxt(), "..", &mut app);
+        if value_ty.is_ref() {
+            c = c.deref();
+        }
+        span_lint_and_sugg(
+            cx,
+            TO_STRING_IN_PUSH_STR,
+            expr.span,
+            msg,
+            "push the `char` directly",
+            format!("{string_snip}.push({c})"),
+            app,
+        );
+        return;
+    }
+
+    // strings can be appended as they are
+    let value_ty = value_ty.peel_refs();
+    if value_ty.is_str() || is_type_lang_item(cx, value_ty, LangItem::String) {
+        return;
+    }
+    let value_snip = snippet_with_context(cx, value.span, expr.span.ctxt(), "..", &mut app).0;
+    let format_args = if path_to_local(value).is_some() {
+        format!("\"{{{value_snip}}}\"")
+    } else {
+        format!("\"{{}}\", {value_snip}")
+    };
+    if let Some(sugg) = write_sugg(cx, expr, &string_snip, &format_args) {
+        span_lint_and_then(cx, TO_STRING_IN_PUSH_STR, expr.span, msg, |diag| {
+            diag.multipart_suggestion("write to the `String` directly", sugg, app);
+        });
+    }
+}
@@ -0,0 +1,126 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::get_parent_expr;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::UNNECESSARY_MAP_OR;
+
+/// lint use of `_.map_or(false, _)` and `_.map_or(true, |_| !_)` for `Option`s and `Result`s
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    def_arg: &'tcx hir::Expr<'_>,
+    map_arg: &'tcx hir::Expr<'_>,
+    method_span: Span,
+    msrv: &Msrv,
+) {
+    if let Some(default) = bool_lit(def_arg) {
+        let used = if default {
+            "map_or(true, <f>)"
+        } else {
+            "map_or(false, <f>)"
+        };
+        lint(cx, expr, recv, map_arg, method_span, used, default, msrv);
+    }
+}
+
+/// lint use of `_.map(_).unwrap_or(false)` and `_.map(|_| !_).unwrap_or(true)` for `Result`s,
+/// `Option`s are handled by `MAP_UNWRAP_OR`
+pub(super) fn check_map_unwrap_or<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    map_arg: &'tcx hir::Expr<'_>,
+    unwrap_arg: &'tcx hir::Expr<'_>,
+    map_span: Span,
+    msrv: &Msrv,
+) {
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result) {
+        return;
+    }
+    if let Some(default) = bool_lit(unwrap_arg) {
+        let used = if default {
+            "map(<f>).unwrap_or(true)"
+        } else {
+            "map(<f>).unwrap_or(false)"
+        };
+        lint(cx, expr, recv, map_arg, map_span, used, default, msrv);
+    }
+}
+
+#[expect(clippy::too_many_arguments)]
+fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    map_arg: &'tcx hir::Expr<'_>,
+    span: Span,
+    used: &str,
+    default: bool,
+    msrv: &Msrv,
+) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let (method, ty_name) = if is_type_diagnostic_item(cx, recv_ty, sym::Option) {
+        ("is_some_and", "an `Option`")
+    } else if is_type_diagnostic_item(cx, recv_ty, sym::Result) {
+        ("is_ok_and", "a `Result`")
+    } else {
+        return;
+    };
+    if span.from_expansion() || !msrv.meets(msrvs::OPTION_IS_SOME_AND) {
+        return;
+    }
+
+    let mut applicability = Applicability::MachineApplicable;
+    let (span, sugg) = if default {
+        // `map_or(true, |x| !p)` is `!is_some_and(|x| p)`, anything else would need `is_none_or`
+        let hir::ExprKind::Closure(closure) = map_arg.kind else {
+            return;
+        };
+        let body = cx.tcx.hir().body(closure.body);
+        let hir::ExprKind::Unary(hir::UnOp::Not, inner) = body.value.kind else {
+            return;
+        };
+        if expr.span.from_expansion() || body.value.span.from_expansion() {
+            return;
+        }
+        let ctxt = expr.span.ctxt();
+        let recv = snippet_with_context(cx, recv.span, ctxt, "..", &mut applicability).0;
+        let head = snippet_with_context(cx, map_arg.span.until(body.value.span), ctxt, "..", &mut applicability).0;
+        let inner = snippet_with_context(cx, inner.span, ctxt, "..", &mut applicability).0;
+        let sugg = format!("!{recv}.{method}({head}{inner})");
+        let needs_paren = get_parent_expr(cx, expr).is_some_and(
+            |parent| matches!(parent.kind, hir::ExprKind::MethodCall(_, parent_recv, ..) if parent_recv.hir_id == expr.hir_id),
+        );
+        (expr.span, if needs_paren { format!("({sugg})") } else { sugg })
+    } else {
+        let map_snippet = snippet_with_context(cx, map_arg.span, span.ctxt(), "..", &mut applicability).0;
+        (span.with_hi(expr.span.hi()), format!("{method}({map_snippet})"))
+    };
+    span_lint_and_sugg(
+        cx,
+        UNNECESSARY_MAP_OR,
+        span,
+        &format!("called `{used}` on {ty_name} value"),
+        "use",
+        sugg,
+        applicability,
+    );
+}
+
+fn bool_lit(expr: &hir::Expr<'_>) -> Option<bool> {
+    match expr.kind {
+        hir::ExprKind::Lit(lit) if !expr.span.from_expansion() => match lit.node {
+            LitKind::Bool(b) => Some(b),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -1,66 +1,236 @@
-//! Lint for `some_result_or_option.unwrap_or_else(Default::default)`
+//! Lint for `some_result_or_option.unwrap_or_else(Default::default)` and the equivalent calls of
+//! the `Entry` API and `Option::get_or_insert_with`
 
 use super::UNWRAP_OR_ELSE_DEFAULT;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
 use clippy_utils::{
-    diagnostics::span_lint_and_sugg, is_default_equivalent_call, source::snippet_with_applicability,
-    ty::is_type_diagnostic_item,
+    is_default_equivalent, is_default_equivalent_call, is_res_lang_ctor, last_path_segment, path_res, peel_blocks,
 };
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::LangItem::{self, OptionSome, ResultOk};
 use rustc_lint::LateContext;
-use rustc_span::{sym, symbol};
+use rustc_middle::ty::Ty;
+use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_span::Span;
+
+/// The methods taking a value, or a function returning it, to use when there is none, and the
+/// method using `Default::default` instead: `(type, eager method, lazy method, default method,
+/// wrapper)`. `or` and `or_else` take the value wrapped in `Some` or `Ok`, which is kept around
+/// the call to the default method.
+const DEFAULT_METHODS: [(Symbol, &str, &str, &str, Option<LangItem>); 7] = [
+    (sym::Option, "unwrap_or", "unwrap_or_else", "unwrap_or_default", None),
+    (sym::Result, "unwrap_or", "unwrap_or_else", "unwrap_or_default", None),
+    (sym::Option, "or", "or_else", "unwrap_or_default", Some(OptionSome)),
+    (sym::Result, "or", "or_else", "unwrap_or_default", Some(ResultOk)),
+    (sym::HashMapEntry, "or_insert", "or_insert_with", "or_default", None),
+    (sym::BTreeEntry, "or_insert", "or_insert_with", "or_default", None),
+    (
+        sym::Option,
+        "get_or_insert",
+        "get_or_insert_with",
+        "get_or_insert_default",
+        None,
+    ),
+];
+
+/// A call which can use a `*_default` method instead.
+pub(super) struct DefaultMethod {
+    /// The method to call instead.
+    pub sugg_method: &'static str,
+    /// Whether the replaced method takes a function constructing the value.
+    pub is_lazy: bool,
+    /// The variant the result of the default method is wrapped in, for `or` and `or_else`.
+    pub wrapper: Option<&'static str>,
+    /// Whether the value is known to be the default one.
+    pub applicability: Applicability,
+    /// The function constructing the value, if it's called directly.
+    pub ctor_name: Option<Symbol>,
+}
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx hir::Expr<'_>,
+    method_span: Span,
+    name: &str,
     recv: &'tcx hir::Expr<'_>,
-    u_arg: &'tcx hir::Expr<'_>,
+    args: &'tcx [hir::Expr<'_>],
 ) {
     // something.unwrap_or_else(Default::default)
     // ^^^^^^^^^- recv          ^^^^^^^^^^^^^^^^- u_arg
     // ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^- expr
-    let recv_ty = cx.typeck_results().expr_ty(recv);
-    let is_option = is_type_diagnostic_item(cx, recv_ty, sym::Option);
-    let is_result = is_type_diagnostic_item(cx, recv_ty, sym::Result);
+    let [u_arg] = args else {
+        return;
+    };
+    let Some(DefaultMethod {
+        sugg_method,
+        is_lazy,
+        wrapper,
+        mut applicability,
+        ctor_name,
+    }) = default_method(cx, name, recv, u_arg)
+    else {
+        return;
+    };
 
-    if_chain! {
-        if is_option || is_result;
-        if closure_body_returns_empty_to_string(cx, u_arg) || is_default_equivalent_call(cx, u_arg);
-        then {
-            let mut applicability = Applicability::MachineApplicable;
+    if is_lazy || wrapper.is_some() {
+        let recv_snip = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            UNWRAP_OR_ELSE_DEFAULT,
+            expr.span,
+            &format!("use of `.{name}(..)` to construct default value"),
+            "try",
+            match wrapper {
+                Some(wrapper) => format!("{wrapper}({recv_snip}.{sugg_method}())"),
+                None => format!("{recv_snip}.{sugg_method}()"),
+            },
+            applicability,
+        );
+    } else if let Some(ctor_name) = ctor_name {
+        span_lint_and_sugg(
+            cx,
+            UNWRAP_OR_ELSE_DEFAULT,
+            method_span.with_hi(expr.span.hi()),
+            &format!("use of `{name}` followed by a call to `{ctor_name}`"),
+            "try this",
+            format!("{sugg_method}()"),
+            applicability,
+        );
+    }
+}
 
-            span_lint_and_sugg(
-                cx,
-                UNWRAP_OR_ELSE_DEFAULT,
-                expr.span,
-                "use of `.unwrap_or_else(..)` to construct default value",
-                "try",
-                format!(
-                    "{}.unwrap_or_default()",
-                    snippet_with_applicability(cx, recv.span, "..", &mut applicability)
-                ),
-                applicability,
-            );
+/// Returns the method to call instead of `name` if its argument constructs the default value.
+pub(super) fn default_method(
+    cx: &LateContext<'_>,
+    name: &str,
+    recv: &hir::Expr<'_>,
+    arg: &hir::Expr<'_>,
+) -> Option<DefaultMethod> {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let &(_, eager, _, sugg_method, wrapper) = DEFAULT_METHODS
+        .iter()
+        .find(|&&(ty, eager, lazy, ..)| (name == eager || name == lazy) && is_type_diagnostic_item(cx, recv_ty, ty))?;
+    // `Option::get_or_insert_default` is unstable
+    if sugg_method == "get_or_insert_default" && !cx.tcx.features().active(sym!(option_get_or_insert_default)) {
+        return None;
+    }
+
+    let is_lazy = name != eager;
+    let (value, applicability) = if is_lazy {
+        match peel_blocks(arg).kind {
+            hir::ExprKind::Closure(&hir::Closure { body, .. }) => {
+                let body = cx.tcx.hir().body(body);
+                // `Result::unwrap_or_else` passes the error to the closure
+                if !body
+                    .params
+                    .iter()
+                    .all(|param| matches!(param.pat.kind, hir::PatKind::Wild))
+                {
+                    return None;
+                }
+                let value = unwrap_variant(cx, peel_blocks(body.value), wrapper)?;
+                (Some(value), default_value_applicability(cx, value)?)
+            },
+            // a function returning the value wrapped in `Some` or `Ok` can't be passed as is
+            _ if wrapper.is_some() => return None,
+            _ => (None, default_fn_applicability(cx, arg)?),
         }
+    } else {
+        let value = unwrap_variant(cx, peel_blocks(arg), wrapper)?;
+        (Some(value), default_value_applicability(cx, value)?)
+    };
+
+    Some(DefaultMethod {
+        sugg_method,
+        is_lazy,
+        wrapper: wrapper.map(|wrapper| if wrapper == OptionSome { "Some" } else { "Ok" }),
+        applicability,
+        ctor_name: value.and_then(called_fn_name),
+    })
+}
+
+/// Gets the value wrapped in the `wrapper` variant, or `e` itself if there is no wrapper.
+fn unwrap_variant<'tcx>(
+    cx: &LateContext<'_>,
+    e: &'tcx hir::Expr<'tcx>,
+    wrapper: Option<LangItem>,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    match wrapper {
+        None => Some(e),
+        Some(wrapper) => {
+            if let hir::ExprKind::Call(fun, [value]) = e.kind
+                && is_res_lang_ctor(cx, path_res(cx, fun), wrapper)
+            {
+                Some(peel_blocks(value))
+            } else {
+                None
+            }
+        },
     }
 }
 
-fn closure_body_returns_empty_to_string(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
-    if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = e.kind {
-        let body = cx.tcx.hir().body(body);
+/// Checks if `e` constructs the default value of its type, and returns how certain that is.
+/// `Default::default()`, the constructors of the standard library types and `"".to_string()` are
+/// equivalent to it, while `T::new()` is only expected to be for types implementing `Default`.
+fn default_value_applicability(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> Option<Applicability> {
+    match e.kind {
+        hir::ExprKind::Call(fun, []) => default_ctor_applicability(cx, fun, cx.typeck_results().expr_ty(e)),
+        // `String::from("")` and `Vec::from([])`
+        hir::ExprKind::Call(_, [_]) if is_default_equivalent(cx, e) => Some(Applicability::MachineApplicable),
+        hir::ExprKind::MethodCall(path, self_arg, [], _) if path.ident.name == sym::to_string => {
+            matches!(self_arg.kind, hir::ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(kw::Empty, _)))
+                .then_some(Applicability::MachineApplicable)
+        },
+        _ => None,
+    }
+}
 
-        if body.params.is_empty()
-            && let hir::Expr{ kind, .. } = &body.value
-            && let hir::ExprKind::MethodCall(hir::PathSegment {ident, ..}, self_arg, _, _) = kind
-            && ident == &symbol::Ident::from_str("to_string")
-            && let hir::Expr{ kind, .. } = self_arg
-            && let hir::ExprKind::Lit(lit) = kind
-            && let LitKind::Str(symbol::kw::Empty, _) = lit.node
-        {
-            return true;
-        }
+/// Checks if the function `fun` constructs the default value, like `default_value_applicability`
+/// does for calls.
+fn default_fn_applicability(cx: &LateContext<'_>, fun: &hir::Expr<'_>) -> Option<Applicability> {
+    let fun_ty = cx.typeck_results().expr_ty(fun);
+    if !fun_ty.is_fn() {
+        return None;
     }
+    let output = cx.tcx.erase_late_bound_regions(fun_ty.fn_sig(cx.tcx).output());
+    default_ctor_applicability(cx, fun, output)
+}
 
-    false
+/// Checks if calling `fun` with no arguments constructs the default value of `ty`.
+fn default_ctor_applicability<'tcx>(
+    cx: &LateContext<'tcx>,
+    fun: &hir::Expr<'_>,
+    ty: Ty<'tcx>,
+) -> Option<Applicability> {
+    if is_default_equivalent_call(cx, fun) {
+        return Some(Applicability::MachineApplicable);
+    }
+    if let hir::ExprKind::Path(ref qpath) = fun.kind
+        && last_path_segment(qpath).ident.name == sym::new
+        && cx
+            .tcx
+            .get_diagnostic_item(sym::Default)
+            .is_some_and(|default_trait_id| implements_trait(cx, ty, default_trait_id, &[]))
+    {
+        // nothing requires `new` to construct the same value as `default`
+        Some(Applicability::MaybeIncorrect)
+    } else {
+        None
+    }
+}
+
+/// Gets the name of the function or method called by `e`.
+fn called_fn_name(e: &hir::Expr<'_>) -> Option<Symbol> {
+    match e.kind {
+        hir::ExprKind::Call(fun, _) => match fun.kind {
+            hir::ExprKind::Path(ref qpath) => Some(last_path_segment(qpath).ident.name),
+            _ => None,
+        },
+        hir::ExprKind::MethodCall(path, ..) => Some(path.ident.name),
+        _ => None,
+    }
 }
@@ -1,17 +1,22 @@
+use clippy_utils::consts::{constant_full_int, FullInt};
 use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{get_parent_expr, path_to_local_id, usage};
+use clippy_utils::visitors::ExprIter;
+use clippy_utils::{get_parent_expr, higher, path_to_local_id, peel_blocks, usage};
+use core::ops::ControlFlow;
 use if_chain::if_chain;
-use rustc_ast::ast;
+use rustc_ast::ast::{self, RangeLimits};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
-use rustc_hir::intravisit::{walk_expr, Visitor};
-use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Mutability, Pat};
+use rustc_hir::intravisit::Visitor;
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, LoopSource, Mutability, Node, Pat, PatKind};
 use rustc_lint::LateContext;
-use rustc_middle::hir::nested_filter;
 use rustc_middle::ty::{self, Ty};
 use rustc_span::symbol::sym;
 
+use super::method_call;
+
 pub(super) fn derefs_to_slice<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx hir::Expr<'tcx>,
@@ -84,85 +89,138 @@ pub(super) fn get_hint_if_single_char_arg(
     }
 }
 
-/// The core logic of `check_for_loop_iter` in `unnecessary_iter_cloned.rs`, this function wraps a
-/// use of `CloneOrCopyVisitor`.
+/// Returns a closure negating the predicate `pred`. If `by_value` is set, the returned closure
+/// takes the item itself instead of the reference `pred` is called with, which is only supported
+/// for closures destructuring the reference in their parameter.
+pub(super) fn negated_predicate(
+    cx: &LateContext<'_>,
+    pred: &Expr<'_>,
+    by_value: bool,
+    applicability: &mut Applicability,
+) -> Option<String> {
+    if let ExprKind::Closure(&hir::Closure { body, .. }) = pred.kind {
+        let body = cx.tcx.hir().body(body);
+        let [param] = body.params else {
+            return None;
+        };
+        let pat = match param.pat.kind {
+            PatKind::Ref(inner, _) if by_value => inner,
+            _ if by_value => return None,
+            _ => param.pat,
+        };
+        let pat = snippet_with_applicability(cx, pat.span, "..", applicability);
+        let value = !Sugg::hir_with_applicability(cx, peel_blocks(body.value), "..", applicability);
+        Some(format!("|{pat}| {value}"))
+    } else {
+        let pred = Sugg::hir_with_applicability(cx, pred, "..", applicability).maybe_par();
+        Some(format!("|x| !{pred}({}x)", if by_value { "&" } else { "" }))
+    }
+}
+
+/// The core logic of `check_for_loop_iter` in `unnecessary_iter_cloned.rs`. The returned flag is
+/// false if the only operations performed on the bindings of `pat` are:
+/// * to take non-mutable references to them
+/// * to use them as non-mutable `&self` in method calls
+/// If any of the bindings is used in any other way, then the flag is true.
 pub(super) fn clone_or_copy_needed<'tcx>(
     cx: &LateContext<'tcx>,
     pat: &Pat<'tcx>,
     body: &'tcx Expr<'tcx>,
 ) -> (bool, Vec<&'tcx Expr<'tcx>>) {
-    let mut visitor = CloneOrCopyVisitor {
-        cx,
-        binding_hir_ids: pat_bindings(pat),
-        clone_or_copy_needed: false,
-        addr_of_exprs: Vec::new(),
-    };
-    visitor.visit_expr(body);
-    (visitor.clone_or_copy_needed, visitor.addr_of_exprs)
-}
-
-/// Returns a vector of all `HirId`s bound by the pattern.
-fn pat_bindings(pat: &Pat<'_>) -> Vec<HirId> {
-    let mut collector = usage::ParamBindingIdCollector {
-        binding_hir_ids: Vec::new(),
-    };
-    collector.visit_pat(pat);
-    collector.binding_hir_ids
-}
-
-/// `clone_or_copy_needed` will be false when `CloneOrCopyVisitor` is done visiting if the only
-/// operations performed on `binding_hir_ids` are:
-/// * to take non-mutable references to them
-/// * to use them as non-mutable `&self` in method calls
-/// If any of `binding_hir_ids` is used in any other way, then `clone_or_copy_needed` will be true
-/// when `CloneOrCopyVisitor` is done visiting.
-struct CloneOrCopyVisitor<'cx, 'tcx> {
-    cx: &'cx LateContext<'tcx>,
-    binding_hir_ids: Vec<HirId>,
-    clone_or_copy_needed: bool,
-    addr_of_exprs: Vec<&'tcx Expr<'tcx>>,
-}
-
-impl<'cx, 'tcx> Visitor<'tcx> for CloneOrCopyVisitor<'cx, 'tcx> {
-    type NestedFilter = nested_filter::OnlyBodies;
-
-    fn nested_visit_map(&mut self) -> Self::Map {
-        self.cx.tcx.hir()
-    }
-
-    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
-        walk_expr(self, expr);
-        if self.is_binding(expr) {
-            if let Some(parent) = get_parent_expr(self.cx, expr) {
+    let binding_hir_ids = pat_bindings(pat);
+    let is_binding = |expr: &Expr<'_>| binding_hir_ids.iter().any(|hir_id| path_to_local_id(expr, *hir_id));
+    let mut clone_or_copy_needed = false;
+    let mut addr_of_exprs = Vec::new();
+    ExprIter::new()
+        .closures(cx)
+        .consts(cx)
+        .for_each_postorder(body, |expr| {
+            if !is_binding(expr) {
+                return ControlFlow::<!>::Continue(());
+            }
+            if let Some(parent) = get_parent_expr(cx, expr) {
                 match parent.kind {
                     ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => {
-                        self.addr_of_exprs.push(parent);
-                        return;
+                        addr_of_exprs.push(parent);
+                        return ControlFlow::Continue(());
                     },
                     ExprKind::MethodCall(.., args, _) => {
                         if_chain! {
-                            if args.iter().all(|arg| !self.is_binding(arg));
-                            if let Some(method_def_id) = self.cx.typeck_results().type_dependent_def_id(parent.hir_id);
-                            let method_ty = self.cx.tcx.type_of(method_def_id).subst_identity();
-                            let self_ty = method_ty.fn_sig(self.cx.tcx).input(0).skip_binder();
+                            if args.iter().all(|arg| !is_binding(arg));
+                            if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(parent.hir_id);
+                            let method_ty = cx.tcx.type_of(method_def_id).subst_identity();
+                            let self_ty = method_ty.fn_sig(cx.tcx).input(0).skip_binder();
                             if matches!(self_ty.kind(), ty::Ref(_, _, Mutability::Not));
                             then {
-                                return;
+                                return ControlFlow::Continue(());
                             }
                         }
                     },
                     _ => {},
                 }
             }
-            self.clone_or_copy_needed = true;
-        }
+            clone_or_copy_needed = true;
+            ControlFlow::Continue(())
+        });
+    (clone_or_copy_needed, addr_of_exprs)
+}
+
+/// Returns a vector of all `HirId`s bound by the pattern.
+fn pat_bindings(pat: &Pat<'_>) -> Vec<HirId> {
+    let mut collector = usage::ParamBindingIdCollector {
+        binding_hir_ids: Vec::new(),
+    };
+    collector.visit_pat(pat);
+    collector.binding_hir_ids
+}
+
+/// Gets the iterator of a `for` loop or the condition of a `while` loop.
+pub(super) fn loop_header<'tcx>(cx: &LateContext<'tcx>, loop_expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match loop_expr.kind {
+        ExprKind::Loop(_, _, LoopSource::ForLoop, _) => cx
+            .tcx
+            .hir()
+            .parent_iter(loop_expr.hir_id)
+            .find_map(|(_, node)| match node {
+                Node::Expr(e) => higher::ForLoop::hir(e),
+                _ => None,
+            })
+            .filter(|for_loop| for_loop.loop_id == loop_expr.hir_id)
+            .map(|for_loop| for_loop.arg),
+        _ => higher::While::hir(loop_expr).map(|while_loop| while_loop.condition),
     }
 }
 
-impl<'cx, 'tcx> CloneOrCopyVisitor<'cx, 'tcx> {
-    fn is_binding(&self, expr: &Expr<'tcx>) -> bool {
-        self.binding_hir_ids
-            .iter()
-            .any(|hir_id| path_to_local_id(expr, *hir_id))
+/// Gets the number of items of an iterator over an array, or of a range with constant bounds.
+/// Arrays themselves, e.g. iterated by a `for` loop, are accepted as well.
+pub(super) fn known_len(cx: &LateContext<'_>, iter: &Expr<'_>) -> Option<u128> {
+    if let Some(higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits,
+    }) = higher::Range::hir(iter)
+    {
+        let start = constant_full_int(cx, cx.typeck_results(), start)?;
+        let end = constant_full_int(cx, cx.typeck_results(), end)?;
+        let len = match (start, end) {
+            (FullInt::U(start), FullInt::U(end)) => end.checked_sub(start),
+            (FullInt::S(start), FullInt::S(end)) => end.checked_sub(start).and_then(|len| len.try_into().ok()),
+            _ => None,
+        }?;
+        return if limits == RangeLimits::Closed {
+            len.checked_add(1)
+        } else {
+            Some(len)
+        };
+    }
+
+    let array = match method_call(iter) {
+        Some(("iter" | "iter_mut" | "into_iter", array, [], _, _)) => array,
+        _ => iter,
+    };
+    if let ty::Array(_, len) = cx.typeck_results().expr_ty(array).peel_refs().kind() {
+        len.try_eval_target_usize(cx.tcx, cx.param_env).map(u128::from)
+    } else {
+        None
     }
 }
@@ -0,0 +1,66 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{enclosing_loop_or_closure_boundary, is_integer_const, path_to_local, ControlFlowBoundary};
+use rustc_hir::{Expr, ExprKind, LoopSource};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::utils::{known_len, loop_header};
+use super::VEC_FRONT_MODIFICATION;
+
+/// Checks for `vec.insert(0, x)` and `vec.remove(0)` in loops.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    index: &'tcx Expr<'_>,
+    name: &str,
+    min_iterations: u64,
+) {
+    if !is_integer_const(cx, index, 0)
+        || !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), sym::Vec)
+    {
+        return;
+    }
+
+    // Only the loops the `Vec` outlives make it grow or shrink from one iteration to the next.
+    let local = path_to_local(recv);
+    let mut iterations = Some(1u128);
+    let mut in_loop = false;
+    let mut id = expr.hir_id;
+    while let Some(ControlFlowBoundary::Loop(loop_expr)) = enclosing_loop_or_closure_boundary(cx, id) {
+        if local.is_some_and(|local| loop_expr.span.contains(cx.tcx.hir().span(local))) {
+            break;
+        }
+        in_loop = true;
+        iterations = iterations
+            .zip(iteration_count(cx, loop_expr))
+            .map(|(total, count)| total.saturating_mul(count));
+        id = loop_expr.hir_id;
+    }
+    if !in_loop || iterations.is_some_and(|total| total < u128::from(min_iterations)) {
+        return;
+    }
+
+    let (msg, help) = if name == "insert" {
+        (
+            "inserting at the front of a `Vec` in a loop",
+            "use a `VecDeque` and `push_front`, or `push` the elements and `reverse` the `Vec` after the loop",
+        )
+    } else {
+        (
+            "removing from the front of a `Vec` in a loop",
+            "use a `VecDeque` and `pop_front`, or `reverse` the `Vec` before the loop and `pop` the elements",
+        )
+    };
+    span_lint_and_help(cx, VEC_FRONT_MODIFICATION, expr.span, msg, None, help);
+}
+
+/// Gets the number of iterations of a `for` loop over a constant range or an array.
+fn iteration_count<'tcx>(cx: &LateContext<'tcx>, loop_expr: &'tcx Expr<'tcx>) -> Option<u128> {
+    if let ExprKind::Loop(_, _, LoopSource::ForLoop, _) = loop_expr.kind {
+        known_len(cx, loop_header(cx, loop_expr)?)
+    } else {
+        None
+    }
+}
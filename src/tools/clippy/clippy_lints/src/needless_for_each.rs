@@ -10,9 +10,9 @@
 use if_chain::if_chain;
 
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::is_trait_method;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::has_iter_method;
+use clippy_utils::{enclosing_loop_or_closure_boundary, is_trait_method, ControlFlowBoundary};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -75,7 +75,11 @@ fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
             let body = cx.tcx.hir().body(body);
             if let ExprKind::Block(..) = body.value.kind;
             then {
-                let mut ret_collector = RetCollector::default();
+                let mut ret_collector = RetCollector {
+                    cx,
+                    spans: Vec::new(),
+                    ret_in_loop: false,
+                };
                 ret_collector.visit_expr(body.value);
 
                 // Skip the lint if `return` is used in `Loop` in order not to suggest using `'label`.
@@ -122,36 +126,25 @@ fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
 ///
 /// NOTE: The functionality of this type is similar to
 /// [`clippy_utils::visitors::find_all_ret_expressions`], but we can't use
-/// `find_all_ret_expressions` instead of this type. The reasons are:
-/// 1. `find_all_ret_expressions` passes the argument of `ExprKind::Ret` to a callback, but what we
-///    need here is `ExprKind::Ret` itself.
-/// 2. We can't trace current loop depth with `find_all_ret_expressions`.
-#[derive(Default)]
-struct RetCollector {
+/// `find_all_ret_expressions` instead of this type, since it passes the argument of
+/// `ExprKind::Ret` to a callback, but what we need here is `ExprKind::Ret` itself.
+struct RetCollector<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
     spans: Vec<Span>,
     ret_in_loop: bool,
-    loop_depth: u16,
 }
 
-impl<'tcx> Visitor<'tcx> for RetCollector {
+impl<'tcx> Visitor<'tcx> for RetCollector<'_, 'tcx> {
     fn visit_expr(&mut self, expr: &Expr<'_>) {
-        match expr.kind {
-            ExprKind::Ret(..) => {
-                if self.loop_depth > 0 && !self.ret_in_loop {
-                    self.ret_in_loop = true;
-                }
-
-                self.spans.push(expr.span);
-            },
-
-            ExprKind::Loop(..) => {
-                self.loop_depth += 1;
-                walk_expr(self, expr);
-                self.loop_depth -= 1;
-                return;
-            },
+        if let ExprKind::Ret(..) = expr.kind {
+            if !self.ret_in_loop {
+                self.ret_in_loop = matches!(
+                    enclosing_loop_or_closure_boundary(self.cx, expr.hir_id),
+                    Some(ControlFlowBoundary::Loop(_))
+                );
+            }
 
-            _ => {},
+            self.spans.push(expr.span);
         }
 
         walk_expr(self, expr);
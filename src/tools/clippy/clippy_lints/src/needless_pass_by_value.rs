@@ -1,9 +1,7 @@
 use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
 use clippy_utils::ptr::get_spans;
 use clippy_utils::source::{snippet, snippet_opt};
-use clippy_utils::ty::{
-    implements_trait, implements_trait_with_env, is_copy, is_type_diagnostic_item, is_type_lang_item,
-};
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item, is_type_lang_item, ImplementsTraitCache};
 use clippy_utils::{get_trait_def_id, is_self, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::Attribute;
@@ -18,7 +16,7 @@
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::mir::FakeReadCause;
 use rustc_middle::ty::{self, TypeVisitableExt, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::def_id::LocalDefId;
 use rustc_span::symbol::kw;
 use rustc_span::{sym, Span};
@@ -60,7 +58,12 @@
     "functions taking arguments by value, but not consuming them in its body"
 }
 
-declare_lint_pass!(NeedlessPassByValue => [NEEDLESS_PASS_BY_VALUE]);
+#[derive(Default)]
+pub struct NeedlessPassByValue<'tcx> {
+    implements_trait_cache: ImplementsTraitCache<'tcx>,
+}
+
+impl_lint_pass!(NeedlessPassByValue<'_> => [NEEDLESS_PASS_BY_VALUE]);
 
 macro_rules! need {
     ($e: expr) => {
@@ -72,7 +75,7 @@ macro_rules! need {
     };
 }
 
-impl<'tcx> LateLintPass<'tcx> for NeedlessPassByValue {
+impl<'tcx> LateLintPass<'tcx> for NeedlessPassByValue<'tcx> {
     #[expect(clippy::too_many_lines)]
     fn check_fn(
         &mut self,
@@ -171,7 +174,8 @@ fn check_fn(
                         let ty_empty_region = Ty::new_imm_ref(cx.tcx,cx.tcx.lifetimes.re_erased, ty);
                         preds.iter().all(|t| {
                             let ty_params = t.trait_ref.substs.iter().skip(1).collect::<Vec<_>>();
-                            implements_trait(cx, ty_empty_region, t.def_id(), &ty_params)
+                            self.implements_trait_cache
+                                .implements_trait(cx, ty_empty_region, t.def_id(), &ty_params)
                         })
                     },
                 )
@@ -182,7 +186,9 @@ fn check_fn(
                 if !ty.is_mutable_ptr();
                 if !is_copy(cx, ty);
                 if ty.is_sized(cx.tcx, cx.param_env);
-                if !allowed_traits.iter().any(|&t| implements_trait_with_env(cx.tcx, cx.param_env, ty, t, [None]));
+                if !allowed_traits
+                    .iter()
+                    .any(|&t| self.implements_trait_cache.implements_trait_with_env(cx.tcx, cx.param_env, ty, t, [None]));
                 if !implements_borrow_trait;
                 if !all_borrowable_trait;
 
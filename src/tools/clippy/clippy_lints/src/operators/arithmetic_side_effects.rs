@@ -1,6 +1,7 @@
 use super::ARITHMETIC_SIDE_EFFECTS;
 use clippy_utils::is_from_proc_macro;
 use clippy_utils::{
+    const_context,
     consts::{constant, constant_simple, Constant},
     diagnostics::span_lint,
     is_lint_allowed, peel_hir_expr_refs, peel_hir_expr_unary,
@@ -30,8 +31,6 @@
 pub struct ArithmeticSideEffects {
     allowed_binary: FxHashMap<String, FxHashSet<String>>,
     allowed_unary: FxHashSet<String>,
-    // Used to check whether expressions are constants, such as in enum discriminants and consts
-    const_span: Option<Span>,
     expr_span: Option<Span>,
     integer_methods: FxHashSet<Symbol>,
 }
@@ -57,7 +56,6 @@ impl ArithmeticSideEffects {
         Self {
             allowed_binary,
             allowed_unary,
-            const_span: None,
             expr_span: None,
             integer_methods: INTEGER_METHODS.iter().map(|el| Symbol::intern(el)).collect(),
         }
@@ -244,7 +242,11 @@ fn should_skip_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &hir::Expr<'t
         is_lint_allowed(cx, ARITHMETIC_SIDE_EFFECTS, expr.hir_id)
             || is_from_proc_macro(cx, expr)
             || self.expr_span.is_some()
-            || self.const_span.map_or(false, |sp| sp.contains(expr.span))
+            // Expressions evaluated at compile time, such as in enum discriminants and consts
+            || matches!(
+                const_context(cx, expr.hir_id),
+                Some(hir::ConstContext::Const | hir::ConstContext::Static(_))
+            )
     }
 }
 
@@ -267,29 +269,6 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &hir::Expr<'tcx>) {
         }
     }
 
-    fn check_body(&mut self, cx: &LateContext<'_>, body: &hir::Body<'_>) {
-        let body_owner = cx.tcx.hir().body_owner(body.id());
-        let body_owner_def_id = cx.tcx.hir().body_owner_def_id(body.id());
-
-        let body_owner_kind = cx.tcx.hir().body_owner_kind(body_owner_def_id);
-        if let hir::BodyOwnerKind::Const | hir::BodyOwnerKind::Static(_) = body_owner_kind {
-            let body_span = cx.tcx.hir().span_with_body(body_owner);
-            if let Some(span) = self.const_span && span.contains(body_span) {
-                return;
-            }
-            self.const_span = Some(body_span);
-        }
-    }
-
-    fn check_body_post(&mut self, cx: &LateContext<'_>, body: &hir::Body<'_>) {
-        let body_owner = cx.tcx.hir().body_owner(body.id());
-        let body_span = cx.tcx.hir().span(body_owner);
-        if let Some(span) = self.const_span && span.contains(body_span) {
-            return;
-        }
-        self.const_span = None;
-    }
-
     fn check_expr_post(&mut self, _: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         if Some(expr.span) == self.expr_span {
             self.expr_span = None;
@@ -641,8 +641,8 @@
     /// Additionally, boolean logic "masked" as bitwise logic is not caught by lints like `unnecessary_fold`
     ///
     /// ### Known problems
-    /// This lint evaluates only when the right side is determined to have no side effects. At this time, that
-    /// determination is quite conservative.
+    /// This lint evaluates only when the right side is determined to have no side effects other than panicking.
+    /// Calls to functions from other crates than the standard library are assumed to have side effects.
     ///
     /// ### Example
     /// ```rust
@@ -1,4 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::side_effects::expr_side_effects_interprocedural;
 use clippy_utils::source::snippet_opt;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
@@ -6,7 +7,7 @@
 
 use super::NEEDLESS_BITWISE_BOOL;
 
-pub(super) fn check(cx: &LateContext<'_>, e: &Expr<'_>, op: BinOpKind, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>, op: BinOpKind, lhs: &Expr<'_>, rhs: &'tcx Expr<'_>) {
     let op_str = match op {
         BinOpKind::BitAnd => "&&",
         BinOpKind::BitOr => "||",
@@ -16,7 +17,8 @@ pub(super) fn check(cx: &LateContext<'_>, e: &Expr<'_>, op: BinOpKind, lhs: &Exp
         rhs.kind,
         ExprKind::Call(..) | ExprKind::MethodCall(..) | ExprKind::Binary(..) | ExprKind::Unary(..)
     ) && cx.typeck_results().expr_ty(e).is_bool()
-        && !rhs.can_have_side_effects()
+        // Skipping the evaluation of an operand which may panic can only remove a panic.
+        && expr_side_effects_interprocedural(cx, rhs).ignoring_panics().is_pure()
     {
         span_lint_and_then(
             cx,
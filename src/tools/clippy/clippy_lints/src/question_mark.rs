@@ -1,20 +1,23 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::higher;
 use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::{
-    eq_expr_value, get_parent_node, in_constant, is_else_clause, is_res_lang_ctor, path_to_local, path_to_local_id,
-    peel_blocks, peel_blocks_with_stmt,
+    enclosing_loop_or_closure_boundary, eq_expr_value, get_parent_node, in_constant, is_else_clause, is_refutable,
+    is_res_lang_ctor, path_to_local, path_to_local_id, peel_blocks, peel_blocks_with_stmt, ControlFlowBoundary,
 };
-use clippy_utils::{higher, is_path_lang_item};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
-use rustc_hir::LangItem::{self, OptionNone, OptionSome, ResultErr, ResultOk};
-use rustc_hir::{BindingAnnotation, ByRef, Expr, ExprKind, Node, PatKind, PathSegment, QPath};
+use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
+use rustc_hir::{
+    BindingAnnotation, Block, ByRef, Expr, ExprKind, HirId, Node, PatKind, PathSegment, QPath, Stmt, StmtKind,
+};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::Ty;
+use rustc_session::declare_lint_pass;
 use rustc_session::declare_tool_lint;
-use rustc_session::impl_lint_pass;
 use rustc_span::{sym, symbol::Symbol};
 
 declare_clippy_lint! {
@@ -36,22 +39,16 @@
     /// ```ignore
     /// option?;
     /// ```
+    ///
+    /// The same goes for `let Some(x) = option else { return None };`, which could be written
+    /// `let x = option?;`.
     #[clippy::version = "pre 1.29.0"]
     pub QUESTION_MARK,
     style,
     "checks for expressions that could be replaced by the question mark operator"
 }
 
-#[derive(Default)]
-pub struct QuestionMark {
-    /// Keeps track of how many try blocks we are in at any point during linting.
-    /// This allows us to answer the question "are we inside of a try block"
-    /// very quickly, without having to walk up the parent chain, by simply checking
-    /// if it is greater than zero.
-    /// As for why we need this in the first place: <https://github.com/rust-lang/rust-clippy/issues/8628>
-    try_block_depth_stack: Vec<u32>,
-}
-impl_lint_pass!(QuestionMark => [QUESTION_MARK]);
+declare_lint_pass!(QuestionMark => [QUESTION_MARK]);
 
 enum IfBlockType<'hir> {
     /// An `if x.is_xxx() { a } else { b } ` expression.
@@ -148,147 +145,186 @@ fn expr_return_none_or_err(
     }
 }
 
-impl QuestionMark {
-    fn inside_try_block(&self) -> bool {
-        self.try_block_depth_stack.last() > Some(&0)
+/// Checks whether a `?` at the given node would jump out of a `try` block rather than return
+/// from the function or closure.
+/// As for why we need this in the first place: <https://github.com/rust-lang/rust-clippy/issues/8628>
+fn inside_try_block(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let mut id = hir_id;
+    while let Some(boundary) = enclosing_loop_or_closure_boundary(cx, id) {
+        if !boundary.is_crossed_by_question_mark() {
+            return matches!(boundary, ControlFlowBoundary::TryBlock(_));
+        }
+        id = boundary.expr().hir_id;
     }
+    false
+}
 
-    /// Checks if the given expression on the given context matches the following structure:
-    ///
-    /// ```ignore
-    /// if option.is_none() {
-    ///    return None;
-    /// }
-    /// ```
-    ///
-    /// ```ignore
-    /// if result.is_err() {
-    ///     return result;
-    /// }
-    /// ```
-    ///
-    /// If it matches, it will suggest to use the question mark operator instead
-    fn check_is_none_or_err_and_early_return<'tcx>(&self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
-        if_chain! {
-            if !self.inside_try_block();
-            if let Some(higher::If { cond, then, r#else }) = higher::If::hir(expr);
-            if !is_else_clause(cx.tcx, expr);
-            if let ExprKind::MethodCall(segment, caller, ..) = &cond.kind;
-            let caller_ty = cx.typeck_results().expr_ty(caller);
-            let if_block = IfBlockType::IfIs(caller, caller_ty, segment.ident.name, then, r#else);
-            if is_early_return(sym::Option, cx, &if_block) || is_early_return(sym::Result, cx, &if_block);
-            then {
-                let mut applicability = Applicability::MachineApplicable;
-                let receiver_str = snippet_with_applicability(cx, caller.span, "..", &mut applicability);
-                let by_ref = !caller_ty.is_copy_modulo_regions(cx.tcx, cx.param_env) &&
-                    !matches!(caller.kind, ExprKind::Call(..) | ExprKind::MethodCall(..));
-                let sugg = if let Some(else_inner) = r#else {
-                    if eq_expr_value(cx, caller, peel_blocks(else_inner)) {
-                        format!("Some({receiver_str}?)")
-                    } else {
-                        return;
-                    }
+/// Checks if the given expression on the given context matches the following structure:
+///
+/// ```ignore
+/// if option.is_none() {
+///    return None;
+/// }
+/// ```
+///
+/// ```ignore
+/// if result.is_err() {
+///     return result;
+/// }
+/// ```
+///
+/// If it matches, it will suggest to use the question mark operator instead
+fn check_is_none_or_err_and_early_return<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
+    if_chain! {
+        if !inside_try_block(cx, expr.hir_id);
+        if let Some(higher::If { cond, then, r#else }) = higher::If::hir(expr);
+        if !is_else_clause(cx.tcx, expr);
+        if let ExprKind::MethodCall(segment, caller, ..) = &cond.kind;
+        let caller_ty = cx.typeck_results().expr_ty(caller);
+        let if_block = IfBlockType::IfIs(caller, caller_ty, segment.ident.name, then, r#else);
+        if is_early_return(sym::Option, cx, &if_block) || is_early_return(sym::Result, cx, &if_block);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let receiver_str = snippet_with_applicability(cx, caller.span, "..", &mut applicability);
+            let by_ref = !caller_ty.is_copy_modulo_regions(cx.tcx, cx.param_env) &&
+                !matches!(caller.kind, ExprKind::Call(..) | ExprKind::MethodCall(..));
+            let sugg = if let Some(else_inner) = r#else {
+                if eq_expr_value(cx, caller, peel_blocks(else_inner)) {
+                    format!("Some({receiver_str}?)")
                 } else {
-                    format!("{receiver_str}{}?;", if by_ref { ".as_ref()" } else { "" })
-                };
+                    return;
+                }
+            } else {
+                format!("{receiver_str}{}?;", if by_ref { ".as_ref()" } else { "" })
+            };
 
-                span_lint_and_sugg(
-                    cx,
-                    QUESTION_MARK,
-                    expr.span,
-                    "this block may be rewritten with the `?` operator",
-                    "replace it with",
-                    sugg,
-                    applicability,
-                );
-            }
+            span_lint_and_sugg(
+                cx,
+                QUESTION_MARK,
+                expr.span,
+                "this block may be rewritten with the `?` operator",
+                "replace it with",
+                sugg,
+                applicability,
+            );
         }
     }
+}
 
-    fn check_if_let_some_or_err_and_early_return<'tcx>(&self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
-        if_chain! {
-            if !self.inside_try_block();
-            if let Some(higher::IfLet { let_pat, let_expr, if_then, if_else }) = higher::IfLet::hir(cx, expr);
-            if !is_else_clause(cx.tcx, expr);
-            if let PatKind::TupleStruct(ref path1, [field], ddpos) = let_pat.kind;
-            if ddpos.as_opt_usize().is_none();
-            if let PatKind::Binding(BindingAnnotation(by_ref, _), bind_id, ident, None) = field.kind;
-            let caller_ty = cx.typeck_results().expr_ty(let_expr);
-            let if_block = IfBlockType::IfLet(
-                cx.qpath_res(path1, let_pat.hir_id),
-                caller_ty,
-                ident.name,
-                let_expr,
-                if_then,
-                if_else
+fn check_if_let_some_or_err_and_early_return<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
+    if_chain! {
+        if !inside_try_block(cx, expr.hir_id);
+        if let Some(higher::IfLet { let_pat, let_expr, if_then, if_else }) = higher::IfLet::hir(cx, expr);
+        if !is_else_clause(cx.tcx, expr);
+        if let PatKind::TupleStruct(ref path1, [field], ddpos) = let_pat.kind;
+        if ddpos.as_opt_usize().is_none();
+        if let PatKind::Binding(BindingAnnotation(by_ref, _), bind_id, ident, None) = field.kind;
+        let caller_ty = cx.typeck_results().expr_ty(let_expr);
+        let if_block = IfBlockType::IfLet(
+            cx.qpath_res(path1, let_pat.hir_id),
+            caller_ty,
+            ident.name,
+            let_expr,
+            if_then,
+            if_else
+        );
+        if (is_early_return(sym::Option, cx, &if_block) && path_to_local_id(peel_blocks(if_then), bind_id))
+            || is_early_return(sym::Result, cx, &if_block);
+        if if_else.map(|e| eq_expr_value(cx, let_expr, peel_blocks(e))).filter(|e| *e).is_none();
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let receiver_str = snippet_with_applicability(cx, let_expr.span, "..", &mut applicability);
+            let requires_semi = matches!(get_parent_node(cx.tcx, expr.hir_id), Some(Node::Stmt(_)));
+            let sugg = format!(
+                "{receiver_str}{}?{}",
+                if by_ref == ByRef::Yes { ".as_ref()" } else { "" },
+                if requires_semi { ";" } else { "" }
+            );
+            span_lint_and_sugg(
+                cx,
+                QUESTION_MARK,
+                expr.span,
+                "this block may be rewritten with the `?` operator",
+                "replace it with",
+                sugg,
+                applicability,
             );
-            if (is_early_return(sym::Option, cx, &if_block) && path_to_local_id(peel_blocks(if_then), bind_id))
-                || is_early_return(sym::Result, cx, &if_block);
-            if if_else.map(|e| eq_expr_value(cx, let_expr, peel_blocks(e))).filter(|e| *e).is_none();
-            then {
-                let mut applicability = Applicability::MachineApplicable;
-                let receiver_str = snippet_with_applicability(cx, let_expr.span, "..", &mut applicability);
-                let requires_semi = matches!(get_parent_node(cx.tcx, expr.hir_id), Some(Node::Stmt(_)));
-                let sugg = format!(
-                    "{receiver_str}{}?{}",
-                    if by_ref == ByRef::Yes { ".as_ref()" } else { "" },
-                    if requires_semi { ";" } else { "" }
-                );
-                span_lint_and_sugg(
-                    cx,
-                    QUESTION_MARK,
-                    expr.span,
-                    "this block may be rewritten with the `?` operator",
-                    "replace it with",
-                    sugg,
-                    applicability,
-                );
-            }
         }
     }
 }
 
-fn is_try_block(cx: &LateContext<'_>, bl: &rustc_hir::Block<'_>) -> bool {
-    if let Some(expr) = bl.expr
-        && let rustc_hir::ExprKind::Call(callee, _) = expr.kind
+/// Checks if the given statement matches the following structure:
+///
+/// ```ignore
+/// let Some(x) = option else {
+///     return None;
+/// };
+/// ```
+///
+/// If it matches, it will suggest `let x = option?;` instead
+fn check_let_some_else_return_none(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+    if !inside_try_block(cx, stmt.hir_id)
+        && !stmt.span.from_expansion()
+        && let Some(higher::LetElse {
+            let_pat,
+            ty: None,
+            let_expr,
+            let_else,
+        }) = higher::LetElse::hir(stmt)
+        && let PatKind::TupleStruct(ref path, [inner_pat], ddpos) = let_pat.kind
+        && ddpos.as_opt_usize().is_none()
+        && is_res_lang_ctor(cx, cx.qpath_res(path, let_pat.hir_id), OptionSome)
+        && !is_refutable(cx, inner_pat)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(let_expr), sym::Option)
+        && let Some(else_expr) = block_single_expr(let_else)
+        && expr_return_none_or_err(sym::Option, cx, else_expr, let_expr, None)
     {
-        is_path_lang_item(cx, callee, LangItem::TryTraitFromOutput)
-    } else {
-        false
+        let mut applicability = Applicability::MachineApplicable;
+        let pat_str = snippet_with_applicability(cx, inner_pat.span, "..", &mut applicability);
+        let receiver_str = Sugg::hir_with_applicability(cx, let_expr, "..", &mut applicability).maybe_par();
+        span_lint_and_sugg(
+            cx,
+            QUESTION_MARK,
+            stmt.span,
+            "this `let...else` may be rewritten with the `?` operator",
+            "replace it with",
+            format!("let {pat_str} = {receiver_str}?;"),
+            applicability,
+        );
+    }
+}
+
+/// Gets the only expression of a block, with or without a trailing semicolon.
+fn block_single_expr<'tcx>(block: &Block<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match *block {
+        Block {
+            stmts: [],
+            expr: Some(expr),
+            ..
+        } => Some(expr),
+        Block {
+            stmts: &[Stmt {
+                kind: StmtKind::Semi(expr),
+                ..
+            }],
+            expr: None,
+            ..
+        } => Some(expr),
+        _ => None,
     }
 }
 
 impl<'tcx> LateLintPass<'tcx> for QuestionMark {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if !in_constant(cx, expr.hir_id) {
-            self.check_is_none_or_err_and_early_return(cx, expr);
-            self.check_if_let_some_or_err_and_early_return(cx, expr);
+            check_is_none_or_err_and_early_return(cx, expr);
+            check_if_let_some_or_err_and_early_return(cx, expr);
         }
     }
 
-    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx rustc_hir::Block<'tcx>) {
-        if is_try_block(cx, block) {
-            *self
-                .try_block_depth_stack
-                .last_mut()
-                .expect("blocks are always part of bodies and must have a depth") += 1;
-        }
-    }
-
-    fn check_body(&mut self, _: &LateContext<'tcx>, _: &'tcx rustc_hir::Body<'tcx>) {
-        self.try_block_depth_stack.push(0);
-    }
-
-    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &'tcx rustc_hir::Body<'tcx>) {
-        self.try_block_depth_stack.pop();
-    }
-
-    fn check_block_post(&mut self, cx: &LateContext<'tcx>, block: &'tcx rustc_hir::Block<'tcx>) {
-        if is_try_block(cx, block) {
-            *self
-                .try_block_depth_stack
-                .last_mut()
-                .expect("blocks are always part of bodies and must have a depth") -= 1;
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        if !in_constant(cx, stmt.hir_id) {
+            check_let_some_else_return_none(cx, stmt);
         }
     }
 }
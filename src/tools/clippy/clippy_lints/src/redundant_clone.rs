@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::{span_lint_hir, span_lint_hir_and_then};
-use clippy_utils::mir::{visit_local_usage, LocalUsage, PossibleBorrowerMap};
+use clippy_utils::mir::{is_local_mutated_between, visit_local_usage, LocalUsage, PossibleBorrowerMap};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::{has_drop, is_copy, is_type_diagnostic_item, is_type_lang_item, walk_ptrs_ty_depth};
 use clippy_utils::{fn_has_unsatisfiable_preds, match_def_path, paths};
@@ -187,19 +187,17 @@ fn check_fn(
             let clone_usage = if local == ret_local {
                 CloneUsage {
                     cloned_used: false,
-                    cloned_consume_or_mutate_loc: None,
                     clone_consumed_or_mutated: true,
                 }
             } else {
                 let clone_usage = visit_clone_usage(local, ret_local, mir, bb);
-                if clone_usage.cloned_used && clone_usage.clone_consumed_or_mutated {
-                    // cloned value is used, and the clone is modified or moved
+                if clone_usage.cloned_used
+                    && (clone_usage.clone_consumed_or_mutated
+                        || cloned_mutated_while_clone_used(mir, &possible_borrower, local, ret_local, loc))
+                {
+                    // cloned value is used, and either the clone is modified or moved, or the cloned
+                    // value is modified or moved while the clone is still used
                     continue;
-                } else if let Some(loc) = clone_usage.cloned_consume_or_mutate_loc {
-                    // cloned value is mutated, and the clone is alive.
-                    if possible_borrower.local_is_alive_at(ret_local, loc) {
-                        continue;
-                    }
                 }
                 clone_usage
             };
@@ -342,8 +340,6 @@ fn base_local_and_movability<'tcx>(
 struct CloneUsage {
     /// Whether the cloned value is used after the clone.
     cloned_used: bool,
-    /// The first location where the cloned value is consumed or mutated, if any.
-    cloned_consume_or_mutate_loc: Option<mir::Location>,
     /// Whether the clone value is mutated.
     clone_consumed_or_mutated: bool,
 }
@@ -352,7 +348,7 @@ fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>,
     if let Some((
         LocalUsage {
             local_use_locs: cloned_use_locs,
-            local_consume_or_mutate_locs: cloned_consume_or_mutate_locs,
+            local_consume_or_mutate_locs: _,
         },
         LocalUsage {
             local_use_locs: _,
@@ -370,7 +366,6 @@ fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>,
     {
         CloneUsage {
             cloned_used: !cloned_use_locs.is_empty(),
-            cloned_consume_or_mutate_loc: cloned_consume_or_mutate_locs.first().copied(),
             // Consider non-temporary clones consumed.
             // TODO: Actually check for mutation of non-temporaries.
             clone_consumed_or_mutated: mir.local_kind(clone) != mir::LocalKind::Temp
@@ -379,8 +374,28 @@ fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>,
     } else {
         CloneUsage {
             cloned_used: true,
-            cloned_consume_or_mutate_loc: None,
             clone_consumed_or_mutated: true,
         }
     }
 }
+
+/// Checks if the cloned value may be mutated or moved after the clone at `loc`, and before a later
+/// use of the clone, either directly or through one of its possible borrowers.
+fn cloned_mutated_while_clone_used(
+    mir: &mir::Body<'_>,
+    possible_borrower: &PossibleBorrowerMap<'_, '_>,
+    cloned: mir::Local,
+    clone: mir::Local,
+    loc: mir::Location,
+) -> bool {
+    let mut locals = vec![clone];
+    if let Some(borrowers) = possible_borrower.map.get(&clone) {
+        locals.extend(borrowers.iter());
+    }
+    visit_local_usage(&locals, mir, loc).map_or(true, |usage| {
+        usage
+            .iter()
+            .flat_map(|usage| &usage.local_use_locs)
+            .any(|&use_loc| is_local_mutated_between(mir, cloned, loc, use_loc))
+    })
+}
@@ -1,15 +1,16 @@
 use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_and_then};
-use clippy_utils::source::{snippet_opt, snippet_with_context};
+use clippy_utils::source::{snippet_opt, snippet_with_context, sugg_with_comments, walk_span_to_context};
 use clippy_utils::visitors::{for_each_expr, Descend};
-use clippy_utils::{fn_def_id, path_to_local_id, span_find_starting_semi};
+use clippy_utils::{expr_diverges, fn_def_id, path_to_local_id, span_find_starting_semi};
 use core::ops::ControlFlow;
+use core::slice;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{Block, Body, Expr, ExprKind, FnDecl, LangItem, MatchSource, PatKind, QPath, StmtKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::{self, subst::GenericArgKind, Ty};
+use rustc_middle::ty::subst::GenericArgKind;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::def_id::LocalDefId;
 use rustc_span::source_map::Span;
@@ -223,7 +224,7 @@ fn check_final_expr<'tcx>(
     semi_spans: Vec<Span>, /* containing all the places where we would need to remove semicolons if finding an
                             * needless return */
     replacement: RetReplacement<'tcx>,
-    match_ty_opt: Option<Ty<'_>>,
+    in_unit_match: Option<bool>,
 ) {
     let peeled_drop_expr = expr.peel_drop_temps();
     match &peeled_drop_expr.kind {
@@ -252,20 +253,12 @@ fn check_final_expr<'tcx>(
                     RetReplacement::Expr(snippet, applicability)
                 }
             } else {
-                match match_ty_opt {
-                    Some(match_ty) => {
-                        match match_ty.kind() {
-                            // If the code got till here with
-                            // tuple not getting detected before it,
-                            // then we are sure it's going to be Unit
-                            // type
-                            ty::Tuple(_) => RetReplacement::Unit,
-                            // We don't want to anything in this case
-                            // cause we can't predict what the user would
-                            // want here
-                            _ => return,
-                        }
-                    },
+                match in_unit_match {
+                    Some(true) => RetReplacement::Unit,
+                    // We don't want to anything in this case
+                    // cause we can't predict what the user would
+                    // want here
+                    Some(false) => return,
                     None => replacement,
                 }
             };
@@ -273,12 +266,14 @@ fn check_final_expr<'tcx>(
             if !cx.tcx.hir().attrs(expr.hir_id).is_empty() {
                 return;
             }
-            let borrows = inner.map_or(false, |inner| last_statement_borrows(cx, inner));
+            let borrows = inner.is_some_and(|inner| last_statement_borrows(cx, inner));
             if borrows {
                 return;
             }
 
-            emit_return_lint(cx, ret_span, semi_spans, &replacement);
+            // The comments within the returned expression are part of the replacement already.
+            let kept_span = inner.map(|inner| walk_span_to_context(inner.span, ret_span.ctxt()).unwrap_or(inner.span));
+            emit_return_lint(cx, ret_span, kept_span, semi_spans, &replacement);
         },
         ExprKind::If(_, then, else_clause_opt) => {
             check_block_return(cx, &then.kind, peeled_drop_expr.span, semi_spans.clone());
@@ -291,9 +286,14 @@ fn check_final_expr<'tcx>(
         // note, if without else is going to be a type checking error anyways
         // (except for unit type functions) so we don't match it
         ExprKind::Match(_, arms, MatchSource::Normal) => {
-            let match_ty = cx.typeck_results().expr_ty(peeled_drop_expr);
+            // Replacing a `return` with `()` only keeps the type of the `match` if every other arm
+            // either has the unit type or diverges.
+            let typeck = cx.typeck_results();
+            let unit_arms = arms
+                .iter()
+                .all(|arm| typeck.expr_ty(arm.body).is_unit() || expr_diverges(cx, arm.body));
             for arm in *arms {
-                check_final_expr(cx, arm.body, semi_spans.clone(), RetReplacement::Unit, Some(match_ty));
+                check_final_expr(cx, arm.body, semi_spans.clone(), RetReplacement::Unit, Some(unit_arms));
             }
         },
         // if it's a whole block, check it
@@ -313,13 +313,21 @@ fn contains_if(expr: &Expr<'_>, on_if: bool) -> bool {
     contains_if(expr, false)
 }
 
-fn emit_return_lint(cx: &LateContext<'_>, ret_span: Span, semi_spans: Vec<Span>, replacement: &RetReplacement<'_>) {
+fn emit_return_lint(
+    cx: &LateContext<'_>,
+    ret_span: Span,
+    kept_span: Option<Span>,
+    semi_spans: Vec<Span>,
+    replacement: &RetReplacement<'_>,
+) {
     if ret_span.from_expansion() {
         return;
     }
 
     span_lint_and_then(cx, NEEDLESS_RETURN, ret_span, "unneeded `return` statement", |diag| {
-        let suggestions = std::iter::once((ret_span, replacement.to_string()))
+        let kept_spans = kept_span.as_ref().map_or(&[][..], slice::from_ref);
+        let sugg = sugg_with_comments(cx, ret_span, kept_spans, replacement.to_string());
+        let suggestions = std::iter::once((ret_span, sugg))
             .chain(semi_spans.into_iter().map(|span| (span, String::new())))
             .collect();
 
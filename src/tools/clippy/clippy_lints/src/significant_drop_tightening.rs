@@ -1,7 +1,8 @@
 use clippy_utils::{
     diagnostics::span_lint_and_then,
-    expr_or_init, get_attr, path_to_local,
+    expr_or_init, path_to_local,
     source::{indent_of, snippet},
+    ClippyAttrs,
 };
 use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
 use rustc_errors::Applicability;
@@ -174,12 +175,8 @@ fn has_sig_drop_attr(&mut self, ty: Ty<'tcx>) -> bool {
 
     fn has_sig_drop_attr_uncached(&mut self, ty: Ty<'tcx>) -> bool {
         if let Some(adt) = ty.ty_adt_def() {
-            let mut iter = get_attr(
-                self.cx.sess(),
-                self.cx.tcx.get_attrs_unchecked(adt.did()),
-                "has_significant_drop",
-            );
-            if iter.next().is_some() {
+            let attrs = ClippyAttrs::parse(self.cx.sess(), self.cx.tcx.get_attrs_unchecked(adt.did()));
+            if attrs.has_significant_drop.is_some() {
                 return true;
             }
         }
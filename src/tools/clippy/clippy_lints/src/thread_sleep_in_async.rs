@@ -0,0 +1,218 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::visitors::{for_each_expr, AsyncBodies};
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{Body, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::TypeckResults;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `std::thread::sleep` inside an `async fn` or an `async` block, either
+    /// directly or through local functions which aren't async themselves.
+    ///
+    /// The suggested replacement is the sleep function of the async runtime the crate depends
+    /// on, which can be configured with `async-sleep-functions`.
+    ///
+    /// ### Why is this bad?
+    /// `thread::sleep` blocks the thread of the executor, so none of the other tasks scheduled
+    /// on it make any progress while the task sleeps.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// async fn retry() {
+    ///     std::thread::sleep(Duration::from_millis(100));
+    ///     connect().await;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn retry() {
+    ///     tokio::time::sleep(Duration::from_millis(100)).await;
+    ///     connect().await;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub THREAD_SLEEP_IN_ASYNC,
+    suspicious,
+    "calling `std::thread::sleep` inside async code"
+}
+
+impl_lint_pass!(ThreadSleepInAsync => [THREAD_SLEEP_IN_ASYNC]);
+
+/// The async runtimes along with the path of their sleep function, in the order they're
+/// suggested in.
+const ASYNC_SLEEP_FUNCTIONS: [(&str, &str); 3] = [
+    ("tokio", "tokio::time::sleep"),
+    ("async-std", "async_std::task::sleep"),
+    ("smol", "smol::Timer::after"),
+];
+
+pub struct ThreadSleepInAsync {
+    conf_sleep_functions: Vec<[String; 2]>,
+    sleep_def_ids: FxHashSet<DefId>,
+    /// The runtime and the path of the sleep function to suggest, if one of them is found.
+    async_sleep: Option<(String, String)>,
+    async_bodies: AsyncBodies,
+    /// The `thread::sleep` call reached by each of the local functions checked so far.
+    sleeping_fns: FxHashMap<LocalDefId, Option<Span>>,
+}
+
+impl ThreadSleepInAsync {
+    pub fn new(conf_sleep_functions: Vec<[String; 2]>) -> Self {
+        Self {
+            conf_sleep_functions,
+            sleep_def_ids: FxHashSet::default(),
+            async_sleep: None,
+            async_bodies: AsyncBodies::default(),
+            sleeping_fns: FxHashMap::default(),
+        }
+    }
+
+    /// Gets the span of the `thread::sleep` call the local function reaches, if any, looking
+    /// through the other local functions it calls.
+    fn sleep_in_local_fn(&mut self, cx: &LateContext<'_>, def_id: LocalDefId) -> Option<Span> {
+        if let Some(&span) = self.sleeping_fns.get(&def_id) {
+            return span;
+        }
+        if !matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) || cx.tcx.asyncness(def_id).is_async() {
+            return None;
+        }
+        let Some(body_id) = cx.tcx.hir().maybe_body_owned_by(def_id) else {
+            return None;
+        };
+        // stops the recursion
+        self.sleeping_fns.insert(def_id, None);
+
+        let typeck = cx.tcx.typeck(def_id);
+        let mut local_callees = Vec::new();
+        let mut span = for_each_expr(cx.tcx.hir().body(body_id).value, |e| match called_fn(typeck, e) {
+            Some(callee) if self.sleep_def_ids.contains(&callee) => ControlFlow::Break(e.span),
+            Some(callee) => {
+                local_callees.extend(callee.as_local());
+                ControlFlow::Continue(())
+            },
+            None => ControlFlow::Continue(()),
+        });
+        if span.is_none() {
+            span = local_callees
+                .into_iter()
+                .find_map(|callee| self.sleep_in_local_fn(cx, callee));
+        }
+        self.sleeping_fns.insert(def_id, span);
+        span
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ThreadSleepInAsync {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        self.sleep_def_ids
+            .extend(def_path_def_ids(cx, &["std", "thread", "sleep"]));
+        self.async_sleep = self
+            .conf_sleep_functions
+            .iter()
+            .map(|[runtime, path]| (runtime.as_str(), path.as_str()))
+            .chain(ASYNC_SLEEP_FUNCTIONS)
+            .find(|(_, path)| {
+                let segs: Vec<_> = path.split("::").collect();
+                def_path_def_ids(cx, &segs).next().is_some()
+            })
+            .map(|(runtime, path)| (runtime.to_owned(), path.to_owned()));
+    }
+
+    fn check_body(&mut self, _: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        self.async_bodies.enter_body(body);
+    }
+
+    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &'tcx Body<'_>) {
+        self.async_bodies.exit_body();
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !self.async_bodies.in_async_body()
+            || !matches!(expr.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            || in_external_macro(cx.sess(), expr.span)
+        {
+            return;
+        }
+        let Some(def_id) = fn_def_id(cx, expr) else {
+            return;
+        };
+
+        if self.sleep_def_ids.contains(&def_id) {
+            span_lint_and_then(
+                cx,
+                THREAD_SLEEP_IN_ASYNC,
+                expr.span,
+                "blocking `thread::sleep` inside async code",
+                |diag| {
+                    diag.note(AsyncBodies::BLOCKS_EXECUTOR_NOTE);
+                    if let ExprKind::Call(_, [duration]) = expr.kind
+                        && !expr.span.from_expansion()
+                        && let Some((runtime, path)) = &self.async_sleep
+                    {
+                        let mut app = Applicability::MaybeIncorrect;
+                        let duration = snippet_with_applicability(cx, duration.span, "..", &mut app);
+                        diag.span_suggestion(
+                            expr.span,
+                            format!("use the async sleep of `{runtime}`"),
+                            format!("{path}({duration}).await"),
+                            app,
+                        );
+                    } else {
+                        diag.help("use the sleep function of the async runtime instead");
+                    }
+                },
+            );
+        } else if let Some(local_id) = def_id.as_local()
+            && let Some(sleep_span) = self.sleep_in_local_fn(cx, local_id)
+        {
+            span_lint_and_then(
+                cx,
+                THREAD_SLEEP_IN_ASYNC,
+                expr.span,
+                &format!(
+                    "call to `{}`, which sleeps the thread, inside async code",
+                    cx.tcx.item_name(def_id)
+                ),
+                |diag| {
+                    diag.span_note(sleep_span, "`thread::sleep` is called here");
+                    diag.note(AsyncBodies::BLOCKS_EXECUTOR_NOTE);
+                    if let Some((_, path)) = &self.async_sleep {
+                        diag.help(format!(
+                            "make the functions calling `thread::sleep` async and use `{path}` instead"
+                        ));
+                    } else {
+                        diag.help(
+                            "make the functions calling `thread::sleep` async and use the sleep function of the \
+                            async runtime instead",
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Gets the function or method called by `e`, using the typeck results of its body.
+fn called_fn(typeck: &TypeckResults<'_>, e: &Expr<'_>) -> Option<DefId> {
+    match e.kind {
+        ExprKind::Call(callee, _) => match callee.kind {
+            ExprKind::Path(ref qpath) => match typeck.qpath_res(qpath, callee.hir_id) {
+                Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => Some(def_id),
+                _ => None,
+            },
+            _ => None,
+        },
+        ExprKind::MethodCall(..) => typeck.type_dependent_def_id(e.hir_id),
+        _ => None,
+    }
+}
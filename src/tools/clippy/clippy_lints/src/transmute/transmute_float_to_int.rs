@@ -41,6 +41,7 @@ pub(super) fn check<'tcx>(
                             let op = format!("{sugg}{}", float_ty.name_str()).into();
                             match sugg {
                                 sugg::Sugg::MaybeParen(_) => sugg = sugg::Sugg::MaybeParen(op),
+                                sugg::Sugg::Prefix(_) => sugg = sugg::Sugg::Prefix(op),
                                 _ => sugg = sugg::Sugg::NonParen(op)
                             }
                         }
@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::{path_def_id, qpath_generic_tys};
+use clippy_utils::{match_any_diagnostic_items, path_def_id, qpath_generic_tys};
 use rustc_hir::{self as hir, def_id::DefId, QPath};
 use rustc_lint::LateContext;
 use rustc_span::{sym, Symbol};
@@ -34,24 +34,23 @@ pub(super) fn check(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, qpath: &QPath<'_
     }
 }
 
+/// The std collections which are already heap allocated.
+const STD_COLLECTIONS: &[Symbol] = &[
+    sym::HashMap,
+    sym::Vec,
+    sym::HashSet,
+    sym::VecDeque,
+    sym::LinkedList,
+    sym::BTreeMap,
+    sym::BTreeSet,
+    sym::BinaryHeap,
+];
+
 fn get_std_collection(cx: &LateContext<'_>, qpath: &QPath<'_>) -> Option<Symbol> {
     let param = qpath_generic_tys(qpath).next()?;
     let id = path_def_id(cx, param)?;
-    cx.tcx
-        .get_diagnostic_name(id)
-        .filter(|&name| {
-            matches!(
-                name,
-                sym::HashMap
-                    | sym::Vec
-                    | sym::HashSet
-                    | sym::VecDeque
-                    | sym::LinkedList
-                    | sym::BTreeMap
-                    | sym::BTreeSet
-                    | sym::BinaryHeap
-            )
-        })
+    match_any_diagnostic_items(cx, id, STD_COLLECTIONS)
+        .map(|i| STD_COLLECTIONS[i])
         .or_else(|| {
             cx.tcx
                 .lang_items()
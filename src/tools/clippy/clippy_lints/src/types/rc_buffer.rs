@@ -1,10 +1,10 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::{path_def_id, qpath_generic_tys};
+use clippy_utils::{lookup_diagnostic_item, path_def_id, qpath_generic_tys};
 use rustc_errors::Applicability;
 use rustc_hir::{self as hir, def_id::DefId, QPath, TyKind};
 use rustc_lint::LateContext;
-use rustc_span::symbol::sym;
+use rustc_span::symbol::{sym, Symbol};
 
 use super::RC_BUFFER;
 
@@ -88,14 +88,14 @@ pub(super) fn check(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, qpath: &QPath<'_
     false
 }
 
+/// The owned buffer types, along with the unsized type each of them dereferences to.
+static BUFFERS: &[(Symbol, &str)] = &[(sym::OsString, "std::ffi::OsStr"), (sym::PathBuf, "std::path::Path")];
+
 fn match_buffer_type(cx: &LateContext<'_>, qpath: &QPath<'_>) -> Option<&'static str> {
     let ty = qpath_generic_tys(qpath).next()?;
     let id = path_def_id(cx, ty)?;
-    let path = match cx.tcx.get_diagnostic_name(id) {
-        Some(sym::OsString) => "std::ffi::OsStr",
-        Some(sym::PathBuf) => "std::path::Path",
-        _ if Some(id) == cx.tcx.lang_items().string() => "str",
-        _ => return None,
-    };
-    Some(path)
+    if Some(id) == cx.tcx.lang_items().string() {
+        return Some("str");
+    }
+    lookup_diagnostic_item(cx, id, BUFFERS).copied()
 }
@@ -98,6 +98,7 @@ fn escape<T: Iterator<Item = char>>(s: T) -> String {
     result
 }
 
+#[allow(clippy::chained_str_replace)]
 fn check_str(cx: &LateContext<'_>, span: Span, id: HirId) {
     if !span_is_local(span) {
         return;
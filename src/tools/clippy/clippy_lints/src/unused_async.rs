@@ -1,16 +1,26 @@
-use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::is_def_id_trait_method;
-use rustc_hir::intravisit::{walk_body, walk_expr, walk_fn, FnKind, Visitor};
-use rustc_hir::{Body, Expr, ExprKind, FnDecl, YieldSource};
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::source::snippet_opt;
+use clippy_utils::visitors::ExprIter;
+use clippy_utils::{get_parent_expr, is_def_id_trait_method};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::{Applicability, Diagnostic};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{AsyncGeneratorKind, Body, Expr, ExprKind, FnDecl, GeneratorKind, MatchSource, StmtKind, YieldSource};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::hir::nested_filter;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::def_id::LocalDefId;
-use rustc_span::Span;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::{DefId, LocalDefId};
+use rustc_span::{BytePos, Span};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for functions that are declared `async` but have no `.await`s inside of them.
+    /// Checks for functions and closures that are declared `async` but have no `.await`s inside of
+    /// them, or only ones which are never reached.
+    ///
+    /// The methods of a trait are checked when the trait isn't exported and none of the
+    /// implementations of the method use `.await`, so `async` can be removed from all of them.
     ///
     /// ### Why is this bad?
     /// Async functions with no async code create overhead, both mentally and computationally.
@@ -38,48 +48,37 @@
     "finds async functions with no await statements"
 }
 
-declare_lint_pass!(UnusedAsync => [UNUSED_ASYNC]);
+impl_lint_pass!(UnusedAsync => [UNUSED_ASYNC]);
 
-struct AsyncFnVisitor<'a, 'tcx> {
-    cx: &'a LateContext<'tcx>,
-    found_await: bool,
-    /// Also keep track of `await`s in nested async blocks so we can mention
-    /// it in a note
-    await_in_async_block: Option<Span>,
-    async_depth: usize,
+#[derive(Default)]
+pub struct UnusedAsync {
+    /// The `async` functions without `.await`s, which are linted once all their calls are known.
+    unused_async_fns: Vec<UnusedAsyncFn>,
+    /// The spans of the `.await`s following the calls of local functions. `None` if the function is
+    /// used in any other way, so the callers can't simply stop awaiting it.
+    awaited_calls: FxHashMap<LocalDefId, Option<Vec<Span>>>,
+    /// The trait methods implemented by `async` functions without `.await`s, with the spans of the
+    /// implementations.
+    unused_trait_impls: FxHashMap<DefId, Vec<Span>>,
+    /// The trait methods with an implementation which needs to stay as it is.
+    used_trait_methods: FxHashSet<DefId>,
 }
 
-impl<'a, 'tcx> Visitor<'tcx> for AsyncFnVisitor<'a, 'tcx> {
-    type NestedFilter = nested_filter::OnlyBodies;
-
-    fn visit_expr(&mut self, ex: &'tcx Expr<'tcx>) {
-        if let ExprKind::Yield(_, YieldSource::Await { .. }) = ex.kind {
-            if self.async_depth == 1 {
-                self.found_await = true;
-            } else if self.await_in_async_block.is_none() {
-                self.await_in_async_block = Some(ex.span);
-            }
-        }
-        walk_expr(self, ex);
-    }
-
-    fn nested_visit_map(&mut self) -> Self::Map {
-        self.cx.tcx.hir()
-    }
-
-    fn visit_body(&mut self, b: &'tcx Body<'tcx>) {
-        let is_async_block = matches!(b.generator_kind, Some(rustc_hir::GeneratorKind::Async(_)));
-
-        if is_async_block {
-            self.async_depth += 1;
-        }
-
-        walk_body(self, b);
+struct UnusedAsyncFn {
+    def_id: LocalDefId,
+    span: Span,
+    /// The span of the `async` keyword along with the following whitespace.
+    async_span: Option<Span>,
+    awaits: Awaits,
+}
 
-        if is_async_block {
-            self.async_depth -= 1;
-        }
-    }
+/// The `.await`s found in an `async` body which don't need it to be `async`.
+#[derive(Default)]
+struct Awaits {
+    /// An `.await` in a nested `async` block.
+    in_async_block: Option<Span>,
+    /// An `.await` which is never reached, e.g. in a branch disabled by `cfg!`.
+    unreachable: Option<Span>,
 }
 
 impl<'tcx> LateLintPass<'tcx> for UnusedAsync {
@@ -87,38 +86,260 @@ fn check_fn(
         &mut self,
         cx: &LateContext<'tcx>,
         fn_kind: FnKind<'tcx>,
-        fn_decl: &'tcx FnDecl<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
         body: &Body<'tcx>,
         span: Span,
         def_id: LocalDefId,
     ) {
-        if !span.from_expansion() && fn_kind.asyncness().is_async() && !is_def_id_trait_method(cx, def_id) {
-            let mut visitor = AsyncFnVisitor {
-                cx,
-                found_await: false,
-                async_depth: 0,
-                await_in_async_block: None,
+        if is_def_id_trait_method(cx, def_id) {
+            let Some(trait_item) = cx.tcx.associated_item(def_id).trait_item_def_id else {
+                return;
             };
-            walk_fn(&mut visitor, fn_kind, fn_decl, body.id(), def_id);
-            if !visitor.found_await {
-                span_lint_and_then(
-                    cx,
-                    UNUSED_ASYNC,
-                    span,
-                    "unused `async` for function with no await statements",
-                    |diag| {
-                        diag.help("consider removing the `async` from this function");
+            if !span.from_expansion() && fn_kind.asyncness().is_async() && unused_awaits(cx, body).is_some() {
+                self.unused_trait_impls.entry(trait_item).or_default().push(span);
+            } else {
+                self.used_trait_methods.insert(trait_item);
+            }
+        } else if !span.from_expansion()
+            && fn_kind.asyncness().is_async()
+            && let Some(awaits) = unused_awaits(cx, body)
+        {
+            let async_span = match fn_kind {
+                FnKind::ItemFn(ident, ..) | FnKind::Method(ident, ..) => async_keyword_span(cx, span.until(ident.span)),
+                FnKind::Closure => None,
+            };
+            self.unused_async_fns.push(UnusedAsyncFn {
+                def_id,
+                span,
+                async_span,
+                awaits,
+            });
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            // `async` closures are lowered to closures returning an `async` block
+            ExprKind::Closure(closure) if !expr.span.from_expansion() => {
+                let body = cx.tcx.hir().body(closure.body);
+                if let ExprKind::Closure(inner) = body.value.kind
+                    && cx.tcx.hir().body(inner.body).generator_kind
+                        == Some(GeneratorKind::Async(AsyncGeneratorKind::Closure))
+                    && let Some(awaits) = unused_awaits(cx, body)
+                {
+                    span_lint_and_then(
+                        cx,
+                        UNUSED_ASYNC,
+                        expr.span,
+                        "unused `async` for closure with no await statements",
+                        |diag| {
+                            diag.help("consider removing the `async` from this closure");
+                            awaits.add_notes(diag);
+                        },
+                    );
+                }
+            },
+            ExprKind::Path(ref qpath) => {
+                if let Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) = cx.qpath_res(qpath, expr.hir_id)
+                    && let Some(def_id) = def_id.as_local()
+                {
+                    let awaited = match get_parent_expr(cx, expr) {
+                        Some(
+                            call @ Expr {
+                                kind: ExprKind::Call(callee, _),
+                                ..
+                            },
+                        ) if callee.hir_id == expr.hir_id => awaited_call(cx, call),
+                        _ => None,
+                    };
+                    self.record_use(def_id, awaited);
+                }
+            },
+            ExprKind::MethodCall(..) => {
+                if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+                    && let Some(def_id) = def_id.as_local()
+                {
+                    self.record_use(def_id, awaited_call(cx, expr));
+                }
+            },
+            _ => {},
+        }
+    }
 
-                        if let Some(span) = visitor.await_in_async_block {
-                            diag.span_note(
-                                span,
-                                "`await` used in an async block, which does not require \
-                                the enclosing function to be `async`",
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for unused in &self.unused_async_fns {
+            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(unused.def_id);
+            // a function which is never used has no calls to change
+            let awaits = self
+                .awaited_calls
+                .get(&unused.def_id)
+                .map_or(Some(&[][..]), |awaits| awaits.as_deref());
+            span_lint_hir_and_then(
+                cx,
+                UNUSED_ASYNC,
+                hir_id,
+                unused.span,
+                "unused `async` for function with no await statements",
+                |diag| {
+                    // the callers can only be changed if they're all known, and the unreachable
+                    // `await`s would have to be removed as well
+                    if let Some(async_span) = unused.async_span
+                        && unused.awaits.unreachable.is_none()
+                        && !cx.effective_visibilities.is_exported(unused.def_id)
+                        && cx.tcx.trait_of_item(unused.def_id.to_def_id()).is_none()
+                        && let Some(awaits) = awaits
+                    {
+                        if awaits.is_empty() {
+                            diag.span_suggestion(
+                                async_span,
+                                "remove the `async` from this function",
+                                "",
+                                Applicability::MaybeIncorrect,
+                            );
+                        } else {
+                            let sugg = [async_span]
+                                .iter()
+                                .chain(awaits)
+                                .map(|&span| (span, String::new()))
+                                .collect();
+                            diag.multipart_suggestion(
+                                "remove the `async` from this function and the `.await` from its calls",
+                                sugg,
+                                Applicability::MaybeIncorrect,
                             );
                         }
+                    } else {
+                        diag.help("consider removing the `async` from this function");
+                    }
+                    unused.awaits.add_notes(diag);
+                },
+            );
+        }
+
+        for (&trait_item, impls) in &self.unused_trait_impls {
+            if !self.used_trait_methods.contains(&trait_item)
+                && let Some(local_item) = trait_item.as_local()
+                && cx.tcx.asyncness(trait_item).is_async()
+                && !cx.tcx.associated_item(trait_item).defaultness(cx.tcx).has_value()
+                && let Some(trait_id) = cx.tcx.trait_of_item(trait_item)
+                && !cx.effective_visibilities.is_exported(trait_id.expect_local())
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    UNUSED_ASYNC,
+                    cx.tcx.hir().local_def_id_to_hir_id(local_item),
+                    cx.tcx.def_span(trait_item),
+                    "unused `async` for trait method with no await statements in its implementations",
+                    |diag| {
+                        diag.span_note(impls.clone(), "the method is implemented without await statements here");
+                        diag.help("consider removing the `async` from the trait method and its implementations");
                     },
                 );
             }
         }
     }
 }
+
+impl UnusedAsync {
+    fn record_use(&mut self, def_id: LocalDefId, awaited: Option<Span>) {
+        let awaits = self.awaited_calls.entry(def_id).or_insert_with(|| Some(Vec::new()));
+        match (awaits, awaited) {
+            (Some(awaits), Some(span)) => awaits.push(span),
+            (awaits, _) => *awaits = None,
+        }
+    }
+}
+
+impl Awaits {
+    fn add_notes(&self, diag: &mut Diagnostic) {
+        if let Some(span) = self.in_async_block {
+            diag.span_note(
+                span,
+                "`await` used in an async block, which does not require \
+                the enclosing function to be `async`",
+            );
+        }
+        if let Some(span) = self.unreachable {
+            diag.span_note(span, "this `await` is never reached");
+        }
+    }
+}
+
+/// Checks the `.await`s of an `async` body, returning `None` if one of them is needed.
+fn unused_awaits<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) -> Option<Awaits> {
+    let unreachable_code = unreachable_code(cx, body);
+    let mut awaits = Awaits::default();
+    let found_await = ExprIter::new()
+        .async_bodies(cx)
+        .for_each_with_async_ctxt(body, |e, async_ctxt| {
+            if let ExprKind::Yield(_, YieldSource::Await { .. }) = e.kind {
+                // Also keep track of `await`s in nested async blocks and unreachable code so we
+                // can mention them in a note
+                if unreachable_code.iter().any(|span| span.contains(e.span)) {
+                    awaits.unreachable.get_or_insert(e.span);
+                } else if async_ctxt.depth == 1 {
+                    return ControlFlow::Break(());
+                } else {
+                    awaits.in_async_block.get_or_insert(e.span);
+                }
+            }
+            ControlFlow::Continue(())
+        })
+        .is_some();
+    (!found_await).then_some(awaits)
+}
+
+/// Gets the spans of the code which is never run: the branches of `if`s with a constant
+/// condition which aren't taken, and the code following a diverging statement. `cfg!(..)` isn't
+/// a constant here, the branch may be taken with another configuration.
+fn unreachable_code<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) -> Vec<Span> {
+    let typeck = cx.typeck_results();
+    let mut spans = Vec::new();
+    ExprIter::new().async_bodies(cx).for_each(body, |e| {
+        match e.kind {
+            ExprKind::If(cond, then, els) => match constant(cx, typeck, cond) {
+                Some(Constant::Bool(false)) => spans.push(then.span),
+                Some(Constant::Bool(true)) => spans.extend(els.map(|els| els.span)),
+                _ => {},
+            },
+            ExprKind::Block(block, _) => {
+                if let Some(stmt) = block.stmts.iter().find(|stmt| match stmt.kind {
+                    StmtKind::Expr(e) | StmtKind::Semi(e) => typeck.expr_ty(e).is_never(),
+                    StmtKind::Local(local) => local.init.is_some_and(|init| typeck.expr_ty(init).is_never()),
+                    StmtKind::Item(_) => false,
+                }) {
+                    spans.push(stmt.span.between(block.span.shrink_to_hi()));
+                }
+            },
+            _ => {},
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    spans
+}
+
+/// Gets the span of the `.await` directly following the call, if any.
+fn awaited_call(cx: &LateContext<'_>, call: &Expr<'_>) -> Option<Span> {
+    if let Some(into_future) = get_parent_expr(cx, call)
+        && let ExprKind::Call(_, [arg]) = into_future.kind
+        && arg.hir_id == call.hir_id
+        && let Some(await_expr) = get_parent_expr(cx, into_future)
+        && let ExprKind::Match(_, _, MatchSource::AwaitDesugar) = await_expr.kind
+        && await_expr.span.ctxt() == call.span.ctxt()
+    {
+        Some(call.span.shrink_to_hi().to(await_expr.span.shrink_to_hi()))
+    } else {
+        None
+    }
+}
+
+/// Finds the `async` keyword in the header of a function, preceding its name.
+fn async_keyword_span(cx: &LateContext<'_>, header: Span) -> Option<Span> {
+    let snip = snippet_opt(cx, header)?;
+    let start = snip.find("async ")?;
+    let len = snip[start + "async".len()..]
+        .find(|c: char| !c.is_whitespace())
+        .map_or(snip.len() - start, |ws| "async".len() + ws);
+    let lo = header.lo() + BytePos(start as u32);
+    Some(header.with_lo(lo).with_hi(lo + BytePos(len as u32)))
+}
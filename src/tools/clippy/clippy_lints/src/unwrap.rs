@@ -1,16 +1,18 @@
 use clippy_utils::diagnostics::span_lint_hir_and_then;
-use clippy_utils::higher;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{get_parent_expr_for_hir, higher};
 use clippy_utils::{path_to_local, usage::is_potentially_mutated};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{walk_expr, walk_fn, FnKind, Visitor};
-use rustc_hir::{BinOpKind, Body, Expr, ExprKind, FnDecl, HirId, PathSegment, UnOp};
+use rustc_hir::{BinOpKind, Block, Body, Expr, ExprKind, FnDecl, HirId, PathSegment, Stmt, StmtKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::Ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::def_id::LocalDefId;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
@@ -40,6 +42,30 @@
     ///     do_something_with(value)
     /// }
     /// ```
+    ///
+    /// The check can also be an early return guard:
+    ///
+    /// ```rust
+    /// # fn do_something_with(_x: usize) {}
+    /// fn f(option: Option<usize>) {
+    ///     if option.is_none() {
+    ///         return;
+    ///     }
+    ///     do_something_with(option.unwrap())
+    /// }
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```rust
+    /// # fn do_something_with(_x: usize) {}
+    /// fn f(option: Option<usize>) {
+    ///     let Some(value) = option else {
+    ///         return;
+    ///     };
+    ///     do_something_with(value)
+    /// }
+    /// ```
     #[clippy::version = "pre 1.29.0"]
     pub UNNECESSARY_UNWRAP,
     complexity,
@@ -77,6 +103,7 @@
 struct UnwrappableVariablesVisitor<'a, 'tcx> {
     unwrappables: Vec<UnwrapInfo<'tcx>>,
     cx: &'a LateContext<'tcx>,
+    msrv: &'a Msrv,
 }
 
 /// What kind of unwrappable this is.
@@ -100,6 +127,15 @@ fn error_variant_pattern(self) -> &'static str {
             UnwrappableKind::Result => "Err(..)",
         }
     }
+
+    fn variant_binding_pattern(self, success: bool, binding: &str) -> Option<String> {
+        match (self, success) {
+            (UnwrappableKind::Option, true) => Some(format!("Some({binding})")),
+            (UnwrappableKind::Option, false) => None,
+            (UnwrappableKind::Result, true) => Some(format!("Ok({binding})")),
+            (UnwrappableKind::Result, false) => Some(format!("Err({binding})")),
+        }
+    }
 }
 
 /// Contains information about whether a variable can be unwrapped.
@@ -115,6 +151,9 @@ struct UnwrapInfo<'tcx> {
     check_name: &'tcx PathSegment<'tcx>,
     /// The branch where the check takes place, like `if x.is_ok() { .. }`
     branch: &'tcx Expr<'tcx>,
+    /// The statement of an early return guard like `if x.is_err() { return; }`, if the check takes
+    /// place in the statements following it instead of in `branch`
+    guard: Option<&'tcx Stmt<'tcx>>,
     /// Whether `is_some()` or `is_ok()` was called (as opposed to `is_err()` or `is_none()`).
     safe_to_unwrap: bool,
     /// What kind of unwrappable this is.
@@ -181,6 +220,7 @@ fn is_relevant_result_call(cx: &LateContext<'_>, ty: Ty<'_>, method_name: &str)
                         check: expr,
                         check_name: method_name,
                         branch,
+                        guard: None,
                         safe_to_unwrap,
                         kind,
                         is_entire_condition,
@@ -213,11 +253,46 @@ fn visit_branch(
         walk_expr(self, branch);
         self.unwrappables.truncate(prev_len);
     }
+
+    /// Adds the unwrappable variables checked by an early return guard like
+    /// `if x.is_none() { return; }` for the rest of the block.
+    fn push_guard(&mut self, block: &'tcx Block<'_>, stmt: &'tcx Stmt<'_>) {
+        if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = stmt.kind
+            && !expr.span.from_expansion()
+            && let Some(higher::If {
+                cond,
+                then,
+                r#else: None,
+            }) = higher::If::hir(expr)
+            && self.cx.typeck_results().expr_ty(then).is_never()
+            && let Some(block_expr) = get_parent_expr_for_hir(self.cx, block.hir_id)
+        {
+            for mut unwrap_info in collect_unwrap_info(self.cx, expr, cond, then, true, true) {
+                if is_potentially_mutated(unwrap_info.local_id, block_expr, self.cx) {
+                    continue;
+                }
+                unwrap_info.guard = Some(stmt);
+                self.unwrappables.push(unwrap_info);
+            }
+        }
+    }
 }
 
 impl<'a, 'tcx> Visitor<'tcx> for UnwrappableVariablesVisitor<'a, 'tcx> {
     type NestedFilter = nested_filter::OnlyBodies;
 
+    fn visit_block(&mut self, block: &'tcx Block<'_>) {
+        let prev_len = self.unwrappables.len();
+        for stmt in block.stmts {
+            self.visit_stmt(stmt);
+            self.push_guard(block, stmt);
+        }
+        if let Some(expr) = block.expr {
+            self.visit_expr(expr);
+        }
+        self.unwrappables.truncate(prev_len);
+    }
+
     fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
         // Shouldn't lint when `expr` is in macro.
         if in_external_macro(self.cx.tcx.sess, expr.span) {
@@ -263,7 +338,33 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
                                 unwrappable.check_name.ident.as_str(),
                             ),
                             |diag| {
-                                if is_entire_condition {
+                                if let Some(guard) = unwrappable.guard {
+                                    let meets_msrv = self.msrv.meets(msrvs::LET_ELSE);
+                                    if is_entire_condition
+                                        && meets_msrv
+                                        && let Some(pattern) = unwrappable
+                                            .kind
+                                            .variant_binding_pattern(call_to_unwrap, unwrappable_variable_name.as_str())
+                                    {
+                                        diag.span_suggestion(
+                                            guard.span,
+                                            "try",
+                                            format!(
+                                                "let {pattern} = {unwrappable_variable_name} else {};",
+                                                snippet(self.cx, unwrappable.branch.span, ".."),
+                                            ),
+                                            // The unwrap still has to be removed.
+                                            Applicability::Unspecified,
+                                        );
+                                    } else {
+                                        diag.span_label(unwrappable.check.span, "the check is happening here");
+                                        diag.help(if meets_msrv {
+                                            "try using `let else` or `match`"
+                                        } else {
+                                            "try using `match`"
+                                        });
+                                    }
+                                } else if is_entire_condition {
                                     diag.span_suggestion(
                                         unwrappable.check.span.with_lo(unwrappable.if_expr.span.lo()),
                                         "try",
@@ -303,7 +404,18 @@ fn nested_visit_map(&mut self) -> Self::Map {
     }
 }
 
-declare_lint_pass!(Unwrap => [PANICKING_UNWRAP, UNNECESSARY_UNWRAP]);
+pub struct Unwrap {
+    msrv: Msrv,
+}
+
+impl Unwrap {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Unwrap => [PANICKING_UNWRAP, UNNECESSARY_UNWRAP]);
 
 impl<'tcx> LateLintPass<'tcx> for Unwrap {
     fn check_fn(
@@ -322,8 +434,11 @@ fn check_fn(
         let mut v = UnwrappableVariablesVisitor {
             cx,
             unwrappables: Vec::new(),
+            msrv: &self.msrv,
         };
 
         walk_fn(&mut v, kind, decl, body.id(), fn_id);
     }
+
+    extract_msrv_attr!(LateContext);
 }
@@ -56,6 +56,8 @@ pub fn new(avoid_breaking_exported_api: bool, aggressive: bool) -> Self {
 
 impl_lint_pass!(UpperCaseAcronyms => [UPPER_CASE_ACRONYMS]);
 
+// the string is reversed twice, which restores any grapheme cluster
+#[allow(clippy::naive_string_reversal)]
 fn correct_ident(ident: &str) -> String {
     let ident = ident.chars().rev().collect::<String>();
     let fragments = ident
@@ -1,7 +1,7 @@
 //! A group of attributes that can be attached to Rust code in order
 //! to generate a clippy lint detecting said code automatically.
 
-use clippy_utils::{get_attr, higher};
+use clippy_utils::{higher, ClippyAttrs};
 use rustc_ast::ast::{LitFloatType, LitKind};
 use rustc_ast::LitIntType;
 use rustc_data_structures::fx::FxHashMap;
@@ -735,7 +735,7 @@ macro_rules! kind {
 
 fn has_attr(cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
     let attrs = cx.tcx.hir().attrs(hir_id);
-    get_attr(cx.sess(), attrs, "author").count() > 0
+    ClippyAttrs::parse(cx.sess(), attrs).author.is_some()
 }
 
 fn path_to_string(path: &QPath<'_>) -> String {
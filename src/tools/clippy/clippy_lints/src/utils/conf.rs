@@ -294,7 +294,7 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, UNNECESSARY_MAP_OR, SORT_BY_PARTIAL_CMP, UNNECESSARY_UNWRAP, CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS.
     ///
     /// The minimum rust version that the project supports
     (msrv: Option<String> = None),
@@ -490,6 +490,10 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Whether print macros (ex. `println!`) should be allowed in test functions or `#[cfg(test)]`
     (allow_print_in_tests: bool = false),
+    /// Lint: PANIC.
+    ///
+    /// Whether `panic!` should be allowed in test functions or `#[cfg(test)]`
+    (allow_panic_in_tests: bool = false),
     /// Lint: RESULT_LARGE_ERR.
     ///
     /// The maximum size of the `Err`-variant in a `Result` returned from a function
@@ -551,6 +555,54 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Whether to allow `r#""#` when `r""` can be used
     (allow_one_hash_in_raw_strings: bool = false),
+    /// Lint: MATCH_SAME_ARMS.
+    ///
+    /// The minimum number of expressions an arm body needs to consist of for the arm to be considered
+    /// for merging. Arms with a body below this complexity, such as `()` or a single literal, are ignored
+    (match_same_arms_min_complexity: u64 = 0),
+    /// Lint: MATCH_SAME_ARMS.
+    ///
+    /// Whether to also lint arms whose bodies only differ in the names of their bindings. A
+    /// suggestion to rename the bindings is given so the arms can be merged afterwards
+    (match_same_arms_allow_renamed_bindings: bool = false),
+    /// Lint: MATCH_SAME_ARMS.
+    ///
+    /// Whether to also lint arms which bind values they don't use, e.g. `Foo(x) => bar()` and
+    /// `Baz(y) => bar()`. The unused bindings are replaced with `_` in the merged pattern
+    (match_same_arms_allow_unused_bindings: bool = false),
+    /// Lint: NAIVE_STRING_REVERSAL.
+    ///
+    /// Whether to lint every `chars().rev()` on a string, e.g. in palindrome checks, not only the
+    /// ones collected into a `String`
+    (naive_string_reversal_all_rev: bool = false),
+    /// Lint: VEC_FRONT_MODIFICATION.
+    ///
+    /// The minimum number of iterations a loop needs to be known to run for `insert(0, _)` and
+    /// `remove(0)` on a `Vec` in it to be linted. Loops with an unknown number of iterations are always linted
+    (vec_front_modification_min_iterations: u64 = 16),
+    /// Lint: BLOCK_ON_IN_ASYNC.
+    ///
+    /// A list of paths to functions blocking on a future, e.g. the `block_on` function of a custom
+    /// executor, which are checked in addition to the ones of the common executors
+    (blocking_executor_functions: Vec<String> = Vec::new()),
+    /// Lint: DETACHED_SPAWN.
+    ///
+    /// A list of paths to functions spawning a task and returning its handle, e.g. the `spawn`
+    /// function of a custom executor, which are checked in addition to the ones of the common
+    /// executors
+    (task_spawn_functions: Vec<String> = Vec::new()),
+    /// Lint: THREAD_SLEEP_IN_ASYNC.
+    ///
+    /// A list of `[runtime, path]` pairs mapping an async runtime to the path of its sleep function,
+    /// which are tried before the ones of `tokio`, `async-std` and `smol`. The first one whose path can
+    /// be resolved is used in the suggestion.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// async-sleep-functions = [["embassy", "embassy_time::Timer::after"]]
+    /// ```
+    (async_sleep_functions: Vec<[String; 2]> = Vec::new()),
 }
 
 /// Search for the configuration file.
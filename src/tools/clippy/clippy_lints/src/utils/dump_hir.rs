@@ -1,4 +1,4 @@
-use clippy_utils::get_attr;
+use clippy_utils::ClippyAttrs;
 use hir::TraitItem;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
@@ -64,5 +64,5 @@ fn check_impl_item(&mut self, cx: &LateContext<'_>, item: &hir::ImplItem<'_>) {
 
 fn has_attr(cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
     let attrs = cx.tcx.hir().attrs(hir_id);
-    get_attr(cx.sess(), attrs, "dump").count() > 0
+    ClippyAttrs::parse(cx.sess(), attrs).dump.is_some()
 }
@@ -1,6 +1,6 @@
-use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::{def_path_def_ids, is_lint_allowed, match_any_def_paths, peel_hir_expr_refs};
+use clippy_utils::{def_path_def_ids, is_lint_allowed, match_any_def_paths, match_def_path, peel_hir_expr_refs};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_data_structures::fx::{FxHashSet, FxIndexSet};
@@ -101,6 +101,15 @@ enum Item {
             &["clippy_utils", "is_expr_path_def_path"],
         ];
 
+        if let [cx_arg, def_arg, paths_arg] = args
+            && let ExprKind::Path(path) = &func.kind
+            && let Some(id) = cx.qpath_res(path, func.hir_id).opt_def_id()
+            && match_def_path(cx, id, &["clippy_utils", "match_any_def_paths"])
+        {
+            self.check_match_any_def_paths(cx, cx_arg, def_arg, paths_arg, span);
+            return;
+        }
+
         if_chain! {
             if let [cx_arg, def_arg, args @ ..] = args;
             if let ExprKind::Path(path) = &func.kind;
@@ -184,7 +193,7 @@ enum Item {
                     (3, Item::LangItem(item)) => (
                         format!(
                             "path_res({cx_snip}, {def_snip}).opt_def_id()\
-                                .map_or(false, |id| {cx_snip}.tcx.lang_items().get(LangItem::{item}) == Some(id))",
+                                .is_some_and(|id| {cx_snip}.tcx.lang_items().get(LangItem::{item}) == Some(id))",
                         ),
                         false,
                     ),
@@ -206,6 +215,55 @@ enum Item {
         }
     }
 
+    /// Checks for `match_any_def_paths` where every path is to a diagnostic item.
+    fn check_match_any_def_paths(
+        &mut self,
+        cx: &LateContext<'_>,
+        cx_arg: &Expr<'_>,
+        def_arg: &Expr<'_>,
+        paths_arg: &Expr<'_>,
+        span: Span,
+    ) {
+        let ExprKind::Array(paths) = peel_hir_expr_refs(paths_arg).0.kind else {
+            return;
+        };
+        let Some(items) = paths
+            .iter()
+            .map(|path| {
+                let segments = path_to_matched_type(cx, path)?;
+                let segments: Vec<&str> = segments.iter().map(|sym| &**sym).collect();
+                let def_id = def_path_def_ids(cx, &segments).next()?;
+                Some((def_id, cx.tcx.get_diagnostic_name(def_id)?))
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+        if items.is_empty() {
+            return;
+        }
+
+        let mut app = Applicability::MachineApplicable;
+        let cx_snip = snippet_with_applicability(cx, cx_arg.span, "..", &mut app);
+        let def_snip = snippet_with_applicability(cx, def_arg.span, "..", &mut app);
+        let items_snip = items
+            .iter()
+            .map(|(_, name)| format!("sym::{name}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_DEF_PATH,
+            span,
+            "use of def paths to diagnostic items",
+            "try",
+            format!("match_any_diagnostic_items({cx_snip}, {def_snip}, &[{items_snip}])"),
+            app,
+        );
+
+        self.linted_def_ids.extend(items.into_iter().map(|(def_id, _)| def_id));
+    }
+
     fn check_array(&mut self, cx: &LateContext<'_>, elements: &[Expr<'_>], span: Span) {
         let Some(path) = path_from_array(elements) else { return };
 
@@ -1,9 +1,10 @@
+use crate::msrvs::parse_msrv;
 use rustc_ast::ast;
 use rustc_ast::attr;
 use rustc_errors::Applicability;
+use rustc_semver::RustcVersion;
 use rustc_session::Session;
-use rustc_span::sym;
-use std::str::FromStr;
+use rustc_span::{sym, Span, Symbol};
 
 /// Deprecation status of attributes known by Clippy.
 pub enum DeprecationStatus {
@@ -43,13 +44,17 @@ pub fn new(limit: u64) -> Self {
     pub fn limit(&self) -> u64 {
         *self.stack.last().expect("there should always be a value in the stack")
     }
-    pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
-        let stack = &mut self.stack;
-        parse_attrs(sess, attrs, name, |val| stack.push(val));
+    /// Pushes the limit set by a `#[clippy::cognitive_complexity]` attribute.
+    pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute]) {
+        if let Some(limit) = ClippyAttrs::parse(sess, attrs).cognitive_complexity {
+            self.stack.push(limit.value);
+        }
     }
-    pub fn pop_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
-        let stack = &mut self.stack;
-        parse_attrs(sess, attrs, name, |val| assert_eq!(stack.pop(), Some(val)));
+    /// Pops the limit pushed by [`LimitStack::push_attrs`] for the same attributes.
+    pub fn pop_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute]) {
+        if let Some(limit) = ClippyAttrs::parse(sess, attrs).cognitive_complexity {
+            assert_eq!(self.stack.pop(), Some(limit.value));
+        }
     }
 }
 
@@ -58,89 +63,116 @@ pub fn get_attr<'a>(
     attrs: &'a [ast::Attribute],
     name: &'static str,
 ) -> impl Iterator<Item = &'a ast::Attribute> {
-    attrs.iter().filter(move |attr| {
-        let attr = if let ast::AttrKind::Normal(ref normal) = attr.kind {
-            &normal.item
-        } else {
-            return false;
-        };
-        let attr_segments = &attr.path.segments;
-        if attr_segments.len() == 2 && attr_segments[0].ident.name == sym::clippy {
-            BUILTIN_ATTRIBUTES
-                .iter()
-                .find_map(|&(builtin_name, ref deprecation_status)| {
-                    if attr_segments[1].ident.name.as_str() == builtin_name {
-                        Some(deprecation_status)
-                    } else {
-                        None
-                    }
-                })
-                .map_or_else(
-                    || {
-                        sess.span_err(attr_segments[1].ident.span, "usage of unknown attribute");
-                        false
-                    },
-                    |deprecation_status| {
-                        let mut diag =
-                            sess.struct_span_err(attr_segments[1].ident.span, "usage of deprecated attribute");
-                        match *deprecation_status {
-                            DeprecationStatus::Deprecated => {
-                                diag.emit();
-                                false
-                            },
-                            DeprecationStatus::Replaced(new_name) => {
-                                diag.span_suggestion(
-                                    attr_segments[1].ident.span,
-                                    "consider using",
-                                    new_name,
-                                    Applicability::MachineApplicable,
-                                );
-                                diag.emit();
-                                false
-                            },
-                            DeprecationStatus::None => {
-                                diag.cancel();
-                                attr_segments[1].ident.name.as_str() == name
-                            },
-                        }
-                    },
-                )
-        } else {
-            false
-        }
-    })
+    attrs
+        .iter()
+        .filter(move |attr| builtin_attr_name(sess, attr) == Some(name))
+}
+
+/// Returns the name of the attribute if it is a known `#[clippy::...]` attribute. Emits an error
+/// for unknown and deprecated attributes.
+fn builtin_attr_name(sess: &Session, attr: &ast::Attribute) -> Option<&'static str> {
+    let ast::AttrKind::Normal(ref normal) = attr.kind else {
+        return None;
+    };
+    let attr_segments = &normal.item.path.segments;
+    if attr_segments.len() != 2 || attr_segments[0].ident.name != sym::clippy {
+        return None;
+    }
+    let name_span = attr_segments[1].ident.span;
+    let Some((builtin_name, deprecation_status)) = BUILTIN_ATTRIBUTES
+        .iter()
+        .find(|(builtin_name, _)| attr_segments[1].ident.name.as_str() == *builtin_name)
+    else {
+        sess.span_err(name_span, "usage of unknown attribute");
+        return None;
+    };
+    match *deprecation_status {
+        DeprecationStatus::Deprecated => {
+            sess.span_err(name_span, "usage of deprecated attribute");
+            None
+        },
+        DeprecationStatus::Replaced(new_name) => {
+            sess.struct_span_err(name_span, "usage of deprecated attribute")
+                .span_suggestion(name_span, "consider using", new_name, Applicability::MachineApplicable)
+                .emit();
+            None
+        },
+        DeprecationStatus::None => Some(builtin_name),
+    }
 }
 
-fn parse_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'static str, mut f: F) {
-    for attr in get_attr(sess, attrs, name) {
-        if let Some(ref value) = attr.value_str() {
-            if let Ok(value) = FromStr::from_str(value.as_str()) {
-                f(value);
-            } else {
-                sess.span_err(attr.span, "not a number");
+/// A `#[clippy::...]` attribute with a value, e.g. `#[clippy::msrv = "1.60"]`.
+#[derive(Clone, Copy, Debug)]
+pub struct ClippyAttr<T> {
+    pub value: T,
+    /// The span of the whole attribute.
+    pub span: Span,
+}
+
+/// The `#[clippy::...]` attributes of a node, see [`ClippyAttrs::parse`]. Attributes without a
+/// value are represented by the span of the attribute.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ClippyAttrs {
+    pub author: Option<Span>,
+    pub cognitive_complexity: Option<ClippyAttr<u64>>,
+    pub dump: Option<Span>,
+    pub has_significant_drop: Option<Span>,
+    pub msrv: Option<ClippyAttr<RustcVersion>>,
+    pub version: Option<ClippyAttr<Symbol>>,
+}
+
+impl ClippyAttrs {
+    /// Parses the `#[clippy::...]` attributes. Emits an error for unknown, deprecated and
+    /// duplicate attributes, and for attributes with an invalid value.
+    pub fn parse(sess: &Session, attrs: &[ast::Attribute]) -> Self {
+        let mut res = Self::default();
+        let mut seen: Vec<(&'static str, Span)> = Vec::new();
+        for attr in attrs {
+            let Some(name) = builtin_attr_name(sess, attr) else {
+                continue;
+            };
+            if let Some(&(_, first)) = seen.iter().find(|&&(seen_name, _)| seen_name == name) {
+                sess.struct_span_err(attr.span, format!("`{name}` is defined multiple times"))
+                    .span_note(first, "first definition found here")
+                    .emit();
+                continue;
+            }
+            seen.push((name, attr.span));
+
+            let span = attr.span;
+            match name {
+                "author" => res.author = Some(span),
+                "dump" => res.dump = Some(span),
+                "has_significant_drop" => res.has_significant_drop = Some(span),
+                "cognitive_complexity" => {
+                    res.cognitive_complexity = attr_value(sess, attr).and_then(|value| {
+                        if let Ok(value) = value.as_str().parse() {
+                            Some(ClippyAttr { value, span })
+                        } else {
+                            sess.span_err(span, "not a number");
+                            None
+                        }
+                    });
+                },
+                "msrv" => {
+                    res.msrv = attr_value(sess, attr)
+                        .and_then(|value| parse_msrv(value.as_str(), Some(sess), Some(span)))
+                        .map(|value| ClippyAttr { value, span });
+                },
+                "version" => res.version = attr_value(sess, attr).map(|value| ClippyAttr { value, span }),
+                _ => unreachable!("unhandled clippy attribute `{name}`"),
             }
-        } else {
-            sess.span_err(attr.span, "bad clippy attribute");
         }
+        res
     }
 }
 
-pub fn get_unique_attr<'a>(
-    sess: &'a Session,
-    attrs: &'a [ast::Attribute],
-    name: &'static str,
-) -> Option<&'a ast::Attribute> {
-    let mut unique_attr: Option<&ast::Attribute> = None;
-    for attr in get_attr(sess, attrs, name) {
-        if let Some(duplicate) = unique_attr {
-            sess.struct_span_err(attr.span, format!("`{name}` is defined multiple times"))
-                .span_note(duplicate.span, "first definition found here")
-                .emit();
-        } else {
-            unique_attr = Some(attr);
-        }
+fn attr_value(sess: &Session, attr: &ast::Attribute) -> Option<Symbol> {
+    let value = attr.value_str();
+    if value.is_none() {
+        sess.span_err(attr.span, "bad clippy attribute");
     }
-    unique_attr
+    value
 }
 
 /// Return true if the attributes contain any of `proc_macro`,
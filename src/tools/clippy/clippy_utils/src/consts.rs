@@ -1,11 +1,11 @@
 #![allow(clippy::float_cmp)]
 
 use crate::source::{get_source_text, walk_span_to_context};
-use crate::{clip, is_direct_expn_of, sext, unsext};
-use if_chain::if_chain;
+use crate::{clip, int_bits, is_diag_trait_item, is_direct_expn_of, sext, unsext};
 use rustc_ast::ast::{self, LitFloatType, LitKind};
 use rustc_data_structures::sync::Lrc;
 use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
 use rustc_hir::{BinOp, BinOpKind, Block, ConstBlock, Expr, ExprKind, HirId, Item, ItemKind, Node, QPath, UnOp};
 use rustc_lexer::tokenize;
 use rustc_lint::LateContext;
@@ -14,8 +14,8 @@
 use rustc_middle::ty::{self, EarlyBinder, FloatTy, ScalarInt, Ty, TyCtxt};
 use rustc_middle::ty::{List, SubstsRef};
 use rustc_middle::{bug, span_bug};
-use rustc_span::symbol::{Ident, Symbol};
-use rustc_span::SyntaxContext;
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, SyntaxContext};
 use std::cmp::Ordering::{self, Equal};
 use std::hash::{Hash, Hasher};
 use std::iter;
@@ -276,6 +276,17 @@ pub fn constant_simple<'tcx>(
     constant_with_source(lcx, typeck_results, e).and_then(|(c, s)| s.is_local().then_some(c))
 }
 
+/// Attempts to evaluate a path resolving to a constant item, e.g. the path of a path pattern.
+pub fn constant_path<'tcx>(
+    lcx: &LateContext<'tcx>,
+    typeck_results: &ty::TypeckResults<'tcx>,
+    qpath: &QPath<'_>,
+    id: HirId,
+    ty: Ty<'tcx>,
+) -> Option<Constant<'tcx>> {
+    ConstEvalLateContext::new(lcx, typeck_results).fetch_path(qpath, id, ty)
+}
+
 pub fn constant_full_int<'tcx>(
     lcx: &LateContext<'tcx>,
     typeck_results: &ty::TypeckResults<'tcx>,
@@ -372,29 +383,17 @@ pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant<'tcx>> {
             ExprKind::If(cond, then, ref otherwise) => self.ifthenelse(cond, then, *otherwise),
             ExprKind::Binary(op, left, right) => self.binop(op, left, right),
             ExprKind::Call(callee, args) => {
-                // We only handle a few const functions for now.
-                if_chain! {
-                    if args.is_empty();
-                    if let ExprKind::Path(qpath) = &callee.kind;
-                    let res = self.typeck_results.qpath_res(qpath, callee.hir_id);
-                    if let Some(def_id) = res.opt_def_id();
-                    let def_path = self.lcx.get_def_path(def_id);
-                    let def_path: Vec<&str> = def_path.iter().take(4).map(Symbol::as_str).collect();
-                    if let ["core", "num", int_impl, "max_value"] = *def_path;
-                    then {
-                        let value = match int_impl {
-                            "<impl i8>" => i8::MAX as u128,
-                            "<impl i16>" => i16::MAX as u128,
-                            "<impl i32>" => i32::MAX as u128,
-                            "<impl i64>" => i64::MAX as u128,
-                            "<impl i128>" => i128::MAX as u128,
-                            _ => return None,
-                        };
-                        Some(Constant::Int(value))
-                    } else {
-                        None
-                    }
-                }
+                let ExprKind::Path(qpath) = &callee.kind else {
+                    return None;
+                };
+                let def_id = self.typeck_results.qpath_res(qpath, callee.hir_id).opt_def_id()?;
+                let args: Vec<_> = args.iter().collect();
+                self.call(def_id, &args, self.typeck_results.expr_ty(e))
+            },
+            ExprKind::MethodCall(_, recv, args, _) => {
+                let def_id = self.typeck_results.type_dependent_def_id(e.hir_id)?;
+                let args: Vec<_> = iter::once(recv).chain(args).collect();
+                self.call(def_id, &args, self.typeck_results.expr_ty(e))
             },
             ExprKind::Index(arr, index) => self.index(arr, index),
             ExprKind::AddrOf(_, _, inner) => self.expr(inner).map(|r| Constant::Ref(Box::new(r))),
@@ -449,6 +448,50 @@ fn constant_negate(&self, o: &Constant<'tcx>, ty: Ty<'_>) -> Option<Constant<'tc
         }
     }
 
+    /// Evaluates a `From` conversion between integers, or a call to `max_value` or `min_value` of
+    /// an integer type. The compiler can't evaluate calls outside of a constant's body, so other
+    /// calls, even to a `const fn`, aren't evaluated.
+    fn call(&mut self, def_id: DefId, args: &[&Expr<'_>], ty: Ty<'tcx>) -> Option<Constant<'tcx>> {
+        let tcx = self.lcx.tcx;
+        if is_diag_trait_item(self.lcx, def_id, sym::From) {
+            let [arg] = args else { return None };
+            let value = match (self.expr(arg)?, *self.typeck_results.expr_ty(arg).kind()) {
+                (Constant::Bool(b), _) => i128::from(b),
+                (Constant::Char(c), _) => i128::from(u32::from(c)),
+                (Constant::Int(value), ty::Int(ity)) => sext(tcx, value, ity),
+                (Constant::Int(value), ty::Uint(_)) => i128::try_from(value).ok()?,
+                _ => return None,
+            };
+            return match *ty.kind() {
+                ty::Int(ity) => Some(Constant::Int(unsext(tcx, value, ity))),
+                ty::Uint(_) => u128::try_from(value).ok().map(Constant::Int),
+                _ => None,
+            };
+        }
+
+        if !args.is_empty() || tcx.crate_name(def_id.krate) != sym::core {
+            return None;
+        }
+        let is_max = match tcx.item_name(def_id).as_str() {
+            "max_value" => true,
+            "min_value" => false,
+            _ => return None,
+        };
+        match *tcx.type_of(tcx.impl_of_method(def_id)?).subst_identity().kind() {
+            ty::Int(ity) => {
+                let bits = int_bits(tcx, ity);
+                let value = if is_max {
+                    i128::MAX >> (128 - bits)
+                } else {
+                    i128::MIN >> (128 - bits)
+                };
+                Some(Constant::Int(unsext(tcx, value, ity)))
+            },
+            ty::Uint(uty) => Some(Constant::Int(if is_max { clip(tcx, u128::MAX, uty) } else { 0 })),
+            _ => None,
+        }
+    }
+
     /// Create `Some(Vec![..])` of all constants, unless there is any
     /// non-constant part.
     fn multi(&mut self, vec: &[Expr<'_>]) -> Option<Vec<Constant<'tcx>>> {
@@ -663,6 +706,7 @@ pub fn miri_to_const<'tcx>(lcx: &LateContext<'tcx>, result: mir::ConstantKind<'t
         mir::ConstantKind::Val(ConstValue::Scalar(Scalar::Int(int)), _) => match result.ty().kind() {
             ty::Adt(adt_def, _) if adt_def.is_struct() => Some(Constant::Adt(result)),
             ty::Bool => Some(Constant::Bool(int == ScalarInt::TRUE)),
+            ty::Char => int.try_into().ok().map(Constant::Char),
             ty::Uint(_) | ty::Int(_) => Some(Constant::Int(int.assert_bits(int.size()))),
             ty::Float(FloatTy::F32) => Some(Constant::F32(f32::from_bits(
                 int.try_into().expect("invalid f32 bit representation"),
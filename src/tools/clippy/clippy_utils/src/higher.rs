@@ -8,7 +8,7 @@
 use if_chain::if_chain;
 use rustc_ast::ast;
 use rustc_hir as hir;
-use rustc_hir::{Arm, Block, Expr, ExprKind, HirId, LoopSource, MatchSource, Node, Pat, QPath};
+use rustc_hir::{Arm, Block, Expr, ExprKind, HirId, LoopSource, MatchSource, Node, Pat, QPath, Stmt, StmtKind};
 use rustc_lint::LateContext;
 use rustc_span::{sym, symbol, Span};
 
@@ -197,6 +197,40 @@ pub const fn hir(expr: &Expr<'hir>) -> Option<Self> {
     }
 }
 
+/// A `let ... else` statement
+pub struct LetElse<'hir> {
+    /// `let` pattern
+    pub let_pat: &'hir Pat<'hir>,
+    /// `let` type annotation
+    pub ty: Option<&'hir hir::Ty<'hir>>,
+    /// `let` initializer
+    pub let_expr: &'hir Expr<'hir>,
+    /// `else` block
+    pub let_else: &'hir Block<'hir>,
+}
+
+impl<'hir> LetElse<'hir> {
+    /// Parses a `let ... else` statement
+    pub fn hir(stmt: &Stmt<'hir>) -> Option<Self> {
+        if let StmtKind::Local(hir::Local {
+            pat: let_pat,
+            ty,
+            init: Some(let_expr),
+            els: Some(let_else),
+            ..
+        }) = stmt.kind
+        {
+            return Some(Self {
+                let_pat,
+                ty,
+                let_expr,
+                let_else,
+            });
+        }
+        None
+    }
+}
+
 /// Represent a range akin to `ast::ExprKind::Range`.
 #[derive(Debug, Copy, Clone)]
 pub struct Range<'a> {
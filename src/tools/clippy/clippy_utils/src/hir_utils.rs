@@ -7,9 +7,9 @@
 use rustc_hir::def::Res;
 use rustc_hir::HirIdMap;
 use rustc_hir::{
-    ArrayLen, BinOpKind, BindingAnnotation, Block, BodyId, Closure, Expr, ExprField, ExprKind, FnRetTy, GenericArg,
-    GenericArgs, Guard, HirId, InlineAsmOperand, Let, Lifetime, LifetimeName, Pat, PatField, PatKind, Path,
-    PathSegment, PrimTy, QPath, Stmt, StmtKind, Ty, TyKind, TypeBinding,
+    ArrayLen, BinOpKind, BindingAnnotation, Block, BodyId, BorrowKind, Closure, Expr, ExprField, ExprKind, FnRetTy,
+    GenericArg, GenericArgs, Guard, HirId, InlineAsmOperand, Let, Lifetime, LifetimeName, Mutability, Pat, PatField,
+    PatKind, Path, PathSegment, PrimTy, QPath, Stmt, StmtKind, Ty, TyKind, TypeBinding, UnOp,
 };
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::LateContext;
@@ -32,6 +32,7 @@ pub struct SpanlessEq<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     maybe_typeck_results: Option<(&'tcx TypeckResults<'tcx>, &'tcx TypeckResults<'tcx>)>,
     allow_side_effects: bool,
+    modulo_adjustments: bool,
     expr_fallback: Option<Box<SpanlessEqCallback<'a>>>,
 }
 
@@ -41,6 +42,7 @@ pub fn new(cx: &'a LateContext<'tcx>) -> Self {
             cx,
             maybe_typeck_results: cx.maybe_typeck_results().map(|x| (x, x)),
             allow_side_effects: true,
+            modulo_adjustments: false,
             expr_fallback: None,
         }
     }
@@ -54,6 +56,17 @@ pub fn deny_side_effects(self) -> Self {
         }
     }
 
+    /// Consider expressions equal if they only differ by explicit borrows and dereferences which
+    /// end up with the same value once the implicit adjustments are applied, e.g. `(*s).len()` and
+    /// `s.len()`, or `f(&s)` and `f(s)` when `s: &String` is coerced to `&str`.
+    #[must_use]
+    pub fn modulo_adjustments(self, modulo_adjustments: bool) -> Self {
+        Self {
+            modulo_adjustments,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn expr_fallback(self, expr_fallback: impl FnMut(&Expr<'_>, &Expr<'_>) -> bool + 'a) -> Self {
         Self {
@@ -217,7 +230,7 @@ fn eq_block(&mut self, left: &Block<'_>, right: &Block<'_>) -> bool {
     }
 
     fn should_ignore(&mut self, expr: &Expr<'_>) -> bool {
-        macro_backtrace(expr.span).last().map_or(false, |macro_call| {
+        macro_backtrace(expr.span).last().is_some_and(|macro_call| {
             matches!(
                 &self.inner.cx.tcx.get_diagnostic_name(macro_call.def_id),
                 Some(sym::todo_macro | sym::unimplemented_macro)
@@ -281,11 +294,13 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
                 self.inner.allow_side_effects && lo.node == ro.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
             },
             (&ExprKind::Block(l, _), &ExprKind::Block(r, _)) => self.eq_block(l, r),
+            (&ExprKind::Closure(l), &ExprKind::Closure(r)) => self.eq_closure(l, r),
             (&ExprKind::Binary(l_op, ll, lr), &ExprKind::Binary(r_op, rl, rr)) => {
                 l_op.node == r_op.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
-                    || swap_binop(l_op.node, ll, lr).map_or(false, |(l_op, ll, lr)| {
-                        l_op == r_op.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
-                    })
+                    || self.is_builtin_op(left, right)
+                        && swap_binop(l_op.node, ll, lr).is_some_and(|(l_op, ll, lr)| {
+                            l_op == r_op.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
+                        })
             },
             (&ExprKind::Break(li, ref le), &ExprKind::Break(ri, ref re)) => {
                 both(&li.label, &ri.label, |l, r| l.ident.name == r.ident.name)
@@ -349,7 +364,43 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
             _ => false,
         };
         (is_eq && (!self.should_ignore(left) || !self.should_ignore(right)))
-            || self.inner.expr_fallback.as_mut().map_or(false, |f| f(left, right))
+            || self.inner.modulo_adjustments && self.eq_expr_modulo_adjustments(left, right)
+            || self.inner.expr_fallback.as_mut().is_some_and(|f| f(left, right))
+    }
+
+    /// Checks whether two expressions are the same once their explicit borrows and dereferences are
+    /// removed, as long as both still have the same type after their adjustments.
+    fn eq_expr_modulo_adjustments(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+        let Some((typeck_lhs, typeck_rhs)) = self.inner.maybe_typeck_results else {
+            return false;
+        };
+        let l_inner = peel_ref_ops(typeck_lhs, left);
+        let r_inner = peel_ref_ops(typeck_rhs, right);
+        // At least one side has to be peeled, otherwise this was already compared.
+        (l_inner.hir_id != left.hir_id || r_inner.hir_id != right.hir_id)
+            && typeck_lhs
+                .expr_ty_adjusted_opt(left)
+                .is_some_and(|l_ty| typeck_rhs.expr_ty_adjusted_opt(right) == Some(l_ty))
+            && self.eq_expr(l_inner, r_inner)
+    }
+
+    /// Checks whether two closures are the same. The parameters of the left closure are mapped to
+    /// the ones of the right closure, so `|a| a + 1` and `|b| b + 1` are considered equal.
+    fn eq_closure(&mut self, left: &Closure<'_>, right: &Closure<'_>) -> bool {
+        let hir = self.inner.cx.tcx.hir();
+        left.capture_clause == right.capture_clause
+            && left.constness == right.constness
+            && left.movability == right.movability
+            && over(left.fn_decl.inputs, right.fn_decl.inputs, |l, r| self.eq_ty(l, r))
+            && match (left.fn_decl.output, right.fn_decl.output) {
+                (FnRetTy::DefaultReturn(_), FnRetTy::DefaultReturn(_)) => true,
+                (FnRetTy::Return(l), FnRetTy::Return(r)) => self.eq_ty(l, r),
+                _ => false,
+            }
+            && over(hir.body(left.body).params, hir.body(right.body).params, |l, r| {
+                self.eq_pat(l.pat, r.pat)
+            })
+            && self.eq_body(left.body, right.body)
     }
 
     fn eq_exprs(&mut self, left: &[Expr<'_>], right: &[Expr<'_>]) -> bool {
@@ -360,7 +411,7 @@ fn eq_expr_field(&mut self, left: &ExprField<'_>, right: &ExprField<'_>) -> bool
         left.ident.name == right.ident.name && self.eq_expr(left.expr, right.expr)
     }
 
-    fn eq_guard(&mut self, left: &Guard<'_>, right: &Guard<'_>) -> bool {
+    pub fn eq_guard(&mut self, left: &Guard<'_>, right: &Guard<'_>) -> bool {
         match (left, right) {
             (Guard::If(l), Guard::If(r)) => self.eq_expr(l, r),
             (Guard::IfLet(l), Guard::IfLet(r)) => {
@@ -483,6 +534,16 @@ fn eq_type_binding(&mut self, left: &TypeBinding<'_>, right: &TypeBinding<'_>) -
         left.ident.name == right.ident.name && self.eq_ty(left.ty(), right.ty())
     }
 
+    /// Checks whether both binary operations are built-in operations, rather than calls to an
+    /// overloaded operator which may have side effects or not be commutative.
+    fn is_builtin_op(&self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+        self.inner
+            .maybe_typeck_results
+            .map_or(true, |(typeck_lhs, typeck_rhs)| {
+                !typeck_lhs.is_method_call(left) && !typeck_rhs.is_method_call(right)
+            })
+    }
+
     fn check_ctxt(&mut self, left: SyntaxContext, right: SyntaxContext) -> bool {
         if self.left_ctxt == left && self.right_ctxt == right {
             return true;
@@ -523,6 +584,19 @@ fn check_ctxt(&mut self, left: SyntaxContext, right: SyntaxContext) -> bool {
     }
 }
 
+/// Removes the explicit shared borrows and builtin dereferences around an expression.
+fn peel_ref_ops<'a>(typeck: &TypeckResults<'_>, mut e: &'a Expr<'a>) -> &'a Expr<'a> {
+    loop {
+        match e.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) => e = inner,
+            ExprKind::Unary(UnOp::Deref, inner) if typeck.expr_ty_opt(inner).is_some_and(|ty| ty.is_ref()) => {
+                e = inner;
+            },
+            _ => return e,
+        }
+    }
+}
+
 /// Some simple reductions like `{ return }` => `return`
 fn reduce_exprkind<'hir>(cx: &LateContext<'_>, kind: &'hir ExprKind<'hir>) -> &'hir ExprKind<'hir> {
     if let ExprKind::Block(block, _) = kind {
@@ -596,7 +670,7 @@ fn swap_binop<'a>(
 /// `eq_fn`.
 pub fn both<X>(l: &Option<X>, r: &Option<X>, mut eq_fn: impl FnMut(&X, &X) -> bool) -> bool {
     l.as_ref()
-        .map_or_else(|| r.is_none(), |x| r.as_ref().map_or(false, |y| eq_fn(x, y)))
+        .map_or_else(|| r.is_none(), |x| r.as_ref().is_some_and(|y| eq_fn(x, y)))
 }
 
 /// Checks if two slices are equal as per `eq_fn`.
@@ -643,6 +717,17 @@ pub fn finish(self) -> u64 {
         self.s.finish()
     }
 
+    /// Hashes an expression on its own, rather than as part of the current hash.
+    fn hash_sub_expr(&self, e: &Expr<'_>) -> u64 {
+        let mut hasher = Self {
+            cx: self.cx,
+            maybe_typeck_results: self.maybe_typeck_results,
+            s: FxHasher::default(),
+        };
+        hasher.hash_expr(e);
+        hasher.finish()
+    }
+
     pub fn hash_block(&mut self, b: &Block<'_>) {
         for s in b.stmts {
             self.hash_stmt(s);
@@ -657,6 +742,12 @@ pub fn hash_block(&mut self, b: &Block<'_>) {
 
     #[expect(clippy::too_many_lines)]
     pub fn hash_expr(&mut self, e: &Expr<'_>) {
+        // Explicit borrows and dereferences are skipped, as `SpanlessEq` can consider expressions
+        // equal modulo adjustments.
+        let e = self
+            .maybe_typeck_results
+            .map_or(e, |typeck_results| peel_ref_ops(typeck_results, e));
+
         let simple_const = self
             .maybe_typeck_results
             .and_then(|typeck_results| constant_simple(self.cx, typeck_results, e));
@@ -668,9 +759,11 @@ pub fn hash_expr(&mut self, e: &Expr<'_>) {
             return;
         }
 
-        std::mem::discriminant(&e.kind).hash(&mut self.s);
+        // Apply the same reductions as `SpanlessEq`, so expressions it considers equal hash the same.
+        let kind = reduce_exprkind(self.cx, &e.kind);
+        std::mem::discriminant(kind).hash(&mut self.s);
 
-        match e.kind {
+        match *kind {
             ExprKind::AddrOf(kind, m, e) => {
                 std::mem::discriminant(&kind).hash(&mut self.s);
                 m.hash(&mut self.s);
@@ -694,9 +787,24 @@ pub fn hash_expr(&mut self, e: &Expr<'_>) {
                 self.hash_block(b);
             },
             ExprKind::Binary(op, l, r) => {
-                std::mem::discriminant(&op.node).hash(&mut self.s);
-                self.hash_expr(l);
-                self.hash_expr(r);
+                if swap_binop(op.node, l, r).is_some() {
+                    // Hash the operands of commutative operators independently of their order, so
+                    // the hash is consistent with `SpanlessEq`.
+                    let op = match op.node {
+                        BinOpKind::Gt => BinOpKind::Lt,
+                        BinOpKind::Ge => BinOpKind::Le,
+                        op => op,
+                    };
+                    std::mem::discriminant(&op).hash(&mut self.s);
+                    let l = self.hash_sub_expr(l);
+                    let r = self.hash_sub_expr(r);
+                    l.min(r).hash(&mut self.s);
+                    l.max(r).hash(&mut self.s);
+                } else {
+                    std::mem::discriminant(&op.node).hash(&mut self.s);
+                    self.hash_expr(l);
+                    self.hash_expr(r);
+                }
             },
             ExprKind::Break(i, ref j) => {
                 if let Some(i) = i.label {
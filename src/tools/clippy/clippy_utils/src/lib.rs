@@ -17,6 +17,7 @@
 
 // FIXME: switch to something more ergonomic here, once available.
 // (Currently there is no way to opt into sysroot crates without `extern crate`.)
+extern crate rustc_arena;
 extern crate rustc_ast;
 extern crate rustc_ast_pretty;
 extern crate rustc_attr;
@@ -55,9 +56,11 @@
 pub mod mir;
 pub mod msrvs;
 pub mod numeric_literal;
+pub mod pat_overlap;
 pub mod paths;
 pub mod ptr;
 pub mod qualify_min_const_fn;
+pub mod side_effects;
 pub mod source;
 pub mod str_utils;
 pub mod sugg;
@@ -79,23 +82,25 @@
 
 use if_chain::if_chain;
 use itertools::Itertools;
-use rustc_ast::ast::{self, LitKind, RangeLimits};
+use rustc_ast::ast::{self, InlineAsmOptions, LitKind, RangeLimits};
 use rustc_ast::Attribute;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::unhash::UnhashMap;
+use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::{CrateNum, DefId, LocalDefId, LOCAL_CRATE};
 use rustc_hir::hir_id::{HirIdMap, HirIdSet};
 use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
 use rustc_hir::LangItem::{OptionNone, ResultErr, ResultOk};
 use rustc_hir::{
-    self as hir, def, Arm, ArrayLen, BindingAnnotation, Block, BlockCheckMode, Body, Closure, Destination, Expr,
-    ExprKind, FnDecl, HirId, Impl, ImplItem, ImplItemKind, ImplItemRef, IsAsync, Item, ItemKind, LangItem, Local,
-    MatchSource, Mutability, Node, OwnerId, Param, Pat, PatKind, Path, PathSegment, PrimTy, QPath, Stmt, StmtKind,
-    TraitItem, TraitItemRef, TraitRef, TyKind, UnOp,
+    self as hir, def, Arm, ArrayLen, AsyncGeneratorKind, BindingAnnotation, Block, BlockCheckMode, Body, Closure,
+    ConstContext, Destination, Expr, ExprKind, FnDecl, GeneratorKind, HirId, Impl, ImplItem, ImplItemKind, ImplItemRef,
+    IsAsync, Item, ItemKind, LangItem, Local, MatchSource, Mutability, Node, OwnerId, Param, Pat, PatKind, Path,
+    PathSegment, PrimTy, QPath, Stmt, StmtKind, TraitItem, TraitItemRef, TraitRef, TyKind, UnOp,
 };
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, Level, Lint, LintContext};
+use rustc_middle::hir::map::associated_body;
 use rustc_middle::hir::place::PlaceBase;
 use rustc_middle::mir::ConstantKind;
 use rustc_middle::ty as rustc_ty;
@@ -105,20 +110,25 @@
     ArraySimplifiedType, BoolSimplifiedType, CharSimplifiedType, FloatSimplifiedType, IntSimplifiedType,
     PtrSimplifiedType, SliceSimplifiedType, StrSimplifiedType, UintSimplifiedType,
 };
+use rustc_middle::ty::subst::SubstsRef;
 use rustc_middle::ty::{
-    layout::IntegerExt, BorrowKind, ClosureKind, Ty, TyCtxt, TypeAndMut, TypeVisitableExt, UpvarCapture,
+    layout::IntegerExt, BorrowKind, ClosureKind, InstanceDef, Ty, TyCtxt, TypeAndMut, TypeVisitableExt, UpvarCapture,
 };
 use rustc_middle::ty::{FloatTy, IntTy, UintTy};
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::SourceMap;
 use rustc_span::sym;
 use rustc_span::symbol::{kw, Ident, Symbol};
-use rustc_span::Span;
+use rustc_span::{FileName, Span};
 use rustc_target::abi::Integer;
 
 use crate::consts::{constant, miri_to_const, Constant};
 use crate::higher::Range;
-use crate::ty::{can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type, ty_is_fn_once_param};
+use crate::sugg::Sugg;
+use crate::ty::{
+    can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type, is_type_diagnostic_item,
+    ty_is_fn_once_param,
+};
 use crate::visitors::for_each_expr;
 
 use rustc_middle::hir::nested_filter;
@@ -200,7 +210,35 @@ pub fn find_binding_init<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Option<
 /// }
 /// ```
 pub fn in_constant(cx: &LateContext<'_>, id: HirId) -> bool {
-    cx.tcx.hir().is_inside_const_context(id)
+    in_constant_context(cx, id)
+}
+
+/// Returns `true` if the given `HirId` is inside a constant context. See `const_context`.
+pub fn in_constant_context(cx: &LateContext<'_>, id: HirId) -> bool {
+    const_context(cx, id).is_some()
+}
+
+/// Gets the constant context the given `HirId` is in, if any. This covers the bodies of `const`
+/// and `static` items and of `const fn`s, as well as enum discriminants, array lengths, const
+/// generic arguments and inline `const` blocks. Closures start a new, non-constant context.
+///
+/// Unlike `Map::is_inside_const_context`, this doesn't panic for nodes outside of any body, and
+/// doesn't consider the signature of a `const fn` to be part of its body.
+pub fn const_context(cx: &LateContext<'_>, id: HirId) -> Option<ConstContext> {
+    let hir = cx.tcx.hir();
+    let mut child = id;
+    for (parent, node) in hir.parent_iter(id) {
+        if let Some((def_id, body_id)) = associated_body(node) {
+            let body = hir.body(body_id);
+            return if child == body.value.hir_id || body.params.iter().any(|param| param.hir_id == child) {
+                hir.body_const_context(def_id)
+            } else {
+                None
+            };
+        }
+        child = parent;
+    }
+    None
 }
 
 /// Checks if a `Res` refers to a constructor of a `LangItem`
@@ -299,7 +337,7 @@ pub fn is_ty_alias(qpath: &QPath<'_>) -> bool {
 pub fn match_trait_method(cx: &LateContext<'_>, expr: &Expr<'_>, path: &[&str]) -> bool {
     let def_id = cx.typeck_results().type_dependent_def_id(expr.hir_id).unwrap();
     let trt_id = cx.tcx.trait_of_item(def_id);
-    trt_id.map_or(false, |trt_id| match_def_path(cx, trt_id, path))
+    trt_id.is_some_and(|trt_id| match_def_path(cx, trt_id, path))
 }
 
 /// Checks if a method is defined in an impl of a diagnostic item
@@ -324,7 +362,78 @@ pub fn is_diag_trait_item(cx: &LateContext<'_>, def_id: DefId, diag_item: Symbol
 pub fn is_trait_method(cx: &LateContext<'_>, expr: &Expr<'_>, diag_item: Symbol) -> bool {
     cx.typeck_results()
         .type_dependent_def_id(expr.hir_id)
-        .map_or(false, |did| is_diag_trait_item(cx, did, diag_item))
+        .is_some_and(|did| is_diag_trait_item(cx, did, diag_item))
+}
+
+/// The method called by a method call expression, see [`resolve_method_call_target`].
+#[derive(Clone, Copy, Debug)]
+pub struct MethodCallTarget<'tcx> {
+    /// The called method. For trait methods this is the method of the implementation if it could
+    /// be resolved, otherwise the method of the trait.
+    pub def_id: DefId,
+    /// The generic arguments of `def_id`.
+    pub substs: SubstsRef<'tcx>,
+    /// The `Self` type of the called method, i.e. the type of the receiver after auto-deref.
+    pub self_ty: Ty<'tcx>,
+    /// Whether the implementation called depends on the caller's generic parameters or the dynamic
+    /// type of the receiver, e.g. for `T: Trait` or `dyn Trait` receivers.
+    pub is_overridable: bool,
+}
+
+/// Resolves the method called by the given method call expression through auto-deref and trait
+/// dispatch. Returns `None` if the expression isn't a method call.
+///
+/// Prefer this over checking the method name and the type of the receiver, which breaks for calls
+/// on references, e.g. `(&&rc).clone()` clones the reference and not the `Rc`.
+pub fn resolve_method_call_target<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<MethodCallTarget<'tcx>> {
+    if !matches!(expr.kind, ExprKind::MethodCall(..)) {
+        return None;
+    }
+    let typeck = cx.typeck_results();
+    let def_id = typeck.type_dependent_def_id(expr.hir_id)?;
+    let substs = typeck.node_substs(expr.hir_id);
+
+    if cx.tcx.trait_of_item(def_id).is_none() {
+        let impl_id = cx.tcx.impl_of_method(def_id)?;
+        return Some(MethodCallTarget {
+            def_id,
+            substs,
+            self_ty: cx.tcx.type_of(impl_id).subst(cx.tcx, substs),
+            is_overridable: false,
+        });
+    }
+
+    let trait_target = MethodCallTarget {
+        def_id,
+        substs,
+        self_ty: substs.type_at(0),
+        is_overridable: true,
+    };
+    let Ok(normalized_substs) = cx.tcx.try_normalize_erasing_regions(cx.param_env, substs) else {
+        return Some(trait_target);
+    };
+    match rustc_ty::Instance::resolve(cx.tcx, cx.param_env, def_id, normalized_substs) {
+        Ok(Some(instance)) => match instance.def {
+            InstanceDef::Item(resolved_id) => Some(MethodCallTarget {
+                def_id: resolved_id,
+                substs: instance.substs,
+                self_ty: cx
+                    .tcx
+                    .impl_of_method(resolved_id)
+                    .map_or(trait_target.self_ty, |impl_id| {
+                        cx.tcx.type_of(impl_id).subst(cx.tcx, instance.substs)
+                    }),
+                is_overridable: false,
+            }),
+            InstanceDef::Virtual(..) => Some(trait_target),
+            // Compiler generated implementations, e.g. `Clone` for tuples.
+            _ => Some(MethodCallTarget {
+                is_overridable: false,
+                ..trait_target
+            }),
+        },
+        _ => Some(trait_target),
+    }
 }
 
 /// Checks if the `def_id` belongs to a function that is part of a trait impl.
@@ -352,7 +461,7 @@ pub fn is_trait_item(cx: &LateContext<'_>, expr: &Expr<'_>, diag_item: Symbol) -
     if let hir::ExprKind::Path(ref qpath) = expr.kind {
         cx.qpath_res(qpath, expr.hir_id)
             .opt_def_id()
-            .map_or(false, |def_id| is_diag_trait_item(cx, def_id, diag_item))
+            .is_some_and(|def_id| is_diag_trait_item(cx, def_id, diag_item))
     } else {
         false
     }
@@ -412,13 +521,13 @@ pub fn match_qpath(path: &QPath<'_>, segments: &[&str]) -> bool {
 ///
 /// Please use `is_path_diagnostic_item` if the target is a diagnostic item.
 pub fn is_expr_path_def_path(cx: &LateContext<'_>, expr: &Expr<'_>, segments: &[&str]) -> bool {
-    path_def_id(cx, expr).map_or(false, |id| match_def_path(cx, id, segments))
+    path_def_id(cx, expr).is_some_and(|id| match_def_path(cx, id, segments))
 }
 
 /// If `maybe_path` is a path node which resolves to an item, resolves it to a `DefId` and checks if
 /// it matches the given lang item.
 pub fn is_path_lang_item<'tcx>(cx: &LateContext<'_>, maybe_path: &impl MaybePath<'tcx>, lang_item: LangItem) -> bool {
-    path_def_id(cx, maybe_path).map_or(false, |id| cx.tcx.lang_items().get(lang_item) == Some(id))
+    path_def_id(cx, maybe_path).is_some_and(|id| cx.tcx.lang_items().get(lang_item) == Some(id))
 }
 
 /// If `maybe_path` is a path node which resolves to an item, resolves it to a `DefId` and checks if
@@ -428,7 +537,7 @@ pub fn is_path_diagnostic_item<'tcx>(
     maybe_path: &impl MaybePath<'tcx>,
     diag_item: Symbol,
 ) -> bool {
-    path_def_id(cx, maybe_path).map_or(false, |id| cx.tcx.is_diagnostic_item(diag_item, id))
+    path_def_id(cx, maybe_path).is_some_and(|id| cx.tcx.is_diagnostic_item(diag_item, id))
 }
 
 /// THIS METHOD IS DEPRECATED and will eventually be removed since it does not match against the
@@ -1234,7 +1343,7 @@ pub fn method_chain_args<'a>(expr: &'a Expr<'_>, methods: &[&str]) -> Option<Vec
 pub fn is_entrypoint_fn(cx: &LateContext<'_>, def_id: DefId) -> bool {
     cx.tcx
         .entry_fn(())
-        .map_or(false, |(entry_fn_def_id, _)| def_id == entry_fn_def_id)
+        .is_some_and(|(entry_fn_def_id, _)| def_id == entry_fn_def_id)
 }
 
 /// Returns `true` if the expression is in the program's `#[panic_handler]`.
@@ -1299,6 +1408,88 @@ pub fn contains_return(expr: &hir::Expr<'_>) -> bool {
     .is_some()
 }
 
+/// Checks if evaluating the expression never completes normally. This is the case when every path
+/// through it returns, breaks or continues out of it, panics, calls a function returning `!`, or
+/// enters a loop which is never broken out of.
+///
+/// Note that a `break` or `continue` is treated as diverging even when it targets a loop inside the
+/// expression. Whether that loop completes is checked when the loop itself is reached.
+///
+/// This may give false negatives, but never false positives.
+pub fn expr_diverges(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let diverges = |e: &Expr<'_>| expr_diverges(cx, e);
+    match e.kind {
+        ExprKind::Ret(_) | ExprKind::Become(_) | ExprKind::Break(..) | ExprKind::Continue(_) => true,
+        ExprKind::Call(callee, args) => {
+            diverges(callee)
+                || args.iter().any(diverges)
+                || cx.typeck_results().expr_ty_opt(e).is_some_and(Ty::is_never)
+        },
+        ExprKind::MethodCall(_, recv, args, _) => {
+            diverges(recv) || args.iter().any(diverges) || cx.typeck_results().expr_ty_opt(e).is_some_and(Ty::is_never)
+        },
+        ExprKind::Array(es) | ExprKind::Tup(es) => es.iter().any(diverges),
+        ExprKind::Binary(op, lhs, rhs) => {
+            // The right-hand side of a lazy operator might not be evaluated.
+            diverges(lhs) || (!matches!(op.node, hir::BinOpKind::And | hir::BinOpKind::Or) && diverges(rhs))
+        },
+        ExprKind::Assign(lhs, rhs, _) | ExprKind::AssignOp(_, lhs, rhs) | ExprKind::Index(lhs, rhs) => {
+            diverges(lhs) || diverges(rhs)
+        },
+        ExprKind::Unary(_, e)
+        | ExprKind::Cast(e, _)
+        | ExprKind::Type(e, _)
+        | ExprKind::DropTemps(e)
+        | ExprKind::Field(e, _)
+        | ExprKind::AddrOf(_, _, e)
+        | ExprKind::Repeat(e, _)
+        | ExprKind::Yield(e, _) => diverges(e),
+        ExprKind::Let(l) => diverges(l.init),
+        ExprKind::Struct(_, fields, base) => fields.iter().any(|f| diverges(f.expr)) || base.is_some_and(diverges),
+        ExprKind::If(cond, then, els) => diverges(cond) || (diverges(then) && els.is_some_and(diverges)),
+        // This also handles `?`, which only diverges if its operand does, as one of its arms
+        // continues with the value.
+        ExprKind::Match(scrutinee, arms, _) => {
+            diverges(scrutinee)
+                || arms
+                    .iter()
+                    .all(|arm| arm.guard.as_ref().is_some_and(|guard| diverges(guard.body())) || diverges(arm.body))
+        },
+        ExprKind::Block(block, label) => {
+            block_diverges(cx, block) && (label.is_none() || !is_break_target(block, e.hir_id))
+        },
+        // A loop only completes by being broken out of. This also covers `while` and `for` loops, as
+        // they're desugared to a `loop` which is broken out of once the condition fails.
+        ExprKind::Loop(block, ..) => !is_break_target(block, e.hir_id),
+        ExprKind::InlineAsm(asm) => asm.options.contains(InlineAsmOptions::NORETURN),
+        ExprKind::ConstBlock(_)
+        | ExprKind::Lit(_)
+        | ExprKind::Path(_)
+        | ExprKind::Closure(_)
+        | ExprKind::OffsetOf(..)
+        | ExprKind::Err(_) => false,
+    }
+}
+
+fn block_diverges(cx: &LateContext<'_>, block: &Block<'_>) -> bool {
+    block.stmts.iter().any(|stmt| match stmt.kind {
+        // The `else` branch of a `let...else` always diverges, but it doesn't make the statement
+        // diverge.
+        StmtKind::Local(local) => local.init.is_some_and(|init| expr_diverges(cx, init)),
+        StmtKind::Expr(e) | StmtKind::Semi(e) => expr_diverges(cx, e),
+        StmtKind::Item(_) => false,
+    }) || block.expr.is_some_and(|e| expr_diverges(cx, e))
+}
+
+/// Checks if the block contains a `break` targeting the given loop or labeled block.
+fn is_break_target(block: &Block<'_>, target: HirId) -> bool {
+    for_each_expr(block, |e| match e.kind {
+        ExprKind::Break(dest, _) if dest.target_id == Ok(target) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
+
 /// Gets the parent node, if any.
 pub fn get_parent_node(tcx: TyCtxt<'_>, id: HirId) -> Option<Node<'_>> {
     tcx.hir().find_parent(id)
@@ -1398,6 +1589,103 @@ pub fn get_enclosing_loop_or_multi_call_closure<'tcx>(
     None
 }
 
+/// An expression which control flow expressions may or may not jump out of. See
+/// `enclosing_loop_or_closure_boundary`.
+#[derive(Clone, Copy, Debug)]
+pub enum ControlFlowBoundary<'tcx> {
+    /// A `loop`, `while` or `for` loop.
+    Loop(&'tcx Expr<'tcx>),
+    /// A closure, including `async` closures.
+    Closure(&'tcx Expr<'tcx>),
+    /// An `async` block, or the body of an `async fn`.
+    AsyncBlock(&'tcx Expr<'tcx>),
+    /// A `try` block.
+    TryBlock(&'tcx Expr<'tcx>),
+}
+
+impl<'tcx> ControlFlowBoundary<'tcx> {
+    /// Gets the loop, closure or block expression.
+    pub fn expr(self) -> &'tcx Expr<'tcx> {
+        match self {
+            Self::Loop(e) | Self::Closure(e) | Self::AsyncBlock(e) | Self::TryBlock(e) => e,
+        }
+    }
+
+    /// Checks whether an unlabeled `break` or `continue` inside the boundary jumps out of it. Note
+    /// that they can't be used directly inside a closure or an `async` block.
+    pub fn is_crossed_by_break(self) -> bool {
+        matches!(self, Self::TryBlock(_))
+    }
+
+    /// Checks whether a `return` inside the boundary jumps out of it.
+    pub fn is_crossed_by_return(self) -> bool {
+        matches!(self, Self::Loop(_) | Self::TryBlock(_))
+    }
+
+    /// Checks whether the `?` operator inside the boundary jumps out of it.
+    pub fn is_crossed_by_question_mark(self) -> bool {
+        matches!(self, Self::Loop(_))
+    }
+}
+
+/// Gets the innermost loop, closure, `async` block or `try` block enclosing the given node, if
+/// any. Items, constants and the bodies of functions which aren't `async` end the search.
+///
+/// To find what a `break`, `return` or `?` jumps out of, call this again on the boundary's
+/// expression as long as the boundary is crossed by it. e.g.
+///
+/// ```rust,ignore
+/// let mut id = expr.hir_id;
+/// while let Some(boundary) = enclosing_loop_or_closure_boundary(cx, id)
+///     && boundary.is_crossed_by_return()
+/// {
+///     id = boundary.expr().hir_id;
+/// }
+/// ```
+pub fn enclosing_loop_or_closure_boundary<'tcx>(
+    cx: &LateContext<'tcx>,
+    hir_id: HirId,
+) -> Option<ControlFlowBoundary<'tcx>> {
+    for (_, node) in cx.tcx.hir().parent_iter(hir_id) {
+        match node {
+            Node::Expr(e) => match e.kind {
+                ExprKind::Loop(..) => return Some(ControlFlowBoundary::Loop(e)),
+                ExprKind::Closure(&Closure { body, .. }) => {
+                    return Some(match cx.tcx.hir().body(body).generator_kind {
+                        Some(GeneratorKind::Async(AsyncGeneratorKind::Block | AsyncGeneratorKind::Fn)) => {
+                            ControlFlowBoundary::AsyncBlock(e)
+                        },
+                        _ => ControlFlowBoundary::Closure(e),
+                    });
+                },
+                ExprKind::Block(block, _) if is_try_block(cx, block) => {
+                    return Some(ControlFlowBoundary::TryBlock(e));
+                },
+                _ => (),
+            },
+            Node::Item(_)
+            | Node::ImplItem(_)
+            | Node::TraitItem(_)
+            | Node::ForeignItem(_)
+            | Node::AnonConst(_)
+            | Node::ConstBlock(_) => break,
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Checks whether the block is the desugaring of a `try` block.
+pub fn is_try_block(cx: &LateContext<'_>, block: &Block<'_>) -> bool {
+    if let Some(expr) = block.expr
+        && let ExprKind::Call(callee, _) = expr.kind
+    {
+        is_path_lang_item(cx, callee, LangItem::TryTraitFromOutput)
+    } else {
+        false
+    }
+}
+
 /// Gets the parent node if it's an impl block.
 pub fn get_parent_as_impl(tcx: TyCtxt<'_>, id: HirId) -> Option<&Impl<'_>> {
     match tcx.hir().parent_iter(id).next() {
@@ -1577,6 +1865,64 @@ pub fn is_integer_literal(expr: &Expr<'_>, value: u128) -> bool {
     false
 }
 
+/// Returns a suggestion for the number of items yielded by iterating over the given expression,
+/// e.g. `v.len()` for `v.iter().enumerate()` or `n` for `0..n`.
+///
+/// Returns `None` if the number isn't known before iterating, or if it can't be computed without
+/// evaluating an expression which might have side effects.
+pub fn iter_len_sugg<'a>(cx: &LateContext<'_>, expr: &Expr<'_>, app: &mut Applicability) -> Option<Sugg<'a>> {
+    let mut expr = peel_hir_expr_refs(expr).0;
+    // adapters which yield one item for each item of the underlying iterator
+    while let ExprKind::MethodCall(path, recv, args, _) = expr.kind
+        && match args {
+            [] => matches!(
+                path.ident.as_str(),
+                "iter" | "iter_mut" | "into_iter" | "cloned" | "copied" | "enumerate" | "rev"
+            ),
+            [_] => matches!(path.ident.as_str(), "map" | "inspect"),
+            _ => false,
+        }
+    {
+        expr = peel_hir_expr_refs(recv).0;
+    }
+
+    if let Some(Range {
+        start: Some(start),
+        end: Some(end),
+        limits: RangeLimits::HalfOpen,
+    }) = Range::hir(expr)
+    {
+        return (is_integer_literal(start, 0)
+            && cx.typeck_results().expr_ty(end).is_usize()
+            && matches!(end.kind, ExprKind::Lit(_) | ExprKind::Path(_)))
+        .then(|| Sugg::hir_with_applicability(cx, end, "..", app));
+    }
+
+    let mut base = expr;
+    while let ExprKind::Field(inner, _) = base.kind {
+        base = inner;
+    }
+    let ty = cx.typeck_results().expr_ty(expr).peel_refs();
+    if matches!(base.kind, ExprKind::Path(_))
+        && (matches!(ty.kind(), rustc_ty::Array(..) | rustc_ty::Slice(_))
+            || [
+                sym::Vec,
+                sym::VecDeque,
+                sym::BinaryHeap,
+                sym::HashMap,
+                sym::HashSet,
+                sym::BTreeMap,
+                sym::BTreeSet,
+            ]
+            .into_iter()
+            .any(|item| is_type_diagnostic_item(cx, ty, item)))
+    {
+        Some(Sugg::hir_with_applicability(cx, expr, "..", app).method_call("len", &[]))
+    } else {
+        None
+    }
+}
+
 /// Returns `true` if the given `Expr` has been coerced before.
 ///
 /// Examples of coercions can be found in the Nomicon at
@@ -1680,7 +2026,7 @@ fn are_refutable<'a, I: IntoIterator<Item = &'a Pat<'a>>>(cx: &LateContext<'_>,
 
     match pat.kind {
         PatKind::Wild => false,
-        PatKind::Binding(_, _, _, pat) => pat.map_or(false, |pat| is_refutable(cx, pat)),
+        PatKind::Binding(_, _, _, pat) => pat.is_some_and(|pat| is_refutable(cx, pat)),
         PatKind::Box(pat) | PatKind::Ref(pat, _) => is_refutable(cx, pat),
         PatKind::Lit(..) | PatKind::Range(..) => true,
         PatKind::Path(ref qpath) => is_enum_variant(cx, qpath, pat.hir_id),
@@ -1899,7 +2245,7 @@ pub fn match_function_call_with_def_id<'tcx>(
 /// Checks if the given `DefId` matches any of the paths. Returns the index of matching path, if
 /// any.
 ///
-/// Please use `tcx.get_diagnostic_name` if the targets are all diagnostic items.
+/// Please use [`match_any_diagnostic_items`] if the targets are all diagnostic items.
 pub fn match_any_def_paths(cx: &LateContext<'_>, did: DefId, paths: &[&[&str]]) -> Option<usize> {
     let search_path = cx.get_def_path(did);
     paths
@@ -1907,6 +2253,28 @@ pub fn match_any_def_paths(cx: &LateContext<'_>, did: DefId, paths: &[&[&str]])
         .position(|p| p.iter().map(|x| Symbol::intern(x)).eq(search_path.iter().copied()))
 }
 
+/// Checks if the given `DefId` is any of the diagnostic items. Returns the index of the matching
+/// item, if any.
+pub fn match_any_diagnostic_items(cx: &LateContext<'_>, did: DefId, diag_items: &[Symbol]) -> Option<usize> {
+    let name = cx.tcx.get_diagnostic_name(did)?;
+    diag_items.iter().position(|&item| item == name)
+}
+
+/// Looks up the given `DefId` in a table keyed by diagnostic items. This lets a lint declare the
+/// std items it handles, along with whatever it needs to know about each of them, in one place.
+///
+/// ```rust,ignore
+/// static BUFFERS: &[(Symbol, &str)] = &[(sym::String, "str"), (sym::PathBuf, "std::path::Path")];
+///
+/// if let Some(&deref_ty) = lookup_diagnostic_item(cx, did, BUFFERS) {
+///     ..
+/// }
+/// ```
+pub fn lookup_diagnostic_item<'a, T>(cx: &LateContext<'_>, did: DefId, table: &'a [(Symbol, T)]) -> Option<&'a T> {
+    let name = cx.tcx.get_diagnostic_name(did)?;
+    table.iter().find(|&&(item, _)| item == name).map(|(_, value)| value)
+}
+
 /// Checks if the given `DefId` matches the path.
 pub fn match_def_path(cx: &LateContext<'_>, did: DefId, syms: &[&str]) -> bool {
     // We should probably move to Symbols in Clippy as well rather than interning every time.
@@ -1919,7 +2287,7 @@ pub fn match_libc_symbol(cx: &LateContext<'_>, did: DefId, name: &str) -> bool {
     let path = cx.get_def_path(did);
     // libc is meant to be used as a flat list of names, but they're all actually defined in different
     // modules based on the target platform. Ignore everything but crate name and the item name.
-    path.first().map_or(false, |s| s.as_str() == "libc") && path.last().map_or(false, |s| s.as_str() == name)
+    path.first().is_some_and(|s| s.as_str() == "libc") && path.last().is_some_and(|s| s.as_str() == name)
 }
 
 /// Returns the list of condition expressions and the list of blocks in a
@@ -2002,7 +2370,7 @@ pub fn is_must_use_func_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
         _ => None,
     };
 
-    did.map_or(false, |did| cx.tcx.has_attr(did, sym::must_use))
+    did.is_some_and(|did| cx.tcx.has_attr(did, sym::must_use))
 }
 
 /// Checks if an expression represents the identity function
@@ -2049,7 +2417,7 @@ fn is_body_identity_function(cx: &LateContext<'_>, func: &Body<'_>) -> bool {
 
     match expr.kind {
         ExprKind::Closure(&Closure { body, .. }) => is_body_identity_function(cx, cx.tcx.hir().body(body)),
-        _ => path_def_id(cx, expr).map_or(false, |id| match_def_path(cx, id, &paths::CONVERT_IDENTITY)),
+        _ => path_def_id(cx, expr).is_some_and(|id| match_def_path(cx, id, &paths::CONVERT_IDENTITY)),
     }
 }
 
@@ -2241,6 +2609,9 @@ pub fn search_same<T, Hash, Eq>(exprs: &[T], hash: Hash, eq: Eq) -> Vec<(&T, &T)
     Hash: Fn(&T) -> u64,
     Eq: Fn(&T, &T) -> bool,
 {
+    #[cfg(debug_assertions)]
+    check_hash_consistency(exprs, &hash, &eq);
+
     match exprs {
         [a, b] if eq(a, b) => return vec![(a, b)],
         _ if exprs.len() <= 2 => return vec![],
@@ -2271,6 +2642,21 @@ pub fn search_same<T, Hash, Eq>(exprs: &[T], hash: Hash, eq: Eq) -> Vec<(&T, &T)
     match_expr_list
 }
 
+/// Checks that any two elements which are equal as per `eq` also have the same hash. Otherwise
+/// `search_same` would silently miss them, e.g. when `SpanlessEq` learns to compare a new kind of
+/// node without `SpanlessHash` being updated accordingly.
+#[cfg(debug_assertions)]
+fn check_hash_consistency<T>(exprs: &[T], hash: impl Fn(&T) -> u64, eq: impl Fn(&T, &T) -> bool) {
+    for (i, a) in exprs.iter().enumerate() {
+        for (j, b) in exprs.iter().enumerate().skip(i + 1) {
+            assert!(
+                !eq(a, b) || hash(a) == hash(b),
+                "`search_same`: elements {i} and {j} are equal, but their hashes differ"
+            );
+        }
+    }
+}
+
 /// Peels off all references on the pattern. Returns the underlying pattern and the number of
 /// references removed.
 pub fn peel_hir_pat_refs<'a>(pat: &'a Pat<'a>) -> (&'a Pat<'a>, usize) {
@@ -2450,6 +2836,25 @@ fn is_cfg_test(attr: &Attribute) -> bool {
         .any(is_cfg_test)
 }
 
+/// Checks if the given `HirId` is part of a doctest, as compiled by `rustdoc --test`.
+pub fn is_in_doctest(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
+    matches!(
+        tcx.sess
+            .source_map()
+            .span_to_filename(tcx.hir().span(id).source_callsite()),
+        FileName::DocTest(..)
+    )
+}
+
+/// Checks if the given `HirId` is in test code: a `#[test]` or `#[bench]` function, an item with
+/// `#[cfg(test)]` applied such as a test module, or a doctest. This is what lints with an
+/// `allow-*-in-tests` configuration option check.
+///
+/// Note: Add `//@compile-flags: --test` to UI tests with a `#[test]` function
+pub fn is_in_test_context(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
+    is_in_test_function(tcx, id) || is_in_cfg_test(tcx, id) || is_in_doctest(tcx, id)
+}
+
 /// Checks whether item either has `test` attribute applied, or
 /// is a module with `test` in its name.
 ///
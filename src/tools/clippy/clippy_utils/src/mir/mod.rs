@@ -99,6 +99,43 @@ pub fn used_exactly_once(mir: &rustc_middle::mir::Body<'_>, local: rustc_middle:
     })
 }
 
+/// Checks if `local` may be mutated on a path from the statement or terminator at `from` to the one
+/// at `to`, excluding both. This includes assigning to the local or one of its fields, and taking a
+/// mutable reference or a raw pointer to it, e.g. to call a method taking `&mut self`. Moving out
+/// of the local also counts, but dropping it doesn't.
+///
+/// Mutations through a reference taken before `from`, or through interior mutability, aren't
+/// detected.
+pub fn is_local_mutated_between(mir: &Body<'_>, local: Local, from: Location, to: Location) -> bool {
+    let mut v = MutationVisitor {
+        local,
+        locations: Vec::new(),
+    };
+    v.visit_body(mir);
+    v.locations
+        .into_iter()
+        .any(|loc| from.is_predecessor_of(loc, mir) && loc.is_predecessor_of(to, mir))
+}
+
+struct MutationVisitor {
+    local: Local,
+    locations: Vec<Location>,
+}
+
+impl<'tcx> Visitor<'tcx> for MutationVisitor {
+    fn visit_place(&mut self, place: &Place<'tcx>, ctx: PlaceContext, loc: Location) {
+        if place.local == self.local
+            && match ctx {
+                PlaceContext::MutatingUse(ctx) => ctx != MutatingUseContext::Drop,
+                PlaceContext::NonMutatingUse(ctx) => ctx == NonMutatingUseContext::Move,
+                PlaceContext::NonUse(_) => false,
+            }
+        {
+            self.locations.push(loc);
+        }
+    }
+}
+
 /// Returns the `mir::Body` containing the node associated with `hir_id`.
 #[allow(clippy::module_name_repetitions)]
 pub fn enclosing_mir(tcx: TyCtxt<'_>, hir_id: HirId) -> &Body<'_> {
@@ -5,7 +5,7 @@
 use rustc_session::Session;
 use rustc_span::Span;
 
-use crate::attrs::get_unique_attr;
+use crate::attrs::ClippyAttrs;
 
 macro_rules! msrv_aliases {
     ($($major:literal,$minor:literal,$patch:literal {
@@ -23,7 +23,7 @@ macro_rules! msrv_aliases {
     1,70,0 { OPTION_IS_SOME_AND }
     1,68,0 { PATH_MAIN_SEPARATOR_STR }
     1,65,0 { LET_ELSE, POINTER_CAST_CONSTNESS }
-    1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE }
+    1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE, TOTAL_CMP }
     1,58,0 { FORMAT_ARGS_CAPTURE, PATTERN_TRAIT_CHAR_ARRAY }
     1,55,0 { SEEK_REWIND }
     1,53,0 { OR_PATTERNS, MANUAL_BITS, BTREE_MAP_RETAIN, BTREE_SET_RETAIN, ARRAY_INTO_ITERATOR }
@@ -53,7 +53,7 @@ macro_rules! msrv_aliases {
     1,15,0 { MAYBE_BOUND_IN_WHERE }
 }
 
-fn parse_msrv(msrv: &str, sess: Option<&Session>, span: Option<Span>) -> Option<RustcVersion> {
+pub(crate) fn parse_msrv(msrv: &str, sess: Option<&Session>, span: Option<Span>) -> Option<RustcVersion> {
     if let Ok(version) = RustcVersion::parse(msrv) {
         return Some(version);
     } else if let Some(sess) = sess {
@@ -123,15 +123,7 @@ pub fn meets(&self, required: RustcVersion) -> bool {
     }
 
     fn parse_attr(sess: &Session, attrs: &[Attribute]) -> Option<RustcVersion> {
-        if let Some(msrv_attr) = get_unique_attr(sess, attrs, "msrv") {
-            if let Some(msrv) = msrv_attr.value_str() {
-                return parse_msrv(&msrv.to_string(), Some(sess), Some(msrv_attr.span));
-            }
-
-            sess.span_err(msrv_attr.span, "bad clippy attribute");
-        }
-
-        None
+        ClippyAttrs::parse(sess, attrs).msrv.map(|msrv| msrv.value)
     }
 
     pub fn enter_lint_attrs(&mut self, sess: &Session, attrs: &[Attribute]) {
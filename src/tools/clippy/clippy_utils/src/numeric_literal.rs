@@ -18,6 +18,17 @@ fn suggest_grouping(self) -> usize {
             Self::Octal | Self::Decimal => 3,
         }
     }
+
+    /// Returns the prefix of literals written in this radix.
+    #[must_use]
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Binary => "0b",
+            Self::Octal => "0o",
+            Self::Decimal => "",
+            Self::Hexadecimal => "0x",
+        }
+    }
 }
 
 /// A helper method to format numeric literals with digit grouping.
@@ -53,7 +64,7 @@ pub fn from_lit_kind(src: &'a str, lit_kind: &LitKind) -> Option<NumericLiteral<
                 .trim_start()
                 .chars()
                 .next()
-                .map_or(false, |c| c.is_ascii_digit())
+                .is_some_and(|c| c.is_ascii_digit())
         {
             let (unsuffixed, suffix) = split_suffix(src, lit_kind);
             let float = matches!(lit_kind, LitKind::Float(..));
@@ -218,6 +229,128 @@ pub fn group_digits(output: &mut String, input: &str, group_size: usize, partial
     }
 }
 
+/// Formats integers synthesized by a suggestion, e.g. the bounds of a range merged from several
+/// patterns, in the style of a literal written by the user.
+///
+/// # Example
+/// ```rust,ignore
+/// // Given the literal `0x00FF_u8`
+/// let builder = NumericLiteralBuilder::from_lit(src, &lit.node).unwrap_or_default();
+/// builder.format_int(4096) // -> "0x1000_u8"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumericLiteralBuilder {
+    radix: Radix,
+    uppercase: bool,
+    group_size: Option<usize>,
+    suffix: Option<&'static str>,
+    separate_suffix: bool,
+}
+
+impl Default for NumericLiteralBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumericLiteralBuilder {
+    /// Creates a builder for plain decimal literals without digit grouping and suffix.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            radix: Radix::Decimal,
+            uppercase: false,
+            group_size: None,
+            suffix: None,
+            separate_suffix: true,
+        }
+    }
+
+    /// Creates a builder using the style of the given integer literal. Returns `None` if it isn't
+    /// an integer literal.
+    ///
+    /// The digit group size is taken from the last group of the literal, if its digits are grouped.
+    #[must_use]
+    pub fn from_lit(src: &str, lit_kind: &LitKind) -> Option<Self> {
+        if !matches!(lit_kind, LitKind::Int(..)) {
+            return None;
+        }
+        let num_lit = NumericLiteral::from_lit_kind(src, lit_kind)?;
+        let (unsuffixed, _) = split_suffix(src, lit_kind);
+        let group_size = num_lit
+            .integer
+            .rsplit_once('_')
+            .map(|(_, last_group)| last_group.len())
+            .filter(|&len| len > 0);
+
+        Some(Self {
+            radix: num_lit.radix,
+            uppercase: num_lit.integer.chars().any(|c| c.is_ascii_uppercase()),
+            group_size,
+            suffix: lit_suffix(lit_kind),
+            separate_suffix: unsuffixed.ends_with('_'),
+        })
+    }
+
+    #[must_use]
+    pub fn radix(mut self, radix: Radix) -> Self {
+        self.radix = radix;
+        self
+    }
+
+    /// Sets the size of the digit groups, `None` disables grouping.
+    #[must_use]
+    pub fn group_size(mut self, group_size: Option<usize>) -> Self {
+        self.group_size = group_size.filter(|&size| size > 0);
+        self
+    }
+
+    /// Sets the type suffix, e.g. `"u8"`.
+    #[must_use]
+    pub fn suffix(mut self, suffix: Option<&'static str>) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Returns the literal for the given value.
+    #[must_use]
+    pub fn format_int(&self, value: u128) -> String {
+        let digits = match (self.radix, self.uppercase) {
+            (Radix::Binary, _) => format!("{value:b}"),
+            (Radix::Octal, _) => format!("{value:o}"),
+            (Radix::Decimal, _) => value.to_string(),
+            (Radix::Hexadecimal, false) => format!("{value:x}"),
+            (Radix::Hexadecimal, true) => format!("{value:X}"),
+        };
+
+        let mut output = String::from(self.radix.prefix());
+        match self.group_size {
+            Some(group_size) => NumericLiteral::group_digits(&mut output, &digits, group_size, true, false),
+            None => output.push_str(&digits),
+        }
+
+        if let Some(suffix) = self.suffix {
+            if self.separate_suffix {
+                output.push('_');
+            }
+            output.push_str(suffix);
+        }
+
+        output
+    }
+
+    /// Returns the literal for the given value, preceded by a minus sign if it is negative.
+    #[must_use]
+    pub fn format_signed_int(&self, value: i128) -> String {
+        let lit = self.format_int(value.unsigned_abs());
+        if value < 0 {
+            format!("-{lit}")
+        } else {
+            lit
+        }
+    }
+}
+
 fn split_suffix<'a>(src: &'a str, lit_kind: &LitKind) -> (&'a str, Option<&'a str>) {
     debug_assert!(lit_kind.is_numeric());
     lit_suffix_length(lit_kind)
@@ -229,8 +362,12 @@ fn split_suffix<'a>(src: &'a str, lit_kind: &LitKind) -> (&'a str, Option<&'a st
 }
 
 fn lit_suffix_length(lit_kind: &LitKind) -> Option<usize> {
+    lit_suffix(lit_kind).map(str::len)
+}
+
+fn lit_suffix(lit_kind: &LitKind) -> Option<&'static str> {
     debug_assert!(lit_kind.is_numeric());
-    let suffix = match lit_kind {
+    match lit_kind {
         LitKind::Int(_, int_lit_kind) => match int_lit_kind {
             LitIntType::Signed(int_ty) => Some(int_ty.name_str()),
             LitIntType::Unsigned(uint_ty) => Some(uint_ty.name_str()),
@@ -241,7 +378,5 @@ fn lit_suffix_length(lit_kind: &LitKind) -> Option<usize> {
             LitFloatType::Unsuffixed => None,
         },
         _ => None,
-    };
-
-    suffix.map(str::len)
+    }
 }
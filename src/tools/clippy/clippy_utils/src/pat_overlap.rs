@@ -0,0 +1,531 @@
+//! Utilities for checking which values patterns can match, e.g. whether two `match` arms can
+//! match the same value.
+
+use crate::consts::{constant, constant_path, Constant, FullInt};
+use core::cmp::Ordering;
+use core::iter;
+use core::slice;
+use rustc_arena::DroplessArena;
+use rustc_ast::ast::LitKind;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Pat, PatKind, RangeEnd};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, VariantDef};
+use rustc_span::Symbol;
+
+/// A summary of the values a pattern can match.
+#[derive(Clone, Copy)]
+pub enum PatSummary<'a> {
+    Wild,
+    /// A pattern which can't be summarized, such as a negative integer. It may overlap with any
+    /// other pattern, but isn't known to match any value.
+    Unknown,
+    Struct(Option<DefId>, &'a [(Symbol, Self)]),
+    Tuple(Option<DefId>, &'a [Self]),
+    Or(&'a [Self]),
+    Path(Option<DefId>),
+    LitStr(Symbol),
+    LitBytes(&'a [u8]),
+    LitInt(u128),
+    LitBool(bool),
+    /// A float literal. The value is stored as the key given by `float_key`.
+    LitFloat(u64),
+    Range(PatRange),
+    /// A float range pattern. The bounds are stored as the keys given by `float_key`.
+    FloatRange(PatRange),
+    /// A slice pattern. If the second value is `None`, then this matches an exact size. Otherwise
+    /// the first value contains everything before the `..` wildcard pattern, and the second value
+    /// contains everything afterwards. Note that either side, or both sides, may contain zero
+    /// patterns.
+    Slice(&'a [Self], Option<&'a [Self]>),
+}
+
+/// A range of values matched by a pattern. Integers, `char`s and bytes are stored as their unsigned
+/// value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PatRange {
+    pub start: u128,
+    pub end: u128,
+    pub bounds: RangeEnd,
+}
+impl PatRange {
+    /// Checks if the value is within the range.
+    #[must_use]
+    pub fn contains(&self, x: u128) -> bool {
+        x >= self.start
+            && match self.bounds {
+                RangeEnd::Included => x <= self.end,
+                RangeEnd::Excluded => x < self.end,
+            }
+    }
+
+    /// Checks if the two ranges have any value in common.
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        // Note: Empty ranges are impossible, so this is correct even though it would return true if an
+        // empty exclusive range were to reside within an inclusive range.
+        (match self.bounds {
+            RangeEnd::Included => self.end >= other.start,
+            RangeEnd::Excluded => self.end > other.start,
+        } && match other.bounds {
+            RangeEnd::Included => self.start <= other.end,
+            RangeEnd::Excluded => self.start < other.end,
+        })
+    }
+}
+
+/// Gets a key for a float value which orders the same way as `f64::total_cmp`.
+fn float_key(val: f64) -> u64 {
+    // `0.0` and `-0.0` are matched by the same patterns.
+    let bits = if val == 0.0 { 0 } else { val.to_bits() };
+    if bits >> 63 == 0 {
+        bits | (1 << 63)
+    } else {
+        !bits
+    }
+}
+
+/// Evaluates a float literal or range bound in a pattern to the key given by `float_key`.
+fn float_pat_key(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u64> {
+    match constant(cx, cx.typeck_results(), e)? {
+        Constant::F32(val) => Some(float_key(val.into())),
+        Constant::F64(val) => Some(float_key(val)),
+        _ => None,
+    }
+}
+
+/// Iterates over the pairs of fields with matching names.
+fn iter_matching_struct_fields<'a>(
+    left: &'a [(Symbol, PatSummary<'a>)],
+    right: &'a [(Symbol, PatSummary<'a>)],
+) -> impl Iterator<Item = (&'a PatSummary<'a>, &'a PatSummary<'a>)> + 'a {
+    struct Iter<'a>(
+        slice::Iter<'a, (Symbol, PatSummary<'a>)>,
+        slice::Iter<'a, (Symbol, PatSummary<'a>)>,
+    );
+    impl<'a> Iterator for Iter<'a> {
+        type Item = (&'a PatSummary<'a>, &'a PatSummary<'a>);
+        fn next(&mut self) -> Option<Self::Item> {
+            // Note: all the fields in each slice are sorted by symbol value.
+            let mut left = self.0.next()?;
+            let mut right = self.1.next()?;
+            loop {
+                match left.0.cmp(&right.0) {
+                    Ordering::Equal => return Some((&left.1, &right.1)),
+                    Ordering::Less => left = self.0.next()?,
+                    Ordering::Greater => right = self.1.next()?,
+                }
+            }
+        }
+    }
+    Iter(left.iter(), right.iter())
+}
+
+#[expect(clippy::similar_names)]
+impl<'a> PatSummary<'a> {
+    /// Summarizes the pattern. Any sub-patterns are allocated in the arena.
+    #[must_use]
+    pub fn from_pat(cx: &LateContext<'_>, arena: &'a DroplessArena, pat: &'a Pat<'_>) -> Self {
+        match pat.kind {
+            PatKind::Wild | PatKind::Binding(.., None) => Self::Wild,
+            PatKind::Binding(.., Some(pat)) | PatKind::Box(pat) | PatKind::Ref(pat, _) => {
+                Self::from_pat(cx, arena, pat)
+            },
+            PatKind::Struct(ref path, fields, _) => {
+                let fields =
+                    arena.alloc_from_iter(fields.iter().map(|f| (f.ident.name, Self::from_pat(cx, arena, f.pat))));
+                fields.sort_by_key(|&(name, _)| name);
+                Self::Struct(cx.qpath_res(path, pat.hir_id).opt_def_id(), fields)
+            },
+            PatKind::TupleStruct(ref path, pats, wild_idx) => {
+                let Some(adt) = cx.typeck_results().pat_ty(pat).ty_adt_def() else {
+                    return Self::Unknown
+                };
+                let (var_id, variant) = if adt.is_enum() {
+                    match cx.qpath_res(path, pat.hir_id).opt_def_id() {
+                        Some(x) => (Some(x), adt.variant_with_ctor_id(x)),
+                        None => return Self::Unknown,
+                    }
+                } else {
+                    (None, adt.non_enum_variant())
+                };
+                let (front, back) = match wild_idx.as_opt_usize() {
+                    Some(i) => pats.split_at(i),
+                    None => (pats, [].as_slice()),
+                };
+                let pats = arena.alloc_from_iter(
+                    front
+                        .iter()
+                        .map(|pat| Self::from_pat(cx, arena, pat))
+                        .chain(iter::repeat_with(|| Self::Wild).take(variant.fields.len() - pats.len()))
+                        .chain(back.iter().map(|pat| Self::from_pat(cx, arena, pat))),
+                );
+                Self::Tuple(var_id, pats)
+            },
+            PatKind::Or(pats) => Self::Or(arena.alloc_from_iter(pats.iter().map(|pat| Self::from_pat(cx, arena, pat)))),
+            PatKind::Path(ref path) => match cx.qpath_res(path, pat.hir_id) {
+                Res::Def(DefKind::Const | DefKind::AssocConst, _) => {
+                    let ty = cx.typeck_results().pat_ty(pat);
+                    // TODO: Handle negative integers. They're currently treated as unknown.
+                    let Some(val) = constant_path(cx, cx.typeck_results(), path, pat.hir_id, ty) else {
+                        return Self::Unknown
+                    };
+                    match val {
+                        Constant::Int(_) => match val.int_value(cx, ty) {
+                            Some(FullInt::U(val)) => Self::LitInt(val),
+                            Some(FullInt::S(val)) if val >= 0 => Self::LitInt(val.unsigned_abs()),
+                            _ => Self::Unknown,
+                        },
+                        Constant::Bool(val) => Self::LitBool(val),
+                        Constant::F32(val) => Self::LitFloat(float_key(val.into())),
+                        Constant::F64(val) => Self::LitFloat(float_key(val)),
+                        Constant::Str(ref val) => Self::LitStr(Symbol::intern(val)),
+                        _ => Self::Unknown,
+                    }
+                },
+                res => Self::Path(res.opt_def_id()),
+            },
+            PatKind::Tuple(pats, wild_idx) => {
+                let field_count = match cx.typeck_results().pat_ty(pat).kind() {
+                    ty::Tuple(subs) => subs.len(),
+                    _ => return Self::Unknown,
+                };
+                let (front, back) = match wild_idx.as_opt_usize() {
+                    Some(i) => pats.split_at(i),
+                    None => (pats, [].as_slice()),
+                };
+                let pats = arena.alloc_from_iter(
+                    front
+                        .iter()
+                        .map(|pat| Self::from_pat(cx, arena, pat))
+                        .chain(iter::repeat_with(|| Self::Wild).take(field_count - pats.len()))
+                        .chain(back.iter().map(|pat| Self::from_pat(cx, arena, pat))),
+                );
+                Self::Tuple(None, pats)
+            },
+            PatKind::Lit(e) if cx.typeck_results().pat_ty(pat).is_floating_point() => match float_pat_key(cx, e) {
+                Some(val) => Self::LitFloat(val),
+                None => Self::Unknown,
+            },
+            PatKind::Lit(e) => match &e.kind {
+                // TODO: Handle negative integers. They're currently treated as unknown.
+                ExprKind::Lit(lit) => match lit.node {
+                    LitKind::Str(sym, _) => Self::LitStr(sym),
+                    LitKind::ByteStr(ref bytes, _) | LitKind::CStr(ref bytes, _) => Self::LitBytes(bytes),
+                    LitKind::Byte(val) => Self::LitInt(val.into()),
+                    LitKind::Char(val) => Self::LitInt(val.into()),
+                    LitKind::Int(val, _) => Self::LitInt(val),
+                    LitKind::Bool(val) => Self::LitBool(val),
+                    LitKind::Float(..) | LitKind::Err => Self::Unknown,
+                },
+                _ => Self::Unknown,
+            },
+            PatKind::Range(start, end, bounds) if cx.typeck_results().pat_ty(pat).is_floating_point() => {
+                let start = match start {
+                    None => 0,
+                    Some(e) => match float_pat_key(cx, e) {
+                        Some(val) => val.into(),
+                        None => return Self::Unknown,
+                    },
+                };
+                let (end, bounds) = match end {
+                    None => (u64::MAX.into(), RangeEnd::Included),
+                    Some(e) => match float_pat_key(cx, e) {
+                        Some(val) => (val.into(), bounds),
+                        None => return Self::Unknown,
+                    },
+                };
+                Self::FloatRange(PatRange { start, end, bounds })
+            },
+            PatKind::Range(start, end, bounds) => {
+                // TODO: Handle negative integers. They're currently treated as unknown.
+                let start = match start {
+                    None => 0,
+                    Some(e) => match &e.kind {
+                        ExprKind::Lit(lit) => match lit.node {
+                            LitKind::Int(val, _) => val,
+                            LitKind::Char(val) => val.into(),
+                            LitKind::Byte(val) => val.into(),
+                            _ => return Self::Unknown,
+                        },
+                        _ => return Self::Unknown,
+                    },
+                };
+                let (end, bounds) = match end {
+                    None => (u128::MAX, RangeEnd::Included),
+                    Some(e) => match &e.kind {
+                        ExprKind::Lit(lit) => match lit.node {
+                            LitKind::Int(val, _) => (val, bounds),
+                            LitKind::Char(val) => (val.into(), bounds),
+                            LitKind::Byte(val) => (val.into(), bounds),
+                            _ => return Self::Unknown,
+                        },
+                        _ => return Self::Unknown,
+                    },
+                };
+                Self::Range(PatRange { start, end, bounds })
+            },
+            PatKind::Slice(front, wild_pat, back) => Self::Slice(
+                arena.alloc_from_iter(front.iter().map(|pat| Self::from_pat(cx, arena, pat))),
+                wild_pat.map(|_| &*arena.alloc_from_iter(back.iter().map(|pat| Self::from_pat(cx, arena, pat)))),
+            ),
+        }
+    }
+
+    /// Checks if every value the pattern can match is within the range. Only integer patterns can
+    /// be contained in a range.
+    #[must_use]
+    pub fn is_contained_in(&self, range: &PatRange) -> bool {
+        match *self {
+            Self::LitInt(x) => range.contains(x),
+            Self::Range(ref x) => {
+                x.start >= range.start
+                    && match (x.bounds, range.bounds) {
+                        (RangeEnd::Included, RangeEnd::Excluded) => x.end < range.end,
+                        // Empty ranges are impossible, so `x.end` is at least one here.
+                        (RangeEnd::Excluded, RangeEnd::Included) => x.end - 1 <= range.end,
+                        _ => x.end <= range.end,
+                    }
+            },
+            Self::Or(pats) => pats.iter().all(|pat| pat.is_contained_in(range)),
+            _ => false,
+        }
+    }
+
+    /// Checks if two patterns overlap in the values they can match assuming they are for the same
+    /// type.
+    #[must_use]
+    pub fn may_overlap(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::Wild | Self::Unknown, _) | (_, Self::Wild | Self::Unknown) => true,
+            (Self::Or(pats), ref other) | (ref other, Self::Or(pats)) => pats.iter().any(|pat| pat.may_overlap(other)),
+            (Self::Struct(lpath, lfields), Self::Struct(rpath, rfields)) => {
+                if lpath != rpath {
+                    return false;
+                }
+                iter_matching_struct_fields(lfields, rfields).all(|(lpat, rpat)| lpat.may_overlap(rpat))
+            },
+            (Self::Tuple(lpath, lpats), Self::Tuple(rpath, rpats)) => {
+                if lpath != rpath {
+                    return false;
+                }
+                lpats
+                    .iter()
+                    .zip(rpats.iter())
+                    .all(|(lpat, rpat)| lpat.may_overlap(rpat))
+            },
+            (Self::Path(x), Self::Path(y)) => x == y,
+            (Self::LitStr(x), Self::LitStr(y)) => x == y,
+            (Self::LitBytes(x), Self::LitBytes(y)) => x == y,
+            (Self::LitInt(x), Self::LitInt(y)) => x == y,
+            (Self::LitBool(x), Self::LitBool(y)) => x == y,
+            (Self::LitFloat(x), Self::LitFloat(y)) => x == y,
+            (Self::Range(ref x), Self::Range(ref y)) => x.overlaps(y),
+            (Self::Range(ref range), Self::LitInt(x)) | (Self::LitInt(x), Self::Range(ref range)) => range.contains(x),
+            (Self::FloatRange(ref x), Self::FloatRange(ref y)) => x.overlaps(y),
+            (Self::FloatRange(ref range), Self::LitFloat(x)) | (Self::LitFloat(x), Self::FloatRange(ref range)) => {
+                range.contains(x.into())
+            },
+            (Self::Slice(lpats, None), Self::Slice(rpats, None)) => {
+                lpats.len() == rpats.len() && lpats.iter().zip(rpats.iter()).all(|(x, y)| x.may_overlap(y))
+            },
+            (Self::Slice(pats, None), Self::Slice(front, Some(back)))
+            | (Self::Slice(front, Some(back)), Self::Slice(pats, None)) => {
+                // Here `pats` is an exact size match. If the combined lengths of `front` and `back` are greater
+                // then the minimum length required will be greater than the length of `pats`.
+                if pats.len() < front.len() + back.len() {
+                    return false;
+                }
+                pats[..front.len()]
+                    .iter()
+                    .zip(front.iter())
+                    .chain(pats[pats.len() - back.len()..].iter().zip(back.iter()))
+                    .all(|(x, y)| x.may_overlap(y))
+            },
+            (Self::Slice(lfront, Some(lback)), Self::Slice(rfront, Some(rback))) => lfront
+                .iter()
+                .zip(rfront.iter())
+                .chain(lback.iter().rev().zip(rback.iter().rev()))
+                .all(|(x, y)| x.may_overlap(y)),
+            (Self::LitBytes(bytes), Self::Slice(pats, None)) | (Self::Slice(pats, None), Self::LitBytes(bytes)) => {
+                bytes.len() == pats.len()
+                    && bytes
+                        .iter()
+                        .zip(pats.iter())
+                        .all(|(&b, pat)| pat.may_overlap(&Self::LitInt(b.into())))
+            },
+            (Self::LitBytes(bytes), Self::Slice(front, Some(back)))
+            | (Self::Slice(front, Some(back)), Self::LitBytes(bytes)) => {
+                // Same as with an exact size slice pattern, the byte string must be at least as long as the
+                // combined lengths of `front` and `back`.
+                if bytes.len() < front.len() + back.len() {
+                    return false;
+                }
+                bytes[..front.len()]
+                    .iter()
+                    .zip(front.iter())
+                    .chain(bytes[bytes.len() - back.len()..].iter().zip(back.iter()))
+                    .all(|(&b, pat)| pat.may_overlap(&Self::LitInt(b.into())))
+            },
+
+            // Enums can mix unit variants with tuple/struct variants. These can never overlap.
+            (Self::Path(_), Self::Tuple(..) | Self::Struct(..))
+            | (Self::Tuple(..) | Self::Struct(..), Self::Path(_)) => false,
+
+            // Tuples can be matched like a struct.
+            (Self::Tuple(x, _), Self::Struct(y, _)) | (Self::Struct(x, _), Self::Tuple(y, _)) => {
+                // TODO: check fields here.
+                x == y
+            },
+
+            _ => true,
+        }
+    }
+}
+
+/// Checks if the patterns together match every value of the type, like the arms of an
+/// exhaustive `match`. Unlike `may_overlap`, this handles patterns which only cover a type
+/// together, e.g. `Some(true)`, `Some(false)` and `None`.
+///
+/// Patterns which can't be summarized are assumed not to match anything, so this may return
+/// `false` for patterns which are actually exhaustive.
+#[must_use]
+pub fn is_exhaustive<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, pats: &[PatSummary<'_>]) -> bool {
+    let rows = pats.iter().map(|&pat| vec![pat]).collect();
+    rows_are_exhaustive(cx, &[ty], rows)
+}
+
+/// Checks if the patterns together match every value of the enum variant, e.g. `E::A(true)` and
+/// `E::A(false)` for `E::A(bool)`. `ty` is the type of the enum.
+#[must_use]
+pub fn is_variant_exhaustive<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    variant: &'tcx VariantDef,
+    pats: &[PatSummary<'_>],
+) -> bool {
+    let ty::Adt(_, substs) = ty.peel_refs().kind() else {
+        return false;
+    };
+    let field_tys: Vec<_> = variant.fields.iter().map(|f| f.ty(cx.tcx, substs)).collect();
+    let rows = expand_or_pats(pats.iter().map(|&pat| vec![pat]).collect())
+        .iter()
+        .filter_map(|row| specialize(row, Ctor::Variant(variant), field_tys.len()))
+        .collect();
+    rows_are_exhaustive(cx, &field_tys, rows)
+}
+
+/// A constructor of the values of a type which patterns can be split on.
+#[derive(Clone, Copy)]
+enum Ctor<'tcx> {
+    Bool(bool),
+    Tuple,
+    /// A variant of an enum, or the only variant of a struct.
+    Variant(&'tcx VariantDef),
+}
+
+/// Gets the constructors of the type along with the types of their fields, or `None` if the type
+/// can only be covered by a wildcard, e.g. integers or foreign `#[non_exhaustive]` enums.
+fn constructors<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Vec<(Ctor<'tcx>, Vec<Ty<'tcx>>)>> {
+    match *ty.kind() {
+        ty::Bool => Some(vec![(Ctor::Bool(false), Vec::new()), (Ctor::Bool(true), Vec::new())]),
+        ty::Tuple(tys) => Some(vec![(Ctor::Tuple, tys.to_vec())]),
+        ty::Adt(adt, substs)
+            if (adt.is_enum() || adt.is_struct())
+                && !(adt.is_variant_list_non_exhaustive() && !adt.did().is_local()) =>
+        {
+            let field_tys = |v: &VariantDef| v.fields.iter().map(|f| f.ty(cx.tcx, substs)).collect();
+            Some(
+                adt.variants()
+                    .iter()
+                    .map(|v| (Ctor::Variant(v), field_tys(v)))
+                    .collect(),
+            )
+        },
+        _ => None,
+    }
+}
+
+/// Replaces the rows starting with an or-pattern by one row for each of its alternatives.
+fn expand_or_pats<'a>(rows: Vec<Vec<PatSummary<'a>>>) -> Vec<Vec<PatSummary<'a>>> {
+    let mut expanded = Vec::with_capacity(rows.len());
+    let mut stack = rows;
+    stack.reverse();
+    while let Some(row) = stack.pop() {
+        match row.first() {
+            Some(PatSummary::Or(pats)) => stack.extend(pats.iter().rev().map(|&pat| {
+                let mut row = row.clone();
+                row[0] = pat;
+                row
+            })),
+            _ => expanded.push(row),
+        }
+    }
+    expanded
+}
+
+/// Gets the row matching the fields of the constructor followed by the remaining columns, if the
+/// first pattern of the row can match the constructor.
+fn specialize<'a>(row: &[PatSummary<'a>], ctor: Ctor<'_>, arity: usize) -> Option<Vec<PatSummary<'a>>> {
+    let (head, rest) = row.split_first()?;
+    let mut fields = match (*head, ctor) {
+        (PatSummary::Wild, _) => vec![PatSummary::Wild; arity],
+        (PatSummary::LitBool(x), Ctor::Bool(y)) if x == y => Vec::new(),
+        (PatSummary::Tuple(None, pats), Ctor::Tuple | Ctor::Variant(_)) if pats.len() == arity => pats.to_vec(),
+        (PatSummary::Tuple(Some(id), pats), Ctor::Variant(v)) if is_variant(v, id) && pats.len() == arity => {
+            pats.to_vec()
+        },
+        (PatSummary::Path(Some(id)), Ctor::Variant(v)) if is_variant(v, id) => vec![PatSummary::Wild; arity],
+        (PatSummary::Struct(Some(id), pats), Ctor::Variant(v)) if is_variant(v, id) => v
+            .fields
+            .iter()
+            .map(|f| {
+                pats.iter()
+                    .find(|&&(name, _)| name == f.name)
+                    .map_or(PatSummary::Wild, |&(_, pat)| pat)
+            })
+            .collect(),
+        _ => return None,
+    };
+    fields.extend_from_slice(rest);
+    Some(fields)
+}
+
+fn is_variant(variant: &VariantDef, id: DefId) -> bool {
+    variant.def_id == id || variant.ctor_def_id() == Some(id)
+}
+
+/// Checks if the rows of patterns match every combination of values of the column types.
+fn rows_are_exhaustive<'tcx>(cx: &LateContext<'tcx>, tys: &[Ty<'tcx>], rows: Vec<Vec<PatSummary<'_>>>) -> bool {
+    let Some((&ty, rest_tys)) = tys.split_first() else {
+        return !rows.is_empty();
+    };
+    let rows = expand_or_pats(rows);
+    // References are matched through, so the patterns are for the referenced type.
+    let ty = ty.peel_refs();
+    let ctors = if rows.iter().all(|row| matches!(row[0], PatSummary::Wild)) {
+        // Splitting on the constructors doesn't change anything, and would never end for
+        // recursive types.
+        None
+    } else {
+        constructors(cx, ty)
+    };
+    if let Some(ctors) = ctors {
+        ctors.into_iter().all(|(ctor, field_tys)| {
+            let rows = rows
+                .iter()
+                .filter_map(|row| specialize(row, ctor, field_tys.len()))
+                .collect();
+            rows_are_exhaustive(cx, &[field_tys.as_slice(), rest_tys].concat(), rows)
+        })
+    } else {
+        // Only wildcards cover every value of the type.
+        let rows = rows
+            .into_iter()
+            .filter(|row| matches!(row[0], PatSummary::Wild))
+            .map(|row| row[1..].to_vec())
+            .collect();
+        rows_are_exhaustive(cx, rest_tys, rows)
+    }
+}
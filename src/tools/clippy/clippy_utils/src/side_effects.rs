@@ -0,0 +1,404 @@
+//! Utilities for classifying the side effects evaluating an expression may have.
+//!
+//! The analysis is conservative: an effect is only ruled out if the expression is known not to
+//! have it. Calls are only understood if they're to a `const fn`, to a function of the standard
+//! library which doesn't call back into user code, or, in the interprocedural mode, to a function
+//! of the local crate.
+
+use crate::consts::constant_simple;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{walk_expr, walk_pat, Visitor};
+use rustc_hir::{BinOpKind, Closure, Expr, ExprKind, HirIdSet, Mutability, Pat, PatKind, QPath, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{self, GenericArgKind, SubstsRef, Ty, TypeckResults};
+use rustc_span::sym;
+use std::{iter, mem, ops};
+
+/// The maximum depth of nested calls to local functions followed by the interprocedural analysis.
+const MAX_CALL_DEPTH: usize = 4;
+
+/// The side effects evaluating an expression may have.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct SideEffects {
+    /// The expression may allocate memory.
+    pub allocates: bool,
+    /// The expression may panic, e.g. on an arithmetic overflow or an out of bounds index.
+    pub may_panic: bool,
+    /// The expression may perform I/O, e.g. print, access files or read the clock.
+    pub does_io: bool,
+    /// The expression may mutate state which outlives it.
+    pub mutates: bool,
+    /// The expression may leave the surrounding code early, e.g. with `return`, `break` or `?`.
+    pub control_flow: bool,
+    /// The expression may call code whose effects aren't known, which may do any of the above.
+    pub calls_unknown: bool,
+}
+
+impl SideEffects {
+    /// Checks if evaluating the expression has no effect other than producing its value.
+    pub fn is_pure(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Discards the possibility of the expression panicking, for checks which don't consider
+    /// panics to be side effects.
+    #[must_use]
+    pub fn ignoring_panics(self) -> Self {
+        Self {
+            may_panic: false,
+            ..self
+        }
+    }
+}
+
+impl ops::BitOr for SideEffects {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            allocates: self.allocates || rhs.allocates,
+            may_panic: self.may_panic || rhs.may_panic,
+            does_io: self.does_io || rhs.does_io,
+            mutates: self.mutates || rhs.mutates,
+            control_flow: self.control_flow || rhs.control_flow,
+            calls_unknown: self.calls_unknown || rhs.calls_unknown,
+        }
+    }
+}
+impl ops::BitOrAssign for SideEffects {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+/// Determines the side effects evaluating the expression may have. Calls to functions of the local
+/// crate which aren't `const fn`s are treated as calls to unknown code.
+pub fn expr_side_effects<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> SideEffects {
+    let mut v = V::new(cx, cx.typeck_results(), cx.param_env, false, Vec::new());
+    v.visit_expr(e);
+    v.effects
+}
+
+/// Like `expr_side_effects`, but also analyzes the bodies of the functions of the local crate the
+/// expression calls, as long as they aren't trait methods.
+pub fn expr_side_effects_interprocedural<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> SideEffects {
+    let mut v = V::new(cx, cx.typeck_results(), cx.param_env, true, Vec::new());
+    v.visit_expr(e);
+    v.effects
+}
+
+/// Checks if evaluating the expression may have any side effects, including panicking.
+pub fn expr_has_side_effects<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+    !expr_side_effects(cx, e).is_pure()
+}
+
+struct V<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    interprocedural: bool,
+    /// The local functions whose bodies are currently being analyzed.
+    call_stack: Vec<LocalDefId>,
+    /// The locals declared within the analyzed code. Mutating them isn't visible outside of it.
+    locals: HirIdSet,
+    /// The loops and labeled blocks within the analyzed code. Breaking out of them doesn't leave it.
+    scopes: HirIdSet,
+    /// Whether the analyzed code is the body of a function or closure, which `return` doesn't
+    /// leave.
+    is_body: bool,
+    effects: SideEffects,
+}
+
+impl<'a, 'tcx> V<'a, 'tcx> {
+    fn new(
+        cx: &'a LateContext<'tcx>,
+        typeck: &'tcx TypeckResults<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+        interprocedural: bool,
+        call_stack: Vec<LocalDefId>,
+    ) -> Self {
+        Self {
+            cx,
+            typeck,
+            param_env,
+            interprocedural,
+            call_stack,
+            locals: HirIdSet::default(),
+            scopes: HirIdSet::default(),
+            is_body: false,
+            effects: SideEffects::default(),
+        }
+    }
+
+    fn call(&mut self, def_id: DefId, substs: SubstsRef<'tcx>, args: &[&'tcx Expr<'tcx>]) {
+        let tcx = self.cx.tcx;
+        if self.interprocedural
+            && let Some(local_id) = def_id.as_local()
+            && tcx.trait_of_item(def_id).is_none()
+        {
+            self.local_fn_call(local_id);
+        } else if tcx.is_const_fn_raw(def_id)
+            || matches!(tcx.crate_name(def_id.krate), sym::core | sym::alloc | sym::std)
+        {
+            let closures: Vec<&Closure<'_>> = args
+                .iter()
+                .filter_map(|arg| match arg.kind {
+                    ExprKind::Closure(closure) => Some(closure),
+                    _ => None,
+                })
+                .collect();
+            let closure_ids: Vec<DefId> = closures.iter().map(|closure| closure.def_id.to_def_id()).collect();
+            if !substs.types().all(|ty| self.is_std_ty(ty, &closure_ids))
+                || !args
+                    .iter()
+                    .all(|arg| self.is_std_ty(self.typeck.expr_ty_adjusted(arg), &closure_ids))
+            {
+                self.effects.calls_unknown = true;
+                return;
+            }
+
+            self.effects.may_panic = true;
+            // A `const fn` can neither allocate nor do I/O, but it can still mutate through its arguments,
+            // e.g. `Option::take` or `mem::swap`.
+            let is_const_fn = tcx.is_const_fn_raw(def_id);
+            if !is_const_fn && tcx.crate_name(def_id.krate) != sym::core {
+                self.effects.allocates = true;
+            }
+            if !is_const_fn
+                && let [krate, module, ..] = &*self.cx.get_def_path(def_id)
+                && *krate == sym::std
+                && matches!(
+                    module.as_str(),
+                    "env" | "fs" | "io" | "net" | "os" | "process" | "thread" | "time"
+                )
+            {
+                self.effects.does_io = true;
+            }
+            if args.iter().any(|arg| self.may_mutate_arg(arg)) {
+                self.effects.mutates = true;
+            }
+            // The closures are assumed to be called by the function.
+            for closure in closures {
+                let body = tcx.hir().body(closure.body);
+                let is_body = mem::replace(&mut self.is_body, true);
+                for param in body.params {
+                    self.visit_pat(param.pat);
+                }
+                self.visit_expr(body.value);
+                self.is_body = is_body;
+            }
+        } else {
+            self.effects.calls_unknown = true;
+        }
+    }
+
+    fn local_fn_call(&mut self, def_id: LocalDefId) {
+        let tcx = self.cx.tcx;
+        if self.call_stack.contains(&def_id) {
+            // The effects of the function are already being collected, but the recursion may not end.
+            self.effects.may_panic = true;
+            return;
+        }
+        let Some(body_id) = tcx.hir().maybe_body_owned_by(def_id) else {
+            self.effects.calls_unknown = true;
+            return;
+        };
+        if self.call_stack.len() >= MAX_CALL_DEPTH {
+            self.effects.calls_unknown = true;
+            return;
+        }
+
+        let call_stack = self.call_stack.iter().copied().chain(iter::once(def_id)).collect();
+        let mut v = V::new(
+            self.cx,
+            tcx.typeck_body(body_id),
+            tcx.param_env(def_id),
+            true,
+            call_stack,
+        );
+        v.is_body = true;
+        let body = tcx.hir().body(body_id);
+        for param in body.params {
+            v.visit_pat(param.pat);
+        }
+        v.visit_expr(body.value);
+        self.effects |= v.effects;
+    }
+
+    fn method_call(&mut self, e: &'tcx Expr<'tcx>, args: &[&'tcx Expr<'tcx>]) {
+        if let Some(def_id) = self.typeck.type_dependent_def_id(e.hir_id) {
+            self.call(def_id, self.typeck.node_substs(e.hir_id), args);
+        } else {
+            self.effects.calls_unknown = true;
+        }
+    }
+
+    /// Checks if the type is made up only of primitive types and types from the standard library,
+    /// or of the given closures, whose bodies are analyzed separately.
+    fn is_std_ty(&self, ty: Ty<'tcx>, closures: &[DefId]) -> bool {
+        let mut walker = ty.walk();
+        while let Some(arg) = walker.next() {
+            let GenericArgKind::Type(ty) = arg.unpack() else {
+                continue;
+            };
+            match *ty.kind() {
+                ty::Closure(def_id, _) if closures.contains(&def_id) => walker.skip_current_subtree(),
+                ty::Adt(adt, _)
+                    if matches!(
+                        self.cx.tcx.crate_name(adt.did().krate),
+                        sym::core | sym::alloc | sym::std
+                    ) => {},
+                ty::Bool
+                | ty::Char
+                | ty::Int(_)
+                | ty::Uint(_)
+                | ty::Float(_)
+                | ty::Str
+                | ty::Array(..)
+                | ty::Slice(_)
+                | ty::RawPtr(_)
+                | ty::Ref(..)
+                | ty::Tuple(_)
+                | ty::Never => {},
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Checks if the place is a local declared within the analyzed code, or a part of one, and
+    /// doesn't contain any references through which other values could be reached.
+    fn is_local_place(&self, mut e: &Expr<'_>) -> bool {
+        loop {
+            match e.kind {
+                ExprKind::Field(base, _) => e = base,
+                ExprKind::Index(base, _) if !self.typeck.is_method_call(e) => e = base,
+                ExprKind::Path(QPath::Resolved(None, path)) => {
+                    return matches!(path.res, Res::Local(id) if self.locals.contains(&id))
+                        && !self.typeck.expr_ty(e).walk().any(|arg| {
+                            matches!(arg.unpack(), GenericArgKind::Type(ty) if matches!(ty.kind(), ty::Ref(..) | ty::RawPtr(_)))
+                        });
+                },
+                _ => return false,
+            }
+        }
+    }
+
+    /// Checks if the function the argument is passed to may mutate any state through it.
+    fn may_mutate_arg(&self, arg: &Expr<'_>) -> bool {
+        match *self.typeck.expr_ty_adjusted(arg).kind() {
+            ty::Ref(_, _, Mutability::Mut) => {
+                let place = match arg.kind {
+                    ExprKind::AddrOf(_, Mutability::Mut, place) => place,
+                    _ => arg,
+                };
+                !self.is_local_place(place)
+            },
+            ty::Ref(_, ty, Mutability::Not) => !ty.is_freeze(self.cx.tcx, self.param_env),
+            _ => false,
+        }
+    }
+
+    fn assign(&mut self, place: &Expr<'_>) {
+        if !self.is_local_place(place) {
+            self.effects.mutates = true;
+        }
+    }
+
+    /// Checks if the arithmetic operation may overflow or divide by zero.
+    fn may_overflow(&self, e: &Expr<'_>, operand: &Expr<'_>) -> bool {
+        self.typeck.expr_ty(operand).is_integral() && constant_simple(self.cx, self.typeck, e).is_none()
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for V<'_, 'tcx> {
+    fn visit_pat(&mut self, pat: &'tcx Pat<'tcx>) {
+        if let PatKind::Binding(_, id, ..) = pat.kind {
+            self.locals.insert(id);
+        }
+        walk_pat(self, pat);
+    }
+
+    fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+        // Dereferencing through a user-defined `Deref` impl calls unknown code.
+        let mut ty = self.typeck.expr_ty(e);
+        for adjustment in self.typeck.expr_adjustments(e) {
+            if let Adjust::Deref(Some(_)) = adjustment.kind
+                && !self.is_std_ty(ty, &[])
+            {
+                self.effects.calls_unknown = true;
+            }
+            ty = adjustment.target;
+        }
+
+        match e.kind {
+            ExprKind::Binary(_, lhs, rhs) | ExprKind::AssignOp(_, lhs, rhs) | ExprKind::Index(lhs, rhs)
+                if self.typeck.is_method_call(e) =>
+            {
+                self.method_call(e, &[lhs, rhs]);
+            },
+            ExprKind::Unary(_, operand) if self.typeck.is_method_call(e) => self.method_call(e, &[operand]),
+            ExprKind::Call(callee, args) => match callee.kind {
+                ExprKind::Path(ref qpath) => match self.typeck.qpath_res(qpath, callee.hir_id) {
+                    Res::Def(DefKind::Ctor(..) | DefKind::Variant, _) | Res::SelfCtor(_) => {},
+                    Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => {
+                        let args: Vec<_> = args.iter().collect();
+                        self.call(def_id, self.typeck.node_substs(callee.hir_id), &args);
+                    },
+                    _ => self.effects.calls_unknown = true,
+                },
+                _ => self.effects.calls_unknown = true,
+            },
+            ExprKind::MethodCall(_, recv, args, _) => {
+                let args: Vec<_> = iter::once(recv).chain(args).collect();
+                self.method_call(e, &args);
+            },
+            ExprKind::Binary(op, lhs, _) => {
+                if matches!(
+                    op.node,
+                    BinOpKind::Add
+                        | BinOpKind::Sub
+                        | BinOpKind::Mul
+                        | BinOpKind::Div
+                        | BinOpKind::Rem
+                        | BinOpKind::Shl
+                        | BinOpKind::Shr
+                ) && self.may_overflow(e, lhs)
+                {
+                    self.effects.may_panic = true;
+                }
+            },
+            ExprKind::Unary(UnOp::Neg, operand) => {
+                if self.may_overflow(e, operand) {
+                    self.effects.may_panic = true;
+                }
+            },
+            ExprKind::AssignOp(op, place, _) => {
+                self.assign(place);
+                if !matches!(op.node, BinOpKind::BitAnd | BinOpKind::BitOr | BinOpKind::BitXor)
+                    && self.typeck.expr_ty(place).is_integral()
+                {
+                    self.effects.may_panic = true;
+                }
+            },
+            ExprKind::Assign(place, ..) => self.assign(place),
+            ExprKind::Index(..) => self.effects.may_panic = true,
+            ExprKind::Loop(block, ..) | ExprKind::Block(block, Some(_)) => {
+                self.scopes.insert(e.hir_id);
+                self.scopes.insert(block.hir_id);
+            },
+            ExprKind::Break(dest, _) | ExprKind::Continue(dest) => {
+                if dest.target_id.map_or(true, |id| !self.scopes.contains(&id)) {
+                    self.effects.control_flow = true;
+                }
+            },
+            ExprKind::Ret(_) if !self.is_body => self.effects.control_flow = true,
+            ExprKind::Yield(..) => self.effects.control_flow = true,
+            ExprKind::InlineAsm(_) => self.effects.calls_unknown = true,
+            _ => {},
+        }
+        walk_expr(self, e);
+    }
+}
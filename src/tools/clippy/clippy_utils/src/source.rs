@@ -5,6 +5,7 @@
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::Applicability;
 use rustc_hir::{BlockCheckMode, Expr, ExprKind, UnsafeSource};
+use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, LintContext};
 use rustc_session::Session;
 use rustc_span::source_map::{original_sp, SourceMap};
@@ -225,6 +226,113 @@ fn reindent_multiline_inner(s: &str, ignore_first: bool, indent: Option<usize>,
         .join("\n")
 }
 
+/// Same as `reindent_multiline`, but lines starting within a block comment or a string literal are
+/// left as they are, and don't count towards the current indentation. Reindenting those would
+/// change the layout of the comment, or the value of the string.
+pub fn reindent_preserving_comments(s: &str, ignore_first: bool, indent: Option<usize>) -> String {
+    // The byte offsets of the lines starting within a token.
+    let mut verbatim_lines = Vec::new();
+    let mut pos = 0;
+    for token in tokenize(s) {
+        let end = pos + token.len as usize;
+        if matches!(token.kind, TokenKind::BlockComment { .. } | TokenKind::Literal { .. }) {
+            verbatim_lines.extend(s[pos..end].match_indices('\n').map(|(i, _)| pos + i + 1));
+        }
+        pos = end;
+    }
+
+    let mut line_start = 0;
+    let lines: Vec<_> = s
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            let verbatim = (ignore_first && i == 0) || verbatim_lines.contains(&line_start);
+            line_start += line.len() + 1;
+            (line, verbatim)
+        })
+        .collect();
+    let indent_len = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let current = lines
+        .iter()
+        .filter(|&&(line, verbatim)| !verbatim && !line.trim().is_empty())
+        .map(|&(line, _)| indent_len(line))
+        .min()
+        .unwrap_or(0);
+    let indent = indent.unwrap_or(0);
+    lines
+        .iter()
+        .map(|&(line, verbatim)| {
+            if verbatim || line.trim().is_empty() {
+                line.to_owned()
+            } else if current > indent {
+                line[current - indent..].to_owned()
+            } else {
+                " ".repeat(indent - current) + line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Gets the comments within `span` which aren't within any of the `kept` spans, in source order.
+pub fn comments_outside(cx: &impl LintContext, span: Span, kept: &[Span]) -> Vec<String> {
+    let Some(snippet) = snippet_opt(cx, span) else {
+        return Vec::new();
+    };
+    let mut comments = Vec::new();
+    let mut pos = span.lo();
+    for token in tokenize(&snippet) {
+        let end = pos + BytePos(token.len);
+        let is_comment = matches!(
+            token.kind,
+            TokenKind::BlockComment { .. } | TokenKind::LineComment { .. }
+        );
+        if is_comment && !kept.iter().any(|kept| kept.lo() <= pos && end <= kept.hi()) {
+            let start = (pos - span.lo()).to_usize();
+            comments.push(snippet[start..start + token.len as usize].to_owned());
+        }
+        pos = end;
+    }
+    comments
+}
+
+/// Prepends the comments within `replaced` which aren't within any of the `kept` spans to `sugg`,
+/// the suggestion replacing it. Applying the suggestion would otherwise delete these comments.
+///
+/// Block comments are kept on the same line as the suggestion. Line comments are followed by a
+/// line break and the indentation of `replaced`.
+pub fn sugg_with_comments(cx: &impl LintContext, replaced: Span, kept: &[Span], sugg: String) -> String {
+    let comments = comments_outside(cx, replaced, kept);
+    if comments.is_empty() {
+        return sugg;
+    }
+    let indent = snippet_indent(cx, replaced).unwrap_or_default();
+    let mut res = String::new();
+    for comment in comments {
+        res.push_str(&comment);
+        if comment.starts_with("//") {
+            res.push('\n');
+            res.push_str(&indent);
+        } else {
+            res.push(' ');
+        }
+    }
+    res + &sugg
+}
+
+/// Same as `snippet_with_applicability`, but the snippet is preceded by the comments within
+/// `replaced` which are outside of `span`. See `sugg_with_comments`.
+pub fn snippet_with_comments(
+    cx: &impl LintContext,
+    span: Span,
+    replaced: Span,
+    default: &str,
+    applicability: &mut Applicability,
+) -> String {
+    let snippet = snippet_with_applicability(cx, span, default, applicability).into_owned();
+    sugg_with_comments(cx, replaced, &[span], snippet)
+}
+
 /// Converts a span to a code snippet if available, otherwise returns the default.
 ///
 /// This is useful if you want to provide suggestions for your lint or more generally, if you want
@@ -359,7 +467,7 @@ pub fn snippet_block_with_context<'a>(
 ) -> (Cow<'a, str>, bool) {
     let (snip, from_macro) = snippet_with_context(cx, span, outer, default, app);
     let indent = indent_relative_to.and_then(|s| indent_of(cx, s));
-    (reindent_multiline(snip, true, indent), from_macro)
+    (reindent_preserving_comments(&snip, true, indent).into(), from_macro)
 }
 
 /// Same as `snippet_with_applicability`, but first walks the span up to the given context. This
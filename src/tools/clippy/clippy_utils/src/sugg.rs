@@ -4,7 +4,8 @@
 use crate::source::{snippet, snippet_opt, snippet_with_applicability, snippet_with_context};
 use crate::ty::expr_sig;
 use crate::{get_parent_expr_for_hir, higher};
-use rustc_ast::util::parser::AssocOp;
+use itertools::Itertools;
+use rustc_ast::util::parser::{AssocOp, PREC_CLOSURE, PREC_PAREN, PREC_PREFIX, PREC_RANGE};
 use rustc_ast::{ast, token};
 use rustc_ast_pretty::pprust::token_kind_to_string;
 use rustc_errors::Applicability;
@@ -28,6 +29,9 @@ pub enum Sugg<'a> {
     NonParen(Cow<'a, str>),
     /// An expression that does not fit in other variants.
     MaybeParen(Cow<'a, str>),
+    /// An expression starting with a prefix operator such as `!x`, `-x`, `*x` or `&x`. These bind
+    /// tighter than any binary operator, but not as tight as a method call or a field access.
+    Prefix(Cow<'a, str>),
     /// A binary operator expression, including `as`-casts and explicit type
     /// coercion.
     BinOp(AssocOp, Cow<'a, str>, Cow<'a, str>),
@@ -43,7 +47,7 @@ pub enum Sugg<'a> {
 impl Display for Sugg<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match *self {
-            Sugg::NonParen(ref s) | Sugg::MaybeParen(ref s) => s.fmt(f),
+            Sugg::NonParen(ref s) | Sugg::MaybeParen(ref s) | Sugg::Prefix(ref s) => s.fmt(f),
             Sugg::BinOp(op, ref lhs, ref rhs) => binop_to_string(op, lhs, rhs).fmt(f),
         }
     }
@@ -124,11 +128,10 @@ fn hir_from_snippet(expr: &hir::Expr<'_>, get_snippet: impl Fn(Span) -> Cow<'a,
         }
 
         match expr.kind {
-            hir::ExprKind::AddrOf(..)
-            | hir::ExprKind::If(..)
+            hir::ExprKind::AddrOf(..) | hir::ExprKind::Unary(..) => Sugg::Prefix(get_snippet(expr.span)),
+            hir::ExprKind::If(..)
             | hir::ExprKind::Let(..)
             | hir::ExprKind::Closure { .. }
-            | hir::ExprKind::Unary(..)
             | hir::ExprKind::Match(..) => Sugg::MaybeParen(get_snippet(expr.span)),
             hir::ExprKind::Continue(..)
             | hir::ExprKind::Yield(..)
@@ -181,11 +184,15 @@ pub fn ast(
 
         match expr.kind {
             _ if expr.span.ctxt() != ctxt => Sugg::NonParen(snippet_with_context(cx, expr.span, ctxt, default, app).0),
-            ast::ExprKind::AddrOf(..)
-            | ast::ExprKind::Closure { .. }
+            ast::ExprKind::AddrOf(..) | ast::ExprKind::Unary(..) => {
+                match snippet_with_context(cx, expr.span, ctxt, default, app) {
+                    (snip, false) => Sugg::Prefix(snip),
+                    (snip, true) => Sugg::NonParen(snip),
+                }
+            },
+            ast::ExprKind::Closure { .. }
             | ast::ExprKind::If(..)
             | ast::ExprKind::Let(..)
-            | ast::ExprKind::Unary(..)
             | ast::ExprKind::Match(..) => match snippet_with_context(cx, expr.span, ctxt, default, app) {
                 (snip, false) => Sugg::MaybeParen(snip),
                 (snip, true) => Sugg::NonParen(snip),
@@ -281,6 +288,12 @@ pub fn as_ty<R: Display>(self, rhs: R) -> Sugg<'static> {
         make_assoc(AssocOp::As, &self, &Sugg::NonParen(rhs.to_string().into()))
     }
 
+    /// Convenience method to create the `<expr>.<method>(<args>)` suggestion. Calls can be chained
+    /// to build a method chain, e.g. `sugg.method_call("iter", &[]).method_call("count", &[])`.
+    pub fn method_call(self, method: &str, args: &[Sugg<'_>]) -> Sugg<'static> {
+        Sugg::NonParen(format!("{}.{method}({})", self.maybe_par(), args.iter().join(", ")).into())
+    }
+
     /// Convenience method to create the `&<expr>` suggestion.
     pub fn addr(self) -> Sugg<'static> {
         make_unop("&", self)
@@ -312,7 +325,7 @@ pub fn mut_addr_deref(self) -> Sugg<'static> {
 
     /// Convenience method to transform suggestion into a return call
     pub fn make_return(self) -> Sugg<'static> {
-        Sugg::NonParen(Cow::Owned(format!("return {self}")))
+        Sugg::MaybeParen(Cow::Owned(format!("return {self}")))
     }
 
     /// Convenience method to transform suggestion into a block
@@ -336,6 +349,52 @@ pub fn range(self, end: &Self, limit: ast::RangeLimits) -> Sugg<'static> {
         }
     }
 
+    /// Returns the precedence of the expression, using the same scale as the parser. An operand
+    /// needs parentheses if its precedence is lower than the one of the operator it's used with.
+    pub fn precedence(&self) -> i8 {
+        match *self {
+            Sugg::NonParen(_) => PREC_PAREN,
+            Sugg::MaybeParen(ref sugg) if has_enclosing_paren(sugg) => PREC_PAREN,
+            // This covers closures, `if`, `match` and jumps, so assume the lowest precedence.
+            Sugg::MaybeParen(_) => PREC_CLOSURE,
+            Sugg::Prefix(_) => PREC_PREFIX,
+            Sugg::BinOp(op, ..) => assoc_op_precedence(op),
+        }
+    }
+
+    /// Adds parentheses if the suggestion needs them to replace `expr`, depending on how `expr` is
+    /// used by its parent expression. Note that the span of an expression in parentheses includes
+    /// the parentheses, so they're replaced along with it.
+    #[must_use]
+    pub fn maybe_par_for(self, cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Self {
+        let Some(parent) = get_parent_expr_for_hir(cx, expr.hir_id) else {
+            return self;
+        };
+        let is_expr = |e: &hir::Expr<'_>| e.hir_id == expr.hir_id;
+        let paren = match parent.kind {
+            hir::ExprKind::MethodCall(_, e, ..)
+            | hir::ExprKind::Field(e, _)
+            | hir::ExprKind::Index(e, _)
+            | hir::ExprKind::Call(e, _) => is_expr(e),
+            hir::ExprKind::Unary(..) | hir::ExprKind::AddrOf(..) => self.precedence() < PREC_PREFIX,
+            hir::ExprKind::Binary(op, lhs, _) => {
+                let dir = if is_expr(lhs) {
+                    Associativity::Left
+                } else {
+                    Associativity::Right
+                };
+                operand_needs_paren(AssocOp::from_ast_binop(op.node.into()), &self, dir)
+            },
+            hir::ExprKind::Cast(..) => operand_needs_paren(AssocOp::As, &self, Associativity::Left),
+            _ => false,
+        };
+        if paren {
+            self.maybe_par()
+        } else {
+            self
+        }
+    }
+
     /// Adds parentheses to any expression that might need them. Suitable to the
     /// `self` argument of a method call
     /// (e.g., to build `bar.foo()` or `(1 + 2).foo()`).
@@ -344,7 +403,7 @@ pub fn maybe_par(self) -> Self {
         match self {
             Sugg::NonParen(..) => self,
             // `(x)` and `(x).y()` both don't need additional parens.
-            Sugg::MaybeParen(sugg) => {
+            Sugg::MaybeParen(sugg) | Sugg::Prefix(sugg) => {
                 if has_enclosing_paren(&sugg) {
                     Sugg::MaybeParen(sugg)
                 } else {
@@ -395,6 +454,15 @@ fn binop_to_string(op: AssocOp, lhs: &str, rhs: &str) -> String {
     }
 }
 
+/// Returns the precedence of a binary operator, on the same scale as `Sugg::precedence`.
+#[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn assoc_op_precedence(op: AssocOp) -> i8 {
+    match op {
+        AssocOp::DotDot | AssocOp::DotDotEq => PREC_RANGE,
+        _ => op.precedence() as i8,
+    }
+}
+
 /// Return `true` if `sugg` is enclosed in parenthesis.
 pub fn has_enclosing_paren(sugg: impl AsRef<str>) -> bool {
     let mut chars = sugg.as_ref().chars();
@@ -522,7 +590,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
 /// operators have the same
 /// precedence.
 pub fn make_unop(op: &str, expr: Sugg<'_>) -> Sugg<'static> {
-    Sugg::MaybeParen(format!("{op}{}", expr.maybe_par()).into())
+    Sugg::Prefix(format!("{op}{}", expr.maybe_par()).into())
 }
 
 /// Builds the string for `<lhs> <op> <rhs>` adding parenthesis when necessary.
@@ -531,6 +599,17 @@ pub fn make_unop(op: &str, expr: Sugg<'_>) -> Sugg<'static> {
 /// often confusing so
 /// parenthesis will always be added for a mix of these.
 pub fn make_assoc(op: AssocOp, lhs: &Sugg<'_>, rhs: &Sugg<'_>) -> Sugg<'static> {
+    let lhs_paren = operand_needs_paren(op, lhs, Associativity::Left);
+    let rhs_paren = operand_needs_paren(op, rhs, Associativity::Right);
+
+    let lhs = ParenHelper::new(lhs_paren, lhs).to_string();
+    let rhs = ParenHelper::new(rhs_paren, rhs).to_string();
+    Sugg::BinOp(op, lhs.into(), rhs.into())
+}
+
+/// Returns `true` if `operand` needs parentheses when used as the operand of `op` in the direction
+/// `dir`.
+fn operand_needs_paren(op: AssocOp, operand: &Sugg<'_>, dir: Associativity) -> bool {
     /// Returns `true` if the operator is a shift operator `<<` or `>>`.
     fn is_shift(op: AssocOp) -> bool {
         matches!(op, AssocOp::ShiftLeft | AssocOp::ShiftRight)
@@ -556,21 +635,11 @@ fn needs_paren(op: AssocOp, other: AssocOp, dir: Associativity) -> bool {
             || is_shift(other) && is_arith(op)
     }
 
-    let lhs_paren = if let Sugg::BinOp(lop, _, _) = *lhs {
-        needs_paren(op, lop, Associativity::Left)
-    } else {
-        false
-    };
-
-    let rhs_paren = if let Sugg::BinOp(rop, _, _) = *rhs {
-        needs_paren(op, rop, Associativity::Right)
+    if let Sugg::BinOp(other, _, _) = *operand {
+        needs_paren(op, other, dir)
     } else {
-        false
-    };
-
-    let lhs = ParenHelper::new(lhs_paren, lhs).to_string();
-    let rhs = ParenHelper::new(rhs_paren, rhs).to_string();
-    Sugg::BinOp(op, lhs.into(), rhs.into())
+        operand.precedence() < assoc_op_precedence(op)
+    }
 }
 
 /// Convenience wrapper around `make_assoc` and `AssocOp::from_ast_binop`.
@@ -901,7 +970,7 @@ fn func_takes_arg_by_double_ref(&self, parent_expr: &'tcx hir::Expr<'_>, cmt_hir
             _ => return false,
         };
 
-        ty.map_or(false, |ty| matches!(ty.kind(), ty::Ref(_, inner, _) if inner.is_ref()))
+        ty.is_some_and(|ty| matches!(ty.kind(), ty::Ref(_, inner, _) if inner.is_ref()))
     }
 }
 
@@ -1059,8 +1128,9 @@ fn fake_read(&mut self, _: &PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
 
 #[cfg(test)]
 mod test {
-    use super::Sugg;
+    use super::{make_binop, Sugg, ONE};
 
+    use rustc_ast::ast::BinOpKind;
     use rustc_ast::util::parser::AssocOp;
     use std::borrow::Cow;
 
@@ -1084,6 +1154,20 @@ fn binop_maybe_par() {
         let sugg = Sugg::BinOp(AssocOp::Add, "(1 + 1)".into(), "(1 + 1)".into());
         assert_eq!("((1 + 1) + (1 + 1))", sugg.maybe_par().to_string());
     }
+
+    #[test]
+    fn operand_precedence() {
+        let prefix = Sugg::Prefix("-x".into());
+        let if_expr = Sugg::MaybeParen("if x { 1 } else { 2 }".into());
+
+        assert_eq!("-x + 1", make_binop(BinOpKind::Add, &prefix, &ONE).to_string());
+        assert_eq!(
+            "(if x { 1 } else { 2 }) + 1",
+            make_binop(BinOpKind::Add, &if_expr, &ONE).to_string()
+        );
+        assert_eq!("-x as u8", prefix.clone().as_ty("u8").to_string());
+        assert_eq!("(-x).pow(1)", prefix.method_call("pow", &[ONE]).to_string());
+    }
     #[test]
     fn not_op() {
         use AssocOp::{Add, Equal, Greater, GreaterEqual, LAnd, LOr, Less, LessEqual, NotEqual};
@@ -15,6 +15,7 @@
 };
 use rustc_lint::LateContext;
 use rustc_middle::mir::interpret::{ConstValue, Scalar};
+use rustc_middle::ty::layout::{LayoutCx, LayoutOf};
 use rustc_middle::ty::{
     self, layout::ValidityRequirement, AdtDef, AliasTy, AssocKind, Binder, BoundRegion, FnSig, IntTy, List, ParamEnv,
     Region, RegionKind, SubstsRef, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypeVisitor,
@@ -39,7 +40,7 @@ pub fn is_copy<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
 pub fn has_debug_impl<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     cx.tcx
         .get_diagnostic_item(sym::Debug)
-        .map_or(false, |debug| implements_trait(cx, ty, debug, &[]))
+        .is_some_and(|debug| implements_trait(cx, ty, debug, &[]))
 }
 
 /// Checks whether a type can be partially moved.
@@ -199,6 +200,7 @@ pub fn has_iter_method(cx: &LateContext<'_>, probably_ref_ty: Ty<'_>) -> Option<
 ///
 /// See:
 /// * [`get_trait_def_id`](super::get_trait_def_id) to get a trait [`DefId`].
+/// * [`ImplementsTraitCache`] to cache the results.
 /// * [Common tools for writing lints] for an example how to use this function and other options.
 ///
 /// [Common tools for writing lints]: https://github.com/rust-lang/rust-clippy/blob/master/book/src/development/common_tools_writing_lints.md#checking-if-a-type-implements-a-specific-trait
@@ -247,6 +249,54 @@ pub fn implements_trait_with_env<'tcx>(
         .must_apply_modulo_regions()
 }
 
+/// Caches the results of `implements_trait`. Lints checking many items tend to ask whether the same
+/// types implement the same traits over and over again, e.g. for the types of struct fields. The
+/// cache is meant to be kept by a lint pass for the whole crate.
+#[derive(Default)]
+pub struct ImplementsTraitCache<'tcx> {
+    results: FxHashMap<ImplementsTraitKey<'tcx>, bool>,
+}
+
+/// The arguments of `implements_trait_with_env` the cache is keyed on.
+type ImplementsTraitKey<'tcx> = (ParamEnv<'tcx>, Ty<'tcx>, DefId, Vec<Option<GenericArg<'tcx>>>);
+
+impl<'tcx> ImplementsTraitCache<'tcx> {
+    /// Same as `implements_trait`, but caches the result.
+    pub fn implements_trait(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+        trait_id: DefId,
+        ty_params: &[GenericArg<'tcx>],
+    ) -> bool {
+        self.implements_trait_with_env(
+            cx.tcx,
+            cx.param_env,
+            ty,
+            trait_id,
+            ty_params.iter().map(|&arg| Some(arg)),
+        )
+    }
+
+    /// Same as `implements_trait_with_env`, but caches the result.
+    pub fn implements_trait_with_env(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        param_env: ParamEnv<'tcx>,
+        ty: Ty<'tcx>,
+        trait_id: DefId,
+        ty_params: impl IntoIterator<Item = Option<GenericArg<'tcx>>>,
+    ) -> bool {
+        let key = (param_env, ty, trait_id, ty_params.into_iter().collect());
+        *self
+            .results
+            .entry(key)
+            .or_insert_with_key(|(param_env, ty, trait_id, ty_params)| {
+                implements_trait_with_env(tcx, *param_env, *ty, *trait_id, ty_params.iter().copied())
+            })
+    }
+}
+
 /// Checks whether this type implements `Drop`.
 pub fn has_drop<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     match ty.ty_adt_def() {
@@ -438,7 +488,7 @@ fn needs_ordered_drop_inner<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, seen: &m
             .tcx
             .lang_items()
             .drop_trait()
-            .map_or(false, |id| implements_trait(cx, ty, id, &[]))
+            .is_some_and(|id| implements_trait(cx, ty, id, &[]))
         {
             // This type doesn't implement drop, so no side effects here.
             // Check if any component type has any.
@@ -679,7 +729,7 @@ pub fn ty_sig<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<ExprFnSig<'t
                 {
                     let output = bounds
                         .projection_bounds()
-                        .find(|p| lang_items.fn_once_output().map_or(false, |id| id == p.item_def_id()))
+                        .find(|p| lang_items.fn_once_output().is_some_and(|id| id == p.item_def_id()))
                         .map(|p| p.map_bound(|p| p.term.ty().unwrap()));
                     Some(ExprFnSig::Trait(bound.map_bound(|b| b.substs.type_at(0)), output, None))
                 },
@@ -714,7 +764,7 @@ fn sig_from_bounds<'tcx>(
                     && p.self_ty() == ty =>
             {
                 let i = pred.kind().rebind(p.trait_ref.substs.type_at(1));
-                if inputs.map_or(false, |inputs| i != inputs) {
+                if inputs.is_some_and(|inputs| i != inputs) {
                     // Multiple different fn trait impls. Is this even allowed?
                     return None;
                 }
@@ -754,7 +804,7 @@ fn sig_for_projection<'tcx>(cx: &LateContext<'tcx>, ty: AliasTy<'tcx>) -> Option
             {
                 let i = pred.kind().rebind(p.trait_ref.substs.type_at(1));
 
-                if inputs.map_or(false, |inputs| inputs != i) {
+                if inputs.is_some_and(|inputs| inputs != i) {
                     // Multiple different fn trait impls. Is this even allowed?
                     return None;
                 }
@@ -964,20 +1014,43 @@ pub fn ty_is_fn_once_param<'tcx>(tcx: TyCtxt<'_>, ty: Ty<'tcx>, predicates: &'tc
         .unwrap_or(false)
 }
 
+/// Computes the size of the type on the compilation target, in bytes. Returns `None` if it
+/// depends on generic parameters which aren't constrained by the `ParamEnv`, e.g. `T` but not `&T`
+/// if `T: Sized`. For an unsized type, this is the size of its sized prefix.
+///
+/// Use [`approx_ty_size`] to get a lower bound of the size of any type.
+pub fn ty_size<'tcx>(cx: &LateContext<'tcx>, param_env: ParamEnv<'tcx>, ty: Ty<'tcx>) -> Option<u64> {
+    if !is_normalizable(cx, param_env, ty) {
+        return None;
+    }
+    let lcx = LayoutCx { tcx: cx.tcx, param_env };
+    lcx.layout_of(ty).ok().map(|layout| layout.size.bytes())
+}
+
+/// Computes the ABI-required alignment of the type on the compilation target, in bytes. Returns
+/// `None` in the same cases as [`ty_size`]. For an unsized type, this is the alignment of its
+/// sized prefix.
+pub fn ty_align<'tcx>(cx: &LateContext<'tcx>, param_env: ParamEnv<'tcx>, ty: Ty<'tcx>) -> Option<u64> {
+    if !is_normalizable(cx, param_env, ty) {
+        return None;
+    }
+    let lcx = LayoutCx { tcx: cx.tcx, param_env };
+    lcx.layout_of(ty).ok().map(|layout| layout.align.abi.bytes())
+}
+
 /// Comes up with an "at least" guesstimate for the type's size, not taking into
 /// account the layout of type parameters.
 pub fn approx_ty_size<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> u64 {
-    use rustc_middle::ty::layout::LayoutOf;
     if !is_normalizable(cx, cx.param_env, ty) {
         return 0;
     }
-    match (cx.layout_of(ty).map(|layout| layout.size.bytes()), ty.kind()) {
-        (Ok(size), _) => size,
-        (Err(_), ty::Tuple(list)) => list.iter().map(|t| approx_ty_size(cx, t)).sum(),
-        (Err(_), ty::Array(t, n)) => {
+    match (ty_size(cx, cx.param_env, ty), ty.kind()) {
+        (Some(size), _) => size,
+        (None, ty::Tuple(list)) => list.iter().map(|t| approx_ty_size(cx, t)).sum(),
+        (None, ty::Array(t, n)) => {
             n.try_eval_target_usize(cx.tcx, cx.param_env).unwrap_or_default() * approx_ty_size(cx, *t)
         },
-        (Err(_), ty::Adt(def, subst)) if def.is_struct() => def
+        (None, ty::Adt(def, subst)) if def.is_struct() => def
             .variants()
             .iter()
             .map(|v| {
@@ -987,7 +1060,7 @@ pub fn approx_ty_size<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> u64 {
                     .sum::<u64>()
             })
             .sum(),
-        (Err(_), ty::Adt(def, subst)) if def.is_enum() => def
+        (None, ty::Adt(def, subst)) if def.is_enum() => def
             .variants()
             .iter()
             .map(|v| {
@@ -998,7 +1071,7 @@ pub fn approx_ty_size<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> u64 {
             })
             .max()
             .unwrap_or_default(),
-        (Err(_), ty::Adt(def, subst)) if def.is_union() => def
+        (None, ty::Adt(def, subst)) if def.is_union() => def
             .variants()
             .iter()
             .map(|v| {
@@ -1010,7 +1083,7 @@ pub fn approx_ty_size<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> u64 {
             })
             .max()
             .unwrap_or_default(),
-        (Err(_), _) => 0,
+        (None, _) => 0,
     }
 }
 
@@ -5,8 +5,8 @@
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::intravisit::{self, walk_block, walk_expr, Visitor};
 use rustc_hir::{
-    AnonConst, Arm, Block, BlockCheckMode, Body, BodyId, Expr, ExprKind, HirId, ItemId, ItemKind, Let, Pat, QPath,
-    Stmt, UnOp, UnsafeSource, Unsafety,
+    AnonConst, Arm, AsyncGeneratorKind, Block, BlockCheckMode, Body, BodyId, Closure, Expr, ExprKind, GeneratorKind,
+    HirId, ItemId, ItemKind, Let, Pat, QPath, Stmt, UnOp, UnsafeSource, Unsafety,
 };
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
@@ -67,38 +67,239 @@ fn visit<V: Visitor<'tcx>>(self, visitor: &mut V) {
 visitable_ref!(Expr, visit_expr);
 visitable_ref!(Stmt, visit_stmt);
 
+/// The position of an expression relative to the `async` blocks and closures containing it, see
+/// [`ExprIter::for_each_with_async_ctxt`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AsyncCtxt {
+    /// The number of entered `async` bodies containing the expression. The body of an `async fn`
+    /// counts as well.
+    pub depth: usize,
+}
+
+/// Tracks whether the bodies checked by a lint pass are `async`, for lints checking the code run by
+/// an executor. A closure inside of async code isn't run by the executor unless it's `async` itself.
+///
+/// [`AsyncBodies::enter_body`] and [`AsyncBodies::exit_body`] are meant to be called from the
+/// pass's `check_body` and `check_body_post`.
+#[derive(Default)]
+pub struct AsyncBodies {
+    /// Whether each of the bodies currently checked is async, the innermost one last.
+    stack: Vec<bool>,
+}
+
+impl AsyncBodies {
+    /// The note for lints about code blocking the executor's thread.
+    pub const BLOCKS_EXECUTOR_NOTE: &'static str = "this blocks the thread of the executor running the async code";
+
+    pub fn enter_body(&mut self, body: &Body<'_>) {
+        self.stack
+            .push(matches!(body.generator_kind, Some(GeneratorKind::Async(_))));
+    }
+
+    pub fn exit_body(&mut self) {
+        self.stack.pop();
+    }
+
+    /// Checks if the innermost body currently checked is async.
+    pub fn in_async_body(&self) -> bool {
+        self.stack.last() == Some(&true)
+    }
+}
+
+/// Configures which nested bodies are entered when visiting the expressions contained in a node.
+/// By default neither the bodies of closures nor those of constants are entered. Nested items are
+/// never entered.
+#[derive(Clone, Copy, Default)]
+pub struct ExprIter<'tcx> {
+    tcx: Option<TyCtxt<'tcx>>,
+    closures: bool,
+    async_bodies: bool,
+    consts: bool,
+}
+
+impl<'tcx> ExprIter<'tcx> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enters the bodies of closures.
+    #[must_use]
+    pub fn closures(self, cx: &LateContext<'tcx>) -> Self {
+        Self {
+            tcx: Some(cx.tcx),
+            closures: true,
+            ..self
+        }
+    }
+
+    /// Enters the bodies of `async` blocks and closures, but not those of other closures.
+    #[must_use]
+    pub fn async_bodies(self, cx: &LateContext<'tcx>) -> Self {
+        Self {
+            tcx: Some(cx.tcx),
+            async_bodies: true,
+            ..self
+        }
+    }
+
+    /// Enters the bodies of constants, e.g. array lengths and inline `const` blocks.
+    #[must_use]
+    pub fn consts(self, cx: &LateContext<'tcx>) -> Self {
+        Self {
+            tcx: Some(cx.tcx),
+            consts: true,
+            ..self
+        }
+    }
+
+    /// Calls the given function once for each expression contained, before any of its
+    /// subexpressions.
+    pub fn for_each<B, C: Continue>(
+        self,
+        node: impl Visitable<'tcx>,
+        mut f: impl FnMut(&'tcx Expr<'tcx>) -> ControlFlow<B, C>,
+    ) -> Option<B> {
+        self.visit(node, false, |e, _| f(e))
+    }
+
+    /// Calls the given function once for each expression contained, before any of its
+    /// subexpressions. The function also receives the position of the expression relative to the
+    /// entered `async` bodies.
+    pub fn for_each_with_async_ctxt<B, C: Continue>(
+        self,
+        node: impl Visitable<'tcx>,
+        f: impl FnMut(&'tcx Expr<'tcx>, AsyncCtxt) -> ControlFlow<B, C>,
+    ) -> Option<B> {
+        self.visit(node, false, f)
+    }
+
+    /// Calls the given function once for each expression contained, after all of its
+    /// subexpressions.
+    pub fn for_each_postorder<B>(
+        self,
+        node: impl Visitable<'tcx>,
+        mut f: impl FnMut(&'tcx Expr<'tcx>) -> ControlFlow<B>,
+    ) -> Option<B> {
+        self.visit(node, true, |e, _| f(e))
+    }
+
+    fn visit<B, C: Continue>(
+        self,
+        node: impl Visitable<'tcx>,
+        postorder: bool,
+        f: impl FnMut(&'tcx Expr<'tcx>, AsyncCtxt) -> ControlFlow<B, C>,
+    ) -> Option<B> {
+        struct V<'tcx, B, F> {
+            iter: ExprIter<'tcx>,
+            postorder: bool,
+            async_ctxt: AsyncCtxt,
+            f: F,
+            res: Option<B>,
+        }
+        impl<'tcx, B, F> V<'tcx, B, F> {
+            fn enters_closure(&self, closure: &Closure<'_>) -> bool {
+                self.iter.closures
+                    || (self.iter.async_bodies && self.iter.tcx.is_some_and(|tcx| is_async_closure(tcx, closure)))
+            }
+        }
+        impl<'tcx, B, C: Continue, F: FnMut(&'tcx Expr<'tcx>, AsyncCtxt) -> ControlFlow<B, C>> Visitor<'tcx>
+            for V<'tcx, B, F>
+        {
+            fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+                if self.res.is_some() {
+                    return;
+                }
+                let descend = match e.kind {
+                    ExprKind::Closure(closure) => self.enters_closure(closure),
+                    _ => true,
+                };
+                if self.postorder {
+                    if descend {
+                        walk_expr(self, e);
+                    }
+                    if self.res.is_none()
+                        && let ControlFlow::Break(b) = (self.f)(e, self.async_ctxt)
+                    {
+                        self.res = Some(b);
+                    }
+                } else {
+                    match (self.f)(e, self.async_ctxt) {
+                        ControlFlow::Continue(c) if c.descend() && descend => walk_expr(self, e),
+                        ControlFlow::Break(b) => self.res = Some(b),
+                        ControlFlow::Continue(_) => (),
+                    }
+                }
+            }
+
+            fn visit_anon_const(&mut self, c: &'tcx AnonConst) {
+                if self.iter.consts {
+                    self.visit_nested_body(c.body);
+                }
+            }
+            fn visit_nested_body(&mut self, id: BodyId) {
+                if let Some(tcx) = self.iter.tcx {
+                    let body = tcx.hir().body(id);
+                    if let Some(GeneratorKind::Async(_)) = body.generator_kind {
+                        self.async_ctxt.depth += 1;
+                        self.visit_body(body);
+                        self.async_ctxt.depth -= 1;
+                    } else {
+                        self.visit_body(body);
+                    }
+                }
+            }
+
+            // Avoid unnecessary `walk_*` calls.
+            fn visit_ty(&mut self, _: &'tcx hir::Ty<'tcx>) {}
+            fn visit_pat(&mut self, _: &'tcx Pat<'tcx>) {}
+            fn visit_qpath(&mut self, _: &'tcx QPath<'tcx>, _: HirId, _: Span) {}
+            // Avoid monomorphising all `visit_*` functions.
+            fn visit_nested_item(&mut self, _: ItemId) {}
+        }
+        let mut v = V {
+            iter: self,
+            postorder,
+            async_ctxt: AsyncCtxt::default(),
+            f,
+            res: None,
+        };
+        node.visit(&mut v);
+        v.res
+    }
+}
+
+/// Checks if the closure is an `async` block, or the closure of an `async fn` or `async` closure.
+fn is_async_closure(tcx: TyCtxt<'_>, closure: &Closure<'_>) -> bool {
+    let body = tcx.hir().body(closure.body);
+    match body.generator_kind {
+        Some(GeneratorKind::Async(_)) => true,
+        // `async` closures are lowered to closures returning an `async` block.
+        None => matches!(
+            body.value.kind,
+            ExprKind::Closure(inner)
+                if tcx.hir().body(inner.body).generator_kind
+                    == Some(GeneratorKind::Async(AsyncGeneratorKind::Closure))
+        ),
+        Some(_) => false,
+    }
+}
+
 /// Calls the given function once for each expression contained. This does not enter any bodies or
 /// nested items.
 pub fn for_each_expr<'tcx, B, C: Continue>(
     node: impl Visitable<'tcx>,
     f: impl FnMut(&'tcx Expr<'tcx>) -> ControlFlow<B, C>,
 ) -> Option<B> {
-    struct V<B, F> {
-        f: F,
-        res: Option<B>,
-    }
-    impl<'tcx, B, C: Continue, F: FnMut(&'tcx Expr<'tcx>) -> ControlFlow<B, C>> Visitor<'tcx> for V<B, F> {
-        fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
-            if self.res.is_some() {
-                return;
-            }
-            match (self.f)(e) {
-                ControlFlow::Continue(c) if c.descend() => walk_expr(self, e),
-                ControlFlow::Break(b) => self.res = Some(b),
-                ControlFlow::Continue(_) => (),
-            }
-        }
+    ExprIter::new().for_each(node, f)
+}
 
-        // Avoid unnecessary `walk_*` calls.
-        fn visit_ty(&mut self, _: &'tcx hir::Ty<'tcx>) {}
-        fn visit_pat(&mut self, _: &'tcx Pat<'tcx>) {}
-        fn visit_qpath(&mut self, _: &'tcx QPath<'tcx>, _: HirId, _: Span) {}
-        // Avoid monomorphising all `visit_*` functions.
-        fn visit_nested_item(&mut self, _: ItemId) {}
-    }
-    let mut v = V { f, res: None };
-    node.visit(&mut v);
-    v.res
+/// Calls the given function once for each expression contained, after all of its subexpressions.
+/// This does not enter any bodies or nested items.
+pub fn for_each_expr_postorder<'tcx, B>(
+    node: impl Visitable<'tcx>,
+    f: impl FnMut(&'tcx Expr<'tcx>) -> ControlFlow<B>,
+) -> Option<B> {
+    ExprIter::new().for_each_postorder(node, f)
 }
 
 /// Calls the given function once for each expression contained. This will enter bodies, but not
@@ -108,44 +309,7 @@ pub fn for_each_expr_with_closures<'tcx, B, C: Continue>(
     node: impl Visitable<'tcx>,
     f: impl FnMut(&'tcx Expr<'tcx>) -> ControlFlow<B, C>,
 ) -> Option<B> {
-    struct V<'tcx, B, F> {
-        tcx: TyCtxt<'tcx>,
-        f: F,
-        res: Option<B>,
-    }
-    impl<'tcx, B, C: Continue, F: FnMut(&'tcx Expr<'tcx>) -> ControlFlow<B, C>> Visitor<'tcx> for V<'tcx, B, F> {
-        type NestedFilter = nested_filter::OnlyBodies;
-        fn nested_visit_map(&mut self) -> Self::Map {
-            self.tcx.hir()
-        }
-
-        fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
-            if self.res.is_some() {
-                return;
-            }
-            match (self.f)(e) {
-                ControlFlow::Continue(c) if c.descend() => walk_expr(self, e),
-                ControlFlow::Break(b) => self.res = Some(b),
-                ControlFlow::Continue(_) => (),
-            }
-        }
-
-        // Only walk closures
-        fn visit_anon_const(&mut self, _: &'tcx AnonConst) {}
-        // Avoid unnecessary `walk_*` calls.
-        fn visit_ty(&mut self, _: &'tcx hir::Ty<'tcx>) {}
-        fn visit_pat(&mut self, _: &'tcx Pat<'tcx>) {}
-        fn visit_qpath(&mut self, _: &'tcx QPath<'tcx>, _: HirId, _: Span) {}
-        // Avoid monomorphising all `visit_*` functions.
-        fn visit_nested_item(&mut self, _: ItemId) {}
-    }
-    let mut v = V {
-        tcx: cx.tcx,
-        f,
-        res: None,
-    };
-    node.visit(&mut v);
-    v.res
+    ExprIter::new().closures(cx).for_each(node, f)
 }
 
 /// returns `true` if expr contains match expr desugared from try
@@ -307,13 +471,13 @@ fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
                     .cx
                     .qpath_res(p, hir_id)
                     .opt_def_id()
-                    .map_or(false, |id| self.cx.tcx.is_const_fn_raw(id)) => {},
+                    .is_some_and(|id| self.cx.tcx.is_const_fn_raw(id)) => {},
                 ExprKind::MethodCall(..)
                     if self
                         .cx
                         .typeck_results()
                         .type_dependent_def_id(e.hir_id)
-                        .map_or(false, |id| self.cx.tcx.is_const_fn_raw(id)) => {},
+                        .is_some_and(|id| self.cx.tcx.is_const_fn_raw(id)) => {},
                 ExprKind::Binary(_, lhs, rhs)
                     if self.cx.typeck_results().expr_ty(lhs).peel_refs().is_primitive_ty()
                         && self.cx.typeck_results().expr_ty(rhs).peel_refs().is_primitive_ty() => {},
@@ -392,9 +556,7 @@ fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
                         .cx
                         .typeck_results()
                         .type_dependent_def_id(e.hir_id)
-                        .map_or(false, |id| {
-                            self.cx.tcx.fn_sig(id).skip_binder().unsafety() == Unsafety::Unsafe
-                        }) =>
+                        .is_some_and(|id| self.cx.tcx.fn_sig(id).skip_binder().unsafety() == Unsafety::Unsafe) =>
                 {
                     self.is_unsafe = true;
                 },
@@ -410,7 +572,7 @@ fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
                         .cx
                         .qpath_res(p, e.hir_id)
                         .opt_def_id()
-                        .map_or(false, |id| self.cx.tcx.is_mutable_static(id)) =>
+                        .is_some_and(|id| self.cx.tcx.is_mutable_static(id)) =>
                 {
                     self.is_unsafe = true;
                 },
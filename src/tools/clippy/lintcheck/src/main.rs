@@ -207,7 +207,7 @@ fn download_and_extract(&self) -> Crate {
                 Crate {
                     version: version.clone(),
                     name: name.clone(),
-                    path: extract_dir.join(format!("{name}-{version}/")),
+                    path: extract_dir.join(format!("{name}-{version}")),
                     options: options.clone(),
                 }
             },
@@ -260,8 +260,7 @@ fn download_and_extract(&self) -> Crate {
             CrateSource::Path { name, path, options } => {
                 fn is_cache_dir(entry: &DirEntry) -> bool {
                     std::fs::read(entry.path().join("CACHEDIR.TAG"))
-                        .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
-                        .unwrap_or(false)
+                        .is_ok_and(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
                 }
 
                 // copy path into the dest_crate_root but skip directories that contain a CACHEDIR.TAG file.
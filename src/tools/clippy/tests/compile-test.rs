@@ -236,6 +236,7 @@ fn main() {
     "map_flatten.rs",
     "map_unwrap_or.rs",
     "match_bool.rs",
+    "match_same_arms_unfixable.rs",
     "mem_replace_macro.rs",
     "needless_arbitrary_self_type_unfixable.rs",
     "needless_borrow_pat.rs",
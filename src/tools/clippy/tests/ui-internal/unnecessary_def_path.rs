@@ -15,7 +15,7 @@
 #[allow(unused)]
 use clippy_utils::{
     is_expr_path_def_path, is_path_diagnostic_item, is_res_diagnostic_ctor, is_res_lang_ctor, is_trait_method,
-    match_def_path, match_trait_method, path_res,
+    match_any_def_paths, match_any_diagnostic_items, match_def_path, match_trait_method, path_res,
 };
 
 #[allow(unused)]
@@ -57,6 +57,8 @@ fn _f<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, did: DefId, expr: &Expr<'_>) {
     let _ = is_expr_path_def_path(cx, expr, &["core", "option", "Option"]);
     let _ = is_expr_path_def_path(cx, expr, &["core", "iter", "traits", "Iterator", "next"]);
     let _ = is_expr_path_def_path(cx, expr, &["core", "option", "Option", "Some"]);
+
+    let _ = match_any_def_paths(cx, did, &[&["alloc", "vec", "Vec"], &["alloc", "rc", "Rc"]]);
 }
 
 fn main() {}
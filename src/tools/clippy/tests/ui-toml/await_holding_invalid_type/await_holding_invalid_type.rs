@@ -33,9 +33,19 @@ fn block_bad() -> impl std::future::Future<Output = u32> {
     }
 }
 
+fn make_string() -> impl Sized {
+    String::from("hi!")
+}
+
+async fn bad_helper() -> u32 {
+    let _x = make_string();
+    baz().await
+}
+
 fn main() {
     good();
     bad();
     bad_reason();
     block_bad();
+    bad_helper();
 }
@@ -0,0 +1,27 @@
+#![warn(clippy::block_on_in_async)]
+
+mod executor {
+    use std::future::Future;
+
+    pub fn run<F: Future>(_future: F) -> F::Output {
+        unimplemented!()
+    }
+
+    pub fn wait_all() {}
+}
+
+async fn load() -> u32 {
+    42
+}
+
+async fn bad() -> u32 {
+    executor::wait_all();
+    executor::run(load())
+}
+
+fn good() -> u32 {
+    executor::wait_all();
+    executor::run(load())
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![warn(clippy::detached_spawn)]
+
+mod executor {
+    use std::future::Future;
+
+    pub struct Task;
+
+    pub fn spawn<F: Future>(_future: F) -> Task {
+        Task
+    }
+
+    pub fn spawn_and_forget<F: Future>(_future: F) {}
+}
+
+async fn work() -> u32 {
+    42
+}
+
+fn main() {
+    executor::spawn(work());
+    let _ = executor::spawn(work());
+    // returns no handle
+    executor::spawn_and_forget(work());
+    let _task = executor::spawn(work());
+}
@@ -0,0 +1,30 @@
+#![warn(clippy::match_same_arms)]
+#![allow(dead_code)]
+
+enum Foo {
+    A(u32),
+    B(u32),
+    C { x: u32 },
+}
+
+fn main() {
+    let foo = Foo::A(0);
+
+    let _ = match foo {
+        Foo::A(x) => x + 1,
+        Foo::B(y) => y + 1, //~ ERROR: this match arm has an identical body to another arm
+        Foo::C { .. } => 0,
+    };
+
+    let _ = match foo {
+        Foo::C { x } => x * 2,
+        Foo::A(a) => a * 2, //~ ERROR: this match arm has an identical body to another arm
+        Foo::B(_) => 0,
+    };
+
+    // Don't lint. The bindings aren't used the same way.
+    let _ = match (1, 2) {
+        (0, x) => x + x,
+        (a, b) => a + b,
+    };
+}
@@ -0,0 +1,35 @@
+#![warn(clippy::match_same_arms)]
+#![allow(dead_code, unused_variables)]
+
+enum Foo {
+    A(u32),
+    B(u32),
+    C { x: u32 },
+}
+
+fn bar() -> u32 {
+    0
+}
+
+fn main() {
+    let foo = Foo::A(0);
+
+    let _ = match foo {
+        Foo::A(x) => bar(),
+        Foo::B(y) => bar(), //~ ERROR: this match arm has an identical body to another arm
+        Foo::C { .. } => 0,
+    };
+
+    let _ = match foo {
+        Foo::C { x } => bar(),
+        Foo::A(a @ 1..=5) => bar(), //~ ERROR: this match arm has an identical body to another arm
+        _ => 0,
+    };
+
+    // Don't lint. The used bindings have different names.
+    let _ = match foo {
+        Foo::A(x) => x + 1,
+        Foo::B(y) => y + 1,
+        Foo::C { .. } => 0,
+    };
+}
@@ -0,0 +1,20 @@
+#![warn(clippy::match_same_arms)]
+
+fn foo() -> i32 {
+    0
+}
+
+fn main() {
+    // Don't lint, the bodies are below the configured complexity.
+    let _ = match 0 {
+        0 => 1,
+        1 => 1,
+        _ => 2,
+    };
+
+    let _ = match 0 {
+        0 => foo() + 1,
+        1 => foo() + 1, //~ ERROR: this match arm has an identical body to another arm
+        _ => 2,
+    };
+}
@@ -0,0 +1,11 @@
+#![warn(clippy::naive_string_reversal)]
+
+fn main() {
+    let s = "noël";
+    let _: String = s.chars().rev().collect();
+    let _ = s.chars().rev().eq(s.chars());
+    let _: Vec<char> = s.chars().rev().collect();
+
+    // not linted, not the `char`s of a string
+    let _ = s.bytes().rev().eq(s.bytes());
+}
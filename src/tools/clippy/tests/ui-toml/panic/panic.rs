@@ -0,0 +1,24 @@
+//@compile-flags: --test
+#![warn(clippy::panic)]
+
+fn main() {
+    panic!("not in a test");
+}
+
+#[test]
+fn test_panic() {
+    panic!("in a test");
+}
+
+#[cfg(test)]
+mod tests {
+    // should not lint in `#[cfg(test)]` modules
+    #[test]
+    fn test_fn() {
+        util();
+    }
+
+    fn util() {
+        panic!("in a test module");
+    }
+}
@@ -0,0 +1,19 @@
+#![warn(clippy::thread_sleep_in_async)]
+
+use std::time::Duration;
+
+mod runtime {
+    use std::future::Future;
+    use std::time::Duration;
+
+    pub fn sleep(_duration: Duration) -> impl Future<Output = ()> {
+        async {}
+    }
+}
+
+async fn bad() {
+    std::thread::sleep(Duration::from_millis(10));
+    runtime::sleep(Duration::from_millis(10)).await;
+}
+
+fn main() {}
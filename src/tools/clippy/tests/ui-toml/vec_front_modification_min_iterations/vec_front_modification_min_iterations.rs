@@ -0,0 +1,16 @@
+#![warn(clippy::vec_front_modification)]
+
+fn main() {
+    let mut v = Vec::new();
+    for i in 0..4 {
+        v.insert(0, i);
+    }
+    for x in [1, 2, 3] {
+        v.insert(0, x);
+    }
+
+    // not linted, fewer iterations
+    for _ in 0..=1 {
+        v.remove(0);
+    }
+}
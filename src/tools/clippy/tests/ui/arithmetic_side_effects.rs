@@ -482,3 +482,6 @@ struct Two {
 }
 
 fn main() {}
+
+// The closure is only called at runtime
+const _: fn(i32) -> i32 = |n| n + 1;
@@ -176,6 +176,16 @@ async fn dropped_before_await(x: std::sync::Mutex<u32>) {
     baz().await;
 }
 
+// The guard is hidden behind the `impl Trait` returned by a helper function.
+fn lock_helper(x: &std::sync::Mutex<u32>) -> impl std::ops::DerefMut<Target = u32> + '_ {
+    x.lock().unwrap()
+}
+
+async fn bad_helper(x: &std::sync::Mutex<u32>) -> u32 {
+    let guard = lock_helper(x);
+    baz().await
+}
+
 fn main() {
     let m = std::sync::Mutex::new(100);
     std_mutex::good(&m);
@@ -0,0 +1,31 @@
+#![allow(unused)]
+#![warn(clippy::block_on_in_async)]
+
+extern crate futures;
+use futures::executor::block_on;
+
+async fn load() -> u32 {
+    42
+}
+
+async fn bad() -> u32 {
+    let x = block_on(load());
+    let y = futures::executor::block_on(async { x + 1 });
+    x + y
+}
+
+fn bad_block() -> impl std::future::Future<Output = u32> {
+    async { block_on(load()) }
+}
+
+async fn good() -> u32 {
+    // a closure isn't run by the executor
+    let f = || block_on(load());
+    load().await
+}
+
+fn not_async() -> u32 {
+    block_on(load())
+}
+
+fn main() {}
@@ -0,0 +1,82 @@
+#![warn(clippy::busy_wait_loop)]
+#![allow(unused, clippy::missing_spin_loop)]
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+fn work() {}
+
+fn lint(ready: &AtomicBool, counter: &AtomicUsize) {
+    let mut spins = 0;
+    while !ready.load(Ordering::Acquire) {
+        spins += 1;
+    }
+
+    while counter.load(Ordering::Relaxed) < 10 {}
+
+    loop {
+        if ready.load(Ordering::Acquire) {
+            break;
+        }
+    }
+
+    loop {
+        if ready
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+        {
+            break;
+        }
+        spins += 1;
+    }
+}
+
+fn no_lint(ready: &AtomicBool, pair: &(Mutex<bool>, Condvar)) {
+    while !ready.load(Ordering::Acquire) {
+        std::hint::spin_loop();
+    }
+
+    while !ready.load(Ordering::Acquire) {
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    loop {
+        if ready.load(Ordering::Acquire) {
+            break;
+        }
+        thread::yield_now();
+    }
+
+    let (lock, cvar) = pair;
+    let mut done = lock.lock().unwrap();
+    while !*done && !ready.load(Ordering::Acquire) {
+        done = cvar.wait(done).unwrap();
+    }
+
+    // may block
+    while !ready.load(Ordering::Acquire) {
+        work();
+    }
+
+    // linted by `missing_spin_loop`
+    while !ready.load(Ordering::Acquire) {}
+
+    // doesn't wait on an atomic
+    let mut i = 0;
+    loop {
+        i += 1;
+        if i > 10 {
+            break;
+        }
+    }
+}
+
+async fn no_lint_async(ready: &AtomicBool) {
+    while !ready.load(Ordering::Acquire) {
+        core::future::ready(()).await;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+#![warn(clippy::cartesian_product_loop)]
+#![allow(unused)]
+
+fn main() {
+    let names = vec!["a", "b"];
+    let sizes = [1, 2, 3];
+    for name in &names {
+        for size in sizes {
+            println!("{name}: {size}");
+        }
+    }
+
+    for x in 0..3 {
+        for y in 0..3 {
+            if x == y {
+                continue;
+            }
+            println!("{x}, {y}");
+        }
+    }
+
+    // the inner iterator depends on the outer item
+    for x in 0..3 {
+        for y in 0..x {
+            println!("{x}, {y}");
+        }
+    }
+
+    // the outer loop does more than the inner loop
+    for x in 0..3 {
+        println!("{x}");
+        for y in 0..3 {
+            println!("{x}, {y}");
+        }
+    }
+
+    // breaks out of the inner loop
+    for x in 0..3 {
+        for y in 0..3 {
+            if y > x {
+                break;
+            }
+        }
+    }
+
+    // continues the outer loop
+    'outer: for x in 0..3 {
+        for y in 0..3 {
+            if y > x {
+                continue 'outer;
+            }
+        }
+    }
+
+    // the inner iterator changes in the body
+    let mut n = 3;
+    for x in 0..3 {
+        for y in 0..n {
+            n -= 1;
+        }
+    }
+}
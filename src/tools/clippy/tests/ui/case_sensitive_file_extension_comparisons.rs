@@ -53,3 +53,8 @@ fn main() {
     let _ = "str".ends_with("a.extA");
     TestStruct {}.ends_with("a.ext");
 }
+
+#[clippy::msrv = "1.69"]
+fn msrv_1_69() {
+    let _ = "str".ends_with(".ext12");
+}
@@ -0,0 +1,19 @@
+#![warn(clippy::chained_str_replace)]
+#![allow(clippy::collapsible_str_replace)]
+
+fn main() {
+    let s = "a < b && c";
+    let string = String::from(s);
+
+    let _ = s.replace('<', "&lt;").replace('&', "&amp;");
+    let _ = string.replace("a", "b").replace("c", "d");
+    let _ = s.replace("ab", "x").replace("xb", "y").replace('z', "w");
+    let _ = s.replace(' ', "").replace("ab", "c");
+
+    // not linted, a single call
+    let _ = s.replace('a', "b");
+    // not linted, `collapsible_str_replace` suggests a single call
+    let _ = s.replace('a', "b").replace('c', "b");
+    // not linted, the chain is interrupted
+    let _ = s.replace('a', "b").trim().replace('c', "d");
+}
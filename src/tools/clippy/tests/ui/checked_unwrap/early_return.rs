@@ -0,0 +1,60 @@
+#![deny(clippy::panicking_unwrap, clippy::unnecessary_unwrap)]
+
+fn option(x: Option<u32>) -> u32 {
+    if x.is_none() {
+        return 0;
+    }
+    x.unwrap() + 1
+}
+
+fn result(x: Result<u32, String>) -> String {
+    if x.is_ok() {
+        return String::new();
+    }
+    x.unwrap_err()
+}
+
+fn negated(x: Option<u32>) -> u32 {
+    if !x.is_some() {
+        panic!("no value");
+    }
+    x.expect("checked above")
+}
+
+fn panicking(x: Option<u32>) {
+    if x.is_some() {
+        return;
+    }
+    x.unwrap(); // will panic
+}
+
+fn partial_condition(values: &[Option<u32>]) {
+    for &v in values {
+        if v.is_none() || v == Some(1) {
+            continue;
+        }
+        v.unwrap();
+    }
+}
+
+#[clippy::msrv = "1.64"]
+fn msrv_1_64(x: Option<u32>) {
+    if x.is_none() {
+        return;
+    }
+    x.unwrap();
+}
+
+fn should_not_lint(mut x: Option<u32>, y: Option<u32>) {
+    if y.is_none() {
+        println!("no value");
+    }
+    y.unwrap();
+    if x.is_none() {
+        return;
+    }
+    x = None;
+    x.unwrap();
+}
+
+fn main() {}
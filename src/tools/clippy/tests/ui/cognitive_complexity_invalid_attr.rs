@@ -0,0 +1,13 @@
+#![warn(clippy::cognitive_complexity)]
+
+fn main() {}
+
+#[clippy::cognitive_complexity = "many"]
+fn not_a_number() {}
+
+#[clippy::cognitive_complexity]
+fn no_value() {}
+
+#[clippy::cognitive_complexity = "25"]
+#[clippy::cognitive_complexity = "50"]
+fn defined_twice() {}
@@ -0,0 +1,27 @@
+#![allow(clippy::let_underscore_future)]
+#![warn(clippy::detached_spawn)]
+
+use tokio::runtime::Handle;
+use tokio::task::{self, JoinHandle};
+
+async fn work() -> u32 {
+    42
+}
+
+fn bad(handle: &Handle) {
+    tokio::spawn(work());
+    let _ = task::spawn(work());
+    task::spawn_blocking(|| 1);
+    handle.spawn(work());
+}
+
+async fn good(handle: &Handle) -> u32 {
+    let a = tokio::spawn(work());
+    let _b = task::spawn_blocking(|| 1);
+    // detached on purpose
+    drop(handle.spawn(work()));
+    let c: JoinHandle<u32> = tokio::spawn(work());
+    a.await.unwrap() + c.await.unwrap()
+}
+
+fn main() {}
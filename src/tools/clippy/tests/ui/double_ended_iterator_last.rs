@@ -0,0 +1,28 @@
+//@run-rustfix
+#![warn(clippy::double_ended_iterator_last)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = v.iter().map(|x| x * 2).last();
+    let _ = v.clone().into_iter().last();
+    let mut it = v.iter().enumerate();
+    let _ = it.by_ref().last();
+    let _ = v
+        .iter()
+        .map(|x| {
+            println!("{x}");
+            x
+        })
+        .last();
+
+    // these implement `last` themselves
+    let _ = v.iter().last();
+    let _ = "abc".chars().last();
+    let _ = (0..10).last();
+    // not a `DoubleEndedIterator`
+    let _ = "a::b".split("::").last();
+}
+
+fn generic<I: DoubleEndedIterator>(it: I) -> Option<I::Item> {
+    it.last()
+}
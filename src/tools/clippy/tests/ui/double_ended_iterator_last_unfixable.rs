@@ -0,0 +1,7 @@
+#![warn(clippy::double_ended_iterator_last)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let it = v.iter().map(|x| x * 2);
+    let _ = it.last();
+}
@@ -0,0 +1,69 @@
+#![warn(clippy::double_map_lookup)]
+#![allow(clippy::map_entry, unused)]
+
+use std::collections::{BTreeMap, HashMap};
+
+struct S {
+    map: BTreeMap<String, u32>,
+}
+
+fn contains_then_get(mut map: HashMap<u32, u32>, k: u32) {
+    if map.contains_key(&k) {
+        println!("{}", map[&k]);
+    }
+    if map.contains_key(&k) {
+        *map.get_mut(&k).unwrap() += 1;
+    }
+
+    // no lint
+    if !map.contains_key(&k) {
+        println!("{:?}", map.get(&k));
+    }
+    if map.contains_key(&k) {
+        println!("{}", map[&(k + 1)]);
+    }
+    if map.contains_key(&k) {
+        map.insert(k, 0);
+    }
+    let mut k = k;
+    if map.contains_key(&k) {
+        k += 1;
+        println!("{:?}", map.get(&k));
+    }
+}
+
+fn get_then_insert(mut map: HashMap<String, u32>, k: String) {
+    let count = map.get(&k).copied().unwrap_or(0);
+    map.insert(k.clone(), count + 1);
+
+    let present = map.contains_key(&k);
+    println!("{present}");
+    if !present {
+        map.insert(k.clone(), 0);
+    }
+
+    // no lint
+    let count = map.get(&k).copied().unwrap_or(0);
+    map.clear();
+    map.insert(k.clone(), count);
+
+    let mut other = k.clone();
+    let count = map.get(&other).copied().unwrap_or(0);
+    other.push('!');
+    map.insert(other, count);
+
+    let mut other_map = HashMap::new();
+    let count = map.get(&k).copied().unwrap_or(0);
+    other_map.insert(k, count);
+}
+
+impl S {
+    fn field(&mut self, k: &str) {
+        if self.map.get(k).is_none() {
+            println!("missing");
+        }
+        self.map.insert(k.to_owned(), 1);
+    }
+}
+
+fn main() {}
@@ -74,4 +74,16 @@ fn not_whole_length(v: &mut Vec<i32>) -> Vec<i32> {
     v.drain(1..).collect()
 }
 
+fn vecdeque_tail(b: &mut VecDeque<i32>, n: usize) -> VecDeque<i32> {
+    b.drain(n..).collect()
+}
+
+fn string_tail(b: &mut String) -> String {
+    b.drain(3..).collect()
+}
+
+fn tail_dont_lint(v: &mut Vec<i32>) -> (Vec<i32>, Vec<i32>, HashSet<i32>) {
+    (v.drain(1..2).collect(), v.drain(..1).collect(), v.drain(1..).collect())
+}
+
 fn main() {}
@@ -0,0 +1,83 @@
+//@run-rustfix
+#![allow(unused)]
+#![warn(clippy::extend_without_reserve)]
+
+fn main() {
+    let points = [(1, 2), (3, 4)];
+    let mut coords = Vec::new();
+    for (x, y) in points {
+        coords.extend([x, y]);
+    }
+
+    let n: usize = 10;
+    let mut v = vec![];
+    for i in 0..n {
+        v.push(i);
+        v.extend_from_slice(&[i, i]);
+    }
+
+    let data = vec![1, 2, 3];
+    let mut bytes = Vec::<u8>::new();
+    for b in data.iter().rev() {
+        bytes.push(*b);
+    }
+
+    let mut s = String::new();
+    for _ in &data {
+        s.push_str("ab");
+        s.push('é');
+    }
+}
+
+fn should_not_lint(text: &str, n: usize) {
+    let lines = [text, text];
+
+    // conditionally extended
+    let mut v = Vec::new();
+    for i in 0..n {
+        if i % 2 == 0 {
+            v.push(i);
+        }
+    }
+
+    // some iterations don't extend it
+    let mut v = Vec::new();
+    for i in 0..n {
+        if i == 3 {
+            continue;
+        }
+        v.push(i);
+    }
+
+    let mut v = Vec::new();
+    for i in 0..n {
+        v.push(i);
+        if i == 3 {
+            break;
+        }
+        v.push(i);
+    }
+
+    // unknown number of iterations
+    let mut v = Vec::new();
+    for line in text.lines() {
+        v.push(line);
+    }
+
+    // unknown number of added elements
+    let mut v = Vec::new();
+    for line in lines {
+        v.extend(line.bytes());
+    }
+
+    // already created with a capacity
+    let mut v = Vec::with_capacity(n);
+    for i in 0..n {
+        v.push(i);
+    }
+
+    let mut s = String::new();
+    for line in lines {
+        s.push_str(line);
+    }
+}
@@ -0,0 +1,16 @@
+//@run-rustfix
+#![allow(unused)]
+#![warn(clippy::filter_last)]
+
+fn main() {
+    let v = vec![3, 2, 1, 0];
+    let _ = v.iter().filter(|x| **x % 2 == 0).last();
+    let _ = v.clone().into_iter().filter(|x| *x > 1).last();
+    let _ = "abc".chars().filter(|c| c.is_alphabetic()).last();
+    // side effects in the chain
+    let _ = v.iter().inspect(|x| println!("{x}")).filter(|x| **x > 1).last();
+
+    // Don't lint, not double-ended
+    let _ = (0..).take(10).filter(|x| x % 2 == 0).last();
+    let _ = "a b".split_whitespace().map(str::len).skip_while(|x| *x > 1).filter(|x| *x > 0).last();
+}
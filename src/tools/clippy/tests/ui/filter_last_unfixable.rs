@@ -0,0 +1,9 @@
+#![allow(unused)]
+#![warn(clippy::filter_last)]
+
+fn main() {
+    let v = vec![3, 2, 1, 0];
+    // the iterator needs to be declared as mutable
+    let iter = v.iter();
+    let _ = iter.filter(|x| **x % 2 == 0).last();
+}
@@ -165,3 +165,13 @@ fn main() {
         print_substring("Hello, world!");
     }
 }
+
+mod to_owned {
+    fn main(s: &str, string: String) {
+        println!("{}", s.to_owned());
+        println!("{}", string.to_owned());
+        println!("{}", s[1..].to_owned());
+        // not linted, `Debug` isn't `Display`
+        println!("{:?}", s.to_owned());
+    }
+}
@@ -34,3 +34,8 @@ pub fn other_cases() {
         });
     }
 }
+
+// the value of the expression is used
+fn push_tail(s: &mut String) {
+    s.push_str(&format!("{}", 1))
+}
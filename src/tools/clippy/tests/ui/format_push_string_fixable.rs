@@ -0,0 +1,20 @@
+//@run-rustfix
+#![allow(unused)]
+#![warn(clippy::format_push_string)]
+
+fn main() {
+    let mut string = String::new();
+    string += &format!("{:?}", 1234);
+    string.push_str(&format!("{:?}", 5678));
+    for i in 0..10 {
+        string.push_str(&format!("{i},"));
+    }
+}
+
+mod imported {
+    use std::fmt::Write;
+
+    fn push(s: &mut String, x: u32) {
+        s.push_str(&format!("{x:02}"));
+    }
+}
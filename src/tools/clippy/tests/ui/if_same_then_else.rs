@@ -215,3 +215,16 @@ fn do_not_lint() {
 }
 
 fn main() {}
+
+fn takes_str(_: &str) {}
+
+#[allow(clippy::needless_borrow, clippy::ptr_arg)]
+fn modulo_adjustments(s: &String) -> usize {
+    if true {
+        takes_str(&s);
+    } else {
+        takes_str(s);
+    }
+
+    if true { (*s).len() } else { s.len() }
+}
@@ -31,3 +31,17 @@ fn main() {
     // Issue #6703
     let _: Vec<isize> = v.iter().copied().collect();
 }
+
+fn vec_receivers() {
+    let v: Vec<isize> = vec![1, 2, 3];
+    let _: Vec<isize> = v.iter().cloned().collect();
+    let _: Vec<isize> = Vec::from_iter(v.iter().copied());
+    let arr = [1, 2, 3];
+    let _: Vec<isize> = Vec::from_iter(arr.iter().cloned());
+    let _: Vec<isize> = v.into_iter().collect();
+
+    // should not be changed
+    let v: Vec<isize> = vec![1, 2, 3];
+    let _: Vec<&isize> = v.iter().collect();
+    let _: VecDeque<isize> = v.into_iter().collect();
+}
@@ -0,0 +1,19 @@
+//@run-rustfix
+#![allow(unused, clippy::iter_count)]
+#![warn(clippy::iter_count_cmp)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = v.iter().take_while(|x| **x < 3).count() == 0;
+    let _ = v.iter().take_while(|x| **x < 3).count() != 0;
+    let _ = v.iter().take_while(|x| **x < 3).count() > 0;
+    let _ = 0 < v.iter().take_while(|x| **x < 3).count();
+    let _ = v.iter().take_while(|x| **x < 3).count() >= 2;
+    let _ = v.iter().take_while(|x| **x < 3).count() < 2;
+    let _ = "abc".chars().count() <= 1;
+
+    // Don't lint
+    let _ = v.iter().take_while(|x| **x < 3).count() > 2;
+    let _ = v.iter().take_while(|x| **x < 3).count() == 1;
+    let _ = v.iter().count() == 0;
+}
@@ -0,0 +1,11 @@
+#![allow(unused, clippy::iter_count)]
+#![warn(clippy::iter_count_cmp)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    // side effects in the chain
+    let _ = v.iter().inspect(|x| println!("{x}")).count() == 0;
+    // the iterator needs to be declared as mutable
+    let iter = v.iter().skip(1);
+    let _ = iter.count() > 0;
+}
@@ -1,4 +1,4 @@
-#![allow(clippy::useless_vec)]
+#![allow(clippy::useless_vec, clippy::suspicious_iterator_adapter)]
 #[warn(clippy::iterator_step_by_zero)]
 fn main() {
     let _ = vec!["A", "B", "B"].iter().step_by(0);
@@ -0,0 +1,40 @@
+//@run-rustfix
+#![warn(clippy::keys_then_get)]
+#![allow(unused)]
+
+use std::collections::{BTreeMap, HashMap};
+
+struct S {
+    map: BTreeMap<String, u32>,
+}
+
+fn main() {
+    let map: HashMap<u32, u32> = HashMap::new();
+    for k in map.keys() {
+        let v = map.get(k).unwrap();
+        println!("{k}: {v}");
+    }
+    for k in map.keys() {
+        println!("{}", map[k]);
+    }
+    let _: Vec<_> = map.keys().filter_map(|k| map.get(k)).collect();
+
+    let s = S { map: BTreeMap::new() };
+    for name in s.map.keys() {
+        if let Some(v) = s.map.get(name) {
+            println!("{v}");
+        }
+    }
+    let _: u32 = s.map.keys().filter_map(|name| s.map.get(name)).sum();
+
+    // not linted, looked up in another map
+    let other: HashMap<u32, u32> = HashMap::new();
+    for k in map.keys() {
+        let _ = other.get(k);
+    }
+    let _: Vec<_> = map.keys().filter_map(|k| other.get(k)).collect();
+    // not linted, not the key
+    for k in map.keys() {
+        let _ = map.get(&(k + 1));
+    }
+}
@@ -0,0 +1,69 @@
+#![warn(clippy::len_mutated_in_loop)]
+#![allow(clippy::manual_retain_loop, clippy::needless_range_loop)]
+
+use std::collections::VecDeque;
+
+fn lint(mut v: Vec<i32>, mut queue: VecDeque<u32>) {
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] > 0 {
+            v.push(v[i] - 1);
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+        }
+        i += 1;
+    }
+
+    for i in 0..v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+        }
+    }
+
+    let mut i = 0;
+    while i < queue.len() {
+        queue.pop_front();
+        i += 1;
+    }
+}
+
+fn no_lint(mut v: Vec<i32>) {
+    // the index only moves on when the element is kept
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // the loop ends after the removal
+    for i in 0..v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+            break;
+        }
+    }
+
+    // the added elements aren't visited
+    for i in 0..v.len() {
+        v.push(v[i]);
+    }
+
+    // the length is read before the loop
+    let len = v.len();
+    let mut i = 0;
+    while i < len {
+        v.push(1);
+        i += 1;
+    }
+}
+
+fn main() {}
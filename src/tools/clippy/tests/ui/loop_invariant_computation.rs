@@ -0,0 +1,69 @@
+#![warn(clippy::loop_invariant_computation)]
+
+extern crate regex;
+
+use regex::Regex;
+use std::env;
+
+fn lint(lines: &[&str], prefix: &str) {
+    for line in lines {
+        let re = Regex::new(r"^\d+$").unwrap();
+        if re.is_match(line) {
+            println!("{line}");
+        }
+    }
+
+    for line in lines {
+        if line.starts_with(&format!("{prefix}:")) {
+            println!("{line}");
+        }
+    }
+
+    let mut i = 0;
+    while i < lines.len() {
+        let home = env::var("HOME").unwrap();
+        println!("{home}/{}", lines[i]);
+        i += 1;
+    }
+
+    loop {
+        let words: Vec<_> = prefix.split(' ').collect();
+        if words.contains(&"stop") {
+            break;
+        }
+    }
+}
+
+fn no_lint(lines: &[&str], prefix: &str) {
+    // depends on the item
+    for line in lines {
+        let re = Regex::new(line).unwrap();
+        if re.is_match(prefix) {
+            println!("{line}");
+        }
+    }
+
+    // a new value is needed in every iteration
+    let mut names = Vec::new();
+    for _ in lines {
+        names.push(format!("{prefix}:"));
+    }
+
+    // changed by the loop
+    let mut prefix = prefix.to_string();
+    for line in lines {
+        if line.starts_with(&format!("{prefix}:")) {
+            prefix.push('.');
+        }
+    }
+
+    // reading the directory again may give a different result
+    for line in lines {
+        let entries: Vec<_> = std::fs::read_dir(".").unwrap().collect();
+        if entries.len() > line.len() {
+            break;
+        }
+    }
+}
+
+fn main() {}
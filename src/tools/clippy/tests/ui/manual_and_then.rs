@@ -0,0 +1,53 @@
+//@run-rustfix
+#![warn(clippy::manual_and_then)]
+#![allow(dead_code)]
+
+fn parse(s: &str) -> Option<u32> {
+    s.parse().ok()
+}
+
+fn fallback() -> Option<u32> {
+    None
+}
+
+fn main() {
+    let opt = Some("1");
+    let _ = match opt {
+        Some(s) => parse(s),
+        None => None,
+    };
+    let _ = match opt {
+        Some(s) => parse(s).map(|n| n + 1),
+        _ => None,
+    };
+    let _ = if let Some(s) = opt { parse(s) } else { None };
+
+    let n = Some(1);
+    let _ = match n {
+        Some(n) => Some(n),
+        None => fallback(),
+    };
+    let _ = match n {
+        None => fallback().map(|n| n * 2),
+        Some(n) => Some(n),
+    };
+}
+
+fn no_lint(opt: Option<&str>, n: Option<u32>) -> Option<u32> {
+    // Linted by `manual_map`
+    #[allow(clippy::manual_map)]
+    let _ = match opt {
+        Some(s) => Some(s.len()),
+        None => None,
+    };
+    // The value is returned as is
+    let _ = match Some(n) {
+        Some(n) => n,
+        None => None,
+    };
+    // Can't be moved into a closure
+    match n {
+        Some(n) => Some(n),
+        None => return Some(0),
+    }
+}
@@ -0,0 +1,90 @@
+#![allow(unused, clippy::extend_without_reserve)]
+#![warn(clippy::manual_collect)]
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+fn push(names: &[String]) {
+    let mut squares = Vec::new();
+    for i in 0..10 {
+        squares.push(i * i);
+    }
+
+    let mut lens = vec![];
+    for name in names {
+        lens.push(name.len());
+    }
+
+    let mut items: Vec<u32> = Vec::new();
+    for i in 0..10 {
+        items.push(i);
+    }
+}
+
+fn insert(names: Vec<String>) {
+    let mut map = HashMap::new();
+    for (i, name) in names.iter().enumerate() {
+        map.insert(name, i);
+    }
+
+    let mut set = HashSet::new();
+    for name in names {
+        set.insert(name);
+    }
+
+    let mut lens = BTreeMap::new();
+    for name in &set {
+        lens.insert(name.len(), name);
+    }
+}
+
+fn extend(names: &[String]) {
+    let mut lens = Vec::with_capacity(names.len());
+    for name in names {
+        lens.push(name.len());
+    }
+}
+
+fn should_not_lint(names: &[String]) -> Option<()> {
+    // used in the loop
+    let mut v = Vec::new();
+    for name in names {
+        v.push(v.len() + name.len());
+    }
+
+    // control flow in the pushed value
+    let mut v = Vec::new();
+    for name in names {
+        v.push(name.parse::<u32>().ok()?);
+    }
+
+    // not directly preceding the loop
+    let mut v = Vec::new();
+    let x = 1;
+    for name in names {
+        v.push(name.len() + x);
+    }
+
+    // the loop does more than pushing
+    let mut v = Vec::new();
+    for name in names {
+        println!("{name}");
+        v.push(name.len());
+    }
+
+    // the value returned by `insert` is used
+    let mut map = BTreeMap::new();
+    for name in names {
+        if map.insert(name.len(), name).is_some() {
+            println!("duplicate length");
+        }
+    }
+
+    // not an empty collection
+    let mut v = vec![1];
+    for name in names {
+        v.push(name.len());
+    }
+    Some(())
+}
+
+fn main() {}
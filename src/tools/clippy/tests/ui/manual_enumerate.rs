@@ -0,0 +1,25 @@
+//@run-rustfix
+#![warn(clippy::manual_enumerate)]
+#![allow(clippy::range_zip_with_len, clippy::useless_vec)]
+
+fn main() {
+    let v = vec![1_usize, 2, 3];
+    let _: Vec<usize> = v.iter().zip(0..).map(|(x, i)| x * i).collect();
+    let _: Vec<usize> = v.iter().zip(0..2).map(|(x, i)| x + i).collect();
+    v.iter().zip(0..).for_each(|(x, i)| println!("{}", x + i));
+    let _ = v.iter().zip(0..).filter(|&(x, i)| *x == i).count();
+    let n: usize = 2;
+    let _ = v.iter().zip(0..n).find(|(_, i)| *i == 1);
+}
+
+fn dont_lint() {
+    let v = vec![1_usize, 2, 3];
+    // Not starting at zero
+    let _: Vec<usize> = v.iter().zip(1..).map(|(x, i)| x * i).collect();
+    // Not counting with `usize`s
+    let _: Vec<u32> = v.iter().zip(0_u32..).map(|(_, i)| i).collect();
+    // Inclusive range
+    let _: Vec<usize> = v.iter().zip(0..=2).map(|(x, i)| x * i).collect();
+    // Linted by `range_zip_with_len`
+    let _ = v.iter().zip(0..v.len()).count();
+}
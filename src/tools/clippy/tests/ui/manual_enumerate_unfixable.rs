@@ -0,0 +1,11 @@
+#![warn(clippy::manual_enumerate)]
+#![allow(clippy::useless_vec)]
+
+fn main() {
+    let v = vec![1_usize, 2, 3];
+    let _: Vec<(&usize, usize)> = v.iter().zip(0..).collect();
+    for (x, i) in v.iter().zip(0..3) {
+        assert!(*x > i);
+    }
+    let _: Vec<usize> = v.iter().zip(0..).map(|pair| pair.1).collect();
+}
@@ -56,6 +56,15 @@ fn fire() {
         std::process::abort()
     };
 
+    // A loop which is never broken out of diverges
+    let v = if let Some(v_some) = g() {
+        v_some
+    } else {
+        loop {
+            g();
+        }
+    };
+
     // If whose two branches diverge also diverges
     let v = if let Some(v_some) = g() {
         v_some
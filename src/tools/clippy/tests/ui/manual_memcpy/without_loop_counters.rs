@@ -134,4 +134,17 @@ pub fn manual_clone(src: &[String], dst: &mut [String]) {
     }
 }
 
+struct Buffer {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+impl Buffer {
+    pub fn write(&mut self, src: &[u8]) {
+        for i in 0..src.len() {
+            self.data[i + self.pos] = src[i];
+        }
+    }
+}
+
 fn main() {}
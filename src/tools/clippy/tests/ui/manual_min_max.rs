@@ -0,0 +1,75 @@
+//@run-rustfix
+#![warn(clippy::manual_min_max)]
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+
+fn min_max(a: i32, b: i32, p: (u32, u32)) {
+    let _ = match a.cmp(&b) {
+        Ordering::Less | Ordering::Equal => a,
+        Ordering::Greater => b,
+    };
+    let _ = match a.cmp(&b) {
+        Ordering::Less => a,
+        _ => b,
+    };
+    let _ = match a.cmp(&b) {
+        Ordering::Greater => a,
+        _ => b,
+    };
+    let _ = match a.cmp(&b) {
+        Ordering::Less => b,
+        Ordering::Equal | Ordering::Greater => a,
+    };
+    let _ = match p.0.cmp(&p.1) {
+        Ordering::Greater => p.1,
+        _ => p.0,
+    };
+}
+
+fn not_copy(s: String, t: String) -> String {
+    match s.cmp(&t) {
+        Ordering::Less => s,
+        _ => t,
+    }
+}
+
+fn clamp(x: u8, lo: u8, hi: u8) -> u8 {
+    match (x.cmp(&lo), x.cmp(&hi)) {
+        (Ordering::Less, _) => lo,
+        (_, Ordering::Greater) => hi,
+        _ => x,
+    }
+}
+
+fn f() -> i32 {
+    0
+}
+
+fn dont_lint(a: i32, b: i32, x: u8, lo: u8, hi: u8) {
+    let _ = match a.cmp(&b) {
+        Ordering::Less => a,
+        _ => 0,
+    };
+    let _ = match a.cmp(&f()) {
+        Ordering::Less => a,
+        _ => f(),
+    };
+    let _ = match a.cmp(&b) {
+        Ordering::Less if a > 0 => a,
+        _ => b,
+    };
+    let _ = match a.cmp(&b) {
+        Ordering::Less => a,
+        Ordering::Equal => 0,
+        Ordering::Greater => b,
+    };
+    // Returns `hi` instead of `x` when both are equal.
+    let _ = match (x.cmp(&lo), x.cmp(&hi)) {
+        (Ordering::Less, _) => lo,
+        (_, Ordering::Greater | Ordering::Equal) => hi,
+        _ => x,
+    };
+}
+
+fn main() {}
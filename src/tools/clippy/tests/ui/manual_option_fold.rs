@@ -0,0 +1,51 @@
+//@run-rustfix
+#![warn(clippy::manual_option_fold)]
+#![allow(clippy::useless_vec)]
+
+fn main() {
+    let v = vec![3, 1, 4, 1, 5];
+    let _ = v
+        .iter()
+        .fold(None, |a, x| if a.is_none() && *x > 3 { Some(x) } else { a });
+    let _ = v
+        .iter()
+        .fold(None, |a, x| if a.map_or(true, |m| x >= m) { Some(x) } else { a });
+    let _ = v
+        .iter()
+        .fold(None, |a, x| if a.map_or(true, |m| m > x) { Some(x) } else { a });
+
+    let words = vec!["a", "bc", "de"];
+    let _ = words.iter().fold(None, |a, w| {
+        if a.map_or(true, |m| w.len() >= m.len()) {
+            Some(w)
+        } else {
+            a
+        }
+    });
+    let _ = words.iter().fold(None, |a, w| {
+        if a.map_or(true, |m| w.len() < m.len()) {
+            Some(w)
+        } else {
+            a
+        }
+    });
+}
+
+fn dont_lint() {
+    let v = vec![3, 1, 4, 1, 5];
+    // Keeps the last match
+    let _ = v.iter().fold(None, |a, x| if *x > 3 { Some(x) } else { a });
+    // Keeps the first maximum, unlike `max`
+    let _ = v
+        .iter()
+        .fold(None, |a, x| if a.map_or(true, |m| x > m) { Some(x) } else { a });
+    // Floats aren't `Ord`
+    let f = vec![1.0, 2.0];
+    let _ = f
+        .iter()
+        .fold(None, |a, x| if a.map_or(true, |m| x >= m) { Some(x) } else { a });
+    // Not starting with `None`
+    let _ = v
+        .iter()
+        .fold(Some(&0), |a, x| if a.map_or(true, |m| x >= m) { Some(x) } else { a });
+}
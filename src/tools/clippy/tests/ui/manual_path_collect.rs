@@ -0,0 +1,72 @@
+#![allow(unused)]
+#![warn(clippy::manual_path_collect)]
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+fn path_buf(parts: &[&str], dirs: Vec<String>) {
+    let mut path = PathBuf::new();
+    for part in parts {
+        path.push(part);
+    }
+
+    let mut path = PathBuf::new();
+    for dir in &dirs {
+        path.push(dir.trim());
+    }
+
+    let mut path: PathBuf = PathBuf::new();
+    for dir in dirs {
+        path.push(dir);
+    }
+    path.set_extension("rs");
+}
+
+fn os_string(parts: Vec<OsString>, names: &[&str]) {
+    let mut s = OsString::new();
+    for part in parts {
+        s.push(part);
+    }
+
+    // an `OsString` can't be collected from `&str`s
+    let mut s = OsString::new();
+    for name in names {
+        s.push(name);
+    }
+}
+
+fn should_not_lint(parts: &[&str]) -> Option<()> {
+    // used in the loop
+    let mut path = PathBuf::new();
+    for part in parts {
+        path.push(part.repeat(path.components().count()));
+    }
+
+    // control flow in the pushed part
+    let mut path = PathBuf::new();
+    for part in parts {
+        path.push(part.strip_prefix('/')?);
+    }
+
+    // the loop does more than pushing
+    let mut path = PathBuf::new();
+    for part in parts {
+        println!("{part}");
+        path.push(part);
+    }
+
+    // not an empty path
+    let mut path = PathBuf::from("/");
+    for part in parts {
+        path.push(part);
+    }
+
+    // not a `PathBuf` or an `OsString`
+    let mut v = Vec::new();
+    for part in parts {
+        v.push(part);
+    }
+    Some(())
+}
+
+fn main() {}
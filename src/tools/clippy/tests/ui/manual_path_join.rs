@@ -0,0 +1,23 @@
+#![warn(clippy::manual_path_join)]
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let dir = "dir";
+    let file = String::from("file");
+
+    let _ = Path::new(&format!("{dir}/{file}"));
+    let _ = PathBuf::from(format!("{dir}/{file}"));
+    let _ = File::open(format!("{dir}/{file}.txt"));
+    let _ = fs::read_to_string(dir.to_string() + "/" + &file);
+    let _: PathBuf = format!("{dir}/cache").into();
+    let _ = Path::new(dir).join(format!("{file}/nested"));
+
+    // don't lint
+    let _ = Path::new(&format!("tests/{file}"));
+    let _ = format!("{dir}/{file}");
+    let _ = File::open(format!("{dir}.txt"));
+    let _ = Path::new(dir).join(file.clone() + ".txt");
+    let _ = fs::read_to_string("dir/".to_string() + &file);
+}
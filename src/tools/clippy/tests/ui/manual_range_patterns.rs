@@ -33,3 +33,10 @@ macro_rules! mac {
     }
     mac!(f);
 }
+
+fn literal_style() {
+    let x = 6u32;
+    let _ = matches!(x, 0x1 | 0x2 | 0x3 | 0x4);
+    let _ = matches!(x, 1_000 | 1_001 | 1_002);
+    let _ = matches!(x, 3u32 | 1u32 | 2u32);
+}
@@ -0,0 +1,43 @@
+//@run-rustfix
+#![warn(clippy::manual_read_to_string)]
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fs;
+use std::io::Read;
+
+fn read_all(mut reader: impl Read) -> Result<String, Box<dyn Error>> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+fn read_all_expect(mut reader: impl Read) -> String {
+    let mut bytes: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut bytes).unwrap();
+    String::from_utf8(bytes).expect("invalid UTF-8")
+}
+
+fn read_file(path: &str) -> Result<String, Box<dyn Error>> {
+    let bytes = fs::read(path)?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+fn read_file_inline(path: &str) -> String {
+    String::from_utf8(fs::read(path).unwrap()).unwrap()
+}
+
+// don't lint
+fn bytes_used_elsewhere(mut reader: impl Read) -> Result<(usize, String), Box<dyn Error>> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let len = bytes.len();
+    Ok((len, String::from_utf8(bytes)?))
+}
+
+fn utf8_error_returned(path: &str) -> Result<String, std::string::FromUtf8Error> {
+    let bytes = fs::read(path).unwrap();
+    String::from_utf8(bytes)
+}
+
+fn main() {}
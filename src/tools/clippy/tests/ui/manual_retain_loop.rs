@@ -0,0 +1,76 @@
+//@run-rustfix
+#![warn(clippy::manual_retain_loop)]
+#![allow(unused)]
+
+fn is_short(s: &str) -> bool {
+    s.len() < 3
+}
+
+fn lint(mut v: Vec<i32>, mut names: Vec<String>, words: &mut Vec<&str>) {
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut i = 0;
+    while i < names.len() {
+        if names[i].starts_with('a') {
+            i += 1;
+        } else {
+            names.remove(i);
+        }
+    }
+
+    for i in (0..words.len()).rev() {
+        if is_short(&words[i]) {
+            words.remove(i);
+        }
+    }
+}
+
+fn no_lint(mut v: Vec<i32>) {
+    // uses the index
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] > i as i32 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // uses another element
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] > v[0] {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // the index is used after the loop
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    println!("{i}");
+
+    // does something else
+    for i in (0..v.len()).rev() {
+        if v[i] == 0 {
+            println!("removing {i}");
+            v.remove(i);
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![warn(clippy::manual_retain_loop)]
+#![allow(unused)]
+
+fn main() {}
+
+// `retain` keeps the order of the elements
+fn swap_remove(mut v: Vec<i32>) {
+    for i in (0..v.len()).rev() {
+        if v[i] < 0 {
+            v.swap_remove(i);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//@run-rustfix
+#![warn(clippy::manual_search_loop)]
+#![allow(unused)]
+
+fn lint(names: &[String], numbers: Vec<u32>, owned: Vec<String>) {
+    let mut found = None;
+    for (i, name) in names.iter().enumerate() {
+        if name.is_empty() {
+            found = Some(i);
+            break;
+        }
+    }
+
+    let mut found: Option<u32> = None;
+    'search: for n in numbers.iter().copied() {
+        if n % 2 == 0 {
+            found = Some(n);
+            break 'search;
+        }
+    }
+
+    let mut found = None;
+    for &n in &numbers {
+        if n > 10 {
+            found = Some(n);
+            break
+        }
+    }
+
+    let mut found = None;
+    for name in names {
+        if name.len() > 2 {
+            found = Some(name);
+            break;
+        }
+    }
+    println!("{found:?}");
+
+    let mut found = None;
+    for name in owned {
+        if name.len() > 2 {
+            found = Some(name);
+            break;
+        }
+    }
+}
+
+fn no_lint(names: &[String]) {
+    // the index is used in the condition
+    let mut found = None;
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 && name.is_empty() {
+            found = Some(i);
+            break;
+        }
+    }
+
+    // the loop does more than assigning
+    let mut found = None;
+    for name in names {
+        if name.is_empty() {
+            println!("found");
+            found = Some(name);
+            break;
+        }
+    }
+
+    // breaks out of an outer loop
+    'outer: for _ in 0..2 {
+        let mut found = None;
+        for name in names {
+            if name.is_empty() {
+                found = Some(name);
+                break 'outer;
+            }
+        }
+    }
+
+    // assigned after the loop
+    let mut found = None;
+    for name in names {
+        if name.is_empty() {
+            found = Some(name);
+            break;
+        }
+    }
+    if found.is_none() {
+        found = names.first();
+    }
+
+    // keeps searching after a match
+    let mut found = None;
+    for name in names {
+        if name.is_empty() {
+            found = Some(name);
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+//@run-rustfix
+#![warn(clippy::manual_split_first_last)]
+#![allow(dead_code, clippy::single_match)]
+
+fn first(v: &[u32]) -> u32 {
+    match v {
+        [first, rest @ ..] => *first + rest.len() as u32,
+        [] => 0,
+    }
+}
+
+fn last(v: &[u32]) -> u32 {
+    match v {
+        [] => 0,
+        [rest @ .., last] => *last + rest.len() as u32,
+    }
+}
+
+fn first_mut(v: &mut [u32]) {
+    match v {
+        [first, rest @ ..] => {
+            *first += 1;
+            rest.fill(0);
+        },
+        _ => {},
+    }
+}
+
+fn no_lint(v: &[u32]) {
+    // Guard
+    match v {
+        [first, rest @ ..] if *first > 0 => println!("{first} {rest:?}"),
+        _ => {},
+    }
+    // Two elements
+    match v {
+        [first, second, ..] => println!("{first} {second}"),
+        _ => {},
+    }
+    // The element isn't bound by reference
+    match v {
+        [&first, rest @ ..] => println!("{first} {rest:?}"),
+        [] => {},
+    }
+}
+
+fn main() {}
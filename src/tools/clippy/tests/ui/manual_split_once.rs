@@ -144,3 +144,22 @@ fn _msrv_1_52() {
     let a = iter.next().unwrap();
     let b = iter.next().unwrap();
 }
+
+fn indexed() {
+    let parts: Vec<&str> = "a.b.c".splitn(3, '.').collect();
+    let a = parts[0];
+    let b = parts[1];
+    let c = parts[2];
+
+    let parts: Vec<&str> = "a.b.c".rsplitn(3, '.').collect();
+    let c = parts[0];
+    let b = parts[1];
+    let a = parts[2];
+
+    // Don't lint, `parts` is used afterwards
+    let parts: Vec<&str> = "a.b.c".splitn(3, '.').collect();
+    let a = parts[0];
+    let b = parts[1];
+    let c = parts[2];
+    let _ = parts.len();
+}
@@ -0,0 +1,66 @@
+//@run-rustfix
+#![warn(clippy::manual_sum)]
+#![allow(unused)]
+
+fn lint(xs: &[i32], numbers: Vec<u64>, names: &[String], weights: &[f64]) {
+    let mut total = 0;
+    for x in xs {
+        total += x;
+    }
+    println!("{total}");
+
+    let mut product: u64 = 1;
+    for &n in &numbers {
+        product *= n;
+    }
+
+    let mut len = 0;
+    for name in names {
+        len += name.len();
+    }
+
+    let mut sum = 0.0;
+    for w in weights {
+        sum += w;
+    }
+}
+
+fn no_lint(xs: &[i32]) {
+    // not the identity
+    let mut total = 10;
+    for x in xs {
+        total += x;
+    }
+
+    // changed after the loop
+    let mut total = 0;
+    for x in xs {
+        total += x;
+    }
+    total += 1;
+
+    // the accumulator is used to compute the value
+    let mut total = 0;
+    for x in xs {
+        total += x * total;
+    }
+
+    // counts the items
+    let mut count = 0;
+    for _ in xs {
+        count += 1;
+    }
+
+    let mut total = 0;
+    for x in xs {
+        total -= x;
+    }
+
+    let mut total = 0;
+    for x in xs {
+        println!("{x}");
+        total += x;
+    }
+}
+
+fn main() {}
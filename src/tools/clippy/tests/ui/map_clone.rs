@@ -61,4 +61,19 @@ fn main() {
 
         let _ = Some(RefCell::new(String::new()).borrow()).map(|s| s.clone());
     }
+
+    // Paths to `Clone::clone` and `ToOwned::to_owned`
+    {
+        let v = vec![1_u32, 2];
+        let _: Vec<u32> = v.iter().map(Clone::clone).collect();
+        let _: Vec<u32> = v.iter().map(u32::clone).collect();
+        let s = vec![String::new()];
+        let _: Vec<String> = s.iter().map(String::clone).collect();
+        let _: Vec<String> = s.iter().map(ToOwned::to_owned).collect();
+        let _: Option<u32> = Some(&1).map(ToOwned::to_owned);
+
+        // Don't lint, `str::to_owned` creates a `String`
+        let _: Vec<String> = vec!["a"].into_iter().map(ToOwned::to_owned).collect();
+        let _: Vec<String> = vec!["a"].into_iter().map(str::to_owned).collect();
+    }
 }
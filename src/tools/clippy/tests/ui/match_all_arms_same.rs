@@ -0,0 +1,48 @@
+//@run-rustfix
+#![warn(clippy::match_all_arms_same)]
+#![allow(dead_code)]
+
+enum Foo {
+    A,
+    B,
+    C,
+}
+
+fn get_foo() -> Foo {
+    Foo::A
+}
+
+fn cond() -> bool {
+    true
+}
+
+fn main() {
+    let foo = Foo::A;
+    let _ = match foo {
+        Foo::A => 1,
+        Foo::B | Foo::C => 1,
+    };
+
+    let _ = match get_foo() {
+        Foo::A => "a",
+        _ => "a",
+    };
+
+    // Don't lint, the bodies are different.
+    let _ = match foo {
+        Foo::A => 1,
+        _ => 2,
+    };
+
+    // Don't lint, an arm binds the scrutinee.
+    let _ = match Some(1) {
+        Some(_x) => 0,
+        None => 0,
+    };
+
+    // Don't lint, an arm has a guard.
+    let _ = match foo {
+        Foo::A if cond() => 0,
+        _ => 0,
+    };
+}
@@ -0,0 +1,46 @@
+//@run-rustfix
+#![warn(clippy::match_as_deref)]
+#![allow(unused, clippy::manual_map)]
+
+fn main() {
+    let mut opt: Option<String> = None;
+
+    let _: Option<&str> = match &opt {
+        Some(s) => Some(&**s),
+        None => None,
+    };
+    let _: Option<&mut str> = match &mut opt {
+        None => None,
+        Some(s) => Some(&mut **s),
+    };
+    let _: Option<&String> = match &opt {
+        Some(s) => Some(s),
+        None => None,
+    };
+
+    let boxed: Option<Box<String>> = None;
+    let _: Option<&str> = match &boxed {
+        Some(s) => Some(&***s),
+        None => None,
+    };
+
+    // Linted by `match_as_ref`
+    #[allow(clippy::match_as_ref)]
+    let _: Option<&String> = match opt {
+        Some(ref s) => Some(s),
+        None => None,
+    };
+
+    // The value is coerced
+    let _: Option<&dyn std::fmt::Debug> = match &opt {
+        Some(s) => Some(s),
+        None => None,
+    };
+
+    // Not a reference to the value of the `Option`
+    let refs: Option<&String> = None;
+    let _: Option<&str> = match refs {
+        Some(s) => Some(&**s),
+        None => None,
+    };
+}
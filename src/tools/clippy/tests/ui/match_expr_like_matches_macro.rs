@@ -1,5 +1,6 @@
 //@run-rustfix
 
+#![feature(if_let_guard)]
 #![warn(clippy::match_like_matches_macro)]
 #![allow(
     unreachable_patterns,
@@ -37,6 +38,13 @@ fn main() {
 
     // Lint
     let _zzz = if let Some(5) = x { true } else { false };
+    let _not_zzz = if let Some(5) = x { false } else { true };
+
+    // No lint, `matches!` doesn't support `if let` guards
+    let _zzzz = match x {
+        Some(r) if let 0 = r => false,
+        _ => true,
+    };
 
     // No lint
     let _a = match x {
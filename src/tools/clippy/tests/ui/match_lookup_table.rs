@@ -0,0 +1,80 @@
+#![warn(clippy::match_lookup_table)]
+#![allow(dead_code)]
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+    Yellow,
+    Black,
+}
+
+fn weight(color: Color) -> u32 {
+    match color {
+        Color::Red => 3,
+        Color::Green => 5,
+        Color::Blue => 1,
+        Color::Yellow | Color::Black => 8,
+    }
+}
+
+fn name(color: &Color) -> &'static str {
+    match color {
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Blue => "blue",
+        Color::Yellow => "yellow",
+        Color::Black => "black",
+    }
+}
+
+// Don't lint, there are too few arms.
+fn is_red(color: Color) -> u32 {
+    match color {
+        Color::Red => 1,
+        Color::Green | Color::Blue | Color::Yellow | Color::Black => 0,
+    }
+}
+
+// Don't lint, one of the arms isn't a literal.
+fn brightness(color: Color, black: u32) -> u32 {
+    match color {
+        Color::Red => 3,
+        Color::Green => 5,
+        Color::Blue => 1,
+        Color::Yellow => 8,
+        Color::Black => black,
+    }
+}
+
+// Don't lint, the arms don't only match unit variants.
+fn weight_or_default(color: Option<Color>) -> u32 {
+    match color {
+        Some(Color::Red) => 3,
+        Some(Color::Green) => 5,
+        Some(Color::Blue) => 1,
+        Some(Color::Yellow | Color::Black) => 8,
+        None => 0,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Level {
+    Low = 1,
+    Mid,
+    High,
+    Max,
+}
+
+// Don't lint, the discriminants don't start at 0.
+fn level_weight(level: Level) -> u32 {
+    match level {
+        Level::Low => 3,
+        Level::Mid => 5,
+        Level::High => 1,
+        Level::Max => 8,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+//@run-rustfix
+#![warn(clippy::match_on_enum_cast)]
+#![allow(dead_code)]
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue = 5,
+}
+
+fn main() {
+    let color = Color::Red;
+
+    let _ = match color as u8 {
+        0 => "red",
+        1 => "green",
+        5 => "blue",
+        _ => unreachable!(),
+    };
+    let _ = match color as i32 {
+        0 | 5 => 1,
+        _ => 0,
+    };
+
+    // Not the discriminant of any variant
+    let _ = match color as u8 {
+        0 => 1,
+        2 => 2,
+        _ => 0,
+    };
+}
@@ -124,6 +124,24 @@ fn overlapping() {
         _ => (),
     }
 
+    match 'x' {
+        'a'..='m' => println!("a..=m"),
+        'g'..='z' => println!("g..=z"),
+        _ => (),
+    }
+
+    match 'x' {
+        'a'..'m' => println!("a..m"),
+        'm'.. => println!("m.."),
+        _ => (),
+    }
+
+    match 'x' {
+        ..='c' => println!("..=c"),
+        'b'.. => println!("b.."),
+        _ => (),
+    }
+
     if let None = Some(42) {
         // nothing
     } else if let None = Some(42) {
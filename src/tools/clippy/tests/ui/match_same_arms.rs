@@ -1,3 +1,4 @@
+//@run-rustfix
 #![warn(clippy::match_same_arms)]
 
 pub enum Abc {
@@ -26,15 +27,6 @@ fn match_same_arms() {
         52 => 2,
         _ => 0,
     };
-
-    let _ = match 42 {
-        1 => 2,
-        2 => 2, //~ ERROR: this match arm has an identical body to another arm
-        //~^ ERROR: this match arm has an identical body to another arm
-        3 => 2, //~ ERROR: this match arm has an identical body to another arm
-        4 => 3,
-        _ => 0,
-    };
 }
 
 mod issue4244 {
@@ -135,4 +127,27 @@ fn main() {
         1 => cfg!(also_not_enabled),
         _ => false,
     };
+
+    // Arms which allow the lint aren't paired with other arms
+    let _ = match 0 {
+        #[allow(clippy::match_same_arms)]
+        0 => 1,
+        1 => 1,
+        _ => 2,
+    };
+
+    // Operands of commutative operators can be in either order
+    let (a, b) = (1u32, 2u32);
+    let _ = match 0 {
+        0 => a + b,
+        1 => b + a,
+        _ => a * b,
+    };
+
+    // Closures which only differ by the names of their parameters
+    let _ = match 0 {
+        0 => Some(1).map(|a| a + 1),
+        1 => Some(1).map(|b| b + 1),
+        _ => None,
+    };
 }
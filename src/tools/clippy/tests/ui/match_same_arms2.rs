@@ -1,7 +1,10 @@
+//@run-rustfix
 #![warn(clippy::match_same_arms)]
 #![allow(
     clippy::disallowed_names,
     clippy::diverging_sub_expression,
+    clippy::match_single_binding,
+    clippy::single_match,
     clippy::uninlined_format_args
 )]
 
@@ -247,3 +250,69 @@ struct Bar {
         _ => false,
     };
 }
+
+fn match_same_arms_guards() {
+    let x: Result<i32, i32> = Ok(3);
+    match x {
+        Ok(x) if x > 0 => bar(x),
+        Err(x) if x > 0 => bar(x), //~ ERROR: this match arm has an identical body to another arm
+        _ => (),
+    }
+
+    // Don't lint. The guards are different.
+    match x {
+        Ok(x) if x > 0 => bar(x),
+        Err(x) if x < 0 => bar(x),
+        _ => (),
+    }
+}
+
+fn match_same_arms_consts() {
+    const FOO: u32 = 2;
+
+    // Suggest moving `1` down. `FOO` doesn't overlap with either arm.
+    let _ = match 0u32 {
+        1 => 1,
+        FOO => 2,
+        3 => 1, //~ ERROR: this match arm has an identical body to another arm
+        _ => 0,
+    };
+}
+
+fn match_same_arms_byte_strings(x: &[u8]) {
+    // Suggest moving `b"ab"` down. `[b'c', ..]` doesn't overlap with either arm.
+    let _ = match x {
+        b"ab" => 1,
+        [b'c', ..] => 2,
+        b"de" => 1, //~ ERROR: this match arm has an identical body to another arm
+        _ => 0,
+    };
+}
+
+#[allow(illegal_floating_point_literal_pattern)]
+fn match_same_arms_floats(x: f64) {
+    // Suggest moving `1.0` down. `2.0..=3.0` doesn't overlap with either arm.
+    let _ = match x {
+        1.0 => 1,
+        2.0..=3.0 => 2,
+        4.0 => 1, //~ ERROR: this match arm has an identical body to another arm
+        _ => 0,
+    };
+
+    // Don't lint. `-1.0..=2.0` overlaps with both arms.
+    let _ = match x {
+        -0.5 => 1,
+        -1.0..=2.0 => 2,
+        1.5..=3.0 => 1,
+        _ => 0,
+    };
+}
+
+#[allow(clippy::ptr_arg)]
+fn match_same_arms_modulo_adjustments(x: u32, s: &String) {
+    let _ = match x {
+        0 => (*s).len(),
+        1 => s.len(), //~ ERROR: this match arm has an identical body to another arm
+        _ => 0,
+    };
+}
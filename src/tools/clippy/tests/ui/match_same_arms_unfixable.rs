@@ -0,0 +1,13 @@
+#![warn(clippy::match_same_arms)]
+
+fn main() {
+    // Only the first pair of arms is merged, as the other suggestions would edit the same arms.
+    let _ = match 42 {
+        1 => 2,
+        2 => 2, //~ ERROR: this match arm has an identical body to another arm
+        //~^ ERROR: this match arm has an identical body to another arm
+        3 => 2, //~ ERROR: this match arm has an identical body to another arm
+        4 => 3,
+        _ => 0,
+    };
+}
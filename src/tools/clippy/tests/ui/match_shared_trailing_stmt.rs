@@ -0,0 +1,68 @@
+//@run-rustfix
+#![warn(clippy::match_shared_trailing_stmt)]
+#![allow(dead_code)]
+
+enum Event {
+    Click,
+    Key(char),
+    Scroll(i32),
+}
+
+fn click() {}
+fn key(_: char) {}
+fn log_event() {}
+
+fn tail(event: Event) {
+    match event {
+        Event::Click => {
+            click();
+            log_event()
+        },
+        _ => {
+            key('a');
+            log_event();
+        },
+    }
+}
+
+fn main() {
+    let event = Event::Click;
+
+    match event {
+        Event::Click => {
+            click();
+            log_event();
+        },
+        Event::Key(c) => {
+            key(c);
+            log_event();
+        },
+        Event::Scroll(_) => {
+            log_event();
+        },
+    }
+
+    // Different statements
+    match event {
+        Event::Click => {
+            click();
+            log_event();
+        },
+        _ => {
+            log_event();
+            click();
+        },
+    }
+
+    // The statement uses a binding of the arm
+    match Some('a') {
+        Some(c) => {
+            click();
+            key(c);
+        },
+        None => {
+            let c = 'b';
+            key(c);
+        },
+    }
+}
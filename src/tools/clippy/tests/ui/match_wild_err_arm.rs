@@ -76,3 +76,23 @@ fn match_wild_err_arm() {
 }
 
 fn main() {}
+
+fn match_all_errors_by_variant() {
+    enum Error {
+        A,
+        B,
+    }
+
+    let x: Result<i32, Error> = Ok(3);
+    match x {
+        Ok(_) => println!("ok"),
+        Err(Error::A | Error::B) => panic!(),
+    }
+
+    // Allowed when not all errors are matched.
+    match x {
+        Ok(_) => println!("ok"),
+        Err(Error::A) => panic!(),
+        Err(Error::B) => println!("err"),
+    }
+}
@@ -154,3 +154,20 @@ fn test() {
         };
     }
 }
+
+mod covered_by_several_arms {
+    enum Foo {
+        A(bool),
+        B,
+        C,
+    }
+
+    fn test(x: Foo) {
+        match x {
+            Foo::A(true) => (),
+            Foo::A(false) => (),
+            Foo::B => (),
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+#![warn(clippy::naive_string_reversal)]
+
+fn main() {
+    let s = "noël";
+    let _: String = s.chars().rev().collect();
+    let _ = String::from("abc").chars().rev().collect::<String>();
+
+    // not linted, not collected into a `String`
+    let _ = s.chars().rev().eq(s.chars());
+    let _: Vec<char> = s.chars().rev().collect();
+    let _ = s.chars().rev().next();
+}
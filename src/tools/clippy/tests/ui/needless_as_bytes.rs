@@ -0,0 +1,35 @@
+//@run-rustfix
+#![warn(clippy::needless_as_bytes)]
+#![allow(unused, clippy::iter_count_cmp)]
+
+struct Bytes;
+
+impl Bytes {
+    fn as_bytes(&self) -> &[u8] {
+        &[]
+    }
+}
+
+fn main() {
+    let s = "foo";
+    let string = String::from("foo");
+    let _ = s.as_bytes().len();
+    let _ = s.as_bytes().is_empty();
+    let _ = string.as_bytes().len();
+    let _ = string.as_bytes().is_empty();
+    let _ = "bar".as_bytes().len();
+
+    let _ = s.chars().count() == 0;
+    let _ = s.chars().count() != 0;
+    let _ = 0 < string.chars().count();
+    let _ = s.chars().count() >= 1;
+
+    // not linted, the byte slice is used on its own
+    let bytes = s.as_bytes();
+    let _ = bytes.len();
+    // not linted, not a string
+    let _ = Bytes.as_bytes().len();
+    let _ = [1, 2].iter().count() == 0;
+    // not linted, counts more than one `char`
+    let _ = s.chars().count() > 1;
+}
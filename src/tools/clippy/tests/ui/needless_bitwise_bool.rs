@@ -10,6 +10,11 @@ const fn const_returns_bool() -> bool {
     false
 }
 
+fn print_bool() -> bool {
+    println!("false");
+    false
+}
+
 fn main() {
     let (x, y) = (false, true);
     if x & y {
@@ -25,16 +30,23 @@ fn main() {
         println!("true")
     }
 
-    // BELOW: lints we hope to catch as `Expr::can_have_side_effects` improves.
+    // Calls to functions with no side effects.
     if y & !const_returns_bool() {
-        println!("true") // This is a const function, in an UnOp
+        println!("true")
     }
-
     if y & "abcD".is_empty() {
-        println!("true") // This is a const method call
+        println!("true")
     }
-
     if y & (0 < 1) {
-        println!("true") // This is a BinOp with no side effects
+        println!("true")
+    }
+
+    // The right operand has side effects.
+    if y & print_bool() {
+        println!("true")
+    }
+    let mut opt = Some(1);
+    if y & opt.take().is_some() {
+        println!("true")
     }
 }
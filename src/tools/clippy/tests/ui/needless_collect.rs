@@ -34,8 +34,12 @@ fn main() {
     sample.iter().collect::<BinaryHeap<_>>().len();
     sample.iter().collect::<BinaryHeap<_>>().is_empty();
 
-    // Don't lint string from str
+    // `String`s are collected from `char`s or strings
     let _ = ["", ""].into_iter().collect::<String>().is_empty();
+    let _ = "abc".chars().collect::<String>().is_empty();
+    let _ = "abc".chars().collect::<String>().len();
+    let _ = ["a", "b"].into_iter().collect::<String>().len();
+    let _ = [String::new()].into_iter().collect::<String>().len();
 
     let _ = sample.iter().collect::<HashSet<_>>().is_empty();
     let _ = sample.iter().collect::<HashSet<_>>().contains(&&0);
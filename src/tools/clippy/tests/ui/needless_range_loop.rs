@@ -38,7 +38,7 @@ fn main() {
         println!("{} {}", vec[i], i);
     }
     for i in 0..vec.len() {
-        // not an error, indexing more than one variable
+        // indexing two variables in parallel
         println!("{} {}", vec[i], vec2[i]);
     }
 
@@ -156,3 +156,30 @@ pub fn test<H: Handle>() -> H {
         unimplemented!()
     }
 }
+
+fn parallel_indexing(a: &[i32], b: &mut [i32], mut c: Vec<i32>, d: [i32; 3]) {
+    for i in 0..a.len() {
+        println!("{} {}", a[i], b[i]);
+    }
+
+    for i in 0..c.len() {
+        c[i] += a[i];
+    }
+
+    for i in 0..d.len() {
+        b[i] += d[i];
+    }
+
+    // no error
+    for i in 0..a.len() {
+        println!("{} {} {}", a[i], b[i], i);
+    }
+
+    for i in 0..a.len() {
+        println!("{} {}", a[i], b[i + 1]);
+    }
+
+    for i in 1..a.len() {
+        println!("{} {}", a[i], b[i]);
+    }
+}
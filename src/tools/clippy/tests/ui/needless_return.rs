@@ -328,4 +328,15 @@ fn test_match_as_stmt() {
     };
 }
 
+fn test_tuple_match_as_stmt(x: u32) {
+    match x {
+        1 => (1, 2),
+        _ => return,
+    };
+}
+
 fn main() {}
+
+fn comment_in_return() -> i32 {
+    return /* the answer */ 42;
+}
@@ -0,0 +1,66 @@
+//@run-rustfix
+#![warn(clippy::nested_match)]
+#![allow(dead_code)]
+
+#[derive(Clone, Copy)]
+enum A {
+    X,
+    Y,
+}
+
+#[derive(Clone, Copy)]
+enum B {
+    P,
+    Q(u32),
+}
+
+fn main() {
+    let (a, b) = (A::X, B::P);
+
+    let _ = match a {
+        A::X => match b {
+            B::P => 1,
+            B::Q(n) => n,
+        },
+        A::Y => 3,
+    };
+
+    let _ = match a {
+        A::X => 0,
+        A::Y => {
+            match b {
+                B::P => 1,
+                B::Q(_) => 2,
+            }
+        },
+    };
+
+    // Don't lint. The inner scrutinee is bound by the outer pattern.
+    let c = Some(b);
+    let _ = match c {
+        Some(b) => match b {
+            B::P => 1,
+            B::Q(n) => n,
+        },
+        None => 0,
+    };
+
+    // Don't lint. The inner `match` has a guard.
+    let _ = match a {
+        A::X => match b {
+            B::Q(n) if n > 1 => n,
+            _ => 0,
+        },
+        A::Y => 3,
+    };
+
+    // Don't lint. Both patterns bind `_n`.
+    let (x, y) = (Some(1), Some(2));
+    let _ = match x {
+        Some(_n) => match y {
+            Some(_n) => 1,
+            None => 0,
+        },
+        None => 0,
+    };
+}
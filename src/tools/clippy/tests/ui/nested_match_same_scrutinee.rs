@@ -0,0 +1,44 @@
+#![warn(clippy::nested_match_same_scrutinee)]
+#![allow(dead_code)]
+
+fn main() {
+    let x = Some(1);
+
+    let _ = match x {
+        Some(_) => match x {
+            Some(y) => y,
+            None => unreachable!(),
+        },
+        None => 0,
+    };
+
+    let v = (1, 2);
+    let _ = match v.0 {
+        0 => 0,
+        _ => {
+            match v.0 {
+                1 => 1,
+                n => n * 2,
+            }
+        },
+    };
+
+    // The scrutinee may evaluate to a different value
+    let mut it = [1, 2].into_iter();
+    let _ = match it.next() {
+        Some(_) => match it.next() {
+            Some(y) => y,
+            None => unreachable!(),
+        },
+        None => 0,
+    };
+
+    // Different scrutinee
+    let _ = match x {
+        Some(_) => match v.0 {
+            0 => 0,
+            _ => 1,
+        },
+        None => 0,
+    };
+}
@@ -125,3 +125,7 @@ fn not(self) -> Self::Output {
     // Should not lint
     let _: bool = !!Foo(true);
 }
+
+fn negated_partial_eq_comparison(a: bool, x: f32) {
+    let _ = !(!a || x == 1.0);
+}
@@ -297,3 +297,33 @@ const fn issue9175(option: Option<()>) -> Option<()> {
     //stuff
     Some(())
 }
+
+fn let_else_return_none(x: Option<u32>, y: &Option<u32>) -> Option<u32> {
+    let Some(a) = x else {
+        return None;
+    };
+    let Some(b) = x else { return None };
+    // should not lint, the inner pattern is refutable
+    let Some(1) = x else { return None };
+    // should not lint, `?` can't be applied to a reference
+    let Some(c) = y else { return None };
+    // should not lint, the `else` block does more than returning `None`
+    let Some(d) = x else {
+        println!("no value");
+        return None;
+    };
+    Some(a + b + c + d)
+}
+
+fn closure_in_try_block(a: Option<u32>) -> Option<u32> {
+    let b: Option<u32> = try {
+        let f = || {
+            if a.is_none() {
+                return None;
+            }
+            Some(32)
+        };
+        f()?
+    };
+    b
+}
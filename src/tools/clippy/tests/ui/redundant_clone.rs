@@ -245,3 +245,18 @@ fn foo(_x: &Alpha, _y: &mut Alpha) {}
     let _z = x.clone(); // pr 7346 can't lint on `x`
     drop(y);
 }
+
+fn mutated_after_clone_used() {
+    let mut s = String::new();
+    let t = &s.clone();
+    println!("{t}");
+    s.push('a');
+    println!("{s}");
+}
+
+fn mutated_while_clone_used() {
+    let mut s = String::new();
+    let t = &s.clone(); // ok, `s` is assigned to while `t` is still used
+    s = String::from("a");
+    println!("{s}{t}");
+}
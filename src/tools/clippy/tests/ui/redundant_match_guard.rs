@@ -0,0 +1,31 @@
+//@run-rustfix
+#![warn(clippy::redundant_match_guard)]
+
+fn main() {
+    let x: u32 = 5;
+    match x {
+        n @ 0..=9 if (0..10).contains(&n) => println!("{n}"),
+        n @ 10..=99 if n >= 10 => println!("{n}"),
+        n @ (100 | 200) if 300 > n => println!("{n}"),
+        _ => (),
+    }
+
+    let opt = Some(1);
+    match opt {
+        x @ Some(_) if x.is_some() => println!("{x:?}"),
+        _ => (),
+    }
+
+    // Don't lint, the guard isn't implied by the pattern.
+    match x {
+        n @ 0..=9 if n < 5 => println!("{n}"),
+        n @ 10.. if (10..100).contains(&n) => println!("{n}"),
+        _ => (),
+    }
+
+    // Don't lint, only unsigned integers are supported.
+    match 5i32 {
+        n @ 0..=9 if n >= 0 => println!("{n}"),
+        _ => (),
+    }
+}
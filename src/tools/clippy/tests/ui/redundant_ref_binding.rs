@@ -0,0 +1,45 @@
+//@run-rustfix
+#![warn(clippy::redundant_ref_binding)]
+#![allow(dead_code, clippy::match_ref_pats, clippy::single_match)]
+
+struct S {
+    a: String,
+    b: u32,
+}
+
+fn main() {
+    let mut x = Some(String::new());
+    match &x {
+        Some(ref s) => println!("{s}"),
+        None => {},
+    }
+    match &mut x {
+        Some(ref mut s) => s.push('a'),
+        None => {},
+    }
+    if let Some(ref s) = &x {
+        println!("{s}");
+    }
+
+    let s = S { a: String::new(), b: 0 };
+    match &s {
+        S { ref a, b: ref b @ 0 } => println!("{a} {b}"),
+        _ => {},
+    }
+
+    // Bound by value without `ref`
+    match x {
+        Some(ref s) => println!("{s}"),
+        None => {},
+    }
+    // `ref` changes the mutability of the reference
+    match &mut x {
+        Some(ref s) => println!("{s}"),
+        None => {},
+    }
+    // The reference pattern resets the binding mode
+    match &x {
+        &Some(ref s) => println!("{s}"),
+        &None => {},
+    }
+}
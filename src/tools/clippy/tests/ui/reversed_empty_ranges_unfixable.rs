@@ -7,6 +7,7 @@ fn main() {
     let arr = [1, 2, 3, 4, 5];
     let _ = &arr[3usize..=1usize];
     let _ = &arr[SOME_NUM..1];
+    let _ = &arr[usize::from(true)..0];
 
     for _ in ANSWER..ANSWER {}
 
@@ -1,4 +1,5 @@
 #![warn(clippy::same_item_push)]
+#![allow(clippy::extend_without_reserve)]
 
 const VALUE: u8 = 7;
 
@@ -45,12 +46,27 @@ fn main() {
         vec.push(item);
     }
 
-    // ** non-linted cases **
     let mut spaces = Vec::with_capacity(10);
     for _ in 0..10 {
         spaces.push(vec![b' ']);
     }
 
+    // the `Vec` may not be empty
+    let mut vec = vec![1u8];
+    let item = 2;
+    for _ in 0..10 {
+        vec.push(item);
+    }
+
+    // the item isn't `Clone`
+    let n: u32 = 10;
+    let mut locks = Vec::new();
+    for _ in 0..n {
+        locks.push(std::sync::Mutex::new(0));
+    }
+
+    // ** non-linted cases **
+
     // Suggestion should not be given as pushed variable can mutate
     let mut vec: Vec<u8> = Vec::new();
     let mut item: u8 = 2;
@@ -88,6 +104,13 @@ fn main() {
         vec.push(item * 2);
     }
 
+    // Suggestion should not be given as the loop does something else
+    let mut vec: Vec<u8> = Vec::new();
+    for _ in 0..30 {
+        vec.push(2);
+        println!("pushed");
+    }
+
     // Suggestion should not be given as Vec is not involved
     for _ in 0..5 {
         println!("Same Item Push");
@@ -130,7 +153,7 @@ impl T for S {}
         vec.push(Box::new(S {}));
     }
 
-    // Fix #5985
+    // Fix #5985, linted since the shadowing binding doesn't change in the loop either
     let mut vec = Vec::new();
     let item = 42;
     let item = fun();
@@ -0,0 +1,78 @@
+#![allow(unused)]
+#![warn(clippy::sequential_await_in_loop)]
+
+struct Client;
+
+impl Client {
+    async fn get(&self, url: &str) -> Result<String, ()> {
+        Ok(String::new())
+    }
+
+    async fn post(&mut self, url: &str) {}
+}
+
+async fn fetch(url: &str) -> String {
+    String::new()
+}
+
+async fn lint(client: &Client, urls: &[&str]) -> Result<(), ()> {
+    let mut pages = Vec::new();
+    for url in urls {
+        pages.push(fetch(url).await);
+    }
+
+    for url in urls {
+        let page = client.get(url).await?;
+        println!("{page}");
+    }
+
+    let mut total = 0;
+    for (i, url) in urls.iter().enumerate() {
+        total += fetch(&url[i..]).await.len();
+    }
+    Ok(())
+}
+
+async fn no_lint(client: &mut Client, urls: &[&str]) {
+    // depends on the previous iterations
+    let mut last = String::new();
+    for url in urls {
+        last = fetch(&format!("{url}{last}")).await;
+    }
+
+    // depends on a value computed in the loop
+    for url in urls {
+        let full = format!("https://{url}");
+        fetch(&full).await;
+    }
+
+    // needs exclusive access to the client
+    for url in urls {
+        client.post(url).await;
+    }
+
+    // more than one `.await`
+    for url in urls {
+        fetch(url).await;
+        fetch("done").await;
+    }
+
+    // stops early
+    for url in urls {
+        if fetch(url).await.is_empty() {
+            break;
+        }
+    }
+
+    // doesn't use the loop item
+    for _ in urls {
+        fetch("ping").await;
+    }
+
+    // awaited in a separate task
+    for url in urls {
+        let task = async move { fetch(url).await };
+    }
+}
+
+fn main() {}
@@ -1,3 +1,4 @@
+//@run-rustfix
 //@aux-build:option_helpers.rs
 
 #![warn(clippy::skip_while_next)]
@@ -27,3 +28,14 @@ fn skip_while_next() {
 fn main() {
     skip_while_next();
 }
+
+fn is_negative(x: &&i32) -> bool {
+    **x < 0
+}
+
+fn negated_predicates() {
+    let v = vec![3, 2, 1, 0, -1, -2, -3];
+    let _ = v.iter().skip_while(is_negative).next();
+    let _ = v.iter().skip_while(|x| **x % 2 == 1).next();
+    let _ = v.iter().skip_while(|x| x.is_positive()).next();
+}
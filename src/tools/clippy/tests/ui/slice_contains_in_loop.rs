@@ -0,0 +1,57 @@
+#![warn(clippy::slice_contains_in_loop)]
+
+fn main() {
+    let allowed = vec![1, 2, 3];
+    let requests = vec![2, 4];
+    for request in &requests {
+        if allowed.contains(request) {
+            println!("{request}");
+        }
+    }
+
+    let names = vec!["a", "b"];
+    let mut i = 0;
+    while i < 10 {
+        let _ = names.contains(&"c");
+        i += 1;
+    }
+}
+
+fn slice_param(haystack: &[String], needles: &[String]) {
+    for needle in needles {
+        let _ = haystack.contains(needle);
+    }
+}
+
+fn not_linted(mut v: Vec<u32>, other: Vec<u32>) {
+    // iterates over the searched collection
+    for x in &v {
+        let _ = v.contains(&(x + 1));
+    }
+    // the collection changes in the loop
+    for x in 0..10 {
+        if !v.contains(&x) {
+            v.push(x);
+        }
+    }
+    // declared in the loop
+    for x in 0..10 {
+        let w = vec![1, 2];
+        let _ = w.contains(&x);
+    }
+    // `f64` is neither `Hash` nor `Ord`
+    let floats: &[f64] = &[1.0, 2.0];
+    for _ in 0..10 {
+        let _ = floats.contains(&1.5);
+    }
+    // not in a loop
+    let _ = other.contains(&1);
+    // in a closure
+    let _ = other.iter().filter(|&x| v.contains(x)).count();
+    // the condition depends on the collection
+    let mut i = 0;
+    while i < other.len() {
+        let _ = other.contains(&5);
+        i += 1;
+    }
+}
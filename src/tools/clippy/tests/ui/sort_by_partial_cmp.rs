@@ -0,0 +1,51 @@
+//@run-rustfix
+#![warn(clippy::sort_by_partial_cmp)]
+#![allow(clippy::unnecessary_sort_by, clippy::useless_vec)]
+
+struct Point {
+    x: f64,
+    id: u32,
+}
+
+fn main() {
+    let mut floats = vec![1.0_f64, 0.5, 2.0];
+    floats.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    floats.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    floats.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    floats.sort_by(|a, b| a.partial_cmp(b).expect("no NaNs"));
+
+    let mut small = [1.0_f32, 0.5];
+    small.sort_by(|x, y| x.partial_cmp(y).unwrap());
+
+    let mut points = vec![Point { x: 1.0, id: 1 }, Point { x: 0.5, id: 2 }];
+    points.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+    points.sort_by(|a, b| a.id.partial_cmp(&b.id).unwrap());
+
+    let mut ints = vec![3, 1, 2];
+    ints.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ints.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    ints.sort_by(|a, b| b.partial_cmp(a).unwrap());
+}
+
+fn dont_lint() {
+    let mut floats = vec![1.0_f64, 0.5];
+    // Incomparable values are already handled
+    floats.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    // Not comparing the two elements
+    floats.sort_by(|a, _| a.partial_cmp(&0.0).unwrap());
+    // Neither floats nor `Ord`
+    let mut pairs = vec![(1.0_f64, 1)];
+    pairs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
+
+#[clippy::msrv = "1.61"]
+fn msrv_1_61() {
+    let mut floats = vec![1.0_f64, 0.5];
+    floats.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
+
+#[clippy::msrv = "1.62"]
+fn msrv_1_62() {
+    let mut floats = vec![1.0_f64, 0.5];
+    floats.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
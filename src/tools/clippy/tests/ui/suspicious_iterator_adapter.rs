@@ -0,0 +1,21 @@
+//@run-rustfix
+#![warn(clippy::suspicious_iterator_adapter)]
+#![allow(clippy::useless_vec)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = v.iter().take(0);
+    let _ = v.iter().skip(usize::MAX);
+    let _: i32 = (0..10).step_by(1).sum();
+    let _ = [1, 2, 3].iter().cycle().take(3);
+    let _: i32 = (0..3).cycle().take(3).sum();
+
+    // don't lint
+    let n = 0;
+    let _ = v.iter().take(n);
+    let _ = v.iter().skip(10);
+    let _ = (0..10).step_by(2);
+    let _ = [1, 2, 3].iter().cycle().take(4);
+    let _ = (0..=2).cycle().take(6);
+    let _ = v.iter().cycle().take(3);
+}
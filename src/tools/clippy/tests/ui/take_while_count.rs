@@ -0,0 +1,37 @@
+//@run-rustfix
+#![warn(clippy::take_while_count)]
+#![allow(clippy::useless_vec)]
+
+struct Data {
+    values: Vec<u32>,
+}
+
+fn is_small(x: &&u32) -> bool {
+    **x < 2
+}
+
+fn make() -> Vec<i32> {
+    vec![1, 2, 0, 3]
+}
+
+fn main() {
+    let v = vec![1, 2, 0, 3];
+    let _ = v.iter().take_while(|&x| *x != 0).count();
+    let _ = v.iter().take_while(|&&x| x < 3).count();
+
+    let data = Data { values: vec![1, 2] };
+    let _ = data.values.iter().take_while(|&x| *x < 5).count();
+
+    let s: &[u32] = &[1, 2];
+    let _ = s.iter().take_while(is_small).count();
+}
+
+fn dont_lint() {
+    let v = vec![1, 2, 0, 3];
+    // The closure needs the reference to the item
+    let _ = v.iter().take_while(|x| **x != 0).count();
+    // Not a slice iterator
+    let _ = (0..10).take_while(|x| *x < 5).count();
+    // The slice would be evaluated twice
+    let _ = make().iter().take_while(|&x| *x != 0).count();
+}
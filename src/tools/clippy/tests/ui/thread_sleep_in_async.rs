@@ -0,0 +1,36 @@
+#![allow(unused)]
+#![warn(clippy::thread_sleep_in_async)]
+
+use std::thread;
+use std::time::Duration;
+
+fn pause() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn wait() {
+    pause();
+}
+
+async fn bad() {
+    thread::sleep(Duration::from_millis(10));
+    std::thread::sleep(Duration::from_secs(1));
+    wait();
+}
+
+fn bad_block() -> impl std::future::Future<Output = ()> {
+    async { pause() }
+}
+
+async fn good() {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    // the closure isn't run by the executor
+    let f = || thread::sleep(Duration::from_millis(10));
+}
+
+fn not_async() {
+    thread::sleep(Duration::from_millis(10));
+    pause();
+}
+
+fn main() {}
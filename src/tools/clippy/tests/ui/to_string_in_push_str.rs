@@ -0,0 +1,27 @@
+//@run-rustfix
+#![allow(unused)]
+#![warn(clippy::to_string_in_push_str)]
+
+fn main() {
+    let mut s = String::new();
+    for (i, c) in ['a', 'b'].into_iter().enumerate() {
+        s.push_str(&c.to_string());
+        s += &i.to_string();
+    }
+    let c = &'c';
+    s += &c.to_string();
+    s.push_str(&u8::MAX.to_string());
+}
+
+mod imported {
+    use std::fmt::Write;
+
+    fn f(s: &mut String, x: u32) {
+        *s += &x.to_string();
+    }
+}
+
+fn should_not_lint(s: &mut String) {
+    s.push('a');
+    s.push_str(&1.to_string())
+}
@@ -204,4 +204,9 @@ fn foo() -> f32 {
     fn issue_9603() {
         let _: f32 = -0x400 as f32;
     }
+
+    fn same_type_cast_receiver() {
+        let x = 1i32;
+        let _ = (-x as i32).abs();
+    }
 }
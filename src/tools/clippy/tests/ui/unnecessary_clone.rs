@@ -95,3 +95,9 @@ fn func() -> Option<Rc<u8>> {
         Some(try_opt!(Some(rc)).clone())
     }
 }
+
+#[allow(clippy::clone_on_copy, suspicious_double_ref_op)]
+fn clone_on_ref_ptr_reference<'a>(rc: &&'a Rc<bool>) -> &'a Rc<bool> {
+    // Clones the reference, not the `Rc`
+    rc.clone()
+}
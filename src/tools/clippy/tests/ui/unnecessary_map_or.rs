@@ -0,0 +1,35 @@
+//@run-rustfix
+#![warn(clippy::unnecessary_map_or)]
+#![allow(clippy::map_unwrap_or)]
+
+fn main() {
+    let option = Some(5u32);
+    let result: Result<u32, ()> = Ok(5);
+
+    let _ = option.map_or(false, |x| x > 3);
+    let _ = result.map_or(false, |x| x > 3);
+    let _ = result.map_or(false, u32::is_power_of_two);
+    let _ = Some('a').map_or(false, char::is_alphanumeric);
+    let _ = !option.map_or(false, |x| x > 3);
+    let _ = result.map(|x| x > 3).unwrap_or(false);
+    let _ = option.map_or(true, |x| !matches!(x, 3 | 4));
+    let _ = result.map_or(true, |x| !x.is_power_of_two());
+    let _ = option.map_or(true, |x| !x.is_power_of_two()).then_some(1);
+    let _ = result.map(|x| !x.is_power_of_two()).unwrap_or(true);
+
+    // should not lint
+    let _ = option.map_or(true, |x| x > 3);
+    let _ = option.map_or(true, u32::is_power_of_two);
+    let _ = option.map_or(option.is_none(), |x| x > 3);
+    let _ = option.map(|x| x > 3).unwrap_or(false);
+}
+
+#[clippy::msrv = "1.69"]
+fn msrv_1_69() {
+    let _ = Some(5).map_or(false, |x| x > 3);
+}
+
+#[clippy::msrv = "1.70"]
+fn msrv_1_70() {
+    let _ = Some(5).map_or(false, |x| x > 3);
+}
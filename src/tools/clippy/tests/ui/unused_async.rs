@@ -1,5 +1,6 @@
 #![warn(clippy::unused_async)]
 #![feature(async_fn_in_trait)]
+#![feature(async_closure)]
 #![allow(incomplete_features)]
 
 use std::future::Future;
@@ -76,6 +77,69 @@ async fn unused() -> i32 {
 }
 async_trait_impl!();
 
+mod unreachable {
+    #![allow(dead_code, unreachable_code)]
+
+    use std::future::ready;
+
+    async fn constant_false() {
+        if false {
+            ready(()).await;
+        }
+    }
+
+    async fn after_return() -> u32 {
+        return 1;
+        ready(2).await
+    }
+
+    async fn cfg_enabled() {
+        if cfg!(all()) {
+            ready(()).await;
+        }
+    }
+
+    // the `await` is reached with another configuration
+    async fn cfg_disabled() {
+        if cfg!(any()) {
+            ready(()).await;
+        }
+    }
+}
+
+mod closures {
+    #![allow(unused)]
+
+    use std::future::ready;
+
+    fn closures() {
+        let unused = async || 1;
+        let used = async |x: u32| ready(x).await;
+    }
+}
+
+mod trait_methods {
+    #![allow(dead_code)]
+
+    use std::future::ready;
+
+    trait Used {
+        async fn get(&self) -> u32;
+    }
+
+    impl Used for u32 {
+        async fn get(&self) -> u32 {
+            *self
+        }
+    }
+
+    impl Used for u64 {
+        async fn get(&self) -> u32 {
+            ready(1).await
+        }
+    }
+}
+
 fn main() {
     foo();
     bar();
@@ -1,5 +1,6 @@
 //@run-rustfix
 
+#![feature(option_get_or_insert_default)]
 #![warn(clippy::unwrap_or_else_default)]
 #![allow(dead_code)]
 #![allow(clippy::unnecessary_wraps, clippy::unnecessary_literal_unwrap)]
@@ -75,3 +76,54 @@ fn make<T, V>(_: V) -> T {
 }
 
 fn main() {}
+
+fn entry_and_option_adapters() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let mut map = HashMap::<u64, Vec<u64>>::new();
+    map.entry(42).or_insert_with(Vec::new);
+    map.entry(42).or_insert(Vec::new());
+
+    let mut btree = BTreeMap::<u64, String>::new();
+    btree.entry(42).or_insert_with(Default::default);
+    btree.entry(42).or_insert(String::new());
+
+    let with_default_call = Some(1);
+    with_default_call.unwrap_or(Default::default());
+
+    let mut option = None::<Vec<u64>>;
+    option.get_or_insert_with(Default::default);
+    option.get_or_insert(Vec::new());
+
+    // should not be changed
+    map.entry(42).or_insert_with(|| vec![1]);
+    option.get_or_insert_with(|| vec![1]);
+}
+
+fn result_and_wrapped_defaults() {
+    #[derive(Default)]
+    struct HasNewAndDefault;
+
+    impl HasNewAndDefault {
+        fn new() -> Self {
+            HasNewAndDefault
+        }
+    }
+
+    let result: Result<String, ()> = Ok(String::new());
+    result.clone().unwrap_or(String::from(""));
+    result.unwrap_or_else(|_| "".to_string());
+
+    let with_new = None::<HasNewAndDefault>;
+    with_new.unwrap_or(HasNewAndDefault::new());
+
+    let option = Some(vec![1]);
+    let _ = option.clone().or(Some(Vec::new()));
+    let _ = option.clone().or_else(|| Some(Default::default()));
+
+    let result: Result<Vec<u64>, ()> = Ok(vec![1]);
+    let _: Result<_, ()> = result.or_else(|_| Ok(Vec::new()));
+
+    // should not be changed
+    let _ = option.or_else(|| Some(vec![1]));
+}
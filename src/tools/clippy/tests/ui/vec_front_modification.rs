@@ -0,0 +1,62 @@
+#![warn(clippy::vec_front_modification)]
+#![allow(unused)]
+
+fn lint(lines: Vec<&str>, mut queue: Vec<u32>, n: usize) {
+    let mut reversed = Vec::new();
+    for line in lines {
+        reversed.insert(0, line);
+    }
+
+    while !queue.is_empty() {
+        let first = queue.remove(0);
+        println!("{first}");
+    }
+
+    let mut v = Vec::new();
+    for i in 0..n {
+        v.insert(0, i);
+    }
+
+    // the outer loop makes it grow
+    let mut v = Vec::new();
+    for i in 0..n {
+        for j in 0..3 {
+            v.insert(0, i + j);
+        }
+    }
+}
+
+fn no_lint(lines: &[&str], n: usize) {
+    // not in a loop
+    let mut v = vec![1, 2];
+    v.insert(0, 0);
+    v.remove(0);
+
+    // a new `Vec` on every iteration
+    for line in lines {
+        let mut words: Vec<_> = line.split(' ').collect();
+        words.remove(0);
+    }
+
+    // not the first element
+    let mut v = Vec::new();
+    for i in 0..n {
+        v.insert(v.len() / 2, i);
+    }
+
+    // few iterations
+    let mut v = Vec::new();
+    for i in 0..4 {
+        v.insert(0, i);
+    }
+    for x in [1, 2, 3] {
+        v.insert(0, x);
+    }
+
+    let mut deque = std::collections::VecDeque::new();
+    for i in 0..n {
+        deque.insert(0, i);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,92 @@
+//@run-rustfix
+#![warn(clippy::while_range_loop)]
+#![allow(unused, clippy::needless_range_loop)]
+
+fn lint(v: &[u8], n: u32) {
+    let mut i = 0;
+    while i < v.len() {
+        println!("{}", v[i]);
+        i += 1;
+    }
+
+    let mut total = 0;
+    let mut x = 1;
+    while x <= n {
+        total += x;
+        x += 1
+    }
+
+    let mut i = 0;
+    while 10 > i {
+        println!("{i}");
+        i += 2;
+    }
+
+    let mut j = 0;
+    'outer: while j < n {
+        if j == 3 {
+            break 'outer;
+        }
+        j += 1;
+    }
+}
+
+fn no_lint(mut v: Vec<u8>, n: u32) {
+    // used after the loop
+    let mut i = 0;
+    while i < n {
+        println!("{i}");
+        i += 1;
+    }
+    println!("{i}");
+
+    // conditional increment
+    let mut i = 0;
+    while i < n {
+        if i % 2 == 0 {
+            i += 1;
+        }
+        println!("{i}");
+    }
+
+    // `continue` skips the increment
+    let mut i = 0;
+    while i < n {
+        if i == 3 {
+            continue;
+        }
+        println!("{i}");
+        i += 1;
+    }
+
+    // the counter is changed in the body
+    let mut i = 0;
+    while i < n {
+        i += 1;
+        println!("{i}");
+        i += 1;
+    }
+
+    // the bound changes
+    let mut i = 0;
+    while i < v.len() {
+        v.pop();
+        i += 1;
+    }
+
+    // zero step
+    let mut i = 0;
+    while i < n {
+        println!("{i}");
+        i += 0;
+    }
+
+    // not a counter
+    let mut s = 0.0;
+    while s < 1.0 {
+        println!("{s}");
+        s += 0.1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![warn(clippy::while_range_loop)]
+#![allow(unused, clippy::needless_range_loop)]
+
+fn main() {}
+
+// the step isn't known to be positive
+fn step(v: &[u8], step: usize) {
+    let mut i = 0;
+    while i < v.len() {
+        println!("{i}");
+        i += step;
+    }
+}
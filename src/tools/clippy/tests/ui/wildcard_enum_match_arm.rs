@@ -99,4 +99,10 @@ pub enum Enum {
             _ => (),
         }
     }
+
+    // A binding with a guard isn't a wildcard, and doesn't cover any variant.
+    match color {
+        c if c.is_monochrome() => {},
+        _ => {},
+    };
 }
@@ -31,4 +31,5 @@ fn main() {
     writeln!(v, "{}", "\r");
     writeln!(v, r#"{}{}"#, '#', '"'); // hard mode
     writeln!(v, r"{}", "\r"); // should not lint
+    writeln!(v, "{}", r#"\""#);
 }